@@ -0,0 +1,113 @@
+#ifndef LIFE_H_
+#define LIFE_H_
+
+/*
+ * C interface to the simulation engines for embedding them in C programs and
+ * other language runtimes. Functions that fail return NULL or -1, the reason
+ * is then returned by gol_last_error().
+ */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \brief A board and the engine advancing it.
+ */
+typedef struct gol_board gol_board;
+
+/**
+ * \brief Return the reason the last failed call on this thread failed.
+ * \details The string stays valid until the next call failing on this thread.
+ */
+const char *gol_last_error(void);
+
+/**
+ * \brief Create a board of \p rows x \p cols cells.
+ * \param [in] rows Number of rows, or of rows of the visible window of
+ *                  unbounded engines.
+ * \param [in] cols Number of columns, or of columns of the visible window of
+ *                  unbounded engines.
+ * \param [in] rule Rulestring, preset or multi-state automaton as accepted by
+ *                  --rule, or NULL for Conway's Life.
+ * \param [in] engine Engine as accepted by --engine, or NULL for the naive
+ *                    engine.
+ * \param [in] wrap Nonzero to wrap around the board edges.
+ * \returns The board to be freed with gol_board_destroy(), or NULL when the
+ *          size is zero or larger than 32768 on a side, or the rule or engine
+ *          is invalid.
+ */
+gol_board *gol_board_create(size_t rows, size_t cols, const char *rule,
+                            const char *engine, int wrap);
+
+/**
+ * \brief Free \p board, which may be NULL.
+ */
+void gol_board_destroy(gol_board *board);
+
+/**
+ * \brief Return the number of rows of \p board.
+ */
+size_t gol_board_rows(const gol_board *board);
+
+/**
+ * \brief Return the number of columns of \p board.
+ */
+size_t gol_board_cols(const gol_board *board);
+
+/**
+ * \brief Return the state of the cell at (\p row, \p col), 0 when dead.
+ * \returns The state or -1 when the cell lies beyond the board edges.
+ */
+int gol_board_get_cell(const gol_board *board, size_t row, size_t col);
+
+/**
+ * \brief Set the cell at (\p row, \p col) to \p state, 0 kills it.
+ * \returns 0, or -1 when the cell lies beyond the board edges or \p state is
+ *          negative or not below the number of states of the automaton.
+ */
+int gol_board_set_cell(gol_board *board, size_t row, size_t col, int state);
+
+/**
+ * \brief Copy the state of every cell to \p cells in row major order.
+ * \param [out] cells Buffer of at least rows x cols bytes.
+ * \param [in] size Size of \p cells in bytes.
+ * \returns 0, or -1 when \p cells is too small.
+ */
+int gol_board_copy_cells(const gol_board *board, uint8_t *cells, size_t size);
+
+/**
+ * \brief Add the embedded pattern or pattern file \p source with its top left
+ *        corner at (\p row, \p col).
+ * \details Cells beyond the board edges are left out, states the automaton
+ *          lacks are plain live cells.
+ * \returns 0, or -1 when \p source cannot be loaded.
+ */
+int gol_board_load_pattern(gol_board *board, const char *source, size_t row,
+                           size_t col);
+
+/**
+ * \brief Advance \p board by \p generations generations.
+ * \returns 0, or -1 when the engine failed.
+ */
+int gol_board_step(gol_board *board, uint64_t generations);
+
+/**
+ * \brief Return the number of generations computed since the creation of
+ *        \p board.
+ */
+uint64_t gol_board_generation(const gol_board *board);
+
+/**
+ * \brief Return the number of live cells of \p board.
+ */
+uint64_t gol_board_population(const gol_board *board);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,73 @@
+#ifndef AGES_H_
+#define AGES_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Tracks how many generations each cell of an Engine has been alive,
+ *        or dead since it last died.
+ * \details CellAges covers the MxN window exposed by an Engine. Call Update()
+ *          after each generation to age live cells and reset dead ones.
+ */
+class CellAges {
+ public:
+  /** Generations dead of cells that died long ago or were never alive. */
+  static constexpr std::uint8_t kLongDead = UINT8_MAX;
+
+  /**
+   * \brief Construct an MxN age matrix with all ages set to zero.
+   * \param [in] num_rows Number of rows.
+   * \param [in] num_cols Number of columns.
+   */
+  [[nodiscard]] CellAges(std::size_t num_rows, std::size_t num_cols);
+  CellAges() = delete;
+  ~CellAges() = default;
+
+  CellAges(const CellAges &) = default;
+  CellAges &operator=(const CellAges &) = default;
+  CellAges(CellAges &&) = default;
+  CellAges &operator=(CellAges &&) = default;
+
+  /**
+   * \brief Increment the age of each live cell and reset the age of each dead
+   *        cell of \p board.
+   */
+  void Update(const Engine &board) noexcept;
+
+  /**
+   * \brief Return the number of consecutive updates in which the cell at
+   *        (\p row, \p col) was alive.
+   */
+  [[nodiscard]] std::uint32_t Age(std::size_t row,
+                                  std::size_t col) const noexcept {
+    return ages_[(row * cols_) + col];
+  }
+
+  /**
+   * \brief Return the number of consecutive updates in which the cell at
+   *        (\p row, \p col) was dead since it died.
+   * \details Live cells return 0. The count saturates at kLongDead.
+   */
+  [[nodiscard]] std::uint8_t DeadFor(std::size_t row,
+                                     std::size_t col) const noexcept {
+    return dead_for_[(row * cols_) + col];
+  }
+
+ private:
+  std::size_t rows_ = 0;               /**< Number of rows. */
+  std::size_t cols_ = 0;               /**< Number of columns. */
+  std::vector<std::uint32_t> ages_;    /**< Row major cell ages. */
+  std::vector<std::uint8_t> dead_for_; /**< Row major generations dead. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
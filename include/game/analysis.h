@@ -0,0 +1,67 @@
+#ifndef ANALYSIS_H_
+#define ANALYSIS_H_
+
+#include <cstdint>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief How a pattern ends up evolving.
+ */
+enum class Fate {
+  kUndetermined, /**< None of the others within the generation limit. */
+  kDiesOut,      /**< No cell is left alive. */
+  kStable,       /**< Settles into a still life. */
+  kOscillator,   /**< Repeats in place with a period above 1. */
+  kSpaceship,    /**< Repeats moved by a displacement. */
+};
+
+/**
+ * \brief The outcome of running a pattern until its fate is known.
+ */
+struct Analysis {
+  Fate fate = Fate::kUndetermined; /**< How the pattern evolves. */
+  std::uint64_t generation = 0;    /**< First generation of the fate. */
+  std::uint64_t period = 0;        /**< Generations per cycle. */
+  std::int64_t dx = 0;             /**< Columns moved right per period. */
+  std::int64_t dy = 0;             /**< Rows moved down per period. */
+  std::uint64_t population = 0;    /**< Live cells once the fate is known. */
+  std::uint64_t escaped = 0;       /**< Spaceships removed on escaping. */
+  bool reached_edge = false;       /**< Stopped at the window edge. */
+};
+
+/**
+ * \brief Advance \p engine until the fate of its live cells is known.
+ * \details The live cells are compared to those of the recent generations
+ *          regardless of their position. A repetition in place is a still life
+ *          or an oscillator, a repetition elsewhere is a spaceship moving by
+ *          the difference of the two positions per period. Still lifes,
+ *          oscillators and spaceships are reported from the first generation
+ *          of their cycle, the engine is left at the generation the cycle was
+ *          detected at. Periods up to CycleDetector::kDefaultHistory
+ *          generations are detected.
+ *
+ *          Small objects repeating shifted within a few generations, like the
+ *          gliders emitted by methuselahs and guns, are removed from the
+ *          engine and counted as escaped once clear of the remaining live
+ *          cells and moving away from them. The fate is then that of the
+ *          remaining cells, such that a methuselah settling while emitting
+ *          gliders is a still life or an oscillator. The fate is reported
+ *          from the first cycle after the last escape. A pattern only made
+ *          of spaceships keeps them all.
+ *
+ *          The analysis stops undetermined at the generation limit or once a
+ *          live cell reaches the edge of the visible window, beyond which
+ *          bounded boards lose cells and unbounded ones hide them.
+ * \param [in] engine The engine holding the pattern, advanced in place.
+ * \param [in] max_generations Maximum number of generations computed.
+ */
+[[nodiscard]] Analysis Analyze(Engine &engine, std::uint64_t max_generations);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
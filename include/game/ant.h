@@ -0,0 +1,162 @@
+#ifndef ANT_H_
+#define ANT_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "game/board.h"
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A board of white and black cells walked by Langton's ants.
+ * \details Each Tick() moves every ant in turn. An ant on a white cell turns
+ *          right, an ant on a black cell turns left. The ant then flips the
+ *          color of its cell and steps forward one cell. Ants that walk off
+ *          the edge of a bounded board are removed.
+ *
+ *          The ants are tracked separately from the cell colors. Through the
+ *          Engine interface, white cells are in state 0, black cells in state
+ *          kBlack and cells holding an ant in state kAnt. Setting a cell to
+ *          kAnt places an ant facing north, setting it to any other state
+ *          removes the ants on it.
+ */
+class AntBoard : public Engine {
+ public:
+  static constexpr std::uint8_t kBlack = 1; /**< Black cell state. */
+  static constexpr std::uint8_t kAnt = 2;   /**< State of a cell with an ant. */
+
+  /**
+   * \brief Direction an ant faces.
+   */
+  enum class Heading {
+    kNorth, /**< Toward row 0. */
+    kEast,  /**< Toward the last column. */
+    kSouth, /**< Toward the last row. */
+    kWest,  /**< Toward column 0. */
+  };
+
+  /**
+   * \brief Position and direction of an ant.
+   */
+  struct Ant {
+    std::size_t row = 0;               /**< Row of the ant's cell. */
+    std::size_t col = 0;               /**< Column of the ant's cell. */
+    Heading heading = Heading::kNorth; /**< Direction the ant faces. */
+  };
+
+  /**
+   * \brief Construct an MxN board of white cells without ants.
+   * \param [in] num_rows Number of rows.
+   * \param [in] num_cols Number of columns.
+   * \param [in] wrap When \c true, ants leaving the board reenter it on the
+   *                  opposite edge.
+   */
+  [[nodiscard]] AntBoard(std::size_t num_rows, std::size_t num_cols,
+                         bool wrap = false);
+  AntBoard() = delete;
+  ~AntBoard() override = default;
+
+  AntBoard(const AntBoard &) = default;
+  AntBoard &operator=(const AntBoard &) = default;
+  AntBoard(AntBoard &&) = default;
+  AntBoard &operator=(AntBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override {
+    return cells_.Rows();
+  }
+  [[nodiscard]] std::size_t Cols() const noexcept override {
+    return cells_.Cols();
+  }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override {
+    return cells_.IsAlive(row, col);
+  }
+
+  void SetCell(std::size_t row, std::size_t col, bool alive) override {
+    SetState(row, col, (alive) ? kBlack : 0);
+  }
+
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return cells_.NumStates();
+  }
+
+  [[nodiscard]] std::uint8_t State(std::size_t row,
+                                   std::size_t col) const noexcept override {
+    return cells_.State(row, col);
+  }
+
+  void SetState(std::size_t row, std::size_t col,
+                std::uint8_t state) override;
+
+  /**
+   * \brief Return the number of black cells and cells holding an ant.
+   */
+  [[nodiscard]] std::uint64_t Population() const noexcept override {
+    return cells_.Population();
+  }
+
+  [[nodiscard]] std::optional<CellBounds> LiveBounds() const override {
+    return cells_.LiveBounds();
+  }
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+  /**
+   * \brief Place \p ant on the board.
+   * \throws std::invalid_argument When \p ant lies outside of the board.
+   */
+  void AddAnt(const Ant &ant);
+
+  /**
+   * \brief Return the ants in the order they move.
+   */
+  [[nodiscard]] const std::vector<Ant> &Ants() const noexcept {
+    return ants_;
+  }
+
+  /**
+   * \brief Return the cell states seen through the Engine interface.
+   */
+  [[nodiscard]] const GameOfLifeBoard &Cells() const noexcept {
+    return cells_;
+  }
+
+  /**
+   * \brief Return the display colors of black cells and ants as 0xRRGGBB
+   *        values.
+   */
+  [[nodiscard]] static std::vector<std::uint32_t> Colors();
+
+ private:
+  [[nodiscard]] std::size_t Index(std::size_t row,
+                                  std::size_t col) const noexcept {
+    return (row * Cols()) + col;
+  }
+
+  void Step(Ant &ant) const noexcept;
+
+  std::vector<std::uint8_t> colors_; /**< Colors of the cells. */
+  std::vector<Ant> ants_;            /**< Ants in the order they move. */
+  GameOfLifeBoard cells_;            /**< Colors with the ants drawn on top. */
+  bool wrap_ = false;                /**< Ants wrap around the edges. */
+  std::uint64_t generation_ = 0;     /**< Number of Tick() calls. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,262 @@
+#ifndef AUTOMATON_H_
+#define AUTOMATON_H_
+
+#include <array>
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A cellular automaton whose cells have more than two states.
+ * \details State 0 is the empty ground state, every other state counts as a
+ *          live cell for the population. The next state of a cell depends on
+ *          its current state and the states of its eight Moore neighbors.
+ */
+class Automaton {
+ public:
+  /**
+   * \brief Positions of the cells of the Moore neighborhood.
+   */
+  enum Neighbor {
+    kNorthWest = 0, /**< Row above, column to the left. */
+    kNorth,         /**< Row above. */
+    kNorthEast,     /**< Row above, column to the right. */
+    kWest,          /**< Column to the left. */
+    kEast,          /**< Column to the right. */
+    kSouthWest,     /**< Row below, column to the left. */
+    kSouth,         /**< Row below. */
+    kSouthEast,     /**< Row below, column to the right. */
+    kNumNeighbors,  /**< Number of neighbors. */
+  };
+
+  /** States of the neighbors of a cell indexed by Neighbor. */
+  using Neighborhood = std::array<std::uint8_t, kNumNeighbors>;
+
+  Automaton() = default;
+  virtual ~Automaton() = default;
+
+  Automaton(const Automaton&) = delete;
+  Automaton& operator=(const Automaton&) = delete;
+  Automaton(Automaton&&) = delete;
+  Automaton& operator=(Automaton&&) = delete;
+
+  /**
+   * \brief Return the name selecting this automaton, e.g. 'brain'.
+   */
+  [[nodiscard]] virtual std::string Name() const = 0;
+
+  /**
+   * \brief Return the number of cell states including the ground state.
+   */
+  [[nodiscard]] virtual std::uint8_t NumStates() const noexcept = 0;
+
+  /**
+   * \brief Return the next state of a cell.
+   * \param [in] state Current state of the cell.
+   * \param [in] neighbors Current states of the cell's neighbors, cells
+   *                       beyond the edges of a bounded board are in state 0.
+   */
+  [[nodiscard]] virtual std::uint8_t Next(
+      std::uint8_t state, const Neighborhood& neighbors) const noexcept = 0;
+
+  /**
+   * \brief Return the display colors of states 1 to NumStates() - 1 as
+   *        0xRRGGBB values.
+   */
+  [[nodiscard]] virtual std::vector<std::uint32_t> Colors() const = 0;
+};
+
+/**
+ * \brief Brian's Brain, cells fire, spend a generation dying and turn off.
+ * \details An off cell (state 0) starts firing (state 1) when exactly two of
+ *          its neighbors are firing. A firing cell starts dying (state 2) and
+ *          a dying cell turns off on the next generation.
+ */
+class BriansBrain : public Automaton {
+ public:
+  static constexpr std::uint8_t kFiring = 1; /**< Firing cell state. */
+  static constexpr std::uint8_t kDying = 2;  /**< Dying cell state. */
+
+  [[nodiscard]] std::string Name() const override { return "brain"; }
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override { return 3; }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood& neighbors)
+      const noexcept override;
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override;
+};
+
+/**
+ * \brief Wireworld, electrons flowing along wires of conductor cells.
+ * \details Empty cells (state 0) never change. An electron head (state 1)
+ *          becomes an electron tail (state 2) and a tail becomes a conductor
+ *          (state 3). A conductor becomes an electron head when one or two of
+ *          its neighbors are electron heads. The state numbers match the ones
+ *          used by Wireworld patterns.
+ */
+class Wireworld : public Automaton {
+ public:
+  static constexpr std::uint8_t kHead = 1;      /**< Electron head state. */
+  static constexpr std::uint8_t kTail = 2;      /**< Electron tail state. */
+  static constexpr std::uint8_t kConductor = 3; /**< Conductor state. */
+
+  [[nodiscard]] std::string Name() const override { return "wireworld"; }
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override { return 4; }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood& neighbors)
+      const noexcept override;
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override;
+};
+
+/**
+ * \brief A rule of the Generations family, live cells fade through dying
+ *        states before they disappear.
+ * \details A live cell (state 1) with a neighbor count of the rule's survival
+ *          set stays alive, otherwise it starts dying (state 2). Dying cells
+ *          advance one state per generation and the last state is followed by
+ *          state 0. A cell in state 0 is born when its neighbor count is in
+ *          the rule's birth set. Only live cells count as neighbors.
+ */
+class Generations : public Automaton {
+ public:
+  /**
+   * \brief Construct a Generations rule.
+   * \param [in] rule Birth and survival neighbor counts.
+   * \param [in] num_states Number of states including the ground state.
+   * \throws std::invalid_argument When \p num_states is less than 2.
+   */
+  [[nodiscard]] Generations(const Rule& rule, std::uint8_t num_states);
+
+  /**
+   * \brief Return the rule as an S/B/C rulestring, e.g., '345/2/4'.
+   */
+  [[nodiscard]] std::string Name() const override;
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return num_states_;
+  }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood& neighbors)
+      const noexcept override;
+
+  /**
+   * \brief Return white for live cells and a gradient from yellow to dark red
+   *        for the dying states.
+   */
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override;
+
+ private:
+  Rule rule_;                   /**< Birth and survival neighbor counts. */
+  std::uint8_t num_states_ = 2; /**< Number of states. */
+};
+
+/**
+ * \brief Life whose live cells come in colors, Immigration with two colors
+ *        and QuadLife with four.
+ * \details Cells are born and survive as in Life, states 1 to NumColors() are
+ *          the colors of live cells. Survivors keep their color and a newborn
+ *          cell takes the color held by most of its three parents. When the
+ *          parents all differ in color, the newborn cell takes the color
+ *          none of them has.
+ */
+class ColoredLife : public Automaton {
+ public:
+  /**
+   * \brief Construct Immigration or QuadLife.
+   * \param [in] num_colors Number of colors of live cells, 2 or 4.
+   * \throws std::invalid_argument When \p num_colors is neither 2 nor 4.
+   */
+  [[nodiscard]] explicit ColoredLife(std::uint8_t num_colors);
+
+  /**
+   * \brief Return 'immigration' or 'quadlife'.
+   */
+  [[nodiscard]] std::string Name() const override;
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return num_colors_ + 1;
+  }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood& neighbors)
+      const noexcept override;
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override;
+
+  /**
+   * \brief Return the number of colors of live cells.
+   */
+  [[nodiscard]] std::uint8_t NumColors() const noexcept { return num_colors_; }
+
+ private:
+  std::uint8_t num_colors_ = 2; /**< Number of colors of live cells. */
+};
+
+/**
+ * \brief The states of cells updated by an engine rather than an automaton.
+ * \details Engines such as the AntBoard keep the states of their cells on a
+ *          GameOfLifeBoard to share its drawing and history. These cells never
+ *          change on their own, Next() returns the current state.
+ */
+class StaticStates : public Automaton {
+ public:
+  /**
+   * \brief Construct the states of an engine.
+   * \param [in] name Name of the engine's simulation.
+   * \param [in] num_states Number of states including the ground state.
+   * \param [in] colors Display colors of states 1 to \p num_states - 1.
+   */
+  [[nodiscard]] StaticStates(std::string name, std::uint8_t num_states,
+                             std::vector<std::uint32_t> colors);
+
+  [[nodiscard]] std::string Name() const override { return name_; }
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return num_states_;
+  }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood&) const noexcept override {
+    return state;
+  }
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override {
+    return colors_;
+  }
+
+ private:
+  std::string name_;                  /**< Name of the simulation. */
+  std::uint8_t num_states_ = 2;       /**< Number of states. */
+  std::vector<std::uint32_t> colors_; /**< Colors of states 1 and above. */
+};
+
+/**
+ * \brief Parse a Generations rulestring.
+ * \details Rulestrings are given as S/B/C, e.g., '345/2/4', or with prefixed
+ *          components as B/S/C, e.g., 'B2/S345/C4'.
+ * \return The rule or \c nullptr when \p rulestring does not have three
+ *         components.
+ * \throws std::invalid_argument When a rulestring with three components is
+ *         malformed.
+ */
+[[nodiscard]] std::shared_ptr<const Generations> ParseGenerations(
+    const std::string& rulestring);
+
+/**
+ * \brief Return the automaton named \p name.
+ * \details Names are case insensitive. Besides the named automata, \p name
+ *          may be a Generations rulestring.
+ * \return The automaton or \c nullptr when no automaton is named \p name.
+ * \throws std::invalid_argument When \p name is a malformed Generations
+ *         rulestring.
+ */
+[[nodiscard]] std::shared_ptr<const Automaton> FindAutomaton(
+    const std::string& name);
+
+/**
+ * \brief Return the names of all automata.
+ */
+[[nodiscard]] std::vector<std::string> AutomatonNames();
+
+}  // namespace game
+}  // namespace gol
+
+#endif
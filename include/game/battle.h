@@ -0,0 +1,64 @@
+#ifndef BATTLE_H_
+#define BATTLE_H_
+
+#include <cstdint>
+#include <string>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief States of the cells of the two players of a battle.
+ * \details Battles are run under Immigration (see ColoredLife), newborn cells
+ *          join the player holding most of their parents.
+ */
+enum class Player : std::uint8_t {
+  kRed = 1,  /**< The first player. */
+  kBlue = 2, /**< The second player. */
+};
+
+/**
+ * \brief Number of live cells of each player.
+ */
+struct BattleScore {
+  std::uint64_t red = 0;  /**< Live cells of the red player. */
+  std::uint64_t blue = 0; /**< Live cells of the blue player. */
+};
+
+/**
+ * \brief How a battle stands.
+ */
+enum class BattleOutcome {
+  kUndecided, /**< Both players are alive and the board still changes. */
+  kRedWins,   /**< Only the red player is left. */
+  kBlueWins,  /**< Only the blue player is left. */
+  kStalemate, /**< Both players died out or the board settled. */
+};
+
+/**
+ * \brief Return the number of live cells of each player on \p board.
+ */
+[[nodiscard]] BattleScore ScoreBattle(const Engine &board);
+
+/**
+ * \brief Return how a battle with score \p score stands.
+ * \param [in] score Live cells of each player.
+ * \param [in] stable When \c true, the board stopped changing or repeats
+ *                    itself, a battle between two living players is then a
+ *                    stalemate.
+ */
+[[nodiscard]] BattleOutcome JudgeBattle(const BattleScore &score,
+                                        bool stable) noexcept;
+
+/**
+ * \brief Return a description of \p outcome such as 'RED WINS', empty while
+ *        the battle is undecided.
+ */
+[[nodiscard]] std::string ToString(BattleOutcome outcome);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
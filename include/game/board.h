@@ -2,8 +2,15 @@
 #define BOARD_H_
 
 #include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
 #include <vector>
 
+#include "game/automaton.h"
+#include "game/engine.h"
+#include "game/rule.h"
+
 namespace gol {
 namespace game {
 
@@ -12,21 +19,58 @@ namespace game {
  * \details The GameOfLifeBoard class implements the core game logic. Users of
  *          the class can construct an MxN game board. Cells on the board can
  *          be manually set live/dead. A Tick() method can called to apply the
- *          Game of Life rules to the current board to achieve the next state.
+ *          board's Rule to the current board to achieve the next state.
+ *
+ *          Cell states are bit-packed, each row of the board is stored as a
+ *          sequence of 64-bit words with one bit per cell. Tick() operates on
+ *          whole words at a time, updating 64 cells in parallel using bitwise
+ *          arithmetic. The rows of large boards can further be split into
+ *          bands that are updated by separate threads, see SetNumThreads().
+ *          The board is double buffered, Tick() writes the next generation
+ *          into a back buffer and swaps it with the front buffer. The buffers
+ *          and the scratch rows of each band are allocated up front such that
+ *          a board updated on the calling thread allocates no memory per
+ *          generation, the worker threads of a multi-threaded board are
+ *          started anew on each Tick().
+ *
+ *          Rules counting neighbors in another neighborhood than the Moore
+ *          neighborhood of radius 1 are applied one cell at a time, as are
+ *          non-totalistic rules.
+ *
+ *          A board running a multi-state Automaton additionally stores one
+ *          byte per cell holding its state. The bits then mark the cells that
+ *          are not in state 0 and Tick() updates one cell at a time.
  */
-class GameOfLifeBoard {
+class GameOfLifeBoard : public Engine {
  public:
-  using CellStateVec = std::vector<bool>;
+  static constexpr std::size_t kMaxSide = 1 << 15; /**< Max rows or cols. */
 
   /**
    * \brief Consruct an MxN game board.
    * \details By default, all cells are marked dead on construction.
    * \param [in] num_rows Number of rows.
    * \param [in] num_cols Number of columns.
+   * \param [in] wrap When \c true, the board edges wrap around such that the
+   *                  board forms a torus. Otherwise, cells beyond the board
+   *                  edges are considered dead.
+   * \param [in] rule Birth/survival rule applied on each Tick().
+   */
+  [[nodiscard]] GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                                bool wrap = false, const Rule &rule = Rule());
+
+  /**
+   * \brief Consruct an MxN game board running a multi-state automaton.
+   * \details By default, all cells are in state 0 on construction.
+   * \param [in] num_rows Number of rows.
+   * \param [in] num_cols Number of columns.
+   * \param [in] wrap When \c true, the board edges wrap around.
+   * \param [in] automaton Automaton applied on each Tick().
    */
-  [[nodiscard]] GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols);
+  [[nodiscard]] GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                                bool wrap,
+                                std::shared_ptr<const Automaton> automaton);
   GameOfLifeBoard() = delete;
-  ~GameOfLifeBoard() = default;
+  ~GameOfLifeBoard() override = default;
 
   GameOfLifeBoard(const GameOfLifeBoard &) = default;
   GameOfLifeBoard &operator=(const GameOfLifeBoard &) = default;
@@ -36,35 +80,193 @@ class GameOfLifeBoard {
   /**
    * \brief Return the number of board rows.
    */
-  [[nodiscard]] std::size_t Rows() const noexcept { return state_.size(); }
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
 
   /**
    * \brief Return the number of board columns.
    */
-  [[nodiscard]] std::size_t Cols() const noexcept { return state_[0].size(); }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  /**
+   * \brief Return \c true if the board edges wrap around.
+   */
+  [[nodiscard]] bool Wraps() const noexcept { return wrap_; }
+
+  /**
+   * \brief Return the rule applied on each Tick().
+   */
+  [[nodiscard]] const Rule &GetRule() const noexcept { return rule_; }
 
   /**
-   * \brief Return the CellStateVec corresponding to index \p i.
-   * \param [in] i A GameOfLifeBoard row index.
-   * \return The #CellStateVec associated with the ith row of the game board.
+   * \brief Return the multi-state automaton applied on each Tick() or
+   *        \c nullptr when the board's Rule is applied.
    */
-  [[nodiscard]] const CellStateVec &operator[](int i) const noexcept {
-    return state_[i];
+  [[nodiscard]] const std::shared_ptr<const Automaton> &GetAutomaton()
+      const noexcept {
+    return automaton_;
   }
-  [[nodiscard]] CellStateVec &operator[](int i) noexcept { return state_[i]; }
 
   /**
-   * \brief Apply the Game of Life rules to the current board.
+   * \brief Return the number of threads used by Tick().
+   */
+  [[nodiscard]] std::size_t NumThreads() const noexcept { return num_threads_; }
+
+  /**
+   * \brief Set the number of threads used to compute the next generation.
+   * \param [in] num_threads Number of worker threads. A value of 0 or 1 keeps
+   *                         the update on the calling thread.
+   */
+  void SetNumThreads(std::size_t num_threads);
+
+  /**
+   * \brief Return the probability of a cell flipping its state per Tick().
+   */
+  [[nodiscard]] double Noise() const noexcept { return noise_; }
+
+  /**
+   * \brief Flip the state of each cell with a given probability after the
+   *        next generation is computed.
+   * \details Flipped live cells die and flipped dead cells are born in state
+   *          1. The flipped cells only depend on \p seed and the generation,
+   *          such that a rewound simulation sees the same noise again.
+   * \param [in] probability Probability of a cell flipping, 0 disables the
+   *                         noise.
+   * \param [in] seed Seed of the random number generator.
+   * \throws std::invalid_argument When \p probability does not lie in
+   *         [0, 1].
    */
-  void Tick() noexcept;
+  void SetNoise(double probability, std::uint64_t seed);
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override {
+    return cells_[WordIndex(row, col)] & BitMask(col);
+  }
+
+  void SetCell(std::size_t row, std::size_t col,
+               bool alive) noexcept override {
+    SetState(row, col, (alive) ? 1 : 0);
+  }
+
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return (automaton_) ? automaton_->NumStates() : 2;
+  }
+
+  [[nodiscard]] std::uint8_t State(std::size_t row,
+                                   std::size_t col) const noexcept override {
+    if (states_.empty()) {
+      return (IsAlive(row, col)) ? 1 : 0;
+    }
+    return states_[(row * cols_) + col];
+  }
+
+  void SetState(std::size_t row, std::size_t col,
+                std::uint8_t state) noexcept override {
+    if (!states_.empty()) {
+      states_[(row * cols_) + col] = state;
+    }
+    if (state) {
+      cells_[WordIndex(row, col)] |= BitMask(col);
+    } else {
+      cells_[WordIndex(row, col)] &= ~BitMask(col);
+    }
+  }
+
+  [[nodiscard]] std::uint64_t Population() const noexcept override;
+
+  [[nodiscard]] std::optional<CellBounds> LiveBounds() const override;
+
+  /**
+   * \brief Return the number of cells alive on this board but dead on
+   *        \p other.
+   * \details Returns 0 when the boards differ in dimensions.
+   */
+  [[nodiscard]] std::uint64_t CountAliveNotIn(
+      const GameOfLifeBoard &other) const noexcept;
+
+  /**
+   * \brief Return \c true if \p other has the same dimensions and cell
+   *        states.
+   * \details The rule, wrapping, and generation of the boards are ignored.
+   */
+  [[nodiscard]] bool SameCells(const GameOfLifeBoard &other) const noexcept {
+    return (rows_ == other.rows_) && (cols_ == other.cols_) &&
+           (cells_ == other.cells_) && (states_ == other.states_);
+  }
+
+  /**
+   * \brief Return a hash of the board's dimensions and cell states.
+   * \details Boards for which SameCells() is \c true have equal hashes.
+   */
+  [[nodiscard]] std::uint64_t Hash() const noexcept;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  /**
+   * \brief Apply the board's Rule to the current board.
+   */
+  void Tick() override;
 
  private:
-  using CellStateMatrix = std::vector<CellStateVec>;
+  using Word = std::uint64_t;
+  using WordVec = std::vector<Word>;
+  using StateVec = std::vector<std::uint8_t>;
+  using AutomatonPtr = std::shared_ptr<const Automaton>;
+
+  static constexpr std::size_t kBitsPerWord = 64;
+
+  [[nodiscard]] std::size_t WordIndex(std::size_t row,
+                                      std::size_t col) const noexcept {
+    return (row * words_per_row_) + (col / kBitsPerWord);
+  }
+
+  [[nodiscard]] static Word BitMask(std::size_t col) noexcept {
+    return Word{1} << (col % kBitsPerWord);
+  }
+
+  [[nodiscard]] Word LastWordMask() const noexcept;
+
+  void ShiftWest(const Word *row, Word *out) const noexcept;
+
+  void ShiftEast(const Word *row, Word *out) const noexcept;
+
+  [[nodiscard]] std::size_t NumBands() const noexcept;
+
+  void UpdateRows(std::size_t first_row, std::size_t last_row, WordVec &next,
+                  Word *scratch) const;
+
+  void UpdateCounts(std::size_t first_row, std::size_t last_row,
+                    WordVec &next) const;
+
+  void UpdateConfigs(std::size_t first_row, std::size_t last_row,
+                     WordVec &next) const;
+
+  void UpdateStates(std::size_t first_row, std::size_t last_row,
+                    StateVec &next_states, WordVec &next) const;
 
-  [[nodiscard]] int CountLiveNeighbors(std::size_t row,
-                                       std::size_t col) const noexcept;
+  void ApplyNoise() noexcept;
 
-  CellStateMatrix state_; /**< 2D boolean state matrix. */
+  std::size_t rows_ = 0;          /**< Number of board rows. */
+  std::size_t cols_ = 0;          /**< Number of board columns. */
+  std::size_t words_per_row_ = 0; /**< Number of words used to store a row. */
+  WordVec cells_;                 /**< Row major bit-packed cell states. */
+  WordVec next_cells_;            /**< Back buffer for the next generation. */
+  WordVec zero_row_;              /**< A row of dead cells. */
+  WordVec scratch_;               /**< Shifted rows of each band. */
+  bool wrap_ = false;             /**< Flag indicating toroidal board edges. */
+  Rule rule_;                     /**< Birth/survival rule. */
+  AutomatonPtr automaton_;        /**< Multi-state automaton or null. */
+  StateVec states_;               /**< Row major multi-state cells. */
+  StateVec next_states_;          /**< Back buffer of states_. */
+  std::size_t num_threads_ = 1;   /**< Number of threads used by Tick(). */
+  double noise_ = 0.0;            /**< Probability of a cell flipping. */
+  std::uint64_t noise_seed_ = 0;  /**< Seed of the noise. */
+  std::uint64_t generation_ = 0;  /**< Number of Tick() calls. */
 };
 
 }  // namespace game
@@ -0,0 +1,120 @@
+#ifndef CHUNKED_H_
+#define CHUNKED_H_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <unordered_map>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A Game of Life board divided into chunks allocated on demand.
+ * \details The ChunkedBoard divides an unbounded plane into 64x64 chunks. Each
+ *          chunk is bit-packed, one 64-bit word per row, and updated 64 cells
+ *          at a time like the GameOfLifeBoard. Only chunks containing live
+ *          cells are stored. Tick() steps the stored chunks along with those
+ *          of their neighbors that border a live cell, and frees the chunks
+ *          that died out.
+ *
+ *          Like the HashLifeBoard, the ChunkedBoard universe is unbounded. The
+ *          MxN window exposed via the Engine interface is the region whose top
+ *          left corner lies at the origin of the universe. Cells that leave
+ *          the window continue to evolve off screen.
+ */
+class ChunkedBoard : public Engine {
+ public:
+  static constexpr std::size_t kChunkSize = 64; /**< Chunk width in cells. */
+
+  /**
+   * \brief Construct an empty universe with an MxN visible window.
+   * \param [in] num_rows Number of rows in the visible window.
+   * \param [in] num_cols Number of columns in the visible window.
+   * \param [in] rule Birth/survival rule applied on each Tick().
+   * \throws std::invalid_argument When \p rule causes births in empty space
+   *         (i.e., B0 rules).
+   */
+  [[nodiscard]] ChunkedBoard(std::size_t num_rows, std::size_t num_cols,
+                             const Rule &rule = Rule());
+  ChunkedBoard() = delete;
+  ~ChunkedBoard() override = default;
+
+  ChunkedBoard(const ChunkedBoard &) = default;
+  ChunkedBoard &operator=(const ChunkedBoard &) = default;
+  ChunkedBoard(ChunkedBoard &&) = default;
+  ChunkedBoard &operator=(ChunkedBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override;
+
+  void SetCell(std::size_t row, std::size_t col, bool alive) override;
+
+  /**
+   * \brief Return the number of live cells in the entire universe.
+   * \details Cells outside of the visible window are included in the count.
+   */
+  [[nodiscard]] std::uint64_t Population() const noexcept override;
+
+  /**
+   * \brief Return the live cells of the entire universe in row major order.
+   */
+  [[nodiscard]] std::vector<LiveCell> LiveCells() const override;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+  /**
+   * \brief Return the number of allocated chunks.
+   */
+  [[nodiscard]] std::size_t NumChunks() const noexcept {
+    return chunks_.size();
+  }
+
+ private:
+  using Chunk = std::array<std::uint64_t, kChunkSize>;
+
+  /**
+   * \brief Coordinates of a chunk, chunk (0, 0) covers cells [0, 64)^2.
+   */
+  struct ChunkKey {
+    std::int64_t row = 0;
+    std::int64_t col = 0;
+
+    bool operator==(const ChunkKey &other) const noexcept = default;
+  };
+
+  struct ChunkKeyHash {
+    std::size_t operator()(const ChunkKey &key) const noexcept;
+  };
+
+  using ChunkMap = std::unordered_map<ChunkKey, Chunk, ChunkKeyHash>;
+
+  [[nodiscard]] Chunk Step(const ChunkKey &key) const noexcept;
+
+  std::size_t rows_ = 0;         /**< Visible window rows. */
+  std::size_t cols_ = 0;         /**< Visible window columns. */
+  Rule rule_;                    /**< Birth/survival rule. */
+  ChunkMap chunks_;              /**< Chunks containing live cells. */
+  ChunkMap next_chunks_;         /**< Next generation's chunks. */
+  std::uint64_t generation_ = 0; /**< Number of Tick() calls. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,88 @@
+#ifndef CYCLE_H_
+#define CYCLE_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <optional>
+#include <unordered_map>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Return a hash of the live cells in the visible window of \p engine.
+ * \details Windows with the same dimensions and live cells have equal hashes
+ *          regardless of the engine type.
+ */
+[[nodiscard]] std::uint64_t HashCells(const Engine &engine);
+
+/**
+ * \brief Detects when a simulation enters a cycle.
+ * \details The CycleDetector remembers the hashes of the most recent
+ *          generations. A generation whose hash matches that of one of the
+ *          remembered generations repeats it, the difference between the two
+ *          generations is the period of the cycle. Still lifes and empty
+ *          boards are reported as period 1 cycles. Only cycles with a period up
+ *          to the history length are detected.
+ */
+class CycleDetector {
+ public:
+  static constexpr std::size_t kDefaultHistory = 256; /**< Generations. */
+
+  /**
+   * \brief Construct a detector remembering \p history generations.
+   * \throws std::invalid_argument When \p history is zero.
+   */
+  [[nodiscard]] explicit CycleDetector(
+      std::size_t history = kDefaultHistory);
+  ~CycleDetector() = default;
+
+  CycleDetector(const CycleDetector &) = default;
+  CycleDetector &operator=(const CycleDetector &) = default;
+  CycleDetector(CycleDetector &&) = default;
+  CycleDetector &operator=(CycleDetector &&) = default;
+
+  /**
+   * \brief Record the current generation of \p board.
+   * \returns The period of the cycle if the generation repeats one of the
+   *          remembered generations.
+   */
+  std::optional<std::uint64_t> Update(const Engine &board);
+
+  /**
+   * \brief Record the generation \p generation whose cells hash to \p hash.
+   * \details Generations must be recorded in increasing order.
+   * \returns The period of the cycle if the generation repeats one of the
+   *          remembered generations.
+   */
+  std::optional<std::uint64_t> Update(std::uint64_t hash,
+                                      std::uint64_t generation);
+
+  /**
+   * \brief Forget all remembered generations.
+   */
+  void Clear() noexcept;
+
+ private:
+  /**
+   * \brief A remembered generation.
+   */
+  struct Entry {
+    std::uint64_t hash = 0;       /**< Hash of the generation's cells. */
+    std::uint64_t generation = 0; /**< Generation number. */
+  };
+
+  using GenerationMap = std::unordered_map<std::uint64_t, std::uint64_t>;
+
+  std::size_t history_ = 0;   /**< Number of generations remembered. */
+  std::deque<Entry> entries_; /**< Remembered generations oldest first. */
+  GenerationMap last_seen_;   /**< Latest generation of each hash. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,115 @@
+#ifndef ELEMENTARY_H_
+#define ELEMENTARY_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A one dimensional elementary cellular automaton.
+ * \details Each generation is a row of cells. The next state of a cell
+ *          depends on its own state and the states of its left and right
+ *          neighbors. The eight possible neighborhoods index the bits of the
+ *          Wolfram code of the rule, e.g., Rule 30 or Rule 110.
+ *
+ *          The MxN window exposed via the Engine interface shows M successive
+ *          generations. The first generation is the top row, each Tick()
+ *          computes the next generation into the row below the current one.
+ *          Once the bottom row is reached, the window scrolls up one row per
+ *          generation. Rows below the current generation are empty and cannot
+ *          be set.
+ */
+class ElementaryBoard : public Engine {
+ public:
+  /**
+   * \brief Construct an MxN window whose first generation is empty.
+   * \param [in] num_rows Number of generations shown.
+   * \param [in] num_cols Number of cells per generation.
+   * \param [in] rule Wolfram code of the rule applied on each Tick().
+   * \param [in] wrap When \c true, the left and right edges wrap around.
+   *                  Otherwise, cells beyond the edges are considered dead.
+   */
+  [[nodiscard]] ElementaryBoard(std::size_t num_rows, std::size_t num_cols,
+                                std::uint8_t rule, bool wrap = false);
+  ElementaryBoard() = delete;
+  ~ElementaryBoard() override = default;
+
+  ElementaryBoard(const ElementaryBoard &) = default;
+  ElementaryBoard &operator=(const ElementaryBoard &) = default;
+  ElementaryBoard(ElementaryBoard &&) = default;
+  ElementaryBoard &operator=(ElementaryBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override {
+    return (row <= current_) && cells_[Index(row, col)];
+  }
+
+  /**
+   * \brief Mark the cell at (\p row, \p col) live or dead.
+   * \details Cells below the current generation are left unchanged.
+   */
+  void SetCell(std::size_t row, std::size_t col, bool alive) override;
+
+  /**
+   * \brief Return the number of live cells in the window.
+   */
+  [[nodiscard]] std::uint64_t Population() const noexcept override;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  /**
+   * \brief Compute the generation following the current one.
+   */
+  void Tick() override;
+
+  /**
+   * \brief Return the Wolfram code of the rule.
+   */
+  [[nodiscard]] std::uint8_t WolframCode() const noexcept { return rule_; }
+
+ private:
+  [[nodiscard]] std::size_t Index(std::size_t row,
+                                  std::size_t col) const noexcept {
+    return (((first_ + row) % rows_) * cols_) + col;
+  }
+
+  std::size_t rows_ = 0;            /**< Generations shown. */
+  std::size_t cols_ = 0;            /**< Cells per generation. */
+  std::uint8_t rule_ = 0;           /**< Wolfram code. */
+  bool wrap_ = false;               /**< Wrap around the edges. */
+  std::vector<std::uint8_t> cells_; /**< Ring buffer of the rows. */
+  std::vector<std::uint8_t> next_;  /**< Next generation's row. */
+  std::size_t first_ = 0;           /**< Ring buffer index of the top row. */
+  std::size_t current_ = 0;         /**< Row of the current generation. */
+  std::uint64_t generation_ = 0;    /**< Number of Tick() calls. */
+};
+
+/**
+ * \brief Return the Wolfram code of an elementary rule named 'W<n>' or
+ *        'rule<n>', e.g., 'W110' or 'rule30', if \p name is one.
+ * \details Names are case insensitive.
+ * \throws std::invalid_argument When the code does not lie in [0, 255].
+ */
+[[nodiscard]] std::optional<std::uint8_t> ParseElementaryRule(
+    const std::string &name);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
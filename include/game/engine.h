@@ -0,0 +1,177 @@
+#ifndef ENGINE_H_
+#define ENGINE_H_
+
+#include <algorithm>
+#include <compare>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief The smallest rectangle of cells holding all live cells.
+ */
+struct CellBounds {
+  std::size_t top = 0;    /**< Row of the topmost live cells. */
+  std::size_t left = 0;   /**< Column of the leftmost live cells. */
+  std::size_t bottom = 0; /**< Row of the bottommost live cells. */
+  std::size_t right = 0;  /**< Column of the rightmost live cells. */
+
+  bool operator==(const CellBounds &other) const noexcept = default;
+};
+
+/**
+ * \brief A live cell anywhere in the universe of an Engine.
+ * \details Coordinates are relative to the top left corner of the visible
+ *          window. Cells of an unbounded universe lying above or left of the
+ *          window have negative coordinates. Cells order row major.
+ */
+struct LiveCell {
+  std::int64_t row = 0;   /**< Row of the cell. */
+  std::int64_t col = 0;   /**< Column of the cell. */
+  std::uint8_t state = 1; /**< State of the cell, never 0. */
+
+  auto operator<=>(const LiveCell &other) const noexcept = default;
+};
+
+/**
+ * \brief Interface implemented by all simulation backends.
+ * \details An Engine exposes an MxN window of cells that can be read, edited
+ *          and advanced one generation at a time. How the cells are stored and
+ *          how the next generation is computed is left to the implementation.
+ */
+class Engine {
+ public:
+  virtual ~Engine() = default;
+
+  /**
+   * \brief Return the number of rows in the visible window.
+   */
+  [[nodiscard]] virtual std::size_t Rows() const noexcept = 0;
+
+  /**
+   * \brief Return the number of columns in the visible window.
+   */
+  [[nodiscard]] virtual std::size_t Cols() const noexcept = 0;
+
+  /**
+   * \brief Return \c true if the cell at (\p row, \p col) is alive.
+   */
+  [[nodiscard]] virtual bool IsAlive(std::size_t row,
+                                     std::size_t col) const noexcept = 0;
+
+  /**
+   * \brief Mark the cell at (\p row, \p col) live or dead.
+   * \param [in] row Row index.
+   * \param [in] col Column index.
+   * \param [in] alive New state of the cell.
+   */
+  virtual void SetCell(std::size_t row, std::size_t col, bool alive) = 0;
+
+  /**
+   * \brief Return the number of states a cell can be in.
+   * \details Cells of two state engines are dead (0) or alive (1). The cells
+   *          of multi-state engines are alive in every state but 0.
+   */
+  [[nodiscard]] virtual std::uint8_t NumStates() const noexcept { return 2; }
+
+  /**
+   * \brief Return the state of the cell at (\p row, \p col).
+   */
+  [[nodiscard]] virtual std::uint8_t State(std::size_t row,
+                                           std::size_t col) const noexcept {
+    return (IsAlive(row, col)) ? 1 : 0;
+  }
+
+  /**
+   * \brief Set the state of the cell at (\p row, \p col).
+   * \param [in] row Row index.
+   * \param [in] col Column index.
+   * \param [in] state New state of the cell, less than NumStates().
+   */
+  virtual void SetState(std::size_t row, std::size_t col, std::uint8_t state) {
+    SetCell(row, col, 0 != state);
+  }
+
+  /**
+   * \brief Return the number of live cells.
+   */
+  [[nodiscard]] virtual std::uint64_t Population() const noexcept = 0;
+
+  /**
+   * \brief Return the number of generations computed since construction.
+   */
+  [[nodiscard]] virtual std::uint64_t Generation() const noexcept = 0;
+
+  /**
+   * \brief Set the number of generations computed, as when resuming a run.
+   */
+  virtual void SetGeneration(std::uint64_t generation) noexcept = 0;
+
+  /**
+   * \brief Advance the simulation by one generation.
+   */
+  virtual void Tick() = 0;
+
+  /**
+   * \brief Advance the simulation by \p generations generations.
+   * \details The default implementation calls Tick() \p generations times,
+   *          engines able to skip ahead faster override it.
+   */
+  virtual void Advance(std::uint64_t generations) {
+    for (std::uint64_t i = 0; i < generations; ++i) {
+      Tick();
+    }
+  }
+
+  /**
+   * \brief Return the bounding box of the live cells, or \c std::nullopt if
+   *        no cell is alive.
+   * \details The default implementation checks every cell, engines able to
+   *          find the live cells faster override it.
+   */
+  [[nodiscard]] virtual std::optional<CellBounds> LiveBounds() const {
+    std::optional<CellBounds> bounds;
+    for (std::size_t i = 0; i < Rows(); ++i) {
+      for (std::size_t j = 0; j < Cols(); ++j) {
+        if (!IsAlive(i, j)) {
+          continue;
+        } else if (!bounds) {
+          bounds = CellBounds{.top = i, .left = j, .bottom = i, .right = j};
+        }
+        bounds->left = std::min(bounds->left, j);
+        bounds->right = std::max(bounds->right, j);
+        bounds->bottom = i;
+      }
+    }
+    return bounds;
+  }
+
+  /**
+   * \brief Return the live cells in row major order.
+   * \details The default implementation lists the live cells of the visible
+   *          window, engines with an unbounded universe override it to include
+   *          the cells outside of the window.
+   */
+  [[nodiscard]] virtual std::vector<LiveCell> LiveCells() const {
+    std::vector<LiveCell> cells;
+    for (std::size_t i = 0; i < Rows(); ++i) {
+      for (std::size_t j = 0; j < Cols(); ++j) {
+        if (IsAlive(i, j)) {
+          cells.push_back({.row = static_cast<std::int64_t>(i),
+                           .col = static_cast<std::int64_t>(j),
+                           .state = State(i, j)});
+        }
+      }
+    }
+    return cells;
+  }
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
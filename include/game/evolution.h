@@ -0,0 +1,127 @@
+#ifndef EVOLUTION_H_
+#define EVOLUTION_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <iterator>
+#include <ranges>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief An endless input range over the generations of an Engine.
+ * \details The range yields the engine at its current generation followed by
+ *          the engine advanced by \p step generations at a time. Advancing is
+ *          lazy, an increment only takes effect once the iterator is
+ *          dereferenced, such that the engine is left at the last generation
+ *          yielded once bounded by e.g. \c std::views::take:
+ *
+ *          \code
+ *          for (const Engine &generation : Evolve(board) |
+ *                                              std::views::take(100)) {
+ *            ...
+ *          }
+ *          \endcode
+ *
+ *          The engine may be edited between two generations, the next
+ *          generation is computed from the edited cells.
+ */
+class Evolution : public std::ranges::view_interface<Evolution> {
+ public:
+  /**
+   * \brief Iterator yielding the engine at successive generations.
+   */
+  class Iterator {
+   public:
+    using value_type = Engine;
+    using difference_type = std::ptrdiff_t;
+
+    Iterator() = default;
+
+    /**
+     * \brief Construct an iterator over the generations of \p engine.
+     * \param [in] engine The engine advanced by the iterator.
+     * \param [in] step Generations advanced per increment.
+     */
+    Iterator(Engine &engine, std::uint64_t step) noexcept
+        : engine_(&engine), step_(step) {}
+
+    /**
+     * \brief Return the engine, first advancing it by the pending
+     *        generations.
+     * \throws Any exception thrown by the engine while advancing.
+     */
+    [[nodiscard]] const Engine &operator*() const {
+      if (pending_) {
+        engine_->Advance(pending_);
+        pending_ = 0;
+      }
+      return *engine_;
+    }
+
+    Iterator &operator++() noexcept {
+      pending_ += step_;
+      return *this;
+    }
+
+    void operator++(int) noexcept { ++*this; }
+
+    /**
+     * \brief The range is endless, an iterator never reaches its end.
+     */
+    [[nodiscard]] bool operator==(std::default_sentinel_t) const noexcept {
+      return false;
+    }
+
+   private:
+    Engine *engine_ = nullptr;          /**< Engine being advanced. */
+    std::uint64_t step_ = 1;            /**< Generations per increment. */
+    mutable std::uint64_t pending_ = 0; /**< Generations not yet computed. */
+  };
+
+  Evolution() = default;
+
+  /**
+   * \brief Construct a range over the generations of \p engine.
+   * \param [in] engine The engine advanced while iterating.
+   * \param [in] step Generations advanced between two yielded generations,
+   *                  0 is treated as 1.
+   */
+  [[nodiscard]] explicit Evolution(Engine &engine,
+                                   std::uint64_t step = 1) noexcept
+      : engine_(&engine), step_((step) ? step : 1) {}
+
+  [[nodiscard]] Iterator begin() const noexcept {
+    return Iterator(*engine_, step_);
+  }
+
+  [[nodiscard]] std::default_sentinel_t end() const noexcept {
+    return std::default_sentinel;
+  }
+
+ private:
+  Engine *engine_ = nullptr; /**< Engine advanced while iterating. */
+  std::uint64_t step_ = 1;   /**< Generations between yielded generations. */
+};
+
+static_assert(std::input_iterator<Evolution::Iterator>);
+static_assert(std::ranges::view<Evolution>);
+
+/**
+ * \brief Return a range over the generations of \p engine starting at its
+ *        current generation.
+ * \param [in] engine The engine advanced while iterating.
+ * \param [in] step Generations advanced between two yielded generations.
+ */
+[[nodiscard]] inline Evolution Evolve(Engine &engine,
+                                      std::uint64_t step = 1) noexcept {
+  return Evolution(engine, step);
+}
+
+}  // namespace game
+}  // namespace gol
+
+#endif
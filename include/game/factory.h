@@ -0,0 +1,94 @@
+#ifndef FACTORY_H_
+#define FACTORY_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/engine.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Simulation backends implementing the Engine interface.
+ */
+enum class EngineType {
+  kNaive,    /**< GameOfLifeBoard, updates every cell of a bounded board. */
+  kHashLife, /**< HashLifeBoard, memoized quadtree of an unbounded board. */
+  kSparse,   /**< SparseBoard, set of the live cells of an unbounded board. */
+  kChunked,  /**< ChunkedBoard, 64x64 chunks of an unbounded board. */
+  kGpu,      /**< GpuBoard, compute shader, requires GOL_GPU. */
+};
+
+/**
+ * \brief Kinds of simulation run on a board.
+ */
+enum class Simulation {
+  kLife,       /**< Life-like rule or multi-state automaton. */
+  kAnt,        /**< Langton's ants, run by the AntBoard. */
+  kElementary, /**< 1D elementary automaton, run by the ElementaryBoard. */
+  kLenia,      /**< Continuous states, run by the LeniaBoard. */
+};
+
+/**
+ * \brief Settings shared by all engines.
+ */
+struct EngineOptions {
+  std::size_t rows = 0; /**< Number of rows in the visible window. */
+  std::size_t cols = 0; /**< Number of columns in the visible window. */
+  Rule rule;            /**< Birth/survival rule. */
+  bool wrap = false;    /**< Wrap around the board edges. */
+  int num_threads = 1;  /**< Number of threads used per generation. */
+
+  /** Multi-state automaton run instead of the rule when set. */
+  std::shared_ptr<const Automaton> automaton;
+
+  /** Simulation run on the board, all but Life use the naive engine. */
+  Simulation simulation = Simulation::kLife;
+
+  /** Wolfram code of the rule of elementary simulations. */
+  std::uint8_t elementary_rule = 30;
+
+  /** Probability of a cell flipping its state per generation. */
+  double noise = 0.0;
+
+  /** Seed of the noise. */
+  std::uint64_t noise_seed = 0;
+};
+
+/**
+ * \brief Return the engine type named \p name.
+ * \throws std::invalid_argument When no engine is named \p name.
+ */
+[[nodiscard]] EngineType ParseEngineType(const std::string &name);
+
+/**
+ * \brief Return the names of all engine types.
+ */
+[[nodiscard]] std::vector<std::string> EngineNames();
+
+/**
+ * \brief Return \c true if engines of type \p type support wrapping around
+ *        the board edges.
+ */
+[[nodiscard]] bool SupportsWrap(EngineType type) noexcept;
+
+/**
+ * \brief Construct an engine of type \p type.
+ * \details Options that do not apply to \p type are ignored. Multi-state
+ *          automata, Langton's ants, elementary automata and noise are only
+ *          run by the naive engine.
+ * \throws std::invalid_argument When \p options are not supported by \p type.
+ */
+[[nodiscard]] std::unique_ptr<Engine> MakeEngine(EngineType type,
+                                                 const EngineOptions &options);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,98 @@
+#ifndef GPU_H_
+#define GPU_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A Game of Life board updated by the GPU.
+ * \details The GpuBoard keeps the board in GPU memory and computes each
+ *          generation with an OpenGL compute shader, one invocation per cell.
+ *          The board is only copied back to the host when its cells are read
+ *          after a Tick() and copied to the GPU when its cells were modified
+ *          before a Tick(). Consecutive generations thus never leave the GPU.
+ *
+ *          The OpenGL context is created headless via EGL and requires an
+ *          OpenGL 4.3 capable driver. The context is only bound to the calling
+ *          thread while the GpuBoard accesses the GPU, the GpuBoard may thus
+ *          be used from any one thread at a time. The GpuBoard is only
+ *          available when the project is configured with GOL_GPU.
+ */
+class GpuBoard : public Engine {
+ public:
+  /**
+   * \brief Construct an MxN board of dead cells.
+   * \param [in] num_rows Number of rows in the board.
+   * \param [in] num_cols Number of columns in the board.
+   * \param [in] wrap Wrap around the board edges.
+   * \param [in] rule Birth/survival rule applied on each Tick().
+   * \throws std::runtime_error When no OpenGL 4.3 context can be created or
+   *         the board does not fit in GPU memory.
+   */
+  [[nodiscard]] GpuBoard(std::size_t num_rows, std::size_t num_cols,
+                         bool wrap = false, const Rule &rule = Rule());
+  GpuBoard() = delete;
+  ~GpuBoard() override;
+
+  GpuBoard(const GpuBoard &) = delete;
+  GpuBoard &operator=(const GpuBoard &) = delete;
+  GpuBoard(GpuBoard &&) = delete;
+  GpuBoard &operator=(GpuBoard &&) = delete;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override;
+
+  void SetCell(std::size_t row, std::size_t col, bool alive) override;
+
+  [[nodiscard]] std::uint64_t Population() const noexcept override;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+ private:
+  /**
+   * \brief Copy the current generation from the GPU if it changed since the
+   *        last copy.
+   */
+  void Download() const noexcept;
+
+  /**
+   * \brief Release the GPU resources and the OpenGL context.
+   */
+  void Release() noexcept;
+
+  std::size_t rows_ = 0;                     /**< Board rows. */
+  std::size_t cols_ = 0;                     /**< Board columns. */
+  bool wrap_ = false;                        /**< Wrap around board edges. */
+  Rule rule_;                                /**< Birth/survival rule. */
+  std::uint64_t generation_ = 0;             /**< Number of Tick() calls. */
+  mutable std::vector<std::uint32_t> cells_; /**< Host copy, one per cell. */
+  mutable bool host_stale_ = false;          /**< GPU holds a newer board. */
+  bool device_stale_ = false;                /**< Host holds a newer board. */
+  void *display_ = nullptr;                  /**< EGL display. */
+  void *context_ = nullptr;                  /**< EGL context. */
+  unsigned program_ = 0;                     /**< Compute shader program. */
+  unsigned buffers_[2] = {0, 0};             /**< Current/next generation. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,24 @@
+#ifndef HASH_H_
+#define HASH_H_
+
+#include <cstdint>
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Return \p seed with \p value mixed into it.
+ * \details Combines hashes the way boost::hash_combine does, such that the
+ *          result depends on the order in which the values are combined.
+ * \param [in] seed The hash of the values combined so far.
+ * \param [in] value The hash of the next value.
+ */
+[[nodiscard]] constexpr std::uint64_t HashCombine(
+    std::uint64_t seed, std::uint64_t value) noexcept {
+  return seed ^ (value + 0x9e3779b97f4a7c15 + (seed << 6) + (seed >> 2));
+}
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,154 @@
+#ifndef HASHLIFE_H_
+#define HASHLIFE_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <unordered_map>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A HashLife implementation of the Game of Life.
+ * \details The HashLifeBoard stores the universe as a quadtree of canonical
+ *          (hash consed) nodes and memoizes the future of each node. Repeated
+ *          structure in space and time is therefore only ever computed once
+ *          which makes it feasible to run large patterns for a very large
+ *          number of generations.
+ *
+ *          Unlike the GameOfLifeBoard, the HashLifeBoard universe is
+ *          unbounded. The MxN window exposed via the Engine interface is the
+ *          region whose top left corner lies at the origin of the universe.
+ *          Cells that leave the window continue to evolve off screen.
+ */
+class HashLifeBoard : public Engine {
+ public:
+  /**
+   * \brief Construct an empty universe with an MxN visible window.
+   * \param [in] num_rows Number of rows in the visible window.
+   * \param [in] num_cols Number of columns in the visible window.
+   * \param [in] rule Birth/survival rule applied on each Tick().
+   * \throws std::invalid_argument When \p rule causes births in empty space
+   *         (i.e., B0 rules).
+   */
+  [[nodiscard]] HashLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                              const Rule &rule = Rule());
+  HashLifeBoard() = delete;
+  ~HashLifeBoard() override = default;
+
+  HashLifeBoard(const HashLifeBoard &) = delete;
+  HashLifeBoard &operator=(const HashLifeBoard &) = delete;
+  HashLifeBoard(HashLifeBoard &&) = default;
+  HashLifeBoard &operator=(HashLifeBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override;
+
+  void SetCell(std::size_t row, std::size_t col, bool alive) override;
+
+  /**
+   * \brief Return the number of live cells in the entire universe.
+   * \details Cells outside of the visible window are included in the count.
+   */
+  [[nodiscard]] std::uint64_t Population() const noexcept override {
+    return root_->population;
+  }
+
+  /**
+   * \brief Return the live cells of the entire universe in row major order.
+   */
+  [[nodiscard]] std::vector<LiveCell> LiveCells() const override;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+  /**
+   * \brief Advance the simulation by \p generations generations.
+   * \details The generations are computed in steps of powers of two, each
+   *          taking time roughly proportional to the size of the pattern
+   *          rather than to the number of generations.
+   */
+  void Advance(std::uint64_t generations) override;
+
+ private:
+  /**
+   * \brief A square quadtree node covering 2^level x 2^level cells.
+   * \details Level 0 nodes are single cells. All other nodes are made up of
+   *          four level - 1 quadrants.
+   */
+  struct Node {
+    Node *nw = nullptr;           /**< Northwest quadrant. */
+    Node *ne = nullptr;           /**< Northeast quadrant. */
+    Node *sw = nullptr;           /**< Southwest quadrant. */
+    Node *se = nullptr;           /**< Southeast quadrant. */
+    int level = 0;                /**< Node is 2^level cells wide. */
+    std::uint64_t population = 0; /**< Number of live cells in the node. */
+    Node *next = nullptr;         /**< Memoized center 2^next_log2 gens on. */
+    int next_log2 = -1;           /**< Step size of the memoized result. */
+  };
+
+  /**
+   * \brief Key used to canonicalize nodes by their four quadrants.
+   */
+  struct NodeKey {
+    const Node *nw = nullptr;
+    const Node *ne = nullptr;
+    const Node *sw = nullptr;
+    const Node *se = nullptr;
+
+    bool operator==(const NodeKey &other) const noexcept = default;
+  };
+
+  struct NodeKeyHash {
+    std::size_t operator()(const NodeKey &key) const noexcept;
+  };
+
+  [[nodiscard]] Node *Join(Node *nw, Node *ne, Node *sw, Node *se);
+  [[nodiscard]] Node *Empty(int level);
+  [[nodiscard]] Node *Centre(Node *node);
+  [[nodiscard]] Node *Life4x4(Node *node);
+  [[nodiscard]] Node *Successor(Node *node, int step_log2);
+  [[nodiscard]] Node *SetCell(Node *node, std::int64_t x, std::int64_t y,
+                              bool alive);
+  [[nodiscard]] bool GetCell(const Node *node, std::int64_t x,
+                             std::int64_t y) const noexcept;
+  void AppendLiveCells(const Node *node, std::int64_t row, std::int64_t col,
+                       std::vector<LiveCell> &cells) const;
+  [[nodiscard]] Node *Copy(Node *node,
+                           std::unordered_map<Node *, Node *> &copies);
+
+  void CollectGarbage();
+  void Step(int step_log2);
+
+  std::size_t rows_ = 0;         /**< Visible window rows. */
+  std::size_t cols_ = 0;         /**< Visible window columns. */
+  Rule rule_;                    /**< Birth/survival rule. */
+  std::deque<Node> nodes_;       /**< Backing storage for all nodes. */
+  std::vector<Node *> empty_;    /**< Empty node of each level. */
+  Node *dead_ = nullptr;         /**< Dead cell leaf. */
+  Node *live_ = nullptr;         /**< Live cell leaf. */
+  Node *root_ = nullptr;         /**< Root of the universe quadtree. */
+  std::uint64_t generation_ = 0; /**< Generations computed. */
+  std::unordered_map<NodeKey, Node *, NodeKeyHash>
+      index_; /**< Canonical node lookup table. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
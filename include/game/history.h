@@ -0,0 +1,89 @@
+#ifndef HISTORY_H_
+#define HISTORY_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <memory>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/pipeline.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A bounded history of the most recent frames.
+ * \details Frames are run length encoded before being stored, boards made of
+ *          large empty or filled regions thus take little memory. Once the
+ *          encoded frames exceed the memory cap, the oldest frames are
+ *          dropped.
+ */
+class History {
+ public:
+  static constexpr std::size_t kDefaultCapBytes = 64 << 20; /**< 64 MiB. */
+
+  /**
+   * \brief Construct an empty history.
+   * \param [in] cap_bytes Maximum number of bytes taken by encoded frames. A
+   *                       cap of 0 disables the history.
+   */
+  [[nodiscard]] explicit History(std::size_t cap_bytes = kDefaultCapBytes)
+      : cap_bytes_(cap_bytes) {}
+  ~History() = default;
+
+  History(const History &) = default;
+  History &operator=(const History &) = default;
+  History(History &&) = default;
+  History &operator=(History &&) = default;
+
+  /**
+   * \brief Append \p frame as the newest frame.
+   * \details The oldest frames are dropped until the history fits within the
+   *          memory cap. A frame that does not fit by itself is not stored.
+   */
+  void Push(const Frame &frame);
+
+  /**
+   * \brief Return the frame at \p index, 0 being the oldest frame.
+   */
+  [[nodiscard]] Frame At(std::size_t index) const;
+
+  /**
+   * \brief Return the number of stored frames.
+   */
+  [[nodiscard]] std::size_t Size() const noexcept { return frames_.size(); }
+
+  /**
+   * \brief Return the number of bytes taken by the encoded frames.
+   */
+  [[nodiscard]] std::size_t Bytes() const noexcept { return bytes_; }
+
+  /**
+   * \brief Drop all frames.
+   */
+  void Clear() noexcept;
+
+ private:
+  /**
+   * \brief A run length encoded frame.
+   */
+  struct EncodedFrame {
+    std::size_t rows = 0;                       /**< Frame rows. */
+    std::size_t cols = 0;                       /**< Frame columns. */
+    std::uint64_t generation = 0;               /**< Frame generation. */
+    std::uint64_t population = 0;               /**< Frame population. */
+    std::shared_ptr<const Automaton> automaton; /**< Multi-state automaton. */
+    std::vector<std::uint8_t> runs;             /**< Varint encoded runs. */
+  };
+
+  std::size_t cap_bytes_ = 0;       /**< Memory cap in bytes. */
+  std::size_t bytes_ = 0;           /**< Bytes taken by frames_. */
+  std::deque<EncodedFrame> frames_; /**< Encoded frames oldest first. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,76 @@
+#ifndef HOOKS_H_
+#define HOOKS_H_
+
+#include <cstddef>
+#include <functional>
+#include <utility>
+#include <vector>
+
+#include "game/pipeline.h"
+#include "game/stats.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Callback observing a generation.
+ * \details The hook receives the frame of the generation and the statistics of
+ *          the step leading up to it. The first generation observed comes
+ *          with statistics counting neither births nor deaths.
+ */
+using GenerationHook =
+    std::function<void(const Frame &frame, const StepStats &step)>;
+
+/**
+ * \brief The hooks notified of each generation computed by the game loop.
+ * \details Loggers, recorders and analytics attach to the loop by registering
+ *          a hook instead of being part of it. Hooks are called in the order
+ *          they were registered in, those registered later thus see the
+ *          effects of the earlier ones.
+ */
+class GenerationHooks {
+ public:
+  using Handle = std::size_t; /**< Identifies a registered hook. */
+
+  GenerationHooks() = default;
+  ~GenerationHooks() = default;
+
+  GenerationHooks(const GenerationHooks &) = default;
+  GenerationHooks &operator=(const GenerationHooks &) = default;
+  GenerationHooks(GenerationHooks &&) = default;
+  GenerationHooks &operator=(GenerationHooks &&) = default;
+
+  /**
+   * \brief Register \p hook to be called on each generation.
+   * \returns The handle by which the hook is removed.
+   */
+  Handle OnGeneration(GenerationHook hook);
+
+  /**
+   * \brief Remove the hook registered under \p handle, if any.
+   */
+  void Remove(Handle handle) noexcept;
+
+  /**
+   * \brief Call the registered hooks with \p frame and \p step.
+   * \throws Any exception thrown by a hook, the remaining hooks are then not
+   *         called.
+   */
+  void Notify(const Frame &frame, const StepStats &step) const;
+
+  /**
+   * \brief Return the number of registered hooks.
+   */
+  [[nodiscard]] std::size_t Size() const noexcept { return hooks_.size(); }
+
+ private:
+  using Entry = std::pair<Handle, GenerationHook>; /**< A registered hook. */
+
+  Handle next_handle_ = 0;   /**< Handle of the next registered hook. */
+  std::vector<Entry> hooks_; /**< Hooks in call order. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
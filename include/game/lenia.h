@@ -0,0 +1,178 @@
+#ifndef LENIA_H_
+#define LENIA_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "game/board.h"
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Parameters of the kernel and growth function of Lenia.
+ * \details The defaults are those of Orbium, the glider of Lenia.
+ */
+struct LeniaParameters {
+  int radius = 13;      /**< Radius of the kernel in cells. */
+  float mu = 0.15f;     /**< Average of the fastest growth. */
+  float sigma = 0.015f; /**< Width of the growth function. */
+  float dt = 0.1f;      /**< Fraction of the growth applied per Tick(). */
+};
+
+/**
+ * \brief A Lenia board of continuous cell states.
+ * \details Each cell holds a value in [0, 1]. Every Tick() convolves the
+ *          board with a ring shaped kernel of the given radius, a weighted
+ *          average of the values around each cell. The growth function maps
+ *          the average to a rate in [-1, 1], largest when the average equals
+ *          \c mu and falling off with \c sigma, and each value grows by a
+ *          fraction \c dt of that rate.
+ *
+ *          Through the Engine interface, the values are quantized to kLevels
+ *          states, state 0 being empty and state kLevels - 1 a value of 1.
+ *          Cells in a state above 0 are alive.
+ */
+class LeniaBoard : public Engine {
+ public:
+  /** Number of states the values are quantized to. */
+  static constexpr std::uint8_t kLevels = 16;
+
+  /**
+   * \brief Construct an MxN board of empty cells.
+   * \param [in] num_rows Number of rows.
+   * \param [in] num_cols Number of columns.
+   * \param [in] wrap When \c true, the board edges wrap around. Otherwise,
+   *                  cells beyond the edges are considered empty.
+   * \param [in] params Parameters of the kernel and growth function.
+   * \throws std::invalid_argument When the radius is not positive.
+   */
+  [[nodiscard]] LeniaBoard(std::size_t num_rows, std::size_t num_cols,
+                           bool wrap = false,
+                           const LeniaParameters &params = {});
+  LeniaBoard() = delete;
+  ~LeniaBoard() override = default;
+
+  LeniaBoard(const LeniaBoard &) = default;
+  LeniaBoard &operator=(const LeniaBoard &) = default;
+  LeniaBoard(LeniaBoard &&) = default;
+  LeniaBoard &operator=(LeniaBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override {
+    return cells_.Rows();
+  }
+  [[nodiscard]] std::size_t Cols() const noexcept override {
+    return cells_.Cols();
+  }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override {
+    return cells_.IsAlive(row, col);
+  }
+
+  /**
+   * \brief Set the value of the cell at (\p row, \p col) to 1 if \p alive,
+   *        0 otherwise.
+   */
+  void SetCell(std::size_t row, std::size_t col, bool alive) override {
+    SetValue(row, col, (alive) ? 1.0f : 0.0f);
+  }
+
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return kLevels;
+  }
+
+  [[nodiscard]] std::uint8_t State(std::size_t row,
+                                   std::size_t col) const noexcept override {
+    return cells_.State(row, col);
+  }
+
+  /**
+   * \brief Set the value of the cell at (\p row, \p col) to the value
+   *        \p state is quantized from.
+   */
+  void SetState(std::size_t row, std::size_t col,
+                std::uint8_t state) override {
+    SetValue(row, col, static_cast<float>(state) / (kLevels - 1));
+  }
+
+  [[nodiscard]] std::uint64_t Population() const noexcept override {
+    return cells_.Population();
+  }
+
+  [[nodiscard]] std::optional<CellBounds> LiveBounds() const override {
+    return cells_.LiveBounds();
+  }
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+  /**
+   * \brief Return the value of the cell at (\p row, \p col).
+   */
+  [[nodiscard]] float Value(std::size_t row, std::size_t col) const noexcept {
+    return values_[Index(row, col)];
+  }
+
+  /**
+   * \brief Set the value of the cell at (\p row, \p col), clamped to [0, 1].
+   */
+  void SetValue(std::size_t row, std::size_t col, float value);
+
+  /**
+   * \brief Return the kernel and growth function parameters.
+   */
+  [[nodiscard]] const LeniaParameters &GetParameters() const noexcept {
+    return params_;
+  }
+
+  /**
+   * \brief Return the quantized states seen through the Engine interface.
+   */
+  [[nodiscard]] const GameOfLifeBoard &Cells() const noexcept {
+    return cells_;
+  }
+
+  /**
+   * \brief Return the display colors of states 1 to kLevels - 1 as 0xRRGGBB
+   *        values, from dim to bright.
+   */
+  [[nodiscard]] static std::vector<std::uint32_t> Colors();
+
+ private:
+  struct KernelCell {
+    std::ptrdiff_t offset = 0;
+    float weight = 0.0f;
+  };
+
+  [[nodiscard]] std::size_t Index(std::size_t row,
+                                  std::size_t col) const noexcept {
+    return (row * Cols()) + col;
+  }
+
+  void Pad();
+
+  LeniaParameters params_;         /**< Kernel and growth parameters. */
+  bool wrap_ = false;              /**< Wrap around the edges. */
+  std::vector<KernelCell> kernel_; /**< Normalized kernel weights. */
+  std::vector<float> values_;      /**< Values of the cells. */
+  std::vector<float> next_;        /**< Values of the next generation. */
+  std::vector<float> padded_;      /**< Values bordered by the edge cells. */
+  GameOfLifeBoard cells_;          /**< Quantized values. */
+  std::uint64_t generation_ = 0;   /**< Number of Tick() calls. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,182 @@
+#ifndef NEIGHBORHOOD_H_
+#define NEIGHBORHOOD_H_
+
+#include <cstddef>
+#include <string>
+#include <vector>
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Shapes of the neighborhood of a cell.
+ */
+enum class NeighborhoodShape {
+  kMoore,      /**< Square of cells around the cell. */
+  kVonNeumann, /**< Diamond of cells within a Manhattan distance. */
+  kCircular,   /**< Disk of cells within a Euclidean distance. */
+  kHexagonal,  /**< Hexagon of cells on a grid skewed into rows. */
+};
+
+/**
+ * \brief Position of a neighbor relative to a cell.
+ */
+struct CellOffset {
+  int row = 0; /**< Rows below the cell, negative above. */
+  int col = 0; /**< Columns right of the cell, negative to the left. */
+
+  bool operator==(const CellOffset &other) const noexcept = default;
+};
+
+/**
+ * \brief The cells whose states are counted to compute the next state of a
+ *        cell.
+ * \details A neighborhood is a shape of a given radius around the cell. The
+ *          default neighborhood is the Moore neighborhood of radius 1, the
+ *          eight cells surrounding the cell. A von Neumann neighborhood of
+ *          radius r holds the cells within a Manhattan distance of r, a
+ *          circular neighborhood the cells within a Euclidean distance of
+ *          r + 1/2. The cell itself is only counted when the neighborhood
+ *          includes the center.
+ *
+ *          Hexagonal neighborhoods treat the board as a hexagonal grid skewed
+ *          into rows, as Golly does: the six neighbors of a cell are those
+ *          of its Moore neighborhood but the northeast and southwest ones. A
+ *          hexagonal neighborhood of radius r holds the cells within r steps
+ *          on that grid.
+ */
+class CellNeighborhood {
+ public:
+  /** Largest supported radius. */
+  static constexpr int kMaxRadius = 10;
+
+  /** Largest number of cells in a neighborhood. */
+  static constexpr int kMaxSize = (2 * kMaxRadius + 1) * (2 * kMaxRadius + 1);
+
+  /**
+   * \brief Construct the Moore neighborhood of radius 1.
+   */
+  CellNeighborhood();
+
+  /**
+   * \brief Construct a neighborhood.
+   * \param [in] shape Shape of the neighborhood.
+   * \param [in] radius Distance of the farthest neighbors from the cell.
+   * \param [in] include_center When \c true, the cell counts itself.
+   * \throws std::invalid_argument When \p radius does not lie in
+   *         [1, kMaxRadius].
+   */
+  CellNeighborhood(NeighborhoodShape shape, int radius,
+                   bool include_center = false);
+
+  /**
+   * \brief Return the shape of the neighborhood.
+   */
+  [[nodiscard]] NeighborhoodShape Shape() const noexcept { return shape_; }
+
+  /**
+   * \brief Return the radius of the neighborhood.
+   */
+  [[nodiscard]] int Radius() const noexcept { return radius_; }
+
+  /**
+   * \brief Return \c true if the cell counts itself.
+   */
+  [[nodiscard]] bool IncludesCenter() const noexcept {
+    return include_center_;
+  }
+
+  /**
+   * \brief Return \c true if this is the Moore neighborhood of radius 1
+   *        without the center, the neighborhood of Life-like rules.
+   */
+  [[nodiscard]] bool IsMoore() const noexcept {
+    return (NeighborhoodShape::kMoore == shape_) && (1 == radius_) &&
+           !include_center_;
+  }
+
+  /**
+   * \brief Return the positions of the neighbors relative to the cell.
+   */
+  [[nodiscard]] const std::vector<CellOffset> &Offsets() const noexcept {
+    return offsets_;
+  }
+
+  /**
+   * \brief Return the number of cells in the neighborhood.
+   */
+  [[nodiscard]] int Size() const noexcept {
+    return static_cast<int>(offsets_.size());
+  }
+
+  /**
+   * \brief Return the number of live cells in the neighborhood of the cell at
+   *        (\p row, \p col) of a \p rows x \p cols board.
+   * \param [in] row Row of the cell.
+   * \param [in] col Column of the cell.
+   * \param [in] rows Number of board rows.
+   * \param [in] cols Number of board columns.
+   * \param [in] wrap When \c true, the board edges wrap around. Otherwise,
+   *                  cells beyond the edges are considered dead.
+   * \param [in] is_alive Callable returning \c true if the cell at the given
+   *                      row and column is alive.
+   */
+  template <typename IsAlive>
+  [[nodiscard]] int CountLive(std::size_t row, std::size_t col,
+                              std::size_t rows, std::size_t cols, bool wrap,
+                              const IsAlive &is_alive) const {
+    const auto kRows = static_cast<long long>(rows);
+    const auto kCols = static_cast<long long>(cols);
+    int count = 0;
+    for (const CellOffset &offset : offsets_) {
+      long long r = static_cast<long long>(row) + offset.row;
+      long long c = static_cast<long long>(col) + offset.col;
+      if (wrap) {
+        r = ((r % kRows) + kRows) % kRows;
+        c = ((c % kCols) + kCols) % kCols;
+      } else if ((r < 0) || (c < 0) || (r >= kRows) || (c >= kCols)) {
+        continue;
+      }
+      count += (is_alive(static_cast<std::size_t>(r),
+                         static_cast<std::size_t>(c)))
+                   ? 1
+                   : 0;
+    }
+    return count;
+  }
+
+  bool operator==(const CellNeighborhood &other) const noexcept {
+    return (shape_ == other.shape_) && (radius_ == other.radius_) &&
+           (include_center_ == other.include_center_);
+  }
+
+ private:
+  [[nodiscard]] bool Contains(int dr, int dc) const noexcept;
+
+  NeighborhoodShape shape_ = NeighborhoodShape::kMoore; /**< Shape. */
+  int radius_ = 1;                  /**< Distance of the farthest cells. */
+  bool include_center_ = false;     /**< The cell counts itself. */
+  std::vector<CellOffset> offsets_; /**< Positions of the neighbors. */
+};
+
+/**
+ * \brief Return the neighborhood shape named \p name.
+ * \throws std::invalid_argument When no shape is named \p name.
+ */
+[[nodiscard]] NeighborhoodShape ParseNeighborhoodShape(
+    const std::string &name);
+
+/**
+ * \brief Return the names of all neighborhood shapes.
+ */
+[[nodiscard]] std::vector<std::string> NeighborhoodShapeNames();
+
+/**
+ * \brief Return the name of \p shape.
+ */
+[[nodiscard]] std::string ToString(NeighborhoodShape shape);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
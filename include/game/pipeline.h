@@ -0,0 +1,123 @@
+#ifndef PIPELINE_H_
+#define PIPELINE_H_
+
+#include <condition_variable>
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <exception>
+#include <mutex>
+#include <optional>
+#include <stop_token>
+#include <thread>
+#include <vector>
+
+#include "game/board.h"
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A copy of the visible window of an Engine at some generation.
+ * \details The live cells an unbounded universe holds beyond the window are
+ *          kept aside, such that frames tell the whole universe apart.
+ */
+struct Frame {
+  /**
+   * \brief Copy the visible window of \p engine.
+   */
+  [[nodiscard]] explicit Frame(const Engine &engine);
+
+  GameOfLifeBoard cells;         /**< Cells of the visible window. */
+  std::vector<LiveCell> outside; /**< Live cells beyond the window. */
+  std::uint64_t generation = 0;  /**< Generation of the copied engine. */
+  std::uint64_t population = 0;  /**< Population of the copied engine. */
+};
+
+/**
+ * \brief Advances an Engine on a worker thread.
+ * \details While running, the worker repeatedly advances the engine and queues
+ *          a Frame of each generation. The queue is bounded, the worker blocks
+ *          once it is full until the oldest frame is popped. This lets the
+ *          caller render and handle input while the next generations are being
+ *          computed.
+ *
+ *          The engine must not be accessed by the caller while the pipeline is
+ *          running. Because the worker runs ahead of the popped frames, the
+ *          engine may be up to capacity + 1 generations ahead of the last
+ *          popped frame once stopped.
+ */
+class Pipeline {
+ public:
+  static constexpr std::size_t kDefaultCapacity = 2; /**< Queued frames. */
+
+  /**
+   * \brief Construct a stopped pipeline advancing \p engine.
+   * \param [in] engine The engine advanced by the worker.
+   * \param [in] capacity Maximum number of queued frames.
+   */
+  [[nodiscard]] explicit Pipeline(Engine &engine,
+                                  std::size_t capacity = kDefaultCapacity);
+  Pipeline() = delete;
+  ~Pipeline() { Stop(); }
+
+  Pipeline(const Pipeline &) = delete;
+  Pipeline &operator=(const Pipeline &) = delete;
+  Pipeline(Pipeline &&) = delete;
+  Pipeline &operator=(Pipeline &&) = delete;
+
+  /**
+   * \brief Start advancing the engine on the worker thread.
+   * \details Does nothing if the pipeline is already running.
+   */
+  void Start();
+
+  /**
+   * \brief Stop the worker thread and discard the queued frames.
+   * \details Blocks until the generation in progress, if any, is complete.
+   */
+  void Stop() noexcept;
+
+  /**
+   * \brief Return \c true if the worker thread is running.
+   */
+  [[nodiscard]] bool Running() const noexcept { return worker_.joinable(); }
+
+  /**
+   * \brief Return \c true if a frame is queued or the worker failed, i.e. if
+   *        Pop() returns without waiting.
+   */
+  [[nodiscard]] bool Ready();
+
+  /**
+   * \brief Remove and return the oldest queued frame, waiting for the worker
+   *        to produce one if the queue is empty.
+   * \throws std::logic_error When the pipeline is not running.
+   * \throws Any exception thrown by the engine while advancing.
+   */
+  [[nodiscard]] Frame Pop();
+
+  /**
+   * \brief Remove and return the oldest queued frame if any.
+   * \throws Any exception thrown by the engine while advancing.
+   */
+  [[nodiscard]] std::optional<Frame> TryPop();
+
+ private:
+  void Run(std::stop_token stop_token);
+
+  Engine *engine_ = nullptr;              /**< Engine advanced by the worker. */
+  std::size_t capacity_ = 0;              /**< Maximum queued frames. */
+  std::deque<Frame> frames_;              /**< Frames oldest first. */
+  std::exception_ptr error_;              /**< Error raised by the worker. */
+  std::mutex mutex_;                      /**< Guards frames_ and error_. */
+  std::condition_variable_any not_full_;  /**< Signaled on pop. */
+  std::condition_variable_any not_empty_; /**< Signaled on push or error. */
+  std::jthread worker_;                   /**< Worker thread. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,103 @@
+#ifndef RASTER_H_
+#define RASTER_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A cell of a rasterized shape.
+ * \details Shapes may extend beyond the board edges, their cells are then
+ *          left out when painted.
+ */
+struct RasterCell {
+  std::int64_t row = 0; /**< Board row, negative above the board. */
+  std::int64_t col = 0; /**< Board column, negative left of the board. */
+
+  auto operator<=>(const RasterCell &other) const noexcept = default;
+};
+
+using RasterCells = std::vector<RasterCell>;
+
+/**
+ * \brief Axes across the board center along which cells are mirrored.
+ */
+enum class Symmetry {
+  kNone,       /**< Cells are not mirrored. */
+  kHorizontal, /**< Mirrored across the horizontal axis. */
+  kVertical,   /**< Mirrored across the vertical axis. */
+  kFourFold,   /**< Mirrored across both axes. */
+  kEightFold,  /**< Mirrored across both axes and both diagonals. */
+};
+
+/**
+ * \brief Return the symmetry named \p name.
+ * \throws std::invalid_argument When no symmetry is named \p name.
+ */
+[[nodiscard]] Symmetry ParseSymmetry(const std::string &name);
+
+/**
+ * \brief Return the names of all symmetries.
+ */
+[[nodiscard]] std::vector<std::string> SymmetryNames();
+
+/**
+ * \brief Return the name of \p symmetry.
+ */
+[[nodiscard]] std::string ToString(Symmetry symmetry);
+
+/**
+ * \brief Return the cells of the line from \p from to \p to, both included.
+ * \details Consecutive cells of the line touch at least diagonally.
+ */
+[[nodiscard]] RasterCells RasterLine(RasterCell from, RasterCell to);
+
+/**
+ * \brief Return the cells of the outline of the rectangle with opposite
+ *        corners \p corner and \p other.
+ */
+[[nodiscard]] RasterCells RasterRectangle(RasterCell corner, RasterCell other);
+
+/**
+ * \brief Return the cells of the disk of radius \p radius around \p center.
+ * \details The disk holds the cells within a Euclidean distance of
+ *          \p radius + 1/2 of the center, a disk of radius 0 is the center
+ *          alone.
+ */
+[[nodiscard]] RasterCells RasterDisk(RasterCell center, std::int64_t radius);
+
+/**
+ * \brief Return the cells within \p radius of any cell of \p cells.
+ * \details Each cell is replaced by the RasterDisk() of radius \p radius
+ *          around it. The returned cells are sorted and unique.
+ */
+[[nodiscard]] RasterCells Thicken(const RasterCells &cells,
+                                  std::int64_t radius);
+
+/**
+ * \brief Return \p cells together with their mirror images across the
+ *        axes of \p symmetry through the center of a \p rows by \p cols
+ *        board.
+ * \details The diagonals run at 45 degrees through the center, on boards
+ *          whose sides differ in parity the images across them are rounded
+ *          towards the top left. The returned cells are sorted and unique.
+ */
+[[nodiscard]] RasterCells Mirror(const RasterCells &cells, Symmetry symmetry,
+                                 std::size_t rows, std::size_t cols);
+
+/**
+ * \brief Set the cells of \p board covered by \p cells to \p state.
+ * \details Cells beyond the board edges are left out.
+ */
+void Paint(const RasterCells &cells, std::uint8_t state, Engine &board);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
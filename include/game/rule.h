@@ -0,0 +1,169 @@
+#ifndef RULE_H_
+#define RULE_H_
+
+#include <bitset>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "game/neighborhood.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A Life-like cellular automaton rule in B/S notation.
+ * \details A rule specifies the live neighbor counts that cause a dead cell to
+ *          be born and the counts that allow a live cell to survive. For
+ *          example, Conway's Game of Life is 'B3/S23', HighLife is 'B36/S23'
+ *          and Seeds is 'B2/S'.
+ *
+ *          Neighbors are counted in the rule's CellNeighborhood, the Moore
+ *          neighborhood of radius 1 unless the rulestring selects another one.
+ *
+ *          Rules of the Moore neighborhood may also be isotropic
+ *          non-totalistic, where a cell's fate depends on the arrangement of
+ *          its live neighbors rather than only their number. Such rules are
+ *          written in Hensel notation, where letters following a count select
+ *          the arrangements of that many neighbors, up to rotations and
+ *          reflections, e.g., 'B2-a/S12'.
+ */
+class Rule {
+ public:
+  static constexpr int kMaxNeighbors = 8; /**< Size of the Moore neighborhood. */
+
+  /** Largest neighbor count of any neighborhood. */
+  static constexpr int kMaxCount = CellNeighborhood::kMaxSize;
+
+  /** Number of arrangements of live cells in the Moore neighborhood. */
+  static constexpr int kNumConfigs = 256;
+
+  /**
+   * \brief Construct the Conway's Game of Life rule (B3/S23).
+   */
+  Rule() noexcept;
+
+  /**
+   * \brief Construct a rule from a B/S or HROT rulestring.
+   * \details The 'B' and 'S' components may appear in either order and are
+   *          case insensitive (e.g., 'B36/S23', 's23/b36'). A trailing 'V'
+   *          selects the von Neumann neighborhood of radius 1 (e.g.,
+   *          'B2/S013V') and a trailing 'H' the hexagonal neighborhood of
+   *          radius 1 (e.g., 'B2/S34H'). In the Moore neighborhood, a count
+   *          may be followed by Hensel letters to only select some
+   *          arrangements of its neighbors, or by '-' and the letters of the
+   *          arrangements to leave out (e.g., 'B2-a/S12', 'B3/S2ae3').
+   *
+   *          Rules with larger neighborhoods are given in HROT notation as
+   *          comma separated components, e.g., 'R2,C0,M0,S5-8,B6-7,NM'. 'R'
+   *          is the radius, 'C' the number of states (0 or 2), 'M' is 1 when
+   *          the cell counts itself, 'S' and 'B' list neighbor counts or
+   *          ranges of counts written as 'a-b' or 'a..b', and 'N' is the shape
+   *          of the neighborhood, 'M' (Moore), 'N' (von Neumann), 'C'
+   *          (circular) or 'H' (hexagonal). Only 'R', 'S' and 'B' are
+   *          required.
+   * \param [in] rulestring A rulestring in B/S or HROT notation.
+   * \throws std::invalid_argument When \p rulestring is malformed.
+   */
+  explicit Rule(const std::string& rulestring);
+
+  /**
+   * \brief Return \c true if a dead cell with \p num_neighbors live neighbors
+   *        is born.
+   * \details For non-totalistic rules, \c true if a dead cell is born in some
+   *          arrangement of \p num_neighbors live neighbors.
+   */
+  [[nodiscard]] bool Born(int num_neighbors) const noexcept {
+    return birth_[num_neighbors];
+  }
+
+  /**
+   * \brief Return \c true if a live cell with \p num_neighbors live neighbors
+   *        survives.
+   * \details For non-totalistic rules, \c true if a live cell survives in
+   *          some arrangement of \p num_neighbors live neighbors.
+   */
+  [[nodiscard]] bool Survives(int num_neighbors) const noexcept {
+    return survival_[num_neighbors];
+  }
+
+  /**
+   * \brief Return \c true if a dead cell whose live Moore neighbors are the
+   *        set bits of \p config is born.
+   * \details Bits 0 to 7 of \p config are the NW, N, NE, W, E, SW, S and SE
+   *          neighbors. Only meaningful for rules of the Moore neighborhood.
+   */
+  [[nodiscard]] bool BornIn(std::uint8_t config) const noexcept {
+    return birth_configs_[config];
+  }
+
+  /**
+   * \brief Return \c true if a live cell whose live Moore neighbors are the
+   *        set bits of \p config survives.
+   * \details See BornIn() for the layout of \p config.
+   */
+  [[nodiscard]] bool SurvivesIn(std::uint8_t config) const noexcept {
+    return survival_configs_[config];
+  }
+
+  /**
+   * \brief Return \c true if the fate of a cell only depends on the number of
+   *        its live neighbors.
+   */
+  [[nodiscard]] bool IsTotalistic() const noexcept { return totalistic_; }
+
+  /**
+   * \brief Return the neighborhood in which neighbors are counted.
+   */
+  [[nodiscard]] const CellNeighborhood& GetNeighborhood() const noexcept {
+    return neighborhood_;
+  }
+
+  /**
+   * \brief Return the rulestring of this rule in canonical notation.
+   * \details Rules of the Moore neighborhood of radius 1 are written in B/S
+   *          notation, rules of the von Neumann and hexagonal neighborhoods of
+   *          radius 1 get a trailing 'V' or 'H', and all other rules are
+   *          written in HROT notation. Non-totalistic rules list the Hensel
+   *          letters of each count in alphabetical order, or the letters left
+   *          out after a '-' when shorter.
+   */
+  [[nodiscard]] std::string ToString() const;
+
+ private:
+  using NeighborSet = std::bitset<kMaxCount + 1>;
+  using ConfigSet = std::bitset<kNumConfigs>;
+
+  void ParseHrot(const std::string& rulestring);
+  void FillConfigs() noexcept;
+
+  NeighborSet birth_;             /**< Neighbor counts resulting in a birth. */
+  NeighborSet survival_;          /**< Neighbor counts resulting in survival. */
+  ConfigSet birth_configs_;       /**< Arrangements resulting in a birth. */
+  ConfigSet survival_configs_;    /**< Arrangements resulting in survival. */
+  bool totalistic_ = true;        /**< Only neighbor counts matter. */
+  CellNeighborhood neighborhood_; /**< Cells counted as neighbors. */
+};
+
+/**
+ * \brief Return the rule named by a preset or given as a B/S rulestring.
+ * \details The presets are 'life' (B3/S23), 'highlife' (B36/S23), 'seeds'
+ *          (B2/S), 'daynight' (B3678/S34678), 'life34' (B34/S34),
+ *          'diamoeba' (B35678/S5678) and the Larger than Life rules 'bosco',
+ *          'majority', 'waffle' and 'globe'. Preset names are case
+ *          insensitive.
+ * \param [in] name A preset name or a rulestring in B/S notation.
+ * \throws std::invalid_argument When \p name is neither a preset nor a valid
+ *         rulestring.
+ */
+[[nodiscard]] Rule ParseRule(const std::string& name);
+
+/**
+ * \brief Return the names of all rule presets.
+ */
+[[nodiscard]] std::vector<std::string> RulePresetNames();
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,106 @@
+#ifndef RULE_TABLE_H_
+#define RULE_TABLE_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <istream>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A user defined automaton read from a Golly rule file.
+ * \details Rule files start with a line '@RULE name' followed by sections
+ *          each starting with a line '@SECTION'. The transitions are given
+ *          by either of the following sections:
+ *
+ *          - @TABLE lists transitions, one per line, as the state of the cell,
+ *            the states of its neighbors and the next state of the cell. The
+ *            header sets the number of states ('n_states:3'), the
+ *            neighborhood ('neighborhood:Moore', 'vonNeumann' or 'hexagonal')
+ *            and the symmetries applied to each transition ('symmetries:
+ *            rotate8'). Variables ('var a={0,1}') stand for any of a set of
+ *            states, a variable appearing more than once in a transition takes
+ *            the same state at each place. The first matching transition
+ *            applies, cells matching no transition keep their state.
+ *          - @TREE gives the transition function as a decision tree over the
+ *            states of the neighbors and the cell.
+ *
+ *          An optional @COLORS section sets the display colors of the states
+ *          by lines of the form 'state red green blue', or a gradient from
+ *          state 1 to the last state by a line of six numbers. Other sections
+ *          are ignored. Hexagonal neighborhoods follow Golly's convention of
+ *          a square grid whose NE and SW neighbors are left out.
+ */
+class RuleTable : public Automaton {
+ public:
+  /**
+   * \brief Parse a rule file.
+   * \param [in] input Stream holding the rule file.
+   * \throws std::invalid_argument When the rule file is malformed.
+   */
+  [[nodiscard]] explicit RuleTable(std::istream& input);
+
+  /**
+   * \brief Return the name given by the @RULE line.
+   */
+  [[nodiscard]] std::string Name() const override { return name_; }
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return num_states_;
+  }
+  [[nodiscard]] std::uint8_t Next(std::uint8_t state,
+                                  const Neighborhood& neighbors)
+      const noexcept override;
+
+  /**
+   * \brief Return the colors of the @COLORS section, states it leaves out
+   *        are colored by a gradient from red to yellow.
+   */
+  [[nodiscard]] std::vector<std::uint32_t> Colors() const override {
+    return colors_;
+  }
+
+ private:
+  using Word = std::uint64_t;
+
+  /* the center follows the neighbors in the inputs of a transition */
+  static constexpr int kCenter = kNumNeighbors;
+  static constexpr int kNumInputs = kNumNeighbors + 1;
+
+  void ParseTable(const std::vector<std::string>& lines, int first_line);
+  void ParseTree(const std::vector<std::string>& lines, int first_line);
+  void ParseColors(const std::vector<std::string>& lines, int first_line);
+
+  [[nodiscard]] std::uint8_t NextByTable(
+      const std::uint8_t* inputs) const noexcept;
+  [[nodiscard]] std::uint8_t NextByTree(
+      const std::uint8_t* inputs) const noexcept;
+
+  std::string name_;                  /**< Name of the rule. */
+  std::uint8_t num_states_ = 2;       /**< Number of states. */
+  std::vector<std::uint32_t> colors_; /**< Colors of states 1 and above. */
+  std::size_t words_per_mask_ = 0;    /**< Words of a transition mask. */
+  std::vector<Word> masks_;           /**< Transitions each input matches. */
+  std::vector<std::uint8_t> outputs_; /**< Next state of each transition. */
+  std::vector<int> tree_order_;       /**< Inputs in the order of the tree. */
+  std::vector<std::uint32_t> tree_;   /**< Children of the tree nodes. */
+  std::uint32_t tree_root_ = 0;       /**< First child of the root node. */
+};
+
+/**
+ * \brief Load the rule file \p filename.
+ * \throws std::invalid_argument When the file cannot be opened or is
+ *         malformed.
+ */
+[[nodiscard]] std::shared_ptr<const RuleTable> LoadRuleTable(
+    const std::string& filename);
+
+}  // namespace game
+}  // namespace gol
+
+#endif
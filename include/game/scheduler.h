@@ -0,0 +1,84 @@
+#ifndef SCHEDULER_H_
+#define SCHEDULER_H_
+
+#include <chrono>
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Schedules frames at a fixed interval.
+ * \details Each frame is due one interval after the previous one was due
+ *          rather than after it was shown, the time spent computing and
+ *          drawing a frame thus does not delay the next one. A frame shown
+ *          more than an interval late restarts the schedule from the time it
+ *          was shown instead of rushing the following frames to catch up.
+ *
+ *          The measured frame rate is a moving average of the time between
+ *          frames. The scheduler falls behind when the measured rate stays
+ *          below the target rate.
+ */
+class FrameScheduler {
+ public:
+  using Clock = std::chrono::steady_clock;
+
+  /**
+   * \brief Construct a scheduler whose first frame is due one \p interval
+   *        after \p now.
+   */
+  [[nodiscard]] explicit FrameScheduler(std::chrono::milliseconds interval,
+                                        Clock::time_point now = Clock::now());
+
+  /**
+   * \brief Change the interval between frames, the next frame is due one
+   *        \p interval after the previous frame was due.
+   */
+  void SetInterval(std::chrono::milliseconds interval) noexcept;
+
+  /**
+   * \brief Return \c true if the next frame is due at \p now.
+   */
+  [[nodiscard]] bool Due(Clock::time_point now) const noexcept {
+    return now >= due_;
+  }
+
+  /**
+   * \brief Return the time left until the next frame is due, rounded up to
+   *        whole milliseconds, or 0 when it is due.
+   */
+  [[nodiscard]] std::chrono::milliseconds TimeLeft(
+      Clock::time_point now) const noexcept;
+
+  /**
+   * \brief Record a frame shown at \p now and schedule the next one.
+   */
+  void Advance(Clock::time_point now) noexcept;
+
+  /**
+   * \brief Schedule the next frame one interval after \p now, e.g. when the
+   *        frames resume after a pause. The measured rate is forgotten.
+   */
+  void Reset(Clock::time_point now) noexcept;
+
+  /**
+   * \brief Return the measured number of frames per second, or 0 until a
+   *        frame is shown.
+   */
+  [[nodiscard]] double Rate() const noexcept;
+
+  /**
+   * \brief Return \c true if the measured rate is below the target rate.
+   */
+  [[nodiscard]] bool Behind() const noexcept;
+
+ private:
+  Clock::duration interval_; /**< Time between frames. */
+  Clock::time_point due_;    /**< When the next frame is due. */
+  Clock::time_point last_;   /**< When the last frame was shown. */
+  double average_s_ = 0.0;   /**< Average seconds between frames. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
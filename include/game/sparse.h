@@ -0,0 +1,111 @@
+#ifndef SPARSE_H_
+#define SPARSE_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <unordered_map>
+#include <unordered_set>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A Game of Life board storing only its live cells.
+ * \details The SparseBoard keeps the coordinates of the live cells in a hash
+ *          set. Tick() tallies the live neighbors of every cell adjacent to a
+ *          live cell and applies the Rule to the tallies. The cost of a
+ *          generation is proportional to the population rather than the board
+ *          area which makes the SparseBoard much faster than the
+ *          GameOfLifeBoard for mostly empty boards.
+ *
+ *          Like the HashLifeBoard, the SparseBoard universe is unbounded. The
+ *          MxN window exposed via the Engine interface is the region whose top
+ *          left corner lies at the origin of the universe. Cells that leave
+ *          the window continue to evolve off screen.
+ */
+class SparseBoard : public Engine {
+ public:
+  /**
+   * \brief Construct an empty universe with an MxN visible window.
+   * \param [in] num_rows Number of rows in the visible window.
+   * \param [in] num_cols Number of columns in the visible window.
+   * \param [in] rule Birth/survival rule applied on each Tick().
+   * \throws std::invalid_argument When \p rule causes births in empty space
+   *         (i.e., B0 rules).
+   */
+  [[nodiscard]] SparseBoard(std::size_t num_rows, std::size_t num_cols,
+                            const Rule &rule = Rule());
+  SparseBoard() = delete;
+  ~SparseBoard() override = default;
+
+  SparseBoard(const SparseBoard &) = default;
+  SparseBoard &operator=(const SparseBoard &) = default;
+  SparseBoard(SparseBoard &&) = default;
+  SparseBoard &operator=(SparseBoard &&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override { return rows_; }
+  [[nodiscard]] std::size_t Cols() const noexcept override { return cols_; }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override;
+
+  void SetCell(std::size_t row, std::size_t col, bool alive) override;
+
+  /**
+   * \brief Return the number of live cells in the entire universe.
+   * \details Cells outside of the visible window are included in the count.
+   */
+  [[nodiscard]] std::uint64_t Population() const noexcept override {
+    return live_.size();
+  }
+
+  /**
+   * \brief Return the live cells of the entire universe in row major order.
+   */
+  [[nodiscard]] std::vector<LiveCell> LiveCells() const override;
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  void SetGeneration(std::uint64_t generation) noexcept override {
+    generation_ = generation;
+  }
+
+  void Tick() override;
+
+ private:
+  /**
+   * \brief Coordinates of a cell in the universe.
+   */
+  struct Cell {
+    std::int64_t row = 0;
+    std::int64_t col = 0;
+
+    bool operator==(const Cell &other) const noexcept = default;
+  };
+
+  struct CellHash {
+    std::size_t operator()(const Cell &cell) const noexcept;
+  };
+
+  using CellSet = std::unordered_set<Cell, CellHash>;
+  using CellCounts = std::unordered_map<Cell, std::uint8_t, CellHash>;
+
+  std::size_t rows_ = 0;         /**< Visible window rows. */
+  std::size_t cols_ = 0;         /**< Visible window columns. */
+  Rule rule_;                    /**< Birth/survival rule. */
+  CellSet live_;                 /**< Coordinates of the live cells. */
+  CellSet next_;                 /**< Next generation's live cells. */
+  CellCounts counts_;            /**< Live neighbor tallies. */
+  std::uint64_t generation_ = 0; /**< Number of Tick() calls. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
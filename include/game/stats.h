@@ -0,0 +1,106 @@
+#ifndef STATS_H_
+#define STATS_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+
+#include "game/engine.h"
+#include "game/pipeline.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief What happened during the step leading up to a generation.
+ * \details Births, deaths and the population are counted over the whole
+ *          engine, including the cells beyond the window of unbounded
+ *          engines.
+ */
+struct StepStats {
+  std::uint64_t generation = 0; /**< Generation reached by the step. */
+  std::uint64_t population = 0; /**< Number of live cells. */
+  std::uint64_t births = 0;     /**< Cells that were dead and came alive. */
+  std::uint64_t deaths = 0;     /**< Cells that were alive and died. */
+};
+
+/**
+ * \brief Advance \p engine by one generation and return the statistics of the
+ *        step.
+ */
+[[nodiscard]] StepStats Step(Engine &engine);
+
+/**
+ * \brief Return the statistics of the step from \p before to \p after.
+ * \details The frames are expected to have the same dimensions, usually
+ *          \p after is the generation following \p before.
+ */
+[[nodiscard]] StepStats CompareFrames(const Frame &before,
+                                      const Frame &after) noexcept;
+
+/**
+ * \brief A bounded history of the statistics of the most recent steps.
+ * \details Once the history holds its capacity, pushing a step drops the
+ *          oldest one.
+ */
+class StatsHistory {
+ public:
+  static constexpr std::size_t kDefaultCapacity = 4096; /**< Steps. */
+
+  /**
+   * \brief Construct an empty history.
+   * \param [in] capacity Maximum number of steps kept. A capacity of 0
+   *                      disables the history.
+   */
+  [[nodiscard]] explicit StatsHistory(
+      std::size_t capacity = kDefaultCapacity)
+      : capacity_(capacity) {}
+  ~StatsHistory() = default;
+
+  StatsHistory(const StatsHistory &) = default;
+  StatsHistory &operator=(const StatsHistory &) = default;
+  StatsHistory(StatsHistory &&) = default;
+  StatsHistory &operator=(StatsHistory &&) = default;
+
+  /**
+   * \brief Append \p stats as the newest step.
+   */
+  void Push(const StepStats &stats);
+
+  /**
+   * \brief Return the step at \p index, 0 being the oldest step.
+   */
+  [[nodiscard]] const StepStats &At(std::size_t index) const {
+    return steps_.at(index);
+  }
+
+  /**
+   * \brief Return the number of stored steps.
+   */
+  [[nodiscard]] std::size_t Size() const noexcept { return steps_.size(); }
+
+  /**
+   * \brief Return the maximum number of stored steps.
+   */
+  [[nodiscard]] std::size_t Capacity() const noexcept { return capacity_; }
+
+  /**
+   * \brief Return the total births and deaths of the stored steps, along with
+   *        the generation and population of the newest one.
+   */
+  [[nodiscard]] StepStats Totals() const noexcept;
+
+  /**
+   * \brief Drop all steps.
+   */
+  void Clear() noexcept { steps_.clear(); }
+
+ private:
+  std::size_t capacity_ = 0;    /**< Maximum number of steps. */
+  std::deque<StepStats> steps_; /**< Steps oldest first. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,30 @@
+#ifndef SWAR_H_
+#define SWAR_H_
+
+#include <array>
+#include <cstdint>
+
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief Return the next state of 64 cells packed into a word.
+ * \details Bit j of each word holds the state of the j-th cell. The neighbor
+ *          words are aligned such that bit j of each neighbor word is one of
+ *          the eight neighbors of the j-th cell. All 64 cells are updated in
+ *          parallel by tallying the neighbors in bit-sliced form, i.e., one
+ *          word per bit of the neighbor counts.
+ * \param [in] neighbors The aligned states of the eight neighbors.
+ * \param [in] alive The current states of the cells.
+ * \param [in] rule Birth/survival rule.
+ */
+[[nodiscard]] std::uint64_t NextCells(
+    const std::array<std::uint64_t, Rule::kMaxNeighbors> &neighbors,
+    std::uint64_t alive, const Rule &rule) noexcept;
+
+}  // namespace game
+}  // namespace gol
+
+#endif
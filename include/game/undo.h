@@ -0,0 +1,101 @@
+#ifndef UNDO_H_
+#define UNDO_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/raster.h"
+
+namespace gol {
+namespace game {
+
+/**
+ * \brief A bounded stack of board edits that can be undone and redone.
+ * \details An edit is recorded by saving the state of the cells it is about
+ *          to change. Undoing an edit swaps the saved states with the current
+ *          ones, such that redoing it swaps them back. Once the saved cells
+ *          exceed the cap, the oldest edits are dropped, an edit that does
+ *          not fit by itself clears the stack.
+ */
+class UndoStack {
+ public:
+  static constexpr std::size_t kDefaultCapCells = 1 << 22; /**< 4 Mi cells. */
+
+  /**
+   * \brief Construct an empty stack.
+   * \param [in] cap_cells Maximum number of cells saved by all edits.
+   */
+  [[nodiscard]] explicit UndoStack(std::size_t cap_cells = kDefaultCapCells)
+      : cap_cells_(cap_cells) {}
+
+  /**
+   * \brief Start a new edit, the cells saved next belong to it.
+   * \details The edit is only pushed once a cell is saved. Pushing it drops
+   *          the undone edits.
+   */
+  void Begin() noexcept {
+    open_ = false;
+    overflow_ = false;
+  }
+
+  /**
+   * \brief Save the state of the cells of \p board covered by \p cells
+   *        before the current edit changes them.
+   * \details Cells beyond the board edges are left out.
+   */
+  void Save(const Engine &board, const RasterCells &cells);
+
+  /**
+   * \brief Save the state of every cell of \p board before the current edit
+   *        changes them.
+   */
+  void SaveAll(const Engine &board);
+
+  /**
+   * \brief Revert the newest edit on \p board.
+   * \returns \c false if there is no edit to undo.
+   */
+  bool Undo(Engine &board);
+
+  /**
+   * \brief Reapply the newest undone edit on \p board.
+   * \returns \c false if there is no edit to redo.
+   */
+  bool Redo(Engine &board);
+
+  /**
+   * \brief Drop all edits.
+   */
+  void Clear() noexcept;
+
+ private:
+  /**
+   * \brief The state of a cell on the other side of an edit.
+   */
+  struct SavedCell {
+    std::size_t row = 0;    /**< Cell row. */
+    std::size_t col = 0;    /**< Cell column. */
+    std::uint8_t state = 0; /**< State before or after the edit. */
+  };
+  using Edit = std::vector<SavedCell>;
+
+  /**
+   * \brief Save the state of the cell at \p row and \p col.
+   */
+  void SaveCell(const Engine &board, std::size_t row, std::size_t col);
+
+  std::size_t cap_cells_ = 0; /**< Maximum number of saved cells. */
+  std::size_t cells_ = 0;     /**< Cells saved by all edits. */
+  bool open_ = false;         /**< The newest edit is being recorded. */
+  bool overflow_ = false;     /**< The open edit outgrew the cap. */
+  std::deque<Edit> undo_;     /**< Edits to undo, oldest first. */
+  std::deque<Edit> redo_;     /**< Undone edits, oldest first. */
+};
+
+}  // namespace game
+}  // namespace gol
+
+#endif
@@ -0,0 +1,126 @@
+#ifndef CELL_STYLE_H_
+#define CELL_STYLE_H_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Color class of a displayed cell.
+ * \details The live shades double as ncurses color pairs and image palette
+ *          indices.
+ */
+enum CellShade : std::uint8_t {
+  kDead = 0,   /**< Dead cell. */
+  kLive,       /**< Live cell when not coloring by age. */
+  kNewborn,    /**< Cell born in the last generation. */
+  kYoung,      /**< Cell alive for a few generations. */
+  kMature,     /**< Cell alive for tens of generations. */
+  kOld,        /**< Long lived cell. */
+  kCursor,     /**< Edit mode cursor, only used by the image renderers. */
+  kGrid,       /**< Grid line, only used by the image renderers. */
+  kGraph,      /**< Graph bar, only used by the image renderers. */
+  kSelection,  /**< Selected dead cell, only used by the image renderers. */
+  kTrail,      /**< Cell that died in the last two generations. */
+  kFadedTrail, /**< Cell that died three or four generations ago. */
+  kFaintTrail, /**< Cell that died five or six generations ago. */
+  kBorn,       /**< Cell born in the last generation of the diff view. */
+  kDied,       /**< Cell that died in the last generation of the diff view. */
+  kSurvived,   /**< Cell that stayed alive in the diff view. */
+  kState,      /**< State 1 of a multi-state automaton, then the others. */
+};
+
+/**
+ * \brief What the colors of the cells show besides their state.
+ * \details The shadings need the ages of the cells, they are ignored when no
+ *          ages are given. The diff view takes precedence over the others.
+ */
+struct Shading {
+  bool ages = false;   /**< Color live cells by age. */
+  bool trails = false; /**< Fade out the cells that died recently. */
+  bool diff = false;   /**< Color the births and deaths of the last step. */
+};
+
+/**
+ * \brief Return the style of a square block of board cells.
+ * \details A block takes on the style of its oldest live cell. Blocks without
+ *          live cells are kDead, or the trail style of their most recently
+ *          dead cell when fading out trails. In the diff view, blocks whose
+ *          live cells were all born in the last generation are kBorn, blocks
+ *          of older live cells kSurvived, and blocks without live cells kDied
+ *          if one of their cells died in the last generation. The cells of
+ *          multi-state boards are styled by state, a block takes on the
+ *          lowest state of its live cells.
+ * \param [in] board Simulation engine containing current game state.
+ * \param [in] ages Optional cell ages, when null live blocks are kLive.
+ * \param [in] row Board row of the top left cell of the block.
+ * \param [in] col Board column of the top left cell of the block.
+ * \param [in] size Number of cells along each side of the block.
+ * \param [in] shading What the style shows besides liveness.
+ */
+[[nodiscard]] std::uint8_t BlockStyle(const game::Engine& board,
+                                      const game::CellAges* ages,
+                                      std::size_t row, std::size_t col,
+                                      std::size_t size,
+                                      const Shading& shading) noexcept;
+
+/**
+ * \brief Return the style of every cell of \p board in row major order.
+ * \param [in] board Simulation engine containing current game state.
+ * \param [in] ages Optional cell ages, when null live cells are kLive.
+ * \param [in] shading What the styles show besides liveness.
+ */
+[[nodiscard]] std::vector<std::uint8_t> CellStyles(
+    const game::Engine& board, const game::CellAges* ages,
+    const Shading& shading);
+
+/**
+ * \brief Return the colors of the trail styles kTrail, kFadedTrail and
+ *        kFaintTrail, the live color of \p theme fading to black.
+ */
+[[nodiscard]] std::array<Color, 3> TrailColors(const Theme& theme) noexcept;
+
+/** Colors of the diff view styles kBorn, kDied and kSurvived. */
+static constexpr std::array<Color, 3> kDiffColors = {46, 196, 15};
+
+/**
+ * \brief Return the colors of \p theme as an image palette indexed by
+ *        CellShade.
+ */
+[[nodiscard]] std::vector<Rgb> StylePalette(const Theme& theme);
+
+static constexpr wchar_t kFullBlock = 0x2588; /**< Unicode full block. */
+
+/**
+ * \brief Characters drawn for live and dead cells in block render mode.
+ */
+struct CellStyle {
+  wchar_t alive = kFullBlock; /**< Character of live cells. */
+  wchar_t dead = L' ';        /**< Character of dead cells. */
+};
+
+/**
+ * \brief Cell characters for terminals or fonts lacking kFullBlock.
+ */
+static constexpr CellStyle kAsciiCellStyle = {.alive = L'#', .dead = L' '};
+
+/**
+ * \brief Return the character encoded by the UTF-8 string \p text.
+ * \throws std::invalid_argument When \p text is not a single printable
+ *         character.
+ */
+[[nodiscard]] wchar_t ParseCellChar(const std::string& text);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
@@ -0,0 +1,100 @@
+#ifndef CHAR_RENDERER_H_
+#define CHAR_RENDERER_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/grid.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Renderer drawing cells as ncurses characters.
+ * \details Only the characters that changed since the previous frame are
+ *          redrawn. A full redraw is performed on the first frame, after
+ *          Invalidate(), and whenever the screen or viewport dimensions
+ *          change. Cells are colored only when the terminal supports color.
+ *          The grid is composited as a layer of characters drawn above the
+ *          cells.
+ *
+ *          In hexagonal mode, each cell is drawn two characters wide and each
+ *          row is shifted left by one character from the row above, shearing
+ *          the rows of a hexagonal grid skewed into rows such that every cell
+ *          touches the six cells of its hexagonal neighborhood.
+ */
+class CharRenderer : public Renderer {
+ public:
+  /**
+   * \brief Construct a character renderer.
+   * \param [in] braille Draw 4x2 blocks of cells as braille characters instead
+   *                     of drawing each cell as a character of \p style.
+   * \param [in] style Characters of live and dead cells.
+   * \param [in] hex Draw cells on sheared rows, ignored in braille mode.
+   */
+  [[nodiscard]] CharRenderer(bool braille, const CellStyle& style,
+                             bool hex = false) noexcept;
+  CharRenderer() = delete;
+  ~CharRenderer() override = default;
+
+  [[nodiscard]] ScreenPosition DotsPerCharacter() const noexcept override;
+  [[nodiscard]] std::size_t CharactersPerDot() const noexcept override {
+    return (hex_) ? 2 : 1;
+  }
+  [[nodiscard]] std::size_t ShearColumns(
+      std::size_t rows) const noexcept override {
+    return (hex_ && rows) ? rows - 1 : 0;
+  }
+  void SetTheme(const Theme& theme) noexcept override;
+  void SetGrid(const GridOverlay& grid) noexcept override;
+  void SetShading(const Shading& shading) noexcept override;
+  void Invalidate() noexcept override { drawn_cells_.clear(); }
+  void DrawBoard(const game::Engine& board, const Viewport& viewport,
+                 const game::CellAges* ages) noexcept override;
+  void DrawCursor(std::size_t row, std::size_t col,
+                  const Viewport& viewport) noexcept override;
+  void DrawSelection(const game::CellBounds& selection,
+                     const Viewport& viewport) noexcept override;
+  void DrawGraph(const std::vector<std::uint64_t>& values,
+                 const Viewport& viewport) noexcept override;
+  void Present() noexcept override {}
+
+ private:
+  [[nodiscard]] std::uint16_t CharCode(const game::Engine& board,
+                                       const game::CellAges* ages,
+                                       const Viewport& viewport,
+                                       std::size_t row,
+                                       std::size_t col) const noexcept;
+  [[nodiscard]] std::vector<wchar_t> ComposeOverlay(
+      const game::Engine& board, const Viewport& viewport) const;
+  [[nodiscard]] std::size_t ScreenCol(const Viewport& viewport,
+                                      std::size_t row,
+                                      std::size_t col) const noexcept;
+  void DrawChar(std::size_t row, std::size_t col, std::uint16_t code,
+                wchar_t overlay) const noexcept;
+
+  bool braille_ = false;                    /**< Draw cells as braille dots. */
+  bool hex_ = false;                        /**< Shear the rows. */
+  CellStyle style_;                         /**< Characters of block cells. */
+  GridOverlay grid_;                        /**< Grid drawn above the cells. */
+  Shading shading_;                         /**< What cell colors show. */
+  std::vector<std::uint16_t> drawn_cells_;  /**< Last frame's characters. */
+  std::vector<wchar_t> drawn_overlay_;      /**< Last frame's overlay. */
+  std::size_t drawn_cols_ = 0;              /**< Last frame's width. */
+  ScreenDimension drawn_dim_;               /**< Last frame's screen size. */
+  std::optional<std::size_t> drawn_cursor_; /**< Last frame's cursor. */
+};
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
@@ -0,0 +1,62 @@
+#ifndef GIF_H_
+#define GIF_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Writes indexed color images as the frames of a looping animated GIF.
+ * \details Frames are LZW compressed and written to the file as they are
+ *          added. The GIF trailer is written when the GifWriter is destroyed.
+ */
+class GifWriter {
+ public:
+  /**
+   * \brief Create the GIF file \p filename.
+   * \param [in] filename Path to the GIF file.
+   * \param [in] width Number of pixels per row of each frame.
+   * \param [in] height Number of pixel rows of each frame.
+   * \param [in] scale Each pixel is drawn as a \p scale x \p scale square.
+   * \param [in] palette Image colors, at most 256.
+   * \throws std::invalid_argument When the scaled image or the palette
+   *         exceeds the limits of the GIF format.
+   * \throws std::runtime_error When \p filename cannot be opened.
+   */
+  [[nodiscard]] GifWriter(const std::string& filename, std::size_t width,
+                          std::size_t height, std::size_t scale,
+                          const std::vector<Rgb>& palette);
+  GifWriter() = delete;
+  ~GifWriter();
+
+  GifWriter(const GifWriter&) = delete;
+  GifWriter& operator=(const GifWriter&) = delete;
+  GifWriter(GifWriter&&) = default;
+  GifWriter& operator=(GifWriter&&) = default;
+
+  /**
+   * \brief Append a frame to the animation.
+   * \param [in] pixels Row major palette indices of the frame's pixels.
+   * \param [in] delay_ms Time the frame is shown for.
+   */
+  void AddFrame(const std::vector<std::uint8_t>& pixels, int delay_ms);
+
+ private:
+  std::ofstream file_;     /**< GIF file. */
+  std::size_t width_ = 0;  /**< Unscaled frame width. */
+  std::size_t height_ = 0; /**< Unscaled frame height. */
+  std::size_t scale_ = 1;  /**< Pixel scale factor. */
+  int min_code_size_ = 2;  /**< LZW minimum code size. */
+};
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
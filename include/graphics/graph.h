@@ -0,0 +1,35 @@
+#ifndef GRAPH_H_
+#define GRAPH_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+namespace gol {
+namespace graphics {
+
+/** Screen columns of the graph drawn over the top right corner of the board. */
+static constexpr std::size_t kGraphWidth = 32;
+
+/** Screen rows of the graph drawn over the top right corner of the board. */
+static constexpr std::size_t kGraphHeight = 4;
+
+/**
+ * \brief Return the heights of the bars of a graph of the last \p width of
+ *        \p values, oldest first.
+ * \details The bars are scaled such that the smallest shown value is 1 high
+ *          and the largest \p height high. When all shown values are equal,
+ *          the bars are half as high as the graph. Fewer than \p width bars
+ *          are returned when there are fewer values.
+ * \param [in] values Values oldest first.
+ * \param [in] width Largest number of bars.
+ * \param [in] height Height of the graph.
+ */
+[[nodiscard]] std::vector<std::size_t> GraphBars(
+    const std::vector<std::uint64_t>& values, std::size_t width,
+    std::size_t height);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
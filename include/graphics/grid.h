@@ -0,0 +1,55 @@
+#ifndef GRID_H_
+#define GRID_H_
+
+#include <cstddef>
+#include <optional>
+
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Grid lines drawn over the board to help reading cell coordinates.
+ */
+struct GridOverlay {
+  static constexpr std::size_t kDefaultSpacing = 10; /**< Toggled spacing. */
+
+  std::size_t spacing = 0; /**< Cells between grid lines, 0 hides the grid. */
+  bool labels = false;     /**< Label the lines with board coordinates. */
+};
+
+/**
+ * \brief Direction of the board coordinates a grid line marks.
+ */
+enum class GridAxis {
+  kRow, /**< Horizontal line at a board row. */
+  kCol, /**< Vertical line at a board column. */
+};
+
+/**
+ * \brief Return the grid line starting in a span of dots of the viewport.
+ * \details A grid line is drawn at every board row (or column) that is a
+ *          multiple of the grid spacing. The line starts in the span when one
+ *          of its dots displays the first dot of the line's cell. No lines are
+ *          returned when the grid is hidden or when the lines would fill every
+ *          span of \p num_dots dots.
+ * \param [in] grid Grid to draw.
+ * \param [in] viewport Region of the board being displayed.
+ * \param [in] axis Whether \p dot counts rows or columns of dots.
+ * \param [in] dot First dot of the span counted from the top or left of the
+ *                 screen.
+ * \param [in] num_dots Number of dots in the span.
+ * \param [in] board_size Number of board rows or columns, no lines are drawn
+ *                        outside of the board.
+ * \return The board row or column of the line or \c std::nullopt if no line
+ *         starts in the span.
+ */
+[[nodiscard]] std::optional<std::size_t> GridLine(
+    const GridOverlay& grid, const Viewport& viewport, GridAxis axis,
+    std::size_t dot, std::size_t num_dots, std::size_t board_size) noexcept;
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
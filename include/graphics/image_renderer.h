@@ -0,0 +1,84 @@
+#ifndef IMAGE_RENDERER_H_
+#define IMAGE_RENDERER_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/grid.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Renderer drawing cells as the pixels of a terminal graphics image.
+ * \details The board is drawn as an image in the top left corner of the
+ *          screen using either the sixel or kitty graphics protocol. Each dot
+ *          of the viewport is drawn as a square block of pixels, avoiding the
+ *          distortion caused by the aspect ratio of the font. The image is
+ *          only written to the terminal by Present() when it changed.
+ */
+class ImageRenderer : public Renderer {
+ public:
+  /**
+   * \brief Construct an image renderer.
+   * \param [in] kitty Use the kitty graphics protocol instead of sixel.
+   */
+  [[nodiscard]] explicit ImageRenderer(bool kitty) noexcept;
+  ImageRenderer() = delete;
+  ~ImageRenderer() override;
+
+  /**
+   * \brief Return \c true if the terminal supports the kitty graphics
+   *        protocol.
+   * \details The terminal is queried and the response is read using ncurses.
+   */
+  [[nodiscard]] static bool IsKittySupported() noexcept;
+
+  [[nodiscard]] ScreenPosition DotsPerCharacter() const noexcept override;
+  void SetTheme(const Theme& theme) noexcept override;
+  void SetGrid(const GridOverlay& grid) noexcept override;
+  void SetShading(const Shading& shading) noexcept override;
+  void Invalidate() noexcept override { dirty_ = true; }
+  void DrawBoard(const game::Engine& board, const Viewport& viewport,
+                 const game::CellAges* ages) noexcept override;
+  void DrawCursor(std::size_t row, std::size_t col,
+                  const Viewport& viewport) noexcept override;
+  void DrawSelection(const game::CellBounds& selection,
+                     const Viewport& viewport) noexcept override;
+  void DrawGraph(const std::vector<std::uint64_t>& values,
+                 const Viewport& viewport) noexcept override;
+  void Present() noexcept override;
+
+ private:
+  using Selections = std::vector<game::CellBounds>;
+
+  bool kitty_ = false;                        /**< Use the kitty protocol. */
+  Theme theme_;                               /**< Image colors. */
+  GridOverlay grid_;                          /**< Grid drawn over the cells. */
+  Shading shading_;                           /**< What cell colors show. */
+  std::vector<std::uint8_t> dots_;            /**< Row major dot styles. */
+  std::size_t cols_ = 0;                      /**< Dots per image row. */
+  ScreenPosition chars_;                      /**< Screen characters covered. */
+  ScreenDimension drawn_dim_;                 /**< Last frame's screen size. */
+  std::optional<std::size_t> cursor_;         /**< Dot covered by the cursor. */
+  std::optional<std::size_t> written_cursor_; /**< Last written cursor. */
+  Selections selections_;                     /**< Dots of the selections. */
+  Selections written_selections_;             /**< Last written selections. */
+  std::vector<std::size_t> graph_;            /**< Graph bar heights. */
+  std::size_t graph_rows_ = 0;                /**< Dot rows of the graph. */
+  std::vector<std::size_t> written_graph_;    /**< Last written graph. */
+  bool dirty_ = true;                         /**< Image must be written. */
+};
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
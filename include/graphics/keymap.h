@@ -0,0 +1,82 @@
+#ifndef KEYMAP_H_
+#define KEYMAP_H_
+
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "graphics/screen.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Maps key presses to commands.
+ * \details A key is bound to at most one command while a command may be bound
+ *          to several keys. The commands acting only in edit mode, such as
+ *          toggling a cell, are bound separately: a key may be bound to one
+ *          of them in addition to another command, in which case it issues
+ *          the edit command in edit mode and the other command otherwise.
+ *          Keys are identified by the codes returned by ncurses, Enter is
+ *          always reported as '\\n'.
+ */
+class Keymap {
+ public:
+  /**
+   * \brief Construct the default keymap.
+   * \details The defaults are listed in the documentation of Command.
+   */
+  [[nodiscard]] Keymap();
+
+  /**
+   * \brief Return the command bound to \p key or Command::kNone if the key is
+   *        not bound.
+   * \param [in] key The key pressed.
+   * \param [in] editing When \c true, the commands acting only in edit mode
+   *                     take precedence, otherwise they are ignored.
+   */
+  [[nodiscard]] Command Lookup(int key, bool editing = false) const noexcept;
+
+  /**
+   * \brief Bind \p keys to \p command.
+   * \details The keys previously bound to \p command are unbound, and each of
+   *          \p keys is unbound from the command it was bound to unless only
+   *          one of the two commands acts in edit mode.
+   */
+  void Bind(Command command, const std::vector<int>& keys);
+
+  /**
+   * \brief Return the name of the first key bound to \p command or an empty
+   *        string if no key is bound to it.
+   */
+  [[nodiscard]] std::string KeyName(Command command) const;
+
+ private:
+  std::vector<std::pair<int, Command>> bindings_; /**< Keys in bind order. */
+};
+
+/**
+ * \brief Return the command named \p name, e.g. 'quit' or 'zoom-in'.
+ * \throws std::invalid_argument When no command is named \p name.
+ */
+[[nodiscard]] Command ParseCommand(const std::string& name);
+
+/**
+ * \brief Return the names of all commands that can be bound to keys.
+ */
+[[nodiscard]] std::vector<std::string> CommandNames();
+
+/**
+ * \brief Return the key named \p name.
+ * \details A key is named by the character it types, by 'ctrl-' followed by a
+ *          letter, or by one of 'space', 'enter', 'esc', 'tab', 'backspace',
+ *          'up', 'down', 'left', 'right', 'home', 'end', 'pageup' and
+ *          'pagedown'. Names are case sensitive.
+ * \throws std::invalid_argument When \p name does not name a key.
+ */
+[[nodiscard]] int ParseKey(const std::string& name);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
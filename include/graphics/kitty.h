@@ -0,0 +1,55 @@
+#ifndef KITTY_H_
+#define KITTY_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Encode an indexed color image using the kitty graphics protocol.
+ * \details The image is transmitted as raw RGB data and displayed at the
+ *          terminal's cursor position, scaled to cover \p columns x \p rows
+ *          screen characters. Each call replaces the image displayed by the
+ *          previous call. The cursor is not moved.
+ * \param [in] pixels Row major palette indices of the image pixels.
+ * \param [in] width Number of pixels per row.
+ * \param [in] columns Number of screen columns covered by the image.
+ * \param [in] rows Number of screen rows covered by the image.
+ * \param [in] palette Image colors.
+ * \returns The kitty graphics escape sequences.
+ */
+[[nodiscard]] std::string EncodeKitty(const std::vector<std::uint8_t>& pixels,
+                                      std::size_t width, std::size_t columns,
+                                      std::size_t rows,
+                                      const std::vector<Rgb>& palette);
+
+/**
+ * \brief Return a kitty graphics query.
+ * \details A terminal supporting the kitty graphics protocol responds to the
+ *          query with an escape sequence containing KittyQueryResponse().
+ *          Other terminals ignore the query.
+ */
+[[nodiscard]] std::string KittyQuery();
+
+/**
+ * \brief Return the substring identifying a successful response to
+ *        KittyQuery().
+ */
+[[nodiscard]] std::string KittyQueryResponse();
+
+/**
+ * \brief Return an escape sequence deleting all images displayed by
+ *        EncodeKitty().
+ */
+[[nodiscard]] std::string KittyDelete();
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
@@ -0,0 +1,36 @@
+#ifndef PNG_H_
+#define PNG_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Write an indexed color image to the PNG file \p filename.
+ * \details The image data is stored uncompressed, PNG files are thus roughly
+ *          the size of the scaled image in pixels.
+ * \param [in] filename Path to the PNG file.
+ * \param [in] width Number of pixels per row of the image.
+ * \param [in] height Number of pixel rows of the image.
+ * \param [in] scale Each pixel is drawn as a \p scale x \p scale square.
+ * \param [in] palette Image colors, at most 256.
+ * \param [in] pixels Row major palette indices of the image's pixels.
+ * \throws std::invalid_argument When the image is empty or the palette has
+ *         more than 256 colors.
+ * \throws std::runtime_error When \p filename cannot be written.
+ */
+void WritePng(const std::string& filename, std::size_t width,
+              std::size_t height, std::size_t scale,
+              const std::vector<Rgb>& palette,
+              const std::vector<std::uint8_t>& pixels);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
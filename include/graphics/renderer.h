@@ -0,0 +1,192 @@
+#ifndef RENDERER_H_
+#define RENDERER_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/graph.h"
+#include "graphics/grid.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief How board cells are drawn.
+ */
+enum class RenderMode {
+  kBlock,   /**< One cell per character drawn as a CellStyle character. */
+  kBraille, /**< A 4x2 block of cells per character drawn as braille dots. */
+  kHex,     /**< Cells two characters wide on rows sheared by half a cell. */
+  kSixel,   /**< Cells drawn as pixels of a sixel image. */
+  kKitty,   /**< Cells drawn as pixels of a kitty graphics protocol image. */
+  kNone,    /**< Nothing is drawn, used for headless runs. */
+};
+
+/**
+ * \brief Draws the cells of a board to the screen.
+ * \details A Renderer draws the cells of an Engine visible through a Viewport.
+ *          The Viewport must split each screen character into
+ *          DotsPerCharacter() dots. Text such as the status bar is drawn
+ *          separately by the functions of screen.h.
+ */
+class Renderer {
+ public:
+  Renderer() = default;
+  virtual ~Renderer() = default;
+
+  Renderer(const Renderer&) = delete;
+  Renderer& operator=(const Renderer&) = delete;
+  Renderer(Renderer&&) = delete;
+  Renderer& operator=(Renderer&&) = delete;
+
+  /**
+   * \brief Return the number of rows and columns of board cells drawn per
+   *        screen character when the viewport is not zoomed.
+   */
+  [[nodiscard]] virtual ScreenPosition DotsPerCharacter() const noexcept = 0;
+
+  /**
+   * \brief Return the number of screen columns drawn per dot.
+   * \details The Viewport of a renderer drawing dots several characters wide
+   *          must be as many times narrower than the screen.
+   */
+  [[nodiscard]] virtual std::size_t CharactersPerDot() const noexcept {
+    return 1;
+  }
+
+  /**
+   * \brief Return the number of screen columns the top row of a given number
+   *        of screen rows is shifted right by from the bottom row.
+   * \details The screen must be as many columns wider than the Viewport of a
+   *          renderer shearing its rows.
+   */
+  [[nodiscard]] virtual std::size_t ShearColumns(
+      std::size_t) const noexcept {
+    return 0;
+  }
+
+  /**
+   * \brief Set the colors used to draw the board.
+   * \details Changing the theme forces the next DrawBoard() call to perform a
+   *          full redraw.
+   */
+  virtual void SetTheme(const Theme& theme) noexcept = 0;
+
+  /**
+   * \brief Set the grid drawn over the cells of the board.
+   * \details Grid lines are only drawn over dead cells while labels are
+   *          drawn over any cell. Image renderers do not draw labels.
+   *          Changing the grid forces the next DrawBoard() call to perform a
+   *          full redraw.
+   */
+  virtual void SetGrid(const GridOverlay& grid) noexcept = 0;
+
+  /**
+   * \brief Set what the colors of the cells show besides their state.
+   * \details Changing the shading forces the next DrawBoard() call to perform
+   *          a full redraw.
+   */
+  virtual void SetShading(const Shading& shading) noexcept = 0;
+
+  /**
+   * \brief Force the next DrawBoard() call to perform a full redraw.
+   * \details Call Invalidate() after the screen is cleared.
+   */
+  virtual void Invalidate() noexcept = 0;
+
+  /**
+   * \brief Queue the cells of the \p board visible in \p viewport for display.
+   * \details When zoomed out, a dot is drawn live if any of the cells it covers
+   *          is live. When \p ages is provided, the cells are colored as set
+   *          by SetShading().
+   * \param [in] board Simulation engine containing current game state.
+   * \param [in] viewport Region of the board to display.
+   * \param [in] ages Optional ages of the cells of \p board.
+   */
+  virtual void DrawBoard(const game::Engine& board, const Viewport& viewport,
+                         const game::CellAges* ages) noexcept = 0;
+
+  /**
+   * \brief Queue the edit mode cursor for display.
+   * \details The cursor is drawn over board cell (\p row, \p col) until the
+   *          next call to DrawBoard().
+   * \param [in] row Cursor board row.
+   * \param [in] col Cursor board column.
+   * \param [in] viewport Region of the board being displayed.
+   */
+  virtual void DrawCursor(std::size_t row, std::size_t col,
+                          const Viewport& viewport) noexcept = 0;
+
+  /**
+   * \brief Queue the highlighting of a rectangle of board cells for display.
+   * \details The selection is highlighted until the next call to
+   *          DrawBoard(), several selections may be highlighted at once.
+   * \param [in] selection Board cells to highlight.
+   * \param [in] viewport Region of the board being displayed.
+   */
+  virtual void DrawSelection(const game::CellBounds& selection,
+                             const Viewport& viewport) noexcept = 0;
+
+  /**
+   * \brief Queue a bar graph of \p values for display over the top right
+   *        corner of the board.
+   * \details The graph shows the last kGraphWidth values as bars scaled by
+   *          GraphBars() until the next call to DrawBoard().
+   * \param [in] values Values oldest first.
+   * \param [in] viewport Region of the board being displayed.
+   */
+  virtual void DrawGraph(const std::vector<std::uint64_t>& values,
+                         const Viewport& viewport) noexcept = 0;
+
+  /**
+   * \brief Output any part of the frame not drawn through ncurses.
+   * \details Call Present() after Refresh().
+   */
+  virtual void Present() noexcept = 0;
+};
+
+/**
+ * \brief A Renderer that draws nothing.
+ */
+class NullRenderer : public Renderer {
+ public:
+  [[nodiscard]] ScreenPosition DotsPerCharacter() const noexcept override {
+    return {.row = 1, .col = 1};
+  }
+  void SetTheme(const Theme&) noexcept override {}
+  void SetGrid(const GridOverlay&) noexcept override {}
+  void SetShading(const Shading&) noexcept override {}
+  void Invalidate() noexcept override {}
+  void DrawBoard(const game::Engine&, const Viewport&,
+                 const game::CellAges*) noexcept override {}
+  void DrawCursor(std::size_t, std::size_t,
+                  const Viewport&) noexcept override {}
+  void DrawSelection(const game::CellBounds&,
+                     const Viewport&) noexcept override {}
+  void DrawGraph(const std::vector<std::uint64_t>&,
+                 const Viewport&) noexcept override {}
+  void Present() noexcept override {}
+};
+
+/**
+ * \brief Construct the Renderer implementing \p mode.
+ * \details The ncurses screen must be initialized. RenderMode::kKitty queries
+ *          the terminal for kitty graphics support and falls back to
+ *          RenderMode::kBlock when the terminal does not respond.
+ * \param [in] mode How board cells are drawn.
+ * \param [in] style Characters of live and dead cells in RenderMode::kBlock.
+ */
+[[nodiscard]] std::unique_ptr<Renderer> MakeRenderer(RenderMode mode,
+                                                     const CellStyle& style);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
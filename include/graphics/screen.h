@@ -1,11 +1,61 @@
 #ifndef SCREEN_H_
 #define SCREEN_H_
 
-#include "game/board.h"
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
 
 namespace gol {
 namespace graphics {
 
+/**
+ * \brief User commands issued via the keyboard.
+ */
+enum class Command {
+  kNone,         /**< No key or an unbound key was pressed. */
+  kQuit,         /**< Exit the simulation ('q' or Esc). */
+  kTogglePause,  /**< Pause or resume the simulation (space). */
+  kStep,         /**< Advance one generation while paused ('n' or '.'). */
+  kStepBack,     /**< Rewind one generation while paused ('b'). */
+  kSpeedUp,      /**< Decrease the update delay ('+' or ']'). */
+  kSlowDown,     /**< Increase the update delay ('-' or '['). */
+  kToggleEdit,   /**< Enter or leave edit mode ('e'). */
+  kMoveUp,       /**< Move the cursor/view up (up arrow or 'k'). */
+  kMoveDown,     /**< Move the cursor/view down (down arrow or 'j'). */
+  kMoveLeft,     /**< Move the cursor/view left (left arrow or 'h'). */
+  kMoveRight,    /**< Move the cursor/view right (right arrow or 'l'). */
+  kToggleCell,   /**< Toggle the cell under the cursor ('x' or Enter). */
+  kSave,         /**< Save the board to a pattern file ('w'). */
+  kSnapshot,     /**< Save the board to a timestamped pattern file ('s'). */
+  kZoomIn,       /**< Show fewer cells per character ('z'). */
+  kZoomOut,      /**< Show more cells per character ('x'). */
+  kRandomize,    /**< Replace the board with a random soup ('r'). */
+  kClear,        /**< Kill all cells ('c'). */
+  kToggleGrid,   /**< Show or hide the grid overlay ('g'). */
+  kToggleGraph,  /**< Show or hide the population graph ('p'). */
+  kFastForward,  /**< Skip generations without drawing them ('f'). */
+  kToggleFollow, /**< Follow the live cells or stop following ('o'). */
+  kToggleFit,    /**< Zoom to fit the live cells or stop zooming ('a'). */
+  kSelect,       /**< Start or cancel selecting a rectangle ('v'). */
+  kCopy,         /**< Copy the selected cells ('y'). */
+  kPaste,        /**< Stamp the copied cells at the cursor ('P'). */
+  kRotate,       /**< Rotate the copied cells clockwise ('R'). */
+  kFlip,         /**< Mirror the copied cells left to right ('M'). */
+  kNextTool,     /**< Switch to the next drawing tool ('T'). */
+  kDraw,         /**< Put the pen down or up, or mark a shape ('d'). */
+  kBrushSmaller, /**< Decrease the brush size ('{'). */
+  kBrushLarger,  /**< Increase the brush size ('}'). */
+  kNextSymmetry, /**< Switch to the next edit symmetry ('m'). */
+  kUndo,         /**< Undo the last edit ('u'). */
+  kRedo,         /**< Redo the last undone edit (Ctrl-r). */
+  kInsert,       /**< Pick a pattern to insert at the cursor ('i'). */
+  kSaveSession,  /**< Save the session to resume it later ('S'). */
+  kResize,       /**< The terminal window was resized. */
+};
+
+class Keymap;
+
 /**
  * \brief Screen Dimensions
  */
@@ -14,19 +64,81 @@ struct ScreenDimension {
   int height = 0; /**< Screen height */
 };
 
+/**
+ * \brief Simulation state displayed in the status bar.
+ */
+struct Status {
+  std::string title;            /**< Optional title shown before the stats. */
+  std::uint64_t generation = 0; /**< Current generation. */
+  std::uint64_t population = 0; /**< Number of live cells. */
+  std::string score;            /**< Optional score shown after the stats. */
+  std::string outcome;          /**< Optional outcome shown as a flag. */
+  int update_rate_ms = 0;       /**< Delay between generations. */
+  bool behind = false;          /**< Generations are shown late. */
+  double rate = 0.0;            /**< Generations shown per second. */
+  bool paused = false;          /**< Simulation is paused. */
+  bool editing = false;         /**< Edit mode is active. */
+  bool stable = false;          /**< Board died out or stopped changing. */
+  bool following = false;       /**< View follows the live cells. */
+  bool fitting = false;         /**< View zooms to fit the live cells. */
+  std::size_t rewound = 0;      /**< Generations shown behind the newest. */
+  std::size_t zoom = 1;         /**< Board cells per screen character. */
+  std::size_t cell_size = 1;    /**< Magnification of each board cell. */
+  std::string message;          /**< Optional message shown after the stats. */
+};
+
 /**
  * \brief Initialize the ncurses screen.
+ * \details Keys are read from stdin unless it is not a terminal, e.g. when a
+ *          pattern is piped in, in which case they are read from /dev/tty.
  * \returns The dimensions of the screen (i.e., terminal window).
  */
 [[nodiscard]] ScreenDimension InitScreen() noexcept;
 
+/**
+ * \brief Return the current dimensions of the screen.
+ * \details The dimensions change when Command::kResize is read.
+ */
+[[nodiscard]] ScreenDimension ScreenSize() noexcept;
+
 /**
  * \brief Cleanup ncurses window(s).
  */
 void TerminateScreen() noexcept;
 
+/**
+ * \brief Keeps the ncurses screen initialized for the lifetime of the guard.
+ * \details The constructor calls InitScreen() and the destructor calls
+ *          TerminateScreen(), such that the terminal is restored when the
+ *          guard goes out of scope, including by an exception. While the guard
+ *          is alive, the terminal is also restored before the program is
+ *          killed by a signal (e.g., Ctrl-C or a crash) or ends due to an
+ *          uncaught exception. Only one guard may be alive at a time.
+ */
+class ScreenGuard {
+ public:
+  [[nodiscard]] ScreenGuard() noexcept;
+  ~ScreenGuard();
+
+  ScreenGuard(const ScreenGuard&) = delete;
+  ScreenGuard& operator=(const ScreenGuard&) = delete;
+  ScreenGuard(ScreenGuard&&) = delete;
+  ScreenGuard& operator=(ScreenGuard&&) = delete;
+
+  /**
+   * \brief Return the dimensions of the screen when it was initialized.
+   */
+  [[nodiscard]] const ScreenDimension& Dimension() const noexcept {
+    return dim_;
+  }
+
+ private:
+  ScreenDimension dim_; /**< Screen dimensions at initialization. */
+};
+
 /**
  * \brief Clear the screen.
+ * \details Call Renderer::Invalidate() after clearing the screen.
  */
 void Clear() noexcept;
 
@@ -44,23 +156,55 @@ void EnableInputDelay(int delay_ms) noexcept;
 void DisableInputDelay() noexcept;
 
 /**
- * \brief Render a sprite per live cell on the \p board.
- * \param [in] board 2D Game of Life Board containing current game state.
+ * \brief Queue a help message for display in the bottom row of the screen.
+ * \param [in] screen_dim Screen dimensions.
+ * \param [in] editing Flag indicating edit mode is active.
+ * \param [in] keymap The keys listed for each command.
  */
-void DrawBoard(const game::GameOfLifeBoard& board) noexcept;
+void DrawInstructions(const ScreenDimension& screen_dim, bool editing,
+                      const Keymap& keymap) noexcept;
 
 /**
- * \brief Print a help message on screen.
+ * \brief Queue the status bar for display in the second to last row of the
+ *        screen.
  * \param [in] screen_dim Screen dimensions.
+ * \param [in] status Simulation state to display.
+ */
+void DrawStatusBar(const ScreenDimension& screen_dim,
+                   const Status& status) noexcept;
+
+/**
+ * \brief Queue a menu of \p items for display over the middle of the board.
+ * \details The menu shows as many items as fit on the screen, scrolled such
+ *          that the selected item is in view. The board under the menu is
+ *          only drawn again after the screen is cleared.
+ * \param [in] screen_dim Screen dimensions.
+ * \param [in] title Title shown in the top border.
+ * \param [in] items Items listed in the menu.
+ * \param [in] selected Index of the highlighted item.
+ */
+void DrawMenu(const ScreenDimension& screen_dim, const std::string& title,
+              const std::vector<std::string>& items,
+              std::size_t selected) noexcept;
+
+/**
+ * \brief Flush all queued drawing operations to the terminal.
+ * \details The Draw*() functions only update an off screen buffer. Call
+ *          Refresh() once per frame to write the whole frame to the terminal
+ *          in a single batch. Call Renderer::Present() afterwards to output
+ *          the parts of the frame drawn without ncurses.
  */
-void DrawInstructions(const ScreenDimension& screen_dim) noexcept;
+void Refresh() noexcept;
 
 /**
- * \brief Return \c true if the user has chosen to quit.
- * \details Quitting in this case means the user pressed the 'q' key with the
- *          ncurses window in focus.
+ * \brief Return the Command bound to the next key press in \p keymap.
+ * \details ReadCommand() waits at most the delay set by EnableInputDelay() for
+ *          a key press with the ncurses window in focus.
+ * \param [in] keymap The key bindings.
+ * \param [in] editing When \c true, the keys of the edit mode commands take
+ *                     precedence.
  */
-[[nodiscard]] bool Quit() noexcept;
+[[nodiscard]] Command ReadCommand(const Keymap& keymap, bool editing) noexcept;
 
 }  // namespace graphics
 }  // namespace gol
@@ -0,0 +1,31 @@
+#ifndef SIXEL_H_
+#define SIXEL_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Encode an indexed color image as a DEC sixel escape sequence.
+ * \details Every pixel of the image is painted including those using palette
+ *          entry 0. The image is drawn at the terminal's cursor position.
+ * \param [in] pixels Row major palette indices of the image pixels.
+ * \param [in] width Number of pixels per row.
+ * \param [in] scale Each pixel is drawn as a \p scale x \p scale square.
+ * \param [in] palette Image colors.
+ * \returns The sixel escape sequence.
+ */
+[[nodiscard]] std::string EncodeSixel(const std::vector<std::uint8_t>& pixels,
+                                      std::size_t width, std::size_t scale,
+                                      const std::vector<Rgb>& palette);
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
@@ -0,0 +1,42 @@
+#ifndef SVG_H_
+#define SVG_H_
+
+#include <cstddef>
+#include <string>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief Options controlling the appearance of an SVG snapshot.
+ */
+struct SvgOptions {
+  std::size_t cell_size = 10; /**< Side of a cell in pixels. */
+  bool grid = false;          /**< Draw lines between the cells. */
+  Theme theme;                /**< Colors of the cells. */
+  Shading shading;            /**< What the cell colors show. */
+};
+
+/**
+ * \brief Return the visible window of \p board as an SVG image.
+ * \details Live cells are drawn as rectangles over a black background,
+ *          horizontally adjacent cells of the same color share a rectangle.
+ * \param [in] board Simulation engine containing current game state.
+ * \param [in] ages Optional cell ages, when null live cells have the theme's
+ *                  live color and dead cells leave no trails.
+ * \param [in] options Cell size, grid, and colors of the image.
+ * \throws std::invalid_argument When the cell size is 0.
+ */
+[[nodiscard]] std::string ToSvg(const game::Engine& board,
+                                const game::CellAges* ages = nullptr,
+                                const SvgOptions& options = {});
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
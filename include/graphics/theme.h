@@ -0,0 +1,69 @@
+#ifndef THEME_H_
+#define THEME_H_
+
+#include <array>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief A color of the xterm 256 color palette.
+ * \details Colors 0-15 are the standard and bright terminal colors, 16-231 are
+ *          a 6x6x6 color cube, and 232-255 are a grayscale ramp. On terminals
+ *          with fewer than 256 colors, the nearest of the 8 standard colors is
+ *          used instead.
+ */
+using Color = short;
+
+/**
+ * \brief A 24-bit RGB color.
+ */
+struct Rgb {
+  std::uint8_t red = 0;   /**< Red component. */
+  std::uint8_t green = 0; /**< Green component. */
+  std::uint8_t blue = 0;  /**< Blue component. */
+};
+
+/**
+ * \brief Colors used to draw the board.
+ */
+struct Theme {
+  std::string name = "classic"; /**< Name used to select the theme. */
+  Color live = 15;              /**< Color of live cells. */
+
+  /** Colors of newborn, young, mature, and old cells when coloring by age. */
+  std::array<Color, 4> ages = {2, 6, 4, 15};
+
+  /** Colors of states 1 and up of multi-state automata. */
+  std::vector<Color> states;
+};
+
+/**
+ * \brief Return the RGB value of \p color using the default xterm palette.
+ */
+[[nodiscard]] Rgb ToRgb(Color color) noexcept;
+
+/**
+ * \brief Return the color of the xterm 256 color palette nearest to \p rgb.
+ * \details The standard colors are skipped as terminals often redefine them.
+ */
+[[nodiscard]] Color NearestColor(const Rgb& rgb) noexcept;
+
+/**
+ * \brief Return the theme named \p name.
+ * \throws std::invalid_argument When no theme is named \p name.
+ */
+[[nodiscard]] Theme FindTheme(const std::string& name);
+
+/**
+ * \brief Return the names of all builtin themes.
+ */
+[[nodiscard]] std::vector<std::string> ThemeNames();
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
@@ -0,0 +1,219 @@
+#ifndef VIEWPORT_H_
+#define VIEWPORT_H_
+
+#include <cstddef>
+#include <optional>
+
+namespace gol {
+namespace graphics {
+
+/**
+ * \brief A screen position.
+ */
+struct ScreenPosition {
+  std::size_t row = 0; /**< Screen row. */
+  std::size_t col = 0; /**< Screen column. */
+};
+
+/**
+ * \brief A camera mapping board coordinates to screen characters.
+ * \details The Viewport displays a window into a board that may be larger than
+ *          the screen. The window can be panned across the board and zoomed
+ *          out such that each dot of a screen character covers a square block
+ *          of Zoom() x Zoom() board cells, or zoomed in such that each board
+ *          cell is magnified to a square block of CellSize() x CellSize()
+ *          dots. At most one of Zoom() and CellSize() is greater than 1.
+ */
+class Viewport {
+ public:
+  static constexpr std::size_t kMaxZoom = 64;     /**< Max cells per dot. */
+  static constexpr std::size_t kMaxCellSize = 16; /**< Max dots per cell. */
+
+  /**
+   * \brief Construct a viewport displaying the top left corner of a board.
+   * \details Each screen character is split into a grid of \p dot_rows x
+   *          \p dot_cols dots. At the default zoom level each dot displays a
+   *          single board cell.
+   * \param [in] height Number of screen rows used to display the board.
+   * \param [in] width Number of screen columns used to display the board.
+   * \param [in] board_rows Number of board rows.
+   * \param [in] board_cols Number of board columns.
+   * \param [in] dot_rows Number of rows of dots per screen character.
+   * \param [in] dot_cols Number of columns of dots per screen character.
+   */
+  [[nodiscard]] Viewport(std::size_t height, std::size_t width,
+                         std::size_t board_rows, std::size_t board_cols,
+                         std::size_t dot_rows = 1,
+                         std::size_t dot_cols = 1) noexcept;
+  Viewport() = delete;
+  ~Viewport() = default;
+
+  Viewport(const Viewport &) = default;
+  Viewport &operator=(const Viewport &) = default;
+  Viewport(Viewport &&) = default;
+  Viewport &operator=(Viewport &&) = default;
+
+  /**
+   * \brief Return the number of screen rows used to display the board.
+   */
+  [[nodiscard]] std::size_t Height() const noexcept { return height_; }
+
+  /**
+   * \brief Return the number of screen columns used to display the board.
+   */
+  [[nodiscard]] std::size_t Width() const noexcept { return width_; }
+
+  /**
+   * \brief Return the board row displayed in the top left corner.
+   */
+  [[nodiscard]] std::size_t Row() const noexcept { return row_; }
+
+  /**
+   * \brief Return the board column displayed in the top left corner.
+   */
+  [[nodiscard]] std::size_t Col() const noexcept { return col_; }
+
+  /**
+   * \brief Return the number of board cells per dot along each axis.
+   */
+  [[nodiscard]] std::size_t Zoom() const noexcept { return zoom_; }
+
+  /**
+   * \brief Return the number of dots per board cell along each axis.
+   */
+  [[nodiscard]] std::size_t CellSize() const noexcept { return cell_size_; }
+
+  /**
+   * \brief Return the number of rows of dots per screen character.
+   */
+  [[nodiscard]] std::size_t DotRows() const noexcept { return dot_rows_; }
+
+  /**
+   * \brief Return the number of columns of dots per screen character.
+   */
+  [[nodiscard]] std::size_t DotCols() const noexcept { return dot_cols_; }
+
+  /**
+   * \brief Change the number of screen rows and columns used to display the
+   *        board.
+   * \details The zoom level is kept and the viewport is moved back within the
+   *          board boundaries if needed.
+   */
+  void Resize(std::size_t height, std::size_t width) noexcept;
+
+  /**
+   * \brief Move the viewport by the given number of screen characters.
+   * \details The viewport is kept within the board boundaries.
+   * \param [in] d_row Number of characters to move down (negative for up).
+   * \param [in] d_col Number of characters to move right (negative for left).
+   */
+  void Pan(int d_row, int d_col) noexcept;
+
+  /**
+   * \brief Halve the number of board cells per dot, or double the number of
+   *        dots per board cell when not zoomed out.
+   */
+  void ZoomIn() noexcept;
+
+  /**
+   * \brief Halve the number of dots per board cell, or double the number of
+   *        board cells per dot when not magnified.
+   */
+  void ZoomOut() noexcept;
+
+  /**
+   * \brief Zoom out until the top left \p rows x \p cols board cells are
+   *        visible or the maximum zoom level is reached.
+   */
+  void ZoomToFit(std::size_t rows, std::size_t cols) noexcept;
+
+  /**
+   * \brief Magnify board cells as much as possible while \p rows x \p cols
+   *        board cells remain visible.
+   * \details Zooms out with ZoomToFit() when the cells do not fit at all.
+   */
+  void ZoomToFill(std::size_t rows, std::size_t cols) noexcept;
+
+  /**
+   * \brief Zoom in or out by one level towards the most magnified level at
+   *        which \p rows x \p cols board cells are visible.
+   * \details Calling it once per frame changes the zoom level gradually
+   *          instead of jumping straight to the target level.
+   */
+  void StepZoomToFit(std::size_t rows, std::size_t cols) noexcept;
+
+  /**
+   * \brief Set the number of board cells per dot and dots per board cell.
+   * \details Both are limited to their maximum, \p cell_size is ignored when
+   *          zoomed out.
+   */
+  void SetZoom(std::size_t zoom, std::size_t cell_size) noexcept;
+
+  /**
+   * \brief Move the viewport such that board cell (\p row, \p col) is
+   *        displayed in the top left corner.
+   * \details The viewport is kept within the board boundaries.
+   */
+  void MoveTo(std::size_t row, std::size_t col) noexcept;
+
+  /**
+   * \brief Move the viewport such that board cell (\p row, \p col) is
+   *        displayed in the center of the screen.
+   * \details The viewport is kept within the board boundaries.
+   */
+  void CenterOn(std::size_t row, std::size_t col) noexcept;
+
+  /**
+   * \brief Move the viewport the minimum amount needed for the board cell at
+   *        (\p row, \p col) to be visible.
+   */
+  void Follow(std::size_t row, std::size_t col) noexcept;
+
+  /**
+   * \brief Return the board row displayed by the \p dot_row th row of dots
+   *        from the top of the screen.
+   * \details When zoomed out, the first row covered by the dot is returned.
+   */
+  [[nodiscard]] std::size_t BoardRow(std::size_t dot_row) const noexcept {
+    return row_ + ((dot_row * zoom_) / cell_size_);
+  }
+
+  /**
+   * \brief Return the board column displayed by the \p dot_col th column of
+   *        dots from the left of the screen.
+   * \details When zoomed out, the first column covered by the dot is
+   *          returned.
+   */
+  [[nodiscard]] std::size_t BoardCol(std::size_t dot_col) const noexcept {
+    return col_ + ((dot_col * zoom_) / cell_size_);
+  }
+
+  /**
+   * \brief Return the screen position displaying board cell (\p row, \p col).
+   * \details When magnified, the top left character of the cell is returned.
+   * \return The screen position or \c std::nullopt if the cell is not visible.
+   */
+  [[nodiscard]] std::optional<ScreenPosition> ToScreen(
+      std::size_t row, std::size_t col) const noexcept;
+
+ private:
+  void Clamp() noexcept;
+  [[nodiscard]] std::size_t VisibleRows() const noexcept;
+  [[nodiscard]] std::size_t VisibleCols() const noexcept;
+
+  std::size_t height_ = 0;     /**< Screen rows used to display the board. */
+  std::size_t width_ = 0;      /**< Screen columns used to display the board. */
+  std::size_t board_rows_ = 0; /**< Number of board rows. */
+  std::size_t board_cols_ = 0; /**< Number of board columns. */
+  std::size_t row_ = 0;        /**< Board row in the top left corner. */
+  std::size_t col_ = 0;        /**< Board column in the top left corner. */
+  std::size_t zoom_ = 1;       /**< Board cells per dot. */
+  std::size_t cell_size_ = 1;  /**< Dots per board cell. */
+  std::size_t dot_rows_ = 1;   /**< Rows of dots per screen character. */
+  std::size_t dot_cols_ = 1;   /**< Columns of dots per screen character. */
+};
+
+}  // namespace graphics
+}  // namespace gol
+
+#endif
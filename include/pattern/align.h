@@ -0,0 +1,66 @@
+#ifndef ALIGN_H_
+#define ALIGN_H_
+
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Where a pattern is placed on the screen.
+ */
+enum class Anchor {
+  kOrigin,  /**< At its own positions, shifted onto the board if negative. */
+  kTopLeft, /**< Bounding box in the top left corner. */
+  kCenter,  /**< Bounding box in the center. */
+};
+
+/**
+ * \brief An anchor and an offset applied after anchoring the pattern.
+ */
+struct Alignment {
+  Anchor anchor = Anchor::kOrigin; /**< Where the pattern is anchored. */
+  Position2D offset;               /**< Columns right and rows down. */
+};
+
+/**
+ * \brief Return the anchor named \p name, one of 'origin', 'top-left' or
+ *        'center'.
+ * \throws std::invalid_argument When no anchor is named \p name.
+ */
+[[nodiscard]] Anchor ParseAnchor(const std::string& name);
+
+/**
+ * \brief Return the names of all anchors.
+ */
+[[nodiscard]] std::vector<std::string> AnchorNames();
+
+/**
+ * \brief Parse an offset of the form 'X,Y' where X and Y may be negative.
+ * \throws std::invalid_argument When \p spec is not a valid offset.
+ */
+[[nodiscard]] Position2D ParseOffset(const std::string& spec);
+
+/**
+ * \brief Return \p live_cells placed on a \p rows x \p cols screen according
+ *        to \p alignment.
+ * \details A centered pattern larger than the screen along an axis starts
+ *          at the edge of the screen along that axis. Cells moved to negative
+ *          positions by the offset are returned as is.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] alignment Where to place the pattern.
+ * \param [in] rows Number of screen rows.
+ * \param [in] cols Number of screen columns.
+ */
+[[nodiscard]] Position2DVec PlacePattern(const Position2DVec& live_cells,
+                                         const Alignment& alignment,
+                                         std::size_t rows, std::size_t cols);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
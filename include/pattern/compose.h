@@ -0,0 +1,88 @@
+#ifndef COMPOSE_H_
+#define COMPOSE_H_
+
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "pattern/diagnostics.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Orientations a pattern can be placed in.
+ */
+enum class Orientation {
+  kIdentity, /**< As loaded. */
+  kRot90,    /**< Rotated 90 degrees clockwise. */
+  kRot180,   /**< Rotated 180 degrees. */
+  kRot270,   /**< Rotated 270 degrees clockwise. */
+  kFlipH,    /**< Mirrored left to right. */
+  kFlipV,    /**< Mirrored top to bottom. */
+};
+
+/**
+ * \brief A pattern placed at an offset on the board.
+ */
+struct Placement {
+  std::string source;                               /**< Pattern source. */
+  std::int32_t x = 0;                               /**< Column offset. */
+  std::int32_t y = 0;                               /**< Row offset. */
+  Orientation orientation = Orientation::kIdentity; /**< Orientation. */
+};
+
+/**
+ * \brief Return the orientation named \p name, one of 'rot0', 'rot90',
+ *        'rot180', 'rot270', 'fliph' or 'flipv'.
+ * \throws std::invalid_argument When no orientation is named \p name.
+ */
+[[nodiscard]] Orientation ParseOrientation(const std::string& name);
+
+/**
+ * \brief Parse a placement of the form 'SOURCE[@X,Y][:ORIENTATION]'.
+ * \details The offset defaults to the origin and the orientation to rot0.
+ *          Offsets may be negative.
+ * \throws std::invalid_argument When the offset or orientation is invalid.
+ */
+[[nodiscard]] Placement ParsePlacement(const std::string& spec);
+
+/**
+ * \brief Return \p live_cells in \p orientation.
+ * \details The top left corner of the returned pattern's bounding box lies at
+ *          the origin.
+ */
+[[nodiscard]] Position2DVec Orient(const Position2DVec& live_cells,
+                                   Orientation orientation);
+
+/**
+ * \brief Return the patterns of two players facing each other.
+ * \details The cells of \p red are put in state 1 and those of \p blue,
+ *          rotated 180 degrees, in state 2. The blue pattern lies \p gap
+ *          columns right of the red one, the two are centered vertically on
+ *          each other.
+ */
+[[nodiscard]] Position2DVec FaceOff(const Position2DVec& red,
+                                    const Position2DVec& blue,
+                                    std::int32_t gap);
+
+/**
+ * \brief Load each placement's pattern and return their union.
+ * \details Sources naming an embedded pattern (see PatternNames()) load the
+ *          embedded pattern, other sources are loaded with LoadPattern(). Each
+ *          pattern is oriented before being moved to its offset. Cells covered
+ *          by several patterns are returned once.
+ * \param [in] placements The patterns and where to place them.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern files are parsed strictly.
+ * \throws std::invalid_argument When a pattern file cannot be opened.
+ * \throws ParseError When a pattern file is malformed in strict mode.
+ */
+[[nodiscard]] Position2DVec Compose(const std::vector<Placement>& placements,
+                                    Diagnostics* diagnostics = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,44 @@
+#ifndef COORDINATES_H_
+#define COORDINATES_H_
+
+#include <istream>
+#include <ostream>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Parse a coordinate list pattern.
+ * \details Each line of a coordinate list contains the '(row, col)' position of
+ *          a single live cell, positions may be negative. Blank lines are
+ *          skipped and lines beginning with '#' are comments, which may carry
+ *          metadata (see ParseMetadataLine()). See the examples/ directory
+ *          for samples.
+ * \param [in] is Input stream containing the coordinate list.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the pattern.
+ * \return The positions of all live cells in the pattern.
+ * \throws ParseError When a line is not a valid position in strict mode.
+ */
+[[nodiscard]] Position2DVec ParseCoordinates(
+    std::istream& is, Diagnostics* diagnostics = nullptr,
+    PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os as a coordinate list.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as comments.
+ */
+void WriteCoordinates(std::ostream& os, const Position2DVec& live_cells,
+                      const PatternMetadata* metadata = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
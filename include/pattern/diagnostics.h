@@ -0,0 +1,101 @@
+#ifndef DIAGNOSTICS_H_
+#define DIAGNOSTICS_H_
+
+#include <cstddef>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief A problem found while parsing a pattern.
+ */
+struct ParseDiagnostic {
+  std::string source;     /**< Pattern file, empty for in-memory patterns. */
+  std::size_t line = 0;   /**< Line number starting at 1, 0 if unknown. */
+  std::size_t column = 0; /**< Column starting at 1, 0 if unknown. */
+  std::string reason;     /**< Description of the problem. */
+};
+
+/**
+ * \brief Return \p diagnostic formatted as 'SOURCE:LINE:COLUMN: REASON'.
+ * \details The source, line and column are omitted when unknown.
+ */
+[[nodiscard]] std::string ToString(const ParseDiagnostic& diagnostic);
+
+/**
+ * \brief Error thrown when a strictly parsed pattern is malformed.
+ */
+class ParseError : public std::runtime_error {
+ public:
+  /**
+   * \brief Construct an error describing \p diagnostic.
+   */
+  [[nodiscard]] explicit ParseError(const ParseDiagnostic& diagnostic);
+
+  /**
+   * \brief Return the problem that caused the error.
+   */
+  [[nodiscard]] const ParseDiagnostic& Diagnostic() const noexcept {
+    return diagnostic_;
+  }
+
+ private:
+  ParseDiagnostic diagnostic_; /**< Problem that caused the error. */
+};
+
+/**
+ * \brief Decides how the problems found while parsing are handled.
+ * \details In strict mode, the first problem aborts parsing with a ParseError.
+ *          In lenient mode, the parser skips over malformed input and each
+ *          problem is collected as a warning.
+ */
+class Diagnostics {
+ public:
+  /**
+   * \brief Construct strict or lenient diagnostics.
+   * \param [in] strict Throw on the first problem rather than collecting
+   *                    warnings.
+   */
+  [[nodiscard]] explicit Diagnostics(bool strict = true) : strict_(strict) {}
+
+  /**
+   * \brief Return \c true if problems abort parsing.
+   */
+  [[nodiscard]] bool Strict() const noexcept { return strict_; }
+
+  /**
+   * \brief Report a problem on \p line.
+   * \throws ParseError In strict mode.
+   */
+  void Report(std::size_t line, const std::string& reason);
+
+  /**
+   * \brief Report a problem at \p column of \p line.
+   * \throws ParseError In strict mode.
+   */
+  void Report(std::size_t line, std::size_t column, const std::string& reason);
+
+  /**
+   * \brief Record \p warning as is, regardless of the mode.
+   */
+  void Add(const ParseDiagnostic& warning);
+
+  /**
+   * \brief Return the problems reported in lenient mode.
+   */
+  [[nodiscard]] const std::vector<ParseDiagnostic>& Warnings() const noexcept {
+    return warnings_;
+  }
+
+ private:
+  bool strict_ = true;                    /**< Throw on the first problem. */
+  std::vector<ParseDiagnostic> warnings_; /**< Collected problems. */
+};
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,49 @@
+#ifndef EXPORT_H_
+#define EXPORT_H_
+
+#include <string>
+
+#include "game/engine.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Return the positions of all live cells of \p board in row major
+ *        order.
+ * \details The cells of unbounded engines that left the visible window are
+ *          included, see game::Engine::LiveCells().
+ * \throws std::out_of_range When a cell lies beyond the range of pattern
+ *         coordinates.
+ */
+[[nodiscard]] Position2DVec ToPoints(const game::Engine& board);
+
+/**
+ * \brief Return the live cells of \p board as a Run Length Encoded (RLE)
+ *        pattern.
+ * \details Parsing the pattern with ParseRle() yields ToPoints(\p board),
+ *          shifted right and down when cells lie above or left of the
+ *          visible window.
+ */
+[[nodiscard]] std::string ToRle(const game::Engine& board);
+
+/**
+ * \brief Return the live cells of \p board as a plaintext (.cells) pattern.
+ * \details Parsing the pattern with ParsePlaintext() yields
+ *          ToPoints(\p board), shifted right and down when cells lie above
+ *          or left of the visible window.
+ */
+[[nodiscard]] std::string ToPlaintext(const game::Engine& board);
+
+/**
+ * \brief Return the live cells of \p board as a coordinate list.
+ * \details Parsing the pattern with ParseCoordinates() yields
+ *          ToPoints(\p board).
+ */
+[[nodiscard]] std::string ToCoordinates(const game::Engine& board);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,231 @@
+#ifndef JSON_H_
+#define JSON_H_
+
+#include <cstdint>
+#include <istream>
+#include <ostream>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/board.h"
+#include "game/rule.h"
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief A JSON value.
+ * \details Numbers are stored as doubles and thus represent integers exactly
+ *          up to 2^53. Object members keep their insertion order.
+ */
+class JsonValue {
+ public:
+  /**
+   * \brief Kinds of JSON values.
+   */
+  enum class Type {
+    kNull,   /**< null */
+    kBool,   /**< true or false */
+    kNumber, /**< A number. */
+    kString, /**< A string. */
+    kArray,  /**< An array of values. */
+    kObject, /**< An object mapping strings to values. */
+  };
+
+  using Member = std::pair<std::string, JsonValue>; /**< Object member. */
+
+  /**
+   * \brief Construct null.
+   */
+  JsonValue() = default;
+  JsonValue(bool value) : type_(Type::kBool), bool_(value) {}
+  JsonValue(double value) : type_(Type::kNumber), number_(value) {}
+  JsonValue(std::int64_t value);
+  JsonValue(std::uint64_t value);
+  JsonValue(std::int32_t value) : JsonValue(std::int64_t{value}) {}
+  JsonValue(std::string value)
+      : type_(Type::kString), string_(std::move(value)) {}
+  JsonValue(const char* value) : JsonValue(std::string(value)) {}
+
+  /**
+   * \brief Return an empty array.
+   */
+  [[nodiscard]] static JsonValue Array();
+
+  /**
+   * \brief Return an empty object.
+   */
+  [[nodiscard]] static JsonValue Object();
+
+  [[nodiscard]] Type GetType() const noexcept { return type_; }
+
+  /**
+   * \brief Return the value of a boolean.
+   * \throws std::invalid_argument When the value is not a boolean.
+   */
+  [[nodiscard]] bool AsBool() const;
+
+  /**
+   * \brief Return the value of a number.
+   * \throws std::invalid_argument When the value is not a number.
+   */
+  [[nodiscard]] double AsNumber() const;
+
+  /**
+   * \brief Return the value of a number that must be an integer within the
+   *        range of \c std::int64_t.
+   * \throws std::invalid_argument When the value is not such an integer.
+   */
+  [[nodiscard]] std::int64_t AsInt() const;
+
+  /**
+   * \brief Return the value of a string.
+   * \throws std::invalid_argument When the value is not a string.
+   */
+  [[nodiscard]] const std::string& AsString() const;
+
+  /**
+   * \brief Return the elements of an array.
+   * \throws std::invalid_argument When the value is not an array.
+   */
+  [[nodiscard]] const std::vector<JsonValue>& AsArray() const;
+
+  /**
+   * \brief Return the members of an object.
+   * \throws std::invalid_argument When the value is not an object.
+   */
+  [[nodiscard]] const std::vector<Member>& AsObject() const;
+
+  /**
+   * \brief Return the member \p key of an object or null if it has none.
+   * \throws std::invalid_argument When the value is not an object.
+   */
+  [[nodiscard]] const JsonValue& Get(const std::string& key) const;
+
+  /**
+   * \brief Return \c true if the object has a member \p key.
+   * \throws std::invalid_argument When the value is not an object.
+   */
+  [[nodiscard]] bool Contains(const std::string& key) const;
+
+  /**
+   * \brief Append \p value to an array.
+   * \throws std::invalid_argument When the value is not an array.
+   */
+  void Push(JsonValue value);
+
+  /**
+   * \brief Set member \p key of an object to \p value.
+   * \throws std::invalid_argument When the value is not an object.
+   */
+  void Set(const std::string& key, JsonValue value);
+
+ private:
+  Type type_ = Type::kNull;         /**< Kind of value. */
+  bool bool_ = false;               /**< Value of a boolean. */
+  double number_ = 0.0;             /**< Value of a number. */
+  std::string string_;              /**< Value of a string. */
+  std::vector<JsonValue> elements_; /**< Elements of an array. */
+  std::vector<Member> members_;     /**< Members of an object. */
+};
+
+/**
+ * \brief Parse the JSON document \p text.
+ * \throws ParseError When \p text is not a single valid JSON value.
+ */
+[[nodiscard]] JsonValue ParseJson(const std::string& text);
+
+/**
+ * \brief Return \p value serialized on a single line without whitespace.
+ */
+[[nodiscard]] std::string DumpJson(const JsonValue& value);
+
+/**
+ * \brief Return \p pos as an [x, y] array, or [x, y, state] when the cell
+ *        is in a state other than 1.
+ */
+[[nodiscard]] JsonValue ToJson(const Position2D& pos);
+
+/**
+ * \brief Return the position stored as an [x, y] or [x, y, state] array.
+ * \throws std::invalid_argument When \p json is not such an array.
+ */
+[[nodiscard]] Position2D PositionFromJson(const JsonValue& json);
+
+/**
+ * \brief Return \p rule as its B/S rulestring.
+ */
+[[nodiscard]] JsonValue ToJson(const game::Rule& rule);
+
+/**
+ * \brief Return the rule stored as a B/S rulestring.
+ * \throws std::invalid_argument When \p json is not a valid rulestring.
+ */
+[[nodiscard]] game::Rule RuleFromJson(const JsonValue& json);
+
+/**
+ * \brief Return the size, edge wrapping, rule, generation, population and live
+ *        cells of \p board.
+ * \details For example, {"rows":2,"cols":3,"wrap":false,"rule":"B3/S23",
+ *          "generation":0,"population":1,"cells":[[2,1]]}. Boards running a
+ *          multi-state automaton store its name in "automaton" instead of
+ *          "rule", e.g., "automaton":"brain", and the states of their cells.
+ */
+[[nodiscard]] JsonValue ToJson(const game::GameOfLifeBoard& board);
+
+/**
+ * \brief Return the board stored by ToJson().
+ * \details "wrap" and "rule" are optional and default to a non-wrapping
+ *          board running Conway's rule. A board naming an automaton, as
+ *          FindAutomaton() does, runs it. The generation and population are
+ *          not restored.
+ * \throws std::invalid_argument When \p json does not hold a board, names an
+ *         unknown automaton, or a cell lies outside of it or has a state the
+ *         board lacks.
+ */
+[[nodiscard]] game::GameOfLifeBoard BoardFromJson(const JsonValue& json);
+
+/**
+ * \brief Parse a JSON pattern.
+ * \details A JSON pattern is an object holding the [x, y] positions of its
+ *          live cells in "cells" and optional "name", "author",
+ *          "description" and "rule" strings. Boards written by ToJson() are
+ *          valid JSON patterns.
+ * \param [in] is Input stream containing the JSON pattern.
+ * \param [out] metadata Optional metadata found in the pattern.
+ * \return The positions of all live cells in the pattern.
+ * \throws ParseError When the pattern is malformed, JSON patterns are always
+ *         parsed strictly.
+ */
+[[nodiscard]] Position2DVec ParseJsonPattern(
+    std::istream& is, PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os as a single line JSON pattern.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as members.
+ */
+void WriteJsonPattern(std::ostream& os, const Position2DVec& live_cells,
+                      const PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write a generation of a board to \p os as a single line of JSON.
+ * \details The line is an object holding the generation, the population and
+ *          the [x, y] positions of the live cells, for example
+ *          {"generation":1,"population":2,"cells":[[0,0],[1,0]]}.
+ * \param [in] os Output stream.
+ * \param [in] generation Generation of the board.
+ * \param [in] live_cells The positions of all live cells of the board.
+ */
+void WriteJsonLine(std::ostream& os, std::uint64_t generation,
+                   const Position2DVec& live_cells);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,42 @@
+#ifndef LIBRARY_H_
+#define LIBRARY_H_
+
+#include <string>
+#include <vector>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief A classic pattern embedded in the program.
+ */
+struct LibraryPattern {
+  std::string name;        /**< Name used to select the pattern. */
+  std::string description; /**< One line description of the pattern. */
+  std::string rle;         /**< The pattern in the RLE format. */
+};
+
+/**
+ * \brief Return the embedded patterns.
+ */
+[[nodiscard]] const std::vector<LibraryPattern>& PatternLibrary();
+
+/**
+ * \brief Return the names of the embedded patterns.
+ */
+[[nodiscard]] std::vector<std::string> PatternNames();
+
+/**
+ * \brief Return the live cells of the embedded pattern named \p name.
+ * \details The top left corner of the pattern's bounding box lies at the
+ *          origin.
+ * \throws std::invalid_argument When no embedded pattern is named \p name.
+ */
+[[nodiscard]] Position2DVec FindPattern(const std::string& name);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,45 @@
+#ifndef LIFE106_H_
+#define LIFE106_H_
+
+#include <istream>
+#include <ostream>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Parse a Life 1.06 pattern.
+ * \details A Life 1.06 file begins with a '#Life 1.06' header followed by one
+ *          'x y' coordinate pair per live cell. Coordinates may be negative
+ *          and are returned as is, relative to the pattern's origin.
+ * \param [in] is Input stream containing the Life 1.06 pattern.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the comment lines, '#D'
+ *              lines are read as description.
+ * \return The positions of all live cells in the pattern.
+ * \throws ParseError When the header is missing or a line is anything but a
+ *         valid coordinate pair in strict mode, such lines are skipped in
+ *         lenient mode.
+ */
+[[nodiscard]] Position2DVec ParseLife106(std::istream& is,
+                                         Diagnostics* diagnostics = nullptr,
+                                         PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os as a Life 1.06 pattern.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as comments.
+ */
+void WriteLife106(std::ostream& os, const Position2DVec& live_cells,
+                  const PatternMetadata* metadata = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
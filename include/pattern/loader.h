@@ -0,0 +1,103 @@
+#ifndef LOADER_H_
+#define LOADER_H_
+
+#include <istream>
+#include <ostream>
+#include <string>
+#include <vector>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Supported pattern file formats.
+ */
+enum class Format {
+  kCoordinates, /**< '(row, col)' coordinate list. */
+  kRle,         /**< Run Length Encoded. */
+  kLife106,     /**< Life 1.06. */
+  kPlaintext,   /**< Plaintext (.cells). */
+  kJson,        /**< JSON object listing the live cells. */
+};
+
+/**
+ * \brief Return the format named \p name, one of 'coords', 'rle', 'life106',
+ *        'plaintext' or 'json'.
+ * \throws std::invalid_argument When no format is named \p name.
+ */
+[[nodiscard]] Format ParseFormat(const std::string& name);
+
+/**
+ * \brief Return the names of all formats.
+ */
+[[nodiscard]] std::vector<std::string> FormatNames();
+
+/**
+ * \brief Return the file extension, including the leading dot, of \p format.
+ */
+[[nodiscard]] std::string FormatExtension(Format format);
+
+/**
+ * \brief Determine the format of a pattern file.
+ * \details The file extension takes precedence. When the extension is not one
+ *          of the well known pattern extensions (.rle, .lif, .life, .cells,
+ *          .json), the format is inferred from the first non-blank line of
+ *          \p contents that is not a '#' comment.
+ * \param [in] filename Path to the pattern file.
+ * \param [in] contents Contents of the pattern file.
+ * \return The detected pattern format. Files that cannot be identified are
+ *         assumed to be coordinate lists.
+ */
+[[nodiscard]] Format DetectFormat(const std::string& filename,
+                                  const std::string& contents);
+
+/**
+ * \brief Parse the pattern in \p is according to \p format.
+ * \param [in] format Format of the pattern.
+ * \param [in] is Input stream containing the pattern.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the pattern.
+ * \return The positions of all live cells in the pattern.
+ * \throws ParseError When the pattern is malformed in strict mode.
+ */
+[[nodiscard]] Position2DVec Parse(Format format, std::istream& is,
+                                  Diagnostics* diagnostics = nullptr,
+                                  PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os according to \p format.
+ * \param [in] format Format of the pattern.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as comments.
+ */
+void Write(Format format, std::ostream& os, const Position2DVec& live_cells,
+           const PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Load the pattern file at \p filename.
+ * \details The pattern format is auto-detected using DetectFormat().
+ *          Diagnostics are attributed to \p filename. A \p filename of '-'
+ *          reads the pattern from standard input, its diagnostics are
+ *          attributed to '<stdin>'.
+ * \param [in] filename Path to the pattern file or '-'.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the pattern.
+ * \return The positions of all live cells in the pattern.
+ * \throws std::invalid_argument When \p filename cannot be opened.
+ * \throws ParseError When the pattern is malformed in strict mode.
+ */
+[[nodiscard]] Position2DVec LoadPattern(const std::string& filename,
+                                        Diagnostics* diagnostics = nullptr,
+                                        PatternMetadata* metadata = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
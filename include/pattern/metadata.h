@@ -0,0 +1,48 @@
+#ifndef METADATA_H_
+#define METADATA_H_
+
+#include <ostream>
+#include <string>
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Descriptive information stored alongside a pattern.
+ * \details Each field is empty when the pattern file does not provide it.
+ */
+struct PatternMetadata {
+  std::string name;        /**< Name of the pattern. */
+  std::string author;      /**< Discoverer or author of the pattern. */
+  std::string description; /**< Comment lines joined by spaces. */
+  std::string rule;        /**< Rulestring the pattern is meant for. */
+};
+
+/**
+ * \brief Record the value of a '#N', '#O', '#C' or '#r' metadata line.
+ * \details The tags follow the RLE format, '#N' names the pattern, '#O' gives
+ *          its author, '#C' (or '#c') adds a line of description and '#r'
+ *          gives its rule. Other lines are left alone.
+ * \param [in] line A line of a pattern file.
+ * \param [in,out] metadata The metadata the value is recorded in.
+ * \return \c true if \p line is a metadata line.
+ */
+bool ParseMetadataLine(const std::string& line, PatternMetadata& metadata);
+
+/**
+ * \brief Write \p metadata to \p os as '#N', '#O', '#C' and '#r' lines.
+ * \details Empty fields are omitted, the description is written as a single
+ *          '#C' line.
+ */
+void WriteMetadataLines(std::ostream& os, const PatternMetadata& metadata);
+
+/**
+ * \brief Return the title of a pattern, its name followed by its author.
+ * \details An empty string is returned for unnamed patterns.
+ */
+[[nodiscard]] std::string Title(const PatternMetadata& metadata);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
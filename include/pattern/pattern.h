@@ -0,0 +1,91 @@
+#ifndef PATTERN_H_
+#define PATTERN_H_
+
+#include <cstddef>
+#include <cstdint>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief A set of live cells that can be rotated, mirrored and moved.
+ * \details Rotations and reflections are applied within the pattern's bounding
+ *          box, the top left corner of the bounding box stays in place. The
+ *          operations return the pattern so that they can be chained, e.g.,
+ *          Pattern(cells).Rotate90().FlipH().Normalize().
+ */
+class Pattern {
+ public:
+  /**
+   * \brief Construct an empty pattern.
+   */
+  [[nodiscard]] Pattern() = default;
+
+  /**
+   * \brief Construct a pattern of the live cells \p cells.
+   */
+  [[nodiscard]] explicit Pattern(Position2DVec cells);
+
+  /**
+   * \brief Return the positions of the live cells.
+   */
+  [[nodiscard]] const Position2DVec& Cells() const noexcept { return cells_; }
+
+  /**
+   * \brief Return the top left corner of the bounding box.
+   * \details The origin is returned for empty patterns.
+   */
+  [[nodiscard]] Position2D Min() const noexcept;
+
+  /**
+   * \brief Return the bottom right corner of the bounding box.
+   * \details The origin is returned for empty patterns.
+   */
+  [[nodiscard]] Position2D Max() const noexcept;
+
+  /**
+   * \brief Return the number of columns spanned by the bounding box.
+   */
+  [[nodiscard]] std::size_t Width() const noexcept;
+
+  /**
+   * \brief Return the number of rows spanned by the bounding box.
+   */
+  [[nodiscard]] std::size_t Height() const noexcept;
+
+  /**
+   * \brief Rotate the pattern 90 degrees clockwise.
+   */
+  Pattern& Rotate90();
+
+  /**
+   * \brief Mirror the pattern left to right.
+   */
+  Pattern& FlipH();
+
+  /**
+   * \brief Mirror the pattern top to bottom.
+   */
+  Pattern& FlipV();
+
+  /**
+   * \brief Move the pattern \p dx columns right and \p dy rows down.
+   */
+  Pattern& Translate(std::int32_t dx, std::int32_t dy);
+
+  /**
+   * \brief Move the pattern such that the top left corner of its bounding box
+   *        lies at the origin.
+   */
+  Pattern& Normalize();
+
+ private:
+  Position2DVec cells_; /**< Live cells. */
+};
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
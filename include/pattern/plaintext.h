@@ -0,0 +1,49 @@
+#ifndef PLAINTEXT_H_
+#define PLAINTEXT_H_
+
+#include <istream>
+#include <ostream>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Parse a plaintext (.cells) pattern.
+ * \details Each line of a plaintext pattern is a row of the pattern. An 'O' or
+ *          '*' marks a live cell and a '.' marks a dead cell. Lines beginning
+ *          with '!' are treated as comments, '!Name:' and '!Author:' comments
+ *          give the name and author of the pattern.
+ * \param [in] is Input stream containing the plaintext pattern.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the pattern.
+ * \return The positions of all live cells in the pattern.
+ * \throws ParseError When a row contains an invalid character in strict mode,
+ *         invalid characters are read as dead cells in lenient mode.
+ */
+[[nodiscard]] Position2DVec ParsePlaintext(
+    std::istream& is, Diagnostics* diagnostics = nullptr,
+    PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os as a plaintext (.cells) pattern.
+ * \details The pattern spans from the origin to the bottom right live cell
+ *          such that parsing it yields the same positions. Patterns with
+ *          negative positions are shifted right and down until they start at
+ *          the origin. Dead cells past the last live cell of a row are
+ *          omitted.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as comments.
+ */
+void WritePlaintext(std::ostream& os, const Position2DVec& live_cells,
+                    const PatternMetadata* metadata = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,38 @@
+#ifndef POSITION_H_
+#define POSITION_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief A live cell position within a pattern.
+ * \details Pattern coordinates are relative to the pattern's origin and may be
+ *          negative. They are converted to board coordinates when the pattern
+ *          is placed on a board.
+ */
+struct Position2D {
+  std::int32_t x = 0;     /**< Column index. */
+  std::int32_t y = 0;     /**< Row index. */
+  std::uint8_t state = 1; /**< Cell state, above 1 in multi-state patterns. */
+};
+
+using Position2DVec = std::vector<Position2D>;
+
+/**
+ * \brief Return \c true if \p pos lies within a \p rows x \p cols board.
+ */
+[[nodiscard]] constexpr bool OnBoard(const Position2D& pos, std::size_t rows,
+                                     std::size_t cols) noexcept {
+  return (pos.x >= 0) && (pos.y >= 0) &&
+         (static_cast<std::size_t>(pos.y) < rows) &&
+         (static_cast<std::size_t>(pos.x) < cols);
+}
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,150 @@
+#ifndef REPLAY_H_
+#define REPLAY_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <string>
+
+#include "game/board.h"
+#include "game/engine.h"
+#include "game/pipeline.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Writes the frames of a simulation to a replay file.
+ * \details A replay file begins with a '#Replay 1' header followed by a
+ *          '#Size ROWS COLS' line and, for multi-state frames, a
+ *          '#States STATES' line. Each frame starts with a
+ *          '#Generation GENERATION POPULATION' line followed by the frame's
+ *          cells as a Run Length Encoded (RLE) pattern.
+ */
+class ReplayWriter {
+ public:
+  /**
+   * \brief Create the replay file \p filename for frames of \p num_rows by
+   *        \p num_cols cells in \p num_states states.
+   * \throws std::runtime_error When \p filename cannot be opened.
+   */
+  [[nodiscard]] ReplayWriter(const std::string& filename, std::size_t num_rows,
+                             std::size_t num_cols,
+                             std::uint8_t num_states = 2);
+  ReplayWriter() = delete;
+  ~ReplayWriter() = default;
+
+  ReplayWriter(const ReplayWriter&) = delete;
+  ReplayWriter& operator=(const ReplayWriter&) = delete;
+  ReplayWriter(ReplayWriter&&) = default;
+  ReplayWriter& operator=(ReplayWriter&&) = default;
+
+  /**
+   * \brief Append \p frame to the replay file.
+   */
+  void Write(const game::Frame& frame);
+
+ private:
+  std::ofstream file_; /**< Replay file. */
+};
+
+/**
+ * \brief An Engine playing back a replay file.
+ * \details The ReplayBoard starts at the first frame of the replay, each
+ *          Tick() advances to the next frame. Once the last frame is reached,
+ *          Tick() has no effect. Frames are read from the file as needed.
+ *          The cells of multi-state recordings keep their recorded states.
+ */
+class ReplayBoard : public game::Engine {
+ public:
+  /**
+   * \brief Open the replay file \p filename and load its first frame.
+   * \throws std::invalid_argument When \p filename cannot be opened.
+   * \throws std::runtime_error When the replay is malformed, including frames
+   *         holding states beyond those of its '#States' line.
+   */
+  [[nodiscard]] explicit ReplayBoard(const std::string& filename);
+  ReplayBoard() = delete;
+  ~ReplayBoard() override = default;
+
+  ReplayBoard(const ReplayBoard&) = delete;
+  ReplayBoard& operator=(const ReplayBoard&) = delete;
+  ReplayBoard(ReplayBoard&&) = default;
+  ReplayBoard& operator=(ReplayBoard&&) = default;
+
+  [[nodiscard]] std::size_t Rows() const noexcept override {
+    return cells_.Rows();
+  }
+  [[nodiscard]] std::size_t Cols() const noexcept override {
+    return cells_.Cols();
+  }
+
+  [[nodiscard]] bool IsAlive(std::size_t row,
+                             std::size_t col) const noexcept override {
+    return cells_.IsAlive(row, col);
+  }
+
+  /**
+   * \brief Edit the cell at (\p row, \p col) of the current frame.
+   * \details The edit is lost once the next frame is played.
+   */
+  void SetCell(std::size_t row, std::size_t col, bool alive) override {
+    cells_.SetCell(row, col, alive);
+  }
+
+  [[nodiscard]] std::uint8_t NumStates() const noexcept override {
+    return cells_.NumStates();
+  }
+
+  [[nodiscard]] std::uint8_t State(std::size_t row,
+                                   std::size_t col) const noexcept override {
+    return cells_.State(row, col);
+  }
+
+  /**
+   * \brief Edit the state of the cell at (\p row, \p col) of the current
+   *        frame.
+   * \details The edit is lost once the next frame is played.
+   */
+  void SetState(std::size_t row, std::size_t col,
+                std::uint8_t state) override {
+    cells_.SetState(row, col, state);
+  }
+
+  [[nodiscard]] std::uint64_t Population() const noexcept override {
+    return population_;
+  }
+
+  [[nodiscard]] std::uint64_t Generation() const noexcept override {
+    return generation_;
+  }
+
+  /**
+   * \brief Has no effect, the generation is the one of the current frame.
+   */
+  void SetGeneration(std::uint64_t) noexcept override {}
+
+  /**
+   * \brief Advance to the next frame of the replay.
+   * \throws std::runtime_error When the next frame is malformed.
+   */
+  void Tick() override;
+
+ private:
+  /**
+   * \brief Read the next frame into cells_.
+   * \return \c false if the replay has no more frames.
+   */
+  bool ReadFrame();
+
+  std::ifstream file_;           /**< Replay file. */
+  std::string header_;           /**< Header line of the next frame. */
+  game::GameOfLifeBoard cells_;  /**< Cells of the current frame. */
+  std::uint64_t generation_ = 0; /**< Generation of the current frame. */
+  std::uint64_t population_ = 0; /**< Population of the current frame. */
+};
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,56 @@
+#ifndef RLE_H_
+#define RLE_H_
+
+#include <istream>
+#include <ostream>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Parse a Run Length Encoded (RLE) pattern.
+ * \details The RLE format is the format used by Golly and LifeWiki. Lines
+ *          beginning with '#' are treated as comments and the
+ *          'x = m, y = n' header line is skipped. In the pattern body, 'b'
+ *          marks a dead cell, 'o' (or any other letter) marks a live cell, '$'
+ *          ends a row and '!' ends the pattern. Each tag may be prefixed by a
+ *          run count. Multi-state patterns mark dead cells with '.' and cells
+ *          in states 1 to 24 with 'A' to 'X', higher states prefix the letter
+ *          with one of 'p' to 'y'.
+ * \param [in] is Input stream containing the RLE pattern.
+ * \param [in] diagnostics Collects the problems found in lenient mode, when
+ *             null the pattern is parsed strictly.
+ * \param [out] metadata Optional metadata found in the '#N', '#O', '#C' and
+ *              '#r' lines and the rule of the header line.
+ * \return The positions and states of all live cells in the pattern.
+ * \throws ParseError When the pattern body contains an invalid character, a
+ *         run count above 2^24 or a run moving past the largest position in
+ *         strict mode, such tags are skipped in lenient mode.
+ */
+[[nodiscard]] Position2DVec ParseRle(std::istream& is,
+                                     Diagnostics* diagnostics = nullptr,
+                                     PatternMetadata* metadata = nullptr);
+
+/**
+ * \brief Write \p live_cells to \p os as a Run Length Encoded (RLE) pattern.
+ * \details The pattern spans from the origin to the bottom right live cell
+ *          such that parsing it yields the same positions. Patterns with
+ *          negative positions are shifted right and down until they start at
+ *          the origin. Patterns with cells in states above 1 are written in
+ *          the multi-state format. Lines of the pattern body are at most 70
+ *          characters long.
+ * \param [in] os Output stream.
+ * \param [in] live_cells The positions of all live cells in the pattern.
+ * \param [in] metadata Optional metadata written as comments.
+ */
+void WriteRle(std::ostream& os, const Position2DVec& live_cells,
+              const PatternMetadata* metadata = nullptr);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,60 @@
+#ifndef SESSION_H_
+#define SESSION_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+
+#include "pattern/json.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief The state of an interactive run from which it can be resumed.
+ */
+struct Session {
+  std::size_t rows = 0;         /**< Number of board rows. */
+  std::size_t cols = 0;         /**< Number of board columns. */
+  bool wrap = false;            /**< The board edges wrap around. */
+  std::string rule;             /**< Rule name, empty for Conway's Life. */
+  std::uint64_t generation = 0; /**< Generation of the saved cells. */
+  int update_rate_ms = 100;     /**< Delay between generations. */
+  std::size_t view_row = 0;     /**< Board row in the top left corner. */
+  std::size_t view_col = 0;     /**< Board column in the top left corner. */
+  std::size_t zoom = 1;         /**< Board cells per dot. */
+  std::size_t cell_size = 1;    /**< Dots per board cell. */
+  Position2DVec cells;          /**< Cells in a state other than dead. */
+};
+
+/**
+ * \brief Return \p session as a JSON object.
+ * \details The cells are stored as in JSON patterns, a session file is thus
+ *          also loadable as a pattern.
+ */
+[[nodiscard]] JsonValue ToJson(const Session& session);
+
+/**
+ * \brief Return the session stored in \p json.
+ * \throws std::invalid_argument When \p json does not hold a valid session.
+ */
+[[nodiscard]] Session SessionFromJson(const JsonValue& json);
+
+/**
+ * \brief Write \p session to the file \p filename.
+ * \throws std::runtime_error When \p filename cannot be opened.
+ */
+void SaveSession(const Session& session, const std::string& filename);
+
+/**
+ * \brief Read the session stored in the file \p filename.
+ * \throws std::invalid_argument When \p filename cannot be opened.
+ * \throws ParseError When the file does not hold a valid session.
+ */
+[[nodiscard]] Session LoadSession(const std::string& filename);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
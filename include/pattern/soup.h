@@ -0,0 +1,33 @@
+#ifndef SOUP_H_
+#define SOUP_H_
+
+#include <cstddef>
+#include <cstdint>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Return a random soup filling a \p rows x \p cols area.
+ * \details Each cell of the area is alive with probability \p density. Live
+ *          cells are in one of the states 1 to \p num_colors picked at random.
+ *          The soup only depends on the arguments, the same seed yields the
+ *          same soup on every platform.
+ * \param [in] rows Number of rows in the area.
+ * \param [in] cols Number of columns in the area.
+ * \param [in] density Probability of a cell being alive in [0, 1].
+ * \param [in] seed Seed of the random number generator.
+ * \param [in] num_colors Number of states of live cells.
+ * \return The positions of all live cells in row major order.
+ * \throws std::invalid_argument When \p density is not within [0, 1].
+ */
+[[nodiscard]] Position2DVec RandomSoup(std::size_t rows, std::size_t cols,
+                                       double density, std::uint64_t seed,
+                                       std::uint8_t num_colors = 1);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
@@ -0,0 +1,52 @@
+#ifndef STATS_FILE_H_
+#define STATS_FILE_H_
+
+#include <cstddef>
+#include <fstream>
+#include <string>
+
+#include "game/stats.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Writes the statistics of each generation to a file.
+ * \details Files ending in '.json' or '.jsonl' hold one JSON object per line,
+ *          for example {"generation":1,"population":5,"births":2,"deaths":2}.
+ *          Other files are CSV files starting with a
+ *          'generation,population,births,deaths' header. The file is flushed
+ *          every kFlushInterval lines and once the writer is destroyed.
+ */
+class StatsWriter {
+ public:
+  static constexpr std::size_t kFlushInterval = 64; /**< Lines per flush. */
+
+  /**
+   * \brief Create the statistics file \p filename.
+   * \throws std::runtime_error When \p filename cannot be opened.
+   */
+  [[nodiscard]] explicit StatsWriter(const std::string& filename);
+  StatsWriter() = delete;
+  ~StatsWriter() = default;
+
+  StatsWriter(const StatsWriter&) = delete;
+  StatsWriter& operator=(const StatsWriter&) = delete;
+  StatsWriter(StatsWriter&&) = default;
+  StatsWriter& operator=(StatsWriter&&) = default;
+
+  /**
+   * \brief Append the statistics of a generation to the file.
+   */
+  void Write(const game::StepStats& stats);
+
+ private:
+  std::ofstream file_;            /**< Statistics file. */
+  bool json_ = false;             /**< Write JSON lines rather than CSV. */
+  std::size_t num_unflushed_ = 0; /**< Lines written since the last flush. */
+};
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
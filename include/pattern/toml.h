@@ -0,0 +1,71 @@
+#ifndef TOML_H_
+#define TOML_H_
+
+#include <cstddef>
+#include <istream>
+#include <string>
+#include <vector>
+
+#include "pattern/diagnostics.h"
+
+namespace gol {
+namespace pattern {
+
+/**
+ * \brief Kinds of TOML values.
+ */
+enum class TomlType {
+  kString,  /**< Basic or literal string. */
+  kInteger, /**< Decimal integer. */
+  kFloat,   /**< Decimal floating point number. */
+  kBoolean, /**< true or false. */
+  kArray,   /**< Array of values. */
+};
+
+/**
+ * \brief A TOML value.
+ */
+struct TomlValue {
+  TomlType type = TomlType::kString; /**< Kind of value. */
+  std::string text;                  /**< Scalar value, strings unescaped. */
+  std::vector<TomlValue> elements;   /**< Elements of an array. */
+};
+
+/**
+ * \brief A key/value pair of a TOML document.
+ */
+struct TomlEntry {
+  std::string key;      /**< Dotted key including the enclosing table. */
+  TomlValue value;      /**< Value assigned to the key. */
+  std::size_t line = 0; /**< Line of the key starting at 1. */
+};
+
+using TomlDocument = std::vector<TomlEntry>; /**< Entries in file order. */
+
+/**
+ * \brief Parse the TOML document in \p is.
+ * \details The subset of TOML used by configuration files is supported:
+ *          comments, [tables], bare, quoted and dotted keys, basic and literal
+ *          strings, decimal integers and floats, booleans and arrays, which
+ *          may span several lines. Inline tables, arrays of tables, multiline
+ *          strings and dates are rejected.
+ * \param [in] is Input stream containing the document.
+ * \return The key/value pairs of the document, keys defined inside a table
+ *         are prefixed with the table name and a '.'.
+ * \throws ParseError When the document is malformed, unsupported or defines a
+ *         key twice.
+ */
+[[nodiscard]] TomlDocument ParseToml(std::istream& is);
+
+/**
+ * \brief Parse the TOML file \p filename.
+ * \throws std::invalid_argument When the file cannot be opened.
+ * \throws ParseError When the document is malformed, the error's source is
+ *         \p filename.
+ */
+[[nodiscard]] TomlDocument LoadToml(const std::string& filename);
+
+}  // namespace pattern
+}  // namespace gol
+
+#endif
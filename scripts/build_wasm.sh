@@ -0,0 +1,33 @@
+#!/bin/bash
+
+source config.sh
+
+Main()
+{
+    # Create the build directory if it does not already exist.
+    mkdir -pv $GOL_WASM_BUILD_DIR
+
+    # Set the build type according to the first program arg if any.
+    BUILD_TYPE="Release"
+    if [ ! -z  "$1" ]
+    then
+        BUILD_TYPE=$1
+    fi
+
+    # The Emscripten toolchain is picked up by running CMake under emcmake.
+    pushd $GOL_WASM_BUILD_DIR > /dev/null
+    emcmake cmake ../ \
+        -DCMAKE_INSTALL_PREFIX=$GOL_BIN_DIR \
+        -DCMAKE_BUILD_TYPE=$BUILD_TYPE && \
+        make -j$(nproc) all && \
+        make install
+
+    # Exit if any of the above commands fails.
+    if [ $? -ne 0 ];
+    then
+        exit 1
+    fi
+    popd > /dev/null
+}
+
+Main $1
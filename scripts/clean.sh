@@ -15,3 +15,10 @@ then
     echo "removing '$GOL_BUILD_DIR'"
     rm -rf $GOL_BUILD_DIR
 fi
+
+# Remove the CMake build directory of the WebAssembly module.
+if [ -d $GOL_WASM_BUILD_DIR ]
+then
+    echo "removing '$GOL_WASM_BUILD_DIR'"
+    rm -rf $GOL_WASM_BUILD_DIR
+fi
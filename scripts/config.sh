@@ -13,3 +13,6 @@ GOL_RES_DIR="${GOL_PROJECT_PATH}/resources"
 
 # CMake build files and cache.
 GOL_BUILD_DIR="${GOL_PROJECT_PATH}/build"
+
+# CMake build files and cache of the WebAssembly module.
+GOL_WASM_BUILD_DIR="${GOL_PROJECT_PATH}/build_wasm"
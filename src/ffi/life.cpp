@@ -0,0 +1,180 @@
+#include "ffi/life.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/engine.h"
+#include "game/factory.h"
+#include "game/rule.h"
+#include "pattern/library.h"
+#include "pattern/loader.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+struct gol_board {
+  std::unique_ptr<gol::game::Engine> engine;
+};
+
+/* Reason of the last failed call on this thread. */
+static thread_local std::string last_error;
+
+/* Record the reason of the exception being handled and return -1. Exceptions
+ * never cross the C interface. */
+static int Fail() noexcept {
+  try {
+    throw;
+  } catch (const std::exception& e) {
+    last_error = e.what();
+  } catch (...) {
+    last_error = "unknown error";
+  }
+  return -1;
+}
+
+/* Return true if (row, col) lies within the board. */
+[[nodiscard]] static bool OnBoard(const gol_board* board, std::size_t row,
+                                  std::size_t col) noexcept {
+  return (row < board->engine->Rows()) && (col < board->engine->Cols());
+}
+
+const char* gol_last_error() { return last_error.c_str(); }
+
+gol_board* gol_board_create(std::size_t rows, std::size_t cols,
+                            const char* rule, const char* engine, int wrap) {
+  try {
+    if ((0 == rows) || (0 == cols)) {
+      last_error = "board dimensions must be positive";
+      return nullptr;
+    } else if ((rows > gol::game::GameOfLifeBoard::kMaxSide) ||
+               (cols > gol::game::GameOfLifeBoard::kMaxSide)) {
+      const std::string kMax =
+          std::to_string(gol::game::GameOfLifeBoard::kMaxSide);
+      last_error = "board size must be at most " + kMax + "x" + kMax;
+      return nullptr;
+    }
+    gol::game::EngineOptions options;
+    options.rows = rows;
+    options.cols = cols;
+    options.wrap = (0 != wrap);
+    if (rule) {
+      options.automaton = gol::game::FindAutomaton(rule);
+      if (!options.automaton) {
+        options.rule = gol::game::ParseRule(rule);
+      }
+    }
+    const gol::game::EngineType kType =
+        (engine) ? gol::game::ParseEngineType(engine)
+                 : gol::game::EngineType::kNaive;
+    if (options.wrap && !gol::game::SupportsWrap(kType)) {
+      last_error = "the selected engine does not support wrapping";
+      return nullptr;
+    }
+    auto board = std::make_unique<gol_board>();
+    board->engine = gol::game::MakeEngine(kType, options);
+    return board.release();
+  } catch (...) {
+    Fail();
+    return nullptr;
+  }
+}
+
+void gol_board_destroy(gol_board* board) { delete board; }
+
+std::size_t gol_board_rows(const gol_board* board) {
+  return board->engine->Rows();
+}
+
+std::size_t gol_board_cols(const gol_board* board) {
+  return board->engine->Cols();
+}
+
+int gol_board_get_cell(const gol_board* board, std::size_t row,
+                       std::size_t col) {
+  if (!OnBoard(board, row, col)) {
+    last_error = "cell lies beyond the board edges";
+    return -1;
+  }
+  return board->engine->State(row, col);
+}
+
+int gol_board_set_cell(gol_board* board, std::size_t row, std::size_t col,
+                       int state) {
+  if (!OnBoard(board, row, col)) {
+    last_error = "cell lies beyond the board edges";
+    return -1;
+  } else if ((state < 0) || (state >= board->engine->NumStates())) {
+    last_error = "state out of range";
+    return -1;
+  }
+  try {
+    board->engine->SetState(row, col, static_cast<std::uint8_t>(state));
+  } catch (...) {
+    return Fail();
+  }
+  return 0;
+}
+
+int gol_board_copy_cells(const gol_board* board, std::uint8_t* cells,
+                         std::size_t size) {
+  const gol::game::Engine& kEngine = *board->engine;
+  if (size / kEngine.Cols() < kEngine.Rows()) {
+    last_error = "buffer is smaller than the board";
+    return -1;
+  }
+  for (std::size_t i = 0; i < kEngine.Rows(); ++i) {
+    for (std::size_t j = 0; j < kEngine.Cols(); ++j) {
+      cells[(i * kEngine.Cols()) + j] = kEngine.State(i, j);
+    }
+  }
+  return 0;
+}
+
+int gol_board_load_pattern(gol_board* board, const char* source,
+                           std::size_t row, std::size_t col) {
+  try {
+    /* embedded patterns take precedence over files of the same name */
+    const std::vector<std::string> kNames = gol::pattern::PatternNames();
+    const bool kEmbedded =
+        std::find(kNames.begin(), kNames.end(), source) != kNames.end();
+    gol::pattern::Pattern pattern((kEmbedded)
+                                      ? gol::pattern::FindPattern(source)
+                                      : gol::pattern::LoadPattern(source));
+    /* states the automaton lacks are plain live cells */
+    const std::uint8_t kNumStates = board->engine->NumStates();
+    for (const gol::pattern::Position2D& pos : pattern.Normalize().Cells()) {
+      const std::size_t kRow = row + static_cast<std::size_t>(pos.y);
+      const std::size_t kCol = col + static_cast<std::size_t>(pos.x);
+      if (OnBoard(board, kRow, kCol)) {
+        board->engine->SetState(kRow, kCol,
+                                (pos.state < kNumStates) ? pos.state : 1);
+      }
+    }
+  } catch (...) {
+    return Fail();
+  }
+  return 0;
+}
+
+int gol_board_step(gol_board* board, std::uint64_t generations) {
+  try {
+    board->engine->Advance(generations);
+  } catch (...) {
+    return Fail();
+  }
+  return 0;
+}
+
+std::uint64_t gol_board_generation(const gol_board* board) {
+  return board->engine->Generation();
+}
+
+std::uint64_t gol_board_population(const gol_board* board) {
+  return board->engine->Population();
+}
@@ -0,0 +1,45 @@
+#include "game/ages.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <limits>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+CellAges::CellAges(std::size_t num_rows, std::size_t num_cols)
+    : rows_(num_rows),
+      cols_(num_cols),
+      ages_(num_rows * num_cols, 0),
+      dead_for_(num_rows * num_cols, kLongDead) {}
+
+void CellAges::Update(const Engine& board) noexcept {
+  const std::size_t kRows = std::min(rows_, board.Rows());
+  const std::size_t kCols = std::min(cols_, board.Cols());
+  for (std::size_t i = 0; i < kRows; ++i) {
+    for (std::size_t j = 0; j < kCols; ++j) {
+      std::uint32_t& age = ages_[(i * cols_) + j];
+      std::uint8_t& dead_for = dead_for_[(i * cols_) + j];
+      if (board.IsAlive(i, j)) {
+        dead_for = 0;
+        if (age < std::numeric_limits<std::uint32_t>::max()) {
+          age++;
+        }
+      } else {
+        /* a cell dies in the update that finds it dead after being alive */
+        if (age) {
+          dead_for = 1;
+        } else if (dead_for < kLongDead) {
+          dead_for++;
+        }
+        age = 0;
+      }
+    }
+  }
+}
+
+}  // namespace game
+}  // namespace gol
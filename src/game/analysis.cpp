@@ -0,0 +1,279 @@
+#include "game/analysis.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <deque>
+#include <map>
+#include <optional>
+#include <ranges>
+#include <utility>
+#include <vector>
+
+#include "game/board.h"
+#include "game/cycle.h"
+#include "game/engine.h"
+#include "game/evolution.h"
+
+namespace gol {
+namespace game {
+
+/* Return a hash of the live cells of engine within bounds, equal for equal
+ * shapes wherever they lie. */
+[[nodiscard]] static std::uint64_t HashShape(const Engine& engine,
+                                             const CellBounds& bounds) {
+  GameOfLifeBoard shape(bounds.bottom - bounds.top + 1,
+                        bounds.right - bounds.left + 1);
+  for (std::size_t i = bounds.top; i <= bounds.bottom; ++i) {
+    for (std::size_t j = bounds.left; j <= bounds.right; ++j) {
+      if (engine.IsAlive(i, j)) {
+        shape.SetCell(i - bounds.top, j - bounds.left, true);
+      }
+    }
+  }
+  return shape.Hash();
+}
+
+/* Live cells at most kObjectGap rows and columns apart belong to the same
+ * object, objects further apart do not affect each other's next generation.
+ * Only objects of at most kMaxShipCells cells repeating within
+ * kMaxShipPeriod generations are tracked as spaceships, and they escape once
+ * kEscapeDistance cells clear of the debris they move away from. */
+static constexpr std::size_t kObjectGap = 2;
+static constexpr std::size_t kMaxShipCells = 64;
+static constexpr std::size_t kMaxShipPeriod = 16;
+static constexpr std::size_t kEscapeDistance = 16;
+
+/* A group of live cells apart from all other live cells. */
+struct Object {
+  std::vector<std::pair<std::size_t, std::size_t>> cells; /* (row, col) */
+  CellBounds bounds;
+  std::uint64_t hash = 0; /* of the shape, 0 for objects too large */
+};
+
+/* Return the index of the group holding i, merging the groups on the way. */
+[[nodiscard]] static std::size_t FindGroup(std::vector<std::size_t>& groups,
+                                           std::size_t i) {
+  while (groups[i] != i) {
+    groups[i] = groups[groups[i]];
+    i = groups[i];
+  }
+  return i;
+}
+
+/* Split the live cells of engine within bounds into objects. */
+[[nodiscard]] static std::vector<Object> FindObjects(
+    const Engine& engine, const CellBounds& bounds) {
+  const std::size_t kRows = bounds.bottom - bounds.top + 1;
+  const std::size_t kCols = bounds.right - bounds.left + 1;
+  const std::size_t kDead = kRows * kCols;
+
+  /* each live cell starts out as its own group and is merged with the groups
+   * of the live cells already seen nearby */
+  std::vector<std::pair<std::size_t, std::size_t>> cells;
+  std::vector<std::size_t> groups;
+  std::vector<std::size_t> index(kRows * kCols, kDead);
+  for (std::size_t i = 0; i < kRows; ++i) {
+    for (std::size_t j = 0; j < kCols; ++j) {
+      if (!engine.IsAlive(bounds.top + i, bounds.left + j)) {
+        continue;
+      }
+      index[i * kCols + j] = cells.size();
+      groups.push_back(cells.size());
+      cells.emplace_back(bounds.top + i, bounds.left + j);
+      for (std::size_t k = (i < kObjectGap) ? 0 : i - kObjectGap; k <= i;
+           ++k) {
+        for (std::size_t l = (j < kObjectGap) ? 0 : j - kObjectGap;
+             l <= std::min(j + kObjectGap, kCols - 1); ++l) {
+          const std::size_t kOther = index[k * kCols + l];
+          if ((kDead != kOther) && (cells.size() - 1 != kOther)) {
+            groups[FindGroup(groups, kOther)] =
+                FindGroup(groups, cells.size() - 1);
+          }
+        }
+      }
+    }
+  }
+
+  std::map<std::size_t, Object> objects;
+  for (std::size_t i = 0; i < cells.size(); ++i) {
+    const auto [kRow, kCol] = cells[i];
+    Object& object = objects[FindGroup(groups, i)];
+    if (object.cells.empty()) {
+      object.bounds =
+          CellBounds{.top = kRow, .left = kCol, .bottom = kRow, .right = kCol};
+    }
+    object.cells.push_back(cells[i]);
+    object.bounds.top = std::min(object.bounds.top, kRow);
+    object.bounds.left = std::min(object.bounds.left, kCol);
+    object.bounds.bottom = std::max(object.bounds.bottom, kRow);
+    object.bounds.right = std::max(object.bounds.right, kCol);
+  }
+
+  std::vector<Object> found;
+  for (auto& entry : objects) {
+    Object& object = entry.second;
+    if (object.cells.size() <= kMaxShipCells) {
+      GameOfLifeBoard shape(object.bounds.bottom - object.bounds.top + 1,
+                            object.bounds.right - object.bounds.left + 1);
+      for (const auto& [kRow, kCol] : object.cells) {
+        shape.SetCell(kRow - object.bounds.top, kCol - object.bounds.left,
+                      true);
+      }
+      object.hash = shape.Hash();
+    }
+    found.push_back(std::move(object));
+  }
+  return found;
+}
+
+/* Return how far object moved since it last had the same shape among the
+ * objects of the recent generations, newest last, nothing if it did not or
+ * stayed in place. */
+[[nodiscard]] static std::optional<std::pair<std::int64_t, std::int64_t>>
+ShipMovement(const Object& object,
+             const std::deque<std::vector<Object>>& recent) {
+  if (!object.hash) {
+    return std::nullopt;
+  }
+
+  /* the first period the shape repeats at decides, a spaceship does not move
+   * faster than one cell per generation */
+  for (std::size_t period = 1; period <= recent.size(); ++period) {
+    std::optional<std::pair<std::int64_t, std::int64_t>> movement;
+    for (const Object& then : recent[recent.size() - period]) {
+      if (then.hash != object.hash) {
+        continue;
+      }
+      const std::int64_t kDx = static_cast<std::int64_t>(object.bounds.left) -
+                               static_cast<std::int64_t>(then.bounds.left);
+      const std::int64_t kDy = static_cast<std::int64_t>(object.bounds.top) -
+                               static_cast<std::int64_t>(then.bounds.top);
+      if (!kDx && !kDy) {
+        return std::nullopt;
+      } else if (static_cast<std::uint64_t>(std::max(std::llabs(kDx),
+                                                     std::llabs(kDy))) <=
+                 period) {
+        movement = std::make_pair(kDx, kDy);
+      }
+    }
+    if (movement) {
+      return movement;
+    }
+  }
+  return std::nullopt;
+}
+
+/* Remove the spaceships of objects moving away from the rest of the live
+ * cells of engine and return how many were removed. */
+[[nodiscard]] static std::uint64_t RemoveEscapedShips(
+    Engine& engine, const std::vector<Object>& objects,
+    const std::deque<std::vector<Object>>& recent) {
+  std::vector<std::optional<std::pair<std::int64_t, std::int64_t>>> movements;
+  std::optional<CellBounds> debris;
+  for (const Object& object : objects) {
+    movements.push_back(ShipMovement(object, recent));
+    if (movements.back()) {
+      continue;
+    } else if (!debris) {
+      debris = object.bounds;
+    }
+    debris->top = std::min(debris->top, object.bounds.top);
+    debris->left = std::min(debris->left, object.bounds.left);
+    debris->bottom = std::max(debris->bottom, object.bounds.bottom);
+    debris->right = std::max(debris->right, object.bounds.right);
+  }
+
+  /* a pattern made of spaceships only is left for the cycle detection */
+  std::uint64_t escaped = 0;
+  if (!debris) {
+    return escaped;
+  }
+  for (std::size_t i = 0; i < objects.size(); ++i) {
+    if (!movements[i]) {
+      continue;
+    }
+    const auto [kDx, kDy] = *movements[i];
+    const CellBounds& kShip = objects[i].bounds;
+    if (((kDx > 0) && (kShip.left > debris->right + kEscapeDistance)) ||
+        ((kDx < 0) && (kShip.right + kEscapeDistance < debris->left)) ||
+        ((kDy > 0) && (kShip.top > debris->bottom + kEscapeDistance)) ||
+        ((kDy < 0) && (kShip.bottom + kEscapeDistance < debris->top))) {
+      for (const auto& [kRow, kCol] : objects[i].cells) {
+        engine.SetCell(kRow, kCol, false);
+      }
+      escaped++;
+    }
+  }
+  return escaped;
+}
+
+Analysis Analyze(Engine& engine, std::uint64_t max_generations) {
+  Analysis analysis;
+  CycleDetector cycles;
+
+  /* the top left corners of the recent generations, newest last, tell how
+   * far a repeated shape moved */
+  std::deque<CellBounds> corners;
+
+  /* the objects of the recent generations, newest last, tell which objects
+   * are spaceships */
+  std::deque<std::vector<Object>> recent;
+  for (const Engine& generation :
+       Evolve(engine) | std::views::take(max_generations + 1)) {
+    analysis.generation = generation.Generation();
+    if (const std::optional<CellBounds> kAll = generation.LiveBounds()) {
+      std::vector<Object> objects = FindObjects(generation, *kAll);
+      const std::uint64_t kEscaped =
+          RemoveEscapedShips(engine, objects, recent);
+      analysis.escaped += kEscaped;
+      if (kEscaped) {
+        objects = FindObjects(generation, *kAll);
+      }
+      recent.push_back(std::move(objects));
+      if (recent.size() > kMaxShipPeriod) {
+        recent.pop_front();
+      }
+    }
+    analysis.population = generation.Population();
+    const std::optional<CellBounds> kBounds = generation.LiveBounds();
+    if (!kBounds) {
+      analysis.fate = Fate::kDiesOut;
+      return analysis;
+    } else if ((0 == kBounds->top) || (0 == kBounds->left) ||
+               (generation.Rows() - 1 == kBounds->bottom) ||
+               (generation.Cols() - 1 == kBounds->right)) {
+      analysis.reached_edge = true;
+      return analysis;
+    }
+
+    corners.push_back(*kBounds);
+    if (corners.size() > CycleDetector::kDefaultHistory + 1) {
+      corners.pop_front();
+    }
+    const std::optional<std::uint64_t> kPeriod = cycles.Update(
+        HashShape(generation, *kBounds), generation.Generation());
+    if (!kPeriod) {
+      continue;
+    }
+
+    const CellBounds& kThen = corners[corners.size() - 1 - *kPeriod];
+    analysis.period = *kPeriod;
+    analysis.generation -= *kPeriod;
+    analysis.dx = static_cast<std::int64_t>(kBounds->left) -
+                  static_cast<std::int64_t>(kThen.left);
+    analysis.dy = static_cast<std::int64_t>(kBounds->top) -
+                  static_cast<std::int64_t>(kThen.top);
+    if (analysis.dx || analysis.dy) {
+      analysis.fate = Fate::kSpaceship;
+    } else {
+      analysis.fate = (1 == *kPeriod) ? Fate::kStable : Fate::kOscillator;
+    }
+    return analysis;
+  }
+  return analysis;
+}
+
+}  // namespace game
+}  // namespace gol
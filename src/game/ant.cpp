@@ -0,0 +1,96 @@
+#include "game/ant.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+
+namespace gol {
+namespace game {
+
+AntBoard::AntBoard(std::size_t num_rows, std::size_t num_cols, bool wrap)
+    : colors_(num_rows * num_cols, 0),
+      cells_(num_rows, num_cols, wrap,
+             std::make_shared<StaticStates>("ant", 3, Colors())),
+      wrap_(wrap) {}
+
+void AntBoard::SetState(std::size_t row, std::size_t col, std::uint8_t state) {
+  std::erase_if(ants_, [row, col](const Ant& ant) {
+    return (row == ant.row) && (col == ant.col);
+  });
+  if (kAnt == state) {
+    ants_.push_back({.row = row, .col = col, .heading = Heading::kNorth});
+  } else {
+    colors_[Index(row, col)] = state;
+  }
+  cells_.SetState(row, col, state);
+}
+
+void AntBoard::AddAnt(const Ant& ant) {
+  if ((ant.row >= Rows()) || (ant.col >= Cols())) {
+    throw std::invalid_argument("ant lies outside of the board");
+  }
+  ants_.push_back(ant);
+  cells_.SetState(ant.row, ant.col, kAnt);
+}
+
+void AntBoard::Step(Ant& ant) const noexcept {
+  /* ants stepping off a bounded board are moved one past its last row or
+   * column */
+  std::size_t row = ant.row;
+  std::size_t col = ant.col;
+  switch (ant.heading) {
+    case Heading::kNorth:
+      row = (row) ? row - 1 : (wrap_) ? Rows() - 1 : Rows();
+      break;
+    case Heading::kEast:
+      col = ((col + 1 == Cols()) && wrap_) ? 0 : col + 1;
+      break;
+    case Heading::kSouth:
+      row = ((row + 1 == Rows()) && wrap_) ? 0 : row + 1;
+      break;
+    case Heading::kWest:
+      col = (col) ? col - 1 : (wrap_) ? Cols() - 1 : Cols();
+      break;
+  }
+  ant.row = row;
+  ant.col = col;
+}
+
+void AntBoard::Tick() {
+  std::vector<std::pair<std::size_t, std::size_t>> left;
+  left.reserve(ants_.size());
+  for (Ant& ant : ants_) {
+    /* white cells turn the ant right, black cells turn it left */
+    std::uint8_t& color = colors_[Index(ant.row, ant.col)];
+    const int kTurn = (kBlack == color) ? 3 : 1;
+    ant.heading =
+        static_cast<Heading>((static_cast<int>(ant.heading) + kTurn) % 4);
+    color = (kBlack == color) ? 0 : kBlack;
+    left.emplace_back(ant.row, ant.col);
+    Step(ant);
+  }
+  std::erase_if(ants_, [this](const Ant& ant) {
+    return (ant.row >= Rows()) || (ant.col >= Cols());
+  });
+
+  /* the vacated cells show their colors unless another ant moved onto them */
+  for (const auto& [row, col] : left) {
+    cells_.SetState(row, col, colors_[Index(row, col)]);
+  }
+  for (const Ant& ant : ants_) {
+    cells_.SetState(ant.row, ant.col, kAnt);
+  }
+  generation_++;
+}
+
+std::vector<std::uint32_t> AntBoard::Colors() { return {0xffffff, 0xff3c28}; }
+
+}  // namespace game
+}  // namespace gol
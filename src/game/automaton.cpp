@@ -0,0 +1,248 @@
+#include "game/automaton.h"
+
+#include <algorithm>
+#include <array>
+#include <cctype>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+std::uint8_t BriansBrain::Next(std::uint8_t state,
+                               const Neighborhood& neighbors) const noexcept {
+  if (kFiring == state) {
+    return kDying;
+  } else if (kDying == state) {
+    return 0;
+  }
+  const auto kFiringNeighbors =
+      std::count(neighbors.begin(), neighbors.end(), kFiring);
+  return (2 == kFiringNeighbors) ? kFiring : 0;
+}
+
+std::vector<std::uint32_t> BriansBrain::Colors() const {
+  return {0xffffff, 0x3c5aff};
+}
+
+std::uint8_t Wireworld::Next(std::uint8_t state,
+                             const Neighborhood& neighbors) const noexcept {
+  if (kHead == state) {
+    return kTail;
+  } else if (kTail == state) {
+    return kConductor;
+  } else if (kConductor != state) {
+    return state;
+  }
+  const auto kHeads = std::count(neighbors.begin(), neighbors.end(), kHead);
+  return ((1 == kHeads) || (2 == kHeads)) ? kHead : kConductor;
+}
+
+std::vector<std::uint32_t> Wireworld::Colors() const {
+  return {0x3c8cff, 0xff3c28, 0xffc814};
+}
+
+Generations::Generations(const Rule& rule, std::uint8_t num_states)
+    : rule_(rule), num_states_(num_states) {
+  if (num_states_ < 2) {
+    throw std::invalid_argument("Generations rules need at least 2 states");
+  }
+}
+
+std::string Generations::Name() const {
+  std::string survival;
+  std::string birth;
+  for (int i = 0; i <= Rule::kMaxNeighbors; ++i) {
+    if (rule_.Survives(i)) {
+      survival += static_cast<char>('0' + i);
+    }
+    if (rule_.Born(i)) {
+      birth += static_cast<char>('0' + i);
+    }
+  }
+  return survival + "/" + birth + "/" + std::to_string(num_states_);
+}
+
+std::uint8_t Generations::Next(std::uint8_t state,
+                               const Neighborhood& neighbors) const noexcept {
+  const int kLive = static_cast<int>(
+      std::count(neighbors.begin(), neighbors.end(), std::uint8_t{1}));
+  if (0 == state) {
+    return (rule_.Born(kLive)) ? 1 : 0;
+  } else if ((1 == state) && rule_.Survives(kLive)) {
+    return 1;
+  }
+  return (state + 1 < num_states_) ? state + 1 : 0;
+}
+
+std::vector<std::uint32_t> Generations::Colors() const {
+  /* the dying states fade linearly from the first color to the last */
+  const std::uint32_t kFirst = 0xffc814;
+  const std::uint32_t kLast = 0x6e1400;
+  std::vector<std::uint32_t> colors = {0xffffff};
+  const int kDying = num_states_ - 2;
+  for (int i = 0; i < kDying; ++i) {
+    std::uint32_t color = 0;
+    for (int shift = 0; shift <= 16; shift += 8) {
+      const int kFrom = static_cast<int>((kFirst >> shift) & 0xff);
+      const int kTo = static_cast<int>((kLast >> shift) & 0xff);
+      const int kChannel =
+          (kDying > 1) ? kFrom + ((kTo - kFrom) * i) / (kDying - 1) : kFrom;
+      color |= static_cast<std::uint32_t>(kChannel) << shift;
+    }
+    colors.push_back(color);
+  }
+  return colors;
+}
+
+ColoredLife::ColoredLife(std::uint8_t num_colors) : num_colors_(num_colors) {
+  if ((2 != num_colors_) && (4 != num_colors_)) {
+    throw std::invalid_argument("colored Life has either 2 or 4 colors");
+  }
+}
+
+std::string ColoredLife::Name() const {
+  return (2 == num_colors_) ? "immigration" : "quadlife";
+}
+
+std::uint8_t ColoredLife::Next(std::uint8_t state,
+                               const Neighborhood& neighbors) const noexcept {
+  std::array<int, 5> counts = {};
+  for (std::uint8_t neighbor : neighbors) {
+    counts[std::min<int>(neighbor, num_colors_)]++;
+  }
+  const int kLive = kNumNeighbors - counts[0];
+  if (state) {
+    return ((2 == kLive) || (3 == kLive)) ? state : 0;
+  } else if (3 != kLive) {
+    return 0;
+  }
+
+  /* three parents of different colors leave out exactly one of the four */
+  for (int color = 1; color <= num_colors_; ++color) {
+    if (counts[color] >= 2) {
+      return static_cast<std::uint8_t>(color);
+    }
+  }
+  for (int color = 1; color <= num_colors_; ++color) {
+    if (!counts[color]) {
+      return static_cast<std::uint8_t>(color);
+    }
+  }
+  return 1;
+}
+
+std::vector<std::uint32_t> ColoredLife::Colors() const {
+  if (2 == num_colors_) {
+    return {0xff3c28, 0x3c8cff};
+  }
+  return {0xff3c28, 0x32c850, 0x3c8cff, 0xffc814};
+}
+
+StaticStates::StaticStates(std::string name, std::uint8_t num_states,
+                           std::vector<std::uint32_t> colors)
+    : name_(std::move(name)),
+      num_states_(num_states),
+      colors_(std::move(colors)) {}
+
+struct GenerationsPreset {
+  const char* name;
+  const char* rulestring;
+};
+
+static constexpr std::array<GenerationsPreset, 5> kGenerationsPresets = {{
+    {"starwars", "345/2/4"},
+    {"frogs", "12/34/3"},
+    {"spirals", "2/234/5"},
+    {"sticks", "3456/2/6"},
+    {"lava", "12345/45/8"},
+}};
+
+std::shared_ptr<const Generations> ParseGenerations(
+    const std::string& rulestring) {
+  std::vector<std::string> components;
+  std::size_t begin = 0;
+  for (std::size_t end = rulestring.find('/'); std::string::npos != end;
+       end = rulestring.find('/', begin)) {
+    components.push_back(rulestring.substr(begin, end - begin));
+    begin = end + 1;
+  }
+  components.push_back(rulestring.substr(begin));
+  if (3 != components.size()) {
+    return nullptr;
+  }
+
+  /* components without a prefix are in S/B/C order */
+  std::string birth = components[1];
+  std::string survival = components[0];
+  std::string states = components[2];
+  for (const std::string& component : components) {
+    const char kType = static_cast<char>(
+        std::toupper(static_cast<unsigned char>(component.c_str()[0])));
+    if ('B' == kType) {
+      birth = component.substr(1);
+    } else if ('S' == kType) {
+      survival = component.substr(1);
+    } else if (('C' == kType) || ('G' == kType)) {
+      states = component.substr(1);
+    }
+  }
+
+  if (states.empty() ||
+      (states.find_first_not_of("0123456789") != std::string::npos) ||
+      (states.size() > 3) || (std::stoi(states) < 2) ||
+      (std::stoi(states) > 255)) {
+    throw std::invalid_argument("invalid Generations state count -> " +
+                                rulestring);
+  }
+  const Rule kRule("B" + birth + "/S" + survival);
+  if (!kRule.IsTotalistic()) {
+    throw std::invalid_argument(
+        "Generations rules do not support Hensel letters -> " + rulestring);
+  }
+  return std::make_shared<Generations>(kRule, std::stoi(states));
+}
+
+std::shared_ptr<const Automaton> FindAutomaton(const std::string& name) {
+  std::string lower = name;
+  std::transform(lower.begin(), lower.end(), lower.begin(), [](char c) {
+    return static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  });
+  for (const GenerationsPreset& preset : kGenerationsPresets) {
+    if (lower == preset.name) {
+      return ParseGenerations(preset.rulestring);
+    }
+  }
+  if (auto generations = ParseGenerations(name)) {
+    return generations;
+  }
+  if ("brain" == lower) {
+    return std::make_shared<BriansBrain>();
+  } else if ("wireworld" == lower) {
+    return std::make_shared<Wireworld>();
+  } else if ("immigration" == lower) {
+    return std::make_shared<ColoredLife>(2);
+  } else if ("quadlife" == lower) {
+    return std::make_shared<ColoredLife>(4);
+  }
+  return nullptr;
+}
+
+std::vector<std::string> AutomatonNames() {
+  std::vector<std::string> names = {"brain", "wireworld", "immigration",
+                                    "quadlife"};
+  for (const GenerationsPreset& preset : kGenerationsPresets) {
+    names.push_back(preset.name);
+  }
+  return names;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,53 @@
+#include "game/battle.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+BattleScore ScoreBattle(const Engine& board) {
+  BattleScore score;
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    for (std::size_t j = 0; j < board.Cols(); ++j) {
+      const auto kState = static_cast<Player>(board.State(i, j));
+      if (Player::kRed == kState) {
+        score.red++;
+      } else if (Player::kBlue == kState) {
+        score.blue++;
+      }
+    }
+  }
+  return score;
+}
+
+BattleOutcome JudgeBattle(const BattleScore& score, bool stable) noexcept {
+  if (score.red && !score.blue) {
+    return BattleOutcome::kRedWins;
+  } else if (!score.red && score.blue) {
+    return BattleOutcome::kBlueWins;
+  } else if (!score.red || stable) {
+    return BattleOutcome::kStalemate;
+  }
+  return BattleOutcome::kUndecided;
+}
+
+std::string ToString(BattleOutcome outcome) {
+  switch (outcome) {
+    case BattleOutcome::kRedWins:
+      return "RED WINS";
+    case BattleOutcome::kBlueWins:
+      return "BLUE WINS";
+    case BattleOutcome::kStalemate:
+      return "STALEMATE";
+    case BattleOutcome::kUndecided:
+      break;
+  }
+  return "";
+}
+
+}  // namespace game
+}  // namespace gol
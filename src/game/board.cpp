@@ -1,65 +1,361 @@
 #include "game/board.h"
 
+#include <algorithm>
+#include <array>
+#include <bit>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <thread>
 #include <utility>
 #include <vector>
 
+#include "game/automaton.h"
+#include "game/hash.h"
+#include "game/neighborhood.h"
+#include "game/rule.h"
+#include "game/swar.h"
+
 namespace gol {
 namespace game {
 
-int GameOfLifeBoard::CountLiveNeighbors(std::size_t row,
-                                        std::size_t col) const noexcept {
-  using Offset = std::pair<int, int>;
+/* UpdateRows() shifts the three rows around a cell both west and east. */
+static constexpr std::size_t kScratchRows = 6;
 
-  /* These are the eight 2D offsets: left/right, up/down, and diagonals. */
-  static const std::vector<Offset> kDirections = {
-      {0, 1}, {1, 0}, {0, -1}, {-1, 0}, {1, 1}, {1, -1}, {-1, 1}, {-1, -1},
-  };
+GameOfLifeBoard::Word GameOfLifeBoard::LastWordMask() const noexcept {
+  const std::size_t kUsedBits = cols_ % kBitsPerWord;
+  return (kUsedBits) ? (BitMask(kUsedBits) - 1) : ~Word{0};
+}
+
+void GameOfLifeBoard::ShiftWest(const Word* row, Word* out) const noexcept {
+  /* bit j of the output is the state of the cell at column j - 1 */
+  const std::size_t kLast = words_per_row_ - 1;
+  for (std::size_t w = kLast; w > 0; --w) {
+    out[w] = (row[w] << 1) | (row[w - 1] >> (kBitsPerWord - 1));
+  }
+  out[0] = row[0] << 1;
+  if (wrap_) {
+    out[0] |= (row[kLast] >> ((cols_ - 1) % kBitsPerWord)) & 1;
+  }
+}
+
+void GameOfLifeBoard::ShiftEast(const Word* row, Word* out) const noexcept {
+  /* bit j of the output is the state of the cell at column j + 1 */
+  const std::size_t kLast = words_per_row_ - 1;
+  for (std::size_t w = 0; w < kLast; ++w) {
+    out[w] = (row[w] >> 1) | (row[w + 1] << (kBitsPerWord - 1));
+  }
+  out[kLast] = row[kLast] >> 1;
+  if (wrap_) {
+    out[kLast] |= (row[0] & 1) << ((cols_ - 1) % kBitsPerWord);
+  }
+}
+
+GameOfLifeBoard::GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                                 bool wrap, const Rule& rule)
+    : rows_(num_rows),
+      cols_(num_cols),
+      words_per_row_((num_cols + kBitsPerWord - 1) / kBitsPerWord),
+      cells_(num_rows * words_per_row_, 0),
+      next_cells_(cells_.size(), 0),
+      zero_row_(words_per_row_, 0),
+      scratch_(kScratchRows * words_per_row_, 0),
+      wrap_(wrap),
+      rule_(rule) {}
+
+GameOfLifeBoard::GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                                 bool wrap,
+                                 std::shared_ptr<const Automaton> automaton)
+    : GameOfLifeBoard(num_rows, num_cols, wrap) {
+  automaton_ = std::move(automaton);
+  if (automaton_) {
+    states_.assign(num_rows * num_cols, 0);
+    next_states_.assign(states_.size(), 0);
+  }
+}
+
+/* Advance the SplitMix64 generator state and return its next output. */
+[[nodiscard]] static std::uint64_t SplitMix64(std::uint64_t& state) noexcept {
+  std::uint64_t z = (state += 0x9e3779b97f4a7c15);
+  z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9;
+  z = (z ^ (z >> 27)) * 0x94d049bb133111eb;
+  return z ^ (z >> 31);
+}
+
+void GameOfLifeBoard::SetNoise(double probability, std::uint64_t seed) {
+  if (!(probability >= 0.0) || (probability > 1.0)) {
+    throw std::invalid_argument("noise must be between 0 and 1");
+  }
+  noise_ = probability;
+  noise_seed_ = seed;
+}
 
-  const int kRowLimit = Rows();
-  const int kColLimit = Cols();
-  int neighbor_row = 0;
-  int neighbor_col = 0;
-  int num_live_neighbors = 0;
-  for (const Offset& direction : kDirections) {
-    neighbor_row = row + direction.first;
-    neighbor_col = col + direction.second;
-    if ((neighbor_row >= 0) && (neighbor_row < kRowLimit) &&
-        (neighbor_col >= 0) && (neighbor_col < kColLimit) &&
-        state_[neighbor_row][neighbor_col]) {
-      num_live_neighbors++;
+void GameOfLifeBoard::SetNumThreads(std::size_t num_threads) {
+  num_threads_ = num_threads;
+  scratch_.assign(NumBands() * kScratchRows * words_per_row_, 0);
+}
+
+std::size_t GameOfLifeBoard::NumBands() const noexcept {
+  return std::max<std::size_t>(std::min(num_threads_, rows_), 1);
+}
+
+void GameOfLifeBoard::UpdateRows(std::size_t first_row, std::size_t last_row,
+                                 WordVec& next, Word* scratch) const {
+  /* The eight neighbors of each cell are shifted into alignment with the
+   * cell such that 64 cells can be updated at a time. */
+  const Word* west = scratch;
+  const Word* east = scratch + (3 * words_per_row_);
+  const Word kLastWordMask = LastWordMask();
+  for (std::size_t i = first_row; i < last_row; ++i) {
+    const Word* rows[3] = {zero_row_.data(), &cells_[WordIndex(i, 0)],
+                           zero_row_.data()};
+    if ((i > 0) || wrap_) {
+      rows[0] = &cells_[WordIndex((i + rows_ - 1) % rows_, 0)];
+    }
+    if ((i + 1 < rows_) || wrap_) {
+      rows[2] = &cells_[WordIndex((i + 1) % rows_, 0)];
+    }
+    for (std::size_t r = 0; r < 3; ++r) {
+      ShiftWest(rows[r], scratch + (r * words_per_row_));
+      ShiftEast(rows[r], scratch + ((r + 3) * words_per_row_));
+    }
+
+    for (std::size_t w = 0; w < words_per_row_; ++w) {
+      const std::array<Word, Rule::kMaxNeighbors> kNeighbors = {
+          west[w],
+          rows[0][w],
+          east[w],
+          west[words_per_row_ + w],
+          east[words_per_row_ + w],
+          west[2 * words_per_row_ + w],
+          rows[2][w],
+          east[2 * words_per_row_ + w],
+      };
+      next[WordIndex(i, 0) + w] = NextCells(kNeighbors, rows[1][w], rule_);
+    }
+
+    /* clear the padding bits past the last column */
+    next[WordIndex(i, 0) + words_per_row_ - 1] &= kLastWordMask;
+  }
+}
+
+void GameOfLifeBoard::UpdateCounts(std::size_t first_row,
+                                   std::size_t last_row, WordVec& next) const {
+  const CellNeighborhood& kNeighborhood = rule_.GetNeighborhood();
+  const auto kIsAlive = [this](std::size_t row, std::size_t col) {
+    return IsAlive(row, col);
+  };
+  for (std::size_t i = first_row; i < last_row; ++i) {
+    std::fill_n(next.begin() + WordIndex(i, 0), words_per_row_, 0);
+    for (std::size_t j = 0; j < cols_; ++j) {
+      const int kCount =
+          kNeighborhood.CountLive(i, j, rows_, cols_, wrap_, kIsAlive);
+      if ((IsAlive(i, j)) ? rule_.Survives(kCount) : rule_.Born(kCount)) {
+        next[WordIndex(i, j)] |= BitMask(j);
+      }
     }
   }
-  return num_live_neighbors;
-}
-
-GameOfLifeBoard::GameOfLifeBoard(std::size_t num_rows, std::size_t num_cols)
-    : state_(num_rows, CellStateVec(num_cols, false)) {}
-
-void GameOfLifeBoard::Tick() noexcept {
-  /* Given the relatively small size of the screen, we go the unsophisticated
-   * route of making a copy of the game board before performing the state
-   * transformation. */
-  CellStateMatrix tmp = state_;
-
-  int num_live_neighbors = 0;
-  for (std::size_t i = 0; i < Rows(); ++i) {
-    for (std::size_t j = 0; j < Cols(); ++j) {
-      num_live_neighbors = CountLiveNeighbors(i, j);
-      if (state_[i][j]) {
-        if (num_live_neighbors < 2) {
-          /* death by underpopulation */
-          tmp[i][j] = false;
-        } else if (num_live_neighbors > 3) {
-          /* death by overpopulation */
-          tmp[i][j] = false;
+}
+
+void GameOfLifeBoard::UpdateConfigs(std::size_t first_row,
+                                    std::size_t last_row,
+                                    WordVec& next) const {
+  /* neighbors beyond the edges of a bounded board are dead */
+  const auto kAlive = [this](std::size_t row, std::size_t col,
+                             const CellOffset& offset) {
+    if (!wrap_ && (((0 == row) && (offset.row < 0)) ||
+                   ((rows_ == row + 1) && (offset.row > 0)) ||
+                   ((0 == col) && (offset.col < 0)) ||
+                   ((cols_ == col + 1) && (offset.col > 0)))) {
+      return false;
+    }
+    return IsAlive((row + rows_ + offset.row) % rows_,
+                   (col + cols_ + offset.col) % cols_);
+  };
+
+  /* the live Moore neighbors of a cell are the bits of its configuration in
+   * the order of the neighborhood's offsets, NW to SE */
+  const CellNeighborhood kMoore;
+  for (std::size_t i = first_row; i < last_row; ++i) {
+    std::fill_n(next.begin() + WordIndex(i, 0), words_per_row_, 0);
+    for (std::size_t j = 0; j < cols_; ++j) {
+      std::uint8_t config = 0;
+      for (std::size_t k = 0; k < kMoore.Offsets().size(); ++k) {
+        if (kAlive(i, j, kMoore.Offsets()[k])) {
+          config |= 1 << k;
         }
-      } else if (num_live_neighbors == 3) {
-        /* life by reproduction */
-        tmp[i][j] = true;
       }
+      if ((IsAlive(i, j)) ? rule_.SurvivesIn(config) : rule_.BornIn(config)) {
+        next[WordIndex(i, j)] |= BitMask(j);
+      }
+    }
+  }
+}
+
+void GameOfLifeBoard::UpdateStates(std::size_t first_row,
+                                   std::size_t last_row,
+                                   StateVec& next_states,
+                                   WordVec& next) const {
+  /* neighbors beyond the edges of a bounded board are in state 0 */
+  const auto kState = [this](std::size_t row, std::size_t col,
+                             int drow, int dcol) -> std::uint8_t {
+    if (!wrap_ && (((0 == row) && (drow < 0)) ||
+                   ((rows_ == row + 1) && (drow > 0)) ||
+                   ((0 == col) && (dcol < 0)) ||
+                   ((cols_ == col + 1) && (dcol > 0)))) {
+      return 0;
+    }
+    const std::size_t kRow = (row + rows_ + drow) % rows_;
+    const std::size_t kCol = (col + cols_ + dcol) % cols_;
+    return states_[(kRow * cols_) + kCol];
+  };
+
+  for (std::size_t i = first_row; i < last_row; ++i) {
+    std::fill_n(next.begin() + WordIndex(i, 0), words_per_row_, 0);
+    for (std::size_t j = 0; j < cols_; ++j) {
+      const Automaton::Neighborhood kNeighbors = {
+          kState(i, j, -1, -1), kState(i, j, -1, 0), kState(i, j, -1, 1),
+          kState(i, j, 0, -1),  kState(i, j, 0, 1),  kState(i, j, 1, -1),
+          kState(i, j, 1, 0),   kState(i, j, 1, 1),
+      };
+      const std::uint8_t kNext =
+          automaton_->Next(states_[(i * cols_) + j], kNeighbors);
+      next_states[(i * cols_) + j] = kNext;
+      if (kNext) {
+        next[WordIndex(i, j)] |= BitMask(j);
+      }
+    }
+  }
+}
+
+void GameOfLifeBoard::Tick() {
+  if (!rows_ || !cols_) {
+    return;
+  }
+
+  /* The next generation is written to the back buffer which is then swapped
+   * with the front buffer. Every word of the back buffer is overwritten so the
+   * stale generation it holds need not be cleared. */
+  const auto kUpdate = [this](std::size_t band, std::size_t first,
+                              std::size_t last) {
+    if (automaton_) {
+      UpdateStates(first, last, next_states_, next_cells_);
+    } else if (!rule_.GetNeighborhood().IsMoore()) {
+      UpdateCounts(first, last, next_cells_);
+    } else if (!rule_.IsTotalistic()) {
+      UpdateConfigs(first, last, next_cells_);
+    } else {
+      UpdateRows(first, last, next_cells_,
+                 &scratch_[band * kScratchRows * words_per_row_]);
+    }
+  };
+  const std::size_t kNumBands = NumBands();
+  if (kNumBands <= 1) {
+    kUpdate(0, 0, rows_);
+  } else {
+    /* each thread updates its own band of rows, the bands are disjoint so no
+     * synchronization beyond the final join is required */
+    const std::size_t kBandHeight = (rows_ + kNumBands - 1) / kNumBands;
+    std::vector<std::jthread> workers;
+    std::size_t band = 0;
+    for (std::size_t first = 0; first < rows_; first += kBandHeight) {
+      const std::size_t kLast = std::min(first + kBandHeight, rows_);
+      workers.emplace_back(
+          [&kUpdate, band, first, kLast] { kUpdate(band, first, kLast); });
+      band++;
+    }
+  }
+  cells_.swap(next_cells_);
+  states_.swap(next_states_);
+  generation_++;
+  if (noise_ > 0.0) {
+    ApplyNoise();
+  }
+}
+
+void GameOfLifeBoard::ApplyNoise() noexcept {
+  /* the gaps between flipped cells are geometrically distributed, drawing the
+   * gaps rather than a number per cell keeps faint noise cheap. Each
+   * generation draws from its own stream of numbers */
+  const double kScale = 1.0 / static_cast<double>(std::uint64_t{1} << 53);
+  const double kLogKeep = std::log1p(-noise_);
+  const auto kCells = static_cast<std::uint64_t>(rows_ * cols_);
+  std::uint64_t state = noise_seed_ ^ (generation_ * 0xd1b54a32d192ed03);
+  const auto kGap = [&] {
+    const double kUniform =
+        (static_cast<double>(SplitMix64(state) >> 11) + 1.0) * kScale;
+    const double kSkipped = std::floor(std::log(kUniform) / kLogKeep);
+    return (kSkipped < static_cast<double>(kCells))
+               ? static_cast<std::uint64_t>(kSkipped)
+               : kCells;
+  };
+  for (std::uint64_t cell = kGap(); cell < kCells; cell += 1 + kGap()) {
+    const std::size_t kRow = cell / cols_;
+    const std::size_t kCol = cell % cols_;
+    SetState(kRow, kCol, (IsAlive(kRow, kCol)) ? 0 : 1);
+  }
+}
+
+std::uint64_t GameOfLifeBoard::Hash() const noexcept {
+  std::uint64_t hash = (rows_ << 32) ^ cols_;
+  for (Word word : cells_) {
+    hash = HashCombine(hash, word);
+  }
+  for (std::uint8_t state : states_) {
+    hash = HashCombine(hash, state);
+  }
+  return hash;
+}
+
+std::uint64_t GameOfLifeBoard::CountAliveNotIn(
+    const GameOfLifeBoard& other) const noexcept {
+  if ((rows_ != other.rows_) || (cols_ != other.cols_)) {
+    return 0;
+  }
+  std::uint64_t count = 0;
+  for (std::size_t w = 0; w < cells_.size(); ++w) {
+    count += std::popcount(cells_[w] & ~other.cells_[w]);
+  }
+  return count;
+}
+
+std::uint64_t GameOfLifeBoard::Population() const noexcept {
+  std::uint64_t population = 0;
+  for (Word word : cells_) {
+    population += std::popcount(word);
+  }
+  return population;
+}
+
+std::optional<CellBounds> GameOfLifeBoard::LiveBounds() const {
+  /* the columns of the outermost live cells are found from the lowest and
+   * highest set bits of each row's words */
+  std::optional<CellBounds> bounds;
+  for (std::size_t i = 0; i < rows_; ++i) {
+    for (std::size_t w = 0; w < words_per_row_; ++w) {
+      const Word kWord = cells_[(i * words_per_row_) + w];
+      if (0 == kWord) {
+        continue;
+      }
+      const std::size_t kFirstCol = w * kBitsPerWord;
+      const std::size_t kLeft =
+          kFirstCol + static_cast<std::size_t>(std::countr_zero(kWord));
+      const std::size_t kRight =
+          kFirstCol + (kBitsPerWord - 1) -
+          static_cast<std::size_t>(std::countl_zero(kWord));
+      if (!bounds) {
+        bounds =
+            CellBounds{.top = i, .left = kLeft, .bottom = i, .right = kRight};
+      }
+      bounds->left = std::min(bounds->left, kLeft);
+      bounds->right = std::max(bounds->right, kRight);
+      bounds->bottom = i;
     }
   }
-  state_ = std::move(tmp);
+  return bounds;
 }
 
 }  // namespace game
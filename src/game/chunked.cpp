@@ -0,0 +1,191 @@
+#include "game/chunked.h"
+
+#include <algorithm>
+#include <array>
+#include <bit>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <stdexcept>
+#include <unordered_map>
+#include <vector>
+
+#include "game/hash.h"
+#include "game/rule.h"
+#include "game/swar.h"
+
+namespace gol {
+namespace game {
+
+/* log2 of ChunkedBoard::kChunkSize. */
+static constexpr int kChunkShift = 6;
+
+/* Mask selecting the position of a cell within its chunk. */
+static constexpr std::int64_t kChunkMask = ChunkedBoard::kChunkSize - 1;
+
+/* Bit of the first and last column of a chunk row. */
+static constexpr std::uint64_t kFirstCol = 1;
+static constexpr std::uint64_t kLastCol = std::uint64_t{1} << kChunkMask;
+
+/* Return true if a live cell of the chunk borders the neighbor chunk in
+ * direction (d_row, d_col). */
+[[nodiscard]] static bool Borders(
+    const std::array<std::uint64_t, ChunkedBoard::kChunkSize>& chunk,
+    int d_row, int d_col) noexcept {
+  std::uint64_t mask = ~std::uint64_t{0};
+  if (d_col < 0) {
+    mask = kFirstCol;
+  } else if (d_col > 0) {
+    mask = kLastCol;
+  }
+
+  if (d_row < 0) {
+    return chunk.front() & mask;
+  } else if (d_row > 0) {
+    return chunk.back() & mask;
+  }
+  return std::any_of(chunk.begin(), chunk.end(),
+                     [mask](std::uint64_t row) { return row & mask; });
+}
+
+std::size_t ChunkedBoard::ChunkKeyHash::operator()(
+    const ChunkKey& key) const noexcept {
+  return static_cast<std::size_t>(
+      HashCombine(std::hash<std::int64_t>{}(key.row),
+                  std::hash<std::int64_t>{}(key.col)));
+}
+
+ChunkedBoard::ChunkedBoard(std::size_t num_rows, std::size_t num_cols,
+                           const Rule& rule)
+    : rows_(num_rows), cols_(num_cols), rule_(rule) {
+  if (rule_.Born(0)) {
+    throw std::invalid_argument(
+        "the chunked engine does not support B0 rules -> " + rule_.ToString());
+  }
+}
+
+bool ChunkedBoard::IsAlive(std::size_t row, std::size_t col) const noexcept {
+  const std::int64_t kRow = static_cast<std::int64_t>(row);
+  const std::int64_t kCol = static_cast<std::int64_t>(col);
+  const auto kIt =
+      chunks_.find({.row = kRow >> kChunkShift, .col = kCol >> kChunkShift});
+  if (kIt == chunks_.end()) {
+    return false;
+  }
+  return (kIt->second[kRow & kChunkMask] >> (kCol & kChunkMask)) & 1;
+}
+
+void ChunkedBoard::SetCell(std::size_t row, std::size_t col, bool alive) {
+  const std::int64_t kRow = static_cast<std::int64_t>(row);
+  const std::int64_t kCol = static_cast<std::int64_t>(col);
+  const ChunkKey kKey = {.row = kRow >> kChunkShift,
+                         .col = kCol >> kChunkShift};
+  const std::uint64_t kBit = std::uint64_t{1} << (kCol & kChunkMask);
+  if (alive) {
+    chunks_[kKey][kRow & kChunkMask] |= kBit;
+    return;
+  }
+
+  auto it = chunks_.find(kKey);
+  if (it != chunks_.end()) {
+    it->second[kRow & kChunkMask] &= ~kBit;
+  }
+}
+
+std::vector<LiveCell> ChunkedBoard::LiveCells() const {
+  std::vector<LiveCell> cells;
+  for (const auto& [kKey, kChunk] : chunks_) {
+    for (std::size_t i = 0; i < kChunkSize; ++i) {
+      for (std::uint64_t row = kChunk[i]; row; row &= row - 1) {
+        cells.push_back(
+            {.row = (kKey.row << kChunkShift) + static_cast<std::int64_t>(i),
+             .col = (kKey.col << kChunkShift) + std::countr_zero(row),
+             .state = 1});
+      }
+    }
+  }
+  std::sort(cells.begin(), cells.end());
+  return cells;
+}
+
+std::uint64_t ChunkedBoard::Population() const noexcept {
+  std::uint64_t population = 0;
+  for (const auto& [kKey, kChunk] : chunks_) {
+    for (std::uint64_t row : kChunk) {
+      population += std::popcount(row);
+    }
+  }
+  return population;
+}
+
+ChunkedBoard::Chunk ChunkedBoard::Step(const ChunkKey& key) const noexcept {
+  /* gather the 3x3 block of chunks centered on the chunk, missing chunks are
+   * empty */
+  static const Chunk kEmpty = {};
+  const Chunk* block[3][3];
+  for (std::int64_t i = 0; i < 3; ++i) {
+    for (std::int64_t j = 0; j < 3; ++j) {
+      const auto kIt = chunks_.find({.row = key.row + i - 1,
+                                     .col = key.col + j - 1});
+      block[i][j] = (kIt == chunks_.end()) ? &kEmpty : &kIt->second;
+    }
+  }
+
+  Chunk next = {};
+  for (std::size_t r = 0; r < kChunkSize; ++r) {
+    /* align the rows above, at, and below r with the cells of row r, the
+     * bits shifted in at the edges come from the neighboring chunks */
+    std::uint64_t west[3];
+    std::uint64_t centre[3];
+    std::uint64_t east[3];
+    for (std::size_t k = 0; k < 3; ++k) {
+      std::size_t block_row = 1;
+      std::size_t row = r + k - 1;
+      if ((0 == r) && (0 == k)) {
+        block_row = 0;
+        row = kChunkSize - 1;
+      } else if ((kChunkSize - 1 == r) && (2 == k)) {
+        block_row = 2;
+        row = 0;
+      }
+      centre[k] = (*block[block_row][1])[row];
+      west[k] = (centre[k] << 1) | ((*block[block_row][0])[row] >> kChunkMask);
+      east[k] = (centre[k] >> 1) | (((*block[block_row][2])[row] & kFirstCol)
+                                    << kChunkMask);
+    }
+
+    next[r] = NextCells({west[0], centre[0], east[0], west[1], east[1],
+                         west[2], centre[2], east[2]},
+                        centre[1], rule_);
+  }
+  return next;
+}
+
+void ChunkedBoard::Tick() {
+  /* step every chunk containing live cells along with the neighboring chunks
+   * bordering one of its live cells */
+  next_chunks_.clear();
+  for (const auto& [kKey, kChunk] : chunks_) {
+    for (int i = -1; i <= 1; ++i) {
+      for (int j = -1; j <= 1; ++j) {
+        const ChunkKey kNeighbor = {.row = kKey.row + i, .col = kKey.col + j};
+        if (Borders(kChunk, i, j) && !next_chunks_.contains(kNeighbor)) {
+          next_chunks_.emplace(kNeighbor, Step(kNeighbor));
+        }
+      }
+    }
+  }
+
+  /* free the chunks that died out */
+  std::erase_if(next_chunks_, [](const auto& key_and_chunk) {
+    const Chunk& kChunk = key_and_chunk.second;
+    return std::all_of(kChunk.begin(), kChunk.end(),
+                       [](std::uint64_t row) { return 0 == row; });
+  });
+
+  chunks_.swap(next_chunks_);
+  generation_++;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,71 @@
+#include "game/cycle.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <stdexcept>
+
+#include "game/board.h"
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+std::uint64_t HashCells(const Engine& engine) {
+  if (const auto* board = dynamic_cast<const GameOfLifeBoard*>(&engine)) {
+    return board->Hash();
+  }
+
+  /* pack the window into a bit-packed board to hash it the same way */
+  GameOfLifeBoard cells(engine.Rows(), engine.Cols());
+  for (std::size_t i = 0; i < engine.Rows(); ++i) {
+    for (std::size_t j = 0; j < engine.Cols(); ++j) {
+      if (engine.IsAlive(i, j)) {
+        cells.SetCell(i, j, true);
+      }
+    }
+  }
+  return cells.Hash();
+}
+
+CycleDetector::CycleDetector(std::size_t history) : history_(history) {
+  if (0 == history_) {
+    throw std::invalid_argument("cycle history must be at least 1");
+  }
+}
+
+std::optional<std::uint64_t> CycleDetector::Update(const Engine& board) {
+  return Update(HashCells(board), board.Generation());
+}
+
+std::optional<std::uint64_t> CycleDetector::Update(std::uint64_t hash,
+                                                   std::uint64_t generation) {
+  /* forget the generations that fell out of the history */
+  while (!entries_.empty() &&
+         (generation - entries_.front().generation > history_)) {
+    const Entry& kOldest = entries_.front();
+    const auto kIt = last_seen_.find(kOldest.hash);
+    if ((kIt != last_seen_.end()) && (kIt->second == kOldest.generation)) {
+      last_seen_.erase(kIt);
+    }
+    entries_.pop_front();
+  }
+
+  std::optional<std::uint64_t> period;
+  const auto kIt = last_seen_.find(hash);
+  if ((kIt != last_seen_.end()) && (kIt->second < generation)) {
+    period = generation - kIt->second;
+  }
+
+  entries_.push_back({.hash = hash, .generation = generation});
+  last_seen_[hash] = generation;
+  return period;
+}
+
+void CycleDetector::Clear() noexcept {
+  entries_.clear();
+  last_seen_.clear();
+}
+
+}  // namespace game
+}  // namespace gol
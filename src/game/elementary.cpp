@@ -0,0 +1,78 @@
+#include "game/elementary.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <stdexcept>
+#include <string>
+
+namespace gol {
+namespace game {
+
+ElementaryBoard::ElementaryBoard(std::size_t num_rows, std::size_t num_cols,
+                                 std::uint8_t rule, bool wrap)
+    : rows_(num_rows),
+      cols_(num_cols),
+      rule_(rule),
+      wrap_(wrap),
+      cells_(num_rows * num_cols, 0),
+      next_(num_cols, 0) {}
+
+void ElementaryBoard::SetCell(std::size_t row, std::size_t col, bool alive) {
+  if (row <= current_) {
+    cells_[Index(row, col)] = alive;
+  }
+}
+
+std::uint64_t ElementaryBoard::Population() const noexcept {
+  return std::count(cells_.begin(), cells_.end(), 1);
+}
+
+void ElementaryBoard::Tick() {
+  /* the neighborhood of a cell, read as the binary number left-center-right,
+   * selects the bit of the rule holding the cell's next state */
+  const std::uint8_t* kRow = &cells_[Index(current_, 0)];
+  for (std::size_t j = 0; j < cols_; ++j) {
+    const int kLeft = (j > 0) ? kRow[j - 1] : (wrap_) ? kRow[cols_ - 1] : 0;
+    const int kRight = (j + 1 < cols_) ? kRow[j + 1] : (wrap_) ? kRow[0] : 0;
+    const int kNeighborhood = (kLeft << 2) | (kRow[j] << 1) | kRight;
+    next_[j] = (rule_ >> kNeighborhood) & 1;
+  }
+
+  /* the top row is dropped to make room once the window is full */
+  if (current_ + 1 < rows_) {
+    current_++;
+  } else {
+    first_ = (first_ + 1) % rows_;
+  }
+  std::copy(next_.begin(), next_.end(), cells_.begin() + Index(current_, 0));
+  generation_++;
+}
+
+std::optional<std::uint8_t> ParseElementaryRule(const std::string& name) {
+  std::string lower = name;
+  std::transform(lower.begin(), lower.end(), lower.begin(), [](char c) {
+    return static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  });
+
+  std::string code;
+  if (lower.starts_with("rule")) {
+    code = lower.substr(4);
+  } else if (lower.starts_with("w")) {
+    code = lower.substr(1);
+  }
+  if (code.empty() || (code.find_first_not_of("0123456789") !=
+                       std::string::npos)) {
+    return std::nullopt;
+  }
+  if ((code.size() > 3) || (std::stoi(code) > 255)) {
+    throw std::invalid_argument("elementary rule must lie in [0, 255] -> " +
+                                name);
+  }
+  return static_cast<std::uint8_t>(std::stoi(code));
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,129 @@
+#include "game/factory.h"
+
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/ant.h"
+#include "game/board.h"
+#include "game/chunked.h"
+#include "game/elementary.h"
+#include "game/engine.h"
+#include "game/hashlife.h"
+#include "game/lenia.h"
+#include "game/sparse.h"
+
+#ifdef GOL_GPU
+#include "game/gpu.h"
+#endif
+
+namespace gol {
+namespace game {
+
+struct EngineName {
+  const char* name;
+  EngineType type;
+};
+
+static constexpr EngineName kEngineNames[] = {
+    {"naive", EngineType::kNaive},
+    {"hashlife", EngineType::kHashLife},
+    {"sparse", EngineType::kSparse},
+    {"chunked", EngineType::kChunked},
+#ifdef GOL_GPU
+    {"gpu", EngineType::kGpu},
+#endif
+};
+
+EngineType ParseEngineType(const std::string& name) {
+  for (const EngineName& engine : kEngineNames) {
+    if (name == engine.name) {
+      return engine.type;
+    }
+  }
+  throw std::invalid_argument("unknown engine -> " + name);
+}
+
+std::vector<std::string> EngineNames() {
+  std::vector<std::string> names;
+  for (const EngineName& engine : kEngineNames) {
+    names.push_back(engine.name);
+  }
+  return names;
+}
+
+bool SupportsWrap(EngineType type) noexcept {
+  return (EngineType::kNaive == type) || (EngineType::kGpu == type);
+}
+
+std::unique_ptr<Engine> MakeEngine(EngineType type,
+                                   const EngineOptions& options) {
+  if (options.wrap && !SupportsWrap(type)) {
+    throw std::invalid_argument("engine does not support wrapping");
+  } else if ((!options.rule.GetNeighborhood().IsMoore() ||
+              !options.rule.IsTotalistic()) &&
+             (EngineType::kNaive != type)) {
+    throw std::invalid_argument("engine does not support " +
+                                options.rule.ToString());
+  } else if (options.automaton && (EngineType::kNaive != type)) {
+    throw std::invalid_argument("engine does not support " +
+                                options.automaton->Name());
+  } else if ((Simulation::kAnt == options.simulation) &&
+             (EngineType::kNaive != type)) {
+    throw std::invalid_argument("engine does not support ant");
+  } else if ((Simulation::kElementary == options.simulation) &&
+             (EngineType::kNaive != type)) {
+    throw std::invalid_argument("engine does not support elementary rules");
+  } else if ((Simulation::kLenia == options.simulation) &&
+             (EngineType::kNaive != type)) {
+    throw std::invalid_argument("engine does not support lenia");
+  } else if ((options.noise > 0.0) &&
+             ((EngineType::kNaive != type) ||
+              (Simulation::kLife != options.simulation))) {
+    throw std::invalid_argument("engine does not support noise");
+  }
+
+  switch (type) {
+    case EngineType::kHashLife:
+      return std::make_unique<HashLifeBoard>(options.rows, options.cols,
+                                             options.rule);
+    case EngineType::kSparse:
+      return std::make_unique<SparseBoard>(options.rows, options.cols,
+                                           options.rule);
+    case EngineType::kChunked:
+      return std::make_unique<ChunkedBoard>(options.rows, options.cols,
+                                            options.rule);
+    case EngineType::kGpu:
+#ifdef GOL_GPU
+      return std::make_unique<GpuBoard>(options.rows, options.cols,
+                                        options.wrap, options.rule);
+#else
+      throw std::invalid_argument("built without GPU support");
+#endif
+    case EngineType::kNaive:
+      break;
+  }
+  if (Simulation::kAnt == options.simulation) {
+    return std::make_unique<AntBoard>(options.rows, options.cols,
+                                      options.wrap);
+  } else if (Simulation::kElementary == options.simulation) {
+    return std::make_unique<ElementaryBoard>(
+        options.rows, options.cols, options.elementary_rule, options.wrap);
+  } else if (Simulation::kLenia == options.simulation) {
+    return std::make_unique<LeniaBoard>(options.rows, options.cols,
+                                        options.wrap);
+  }
+  auto board =
+      (options.automaton)
+          ? std::make_unique<GameOfLifeBoard>(options.rows, options.cols,
+                                              options.wrap, options.automaton)
+          : std::make_unique<GameOfLifeBoard>(options.rows, options.cols,
+                                              options.wrap, options.rule);
+  board->SetNumThreads(options.num_threads);
+  board->SetNoise(options.noise, options.noise_seed);
+  return board;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,283 @@
+#define GL_GLEXT_PROTOTYPES
+
+#include "game/gpu.h"
+
+#include <EGL/egl.h>
+#include <EGL/eglext.h>
+#include <GL/glcorearb.h>
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/* Width and height of a compute shader work group in cells. */
+static constexpr GLuint kGroupSize = 16;
+
+/* The compute shader applying the rule to every cell of the board. Cells are
+ * stored one per uint, bit n of the birth/survival masks is set when n
+ * neighbors cause a birth/survival. */
+static constexpr const char* kShaderSource = R"(
+#version 430
+layout(local_size_x = 16, local_size_y = 16) in;
+
+layout(std430, binding = 0) readonly buffer Current { uint current[]; };
+layout(std430, binding = 1) writeonly buffer Next { uint next[]; };
+
+uniform int rows;
+uniform int cols;
+uniform bool wrap;
+uniform uint birth;
+uniform uint survival;
+
+uint Cell(int row, int col) {
+  if (wrap) {
+    row = (row + rows) % rows;
+    col = (col + cols) % cols;
+  } else if (row < 0 || col < 0 || row >= rows || col >= cols) {
+    return 0u;
+  }
+  return current[row * cols + col];
+}
+
+void main() {
+  int row = int(gl_GlobalInvocationID.y);
+  int col = int(gl_GlobalInvocationID.x);
+  if (row >= rows || col >= cols) {
+    return;
+  }
+
+  uint neighbors = 0u;
+  for (int i = -1; i <= 1; ++i) {
+    for (int j = -1; j <= 1; ++j) {
+      if (i != 0 || j != 0) {
+        neighbors += Cell(row + i, col + j);
+      }
+    }
+  }
+
+  uint mask = (current[row * cols + col] != 0u) ? survival : birth;
+  next[row * cols + col] = (mask >> neighbors) & 1u;
+}
+)";
+
+/* Return the neighbor counts of rule satisfying predicate as a bit mask. */
+template <typename Predicate>
+[[nodiscard]] static GLuint RuleMask(const Rule& rule, Predicate predicate) {
+  GLuint mask = 0;
+  for (int n = 0; n <= Rule::kMaxNeighbors; ++n) {
+    if ((rule.*predicate)(n)) {
+      mask |= GLuint{1} << n;
+    }
+  }
+  return mask;
+}
+
+/* Return the default EGL display, preferring Mesa's surfaceless platform which
+ * does not require a window system. */
+[[nodiscard]] static EGLDisplay GetDisplay() {
+  const auto kGetPlatformDisplay =
+      reinterpret_cast<PFNEGLGETPLATFORMDISPLAYEXTPROC>(
+          eglGetProcAddress("eglGetPlatformDisplayEXT"));
+  if (kGetPlatformDisplay) {
+    EGLDisplay display = kGetPlatformDisplay(EGL_PLATFORM_SURFACELESS_MESA,
+                                             EGL_DEFAULT_DISPLAY, nullptr);
+    if (EGL_NO_DISPLAY != display) {
+      return display;
+    }
+  }
+  return eglGetDisplay(EGL_DEFAULT_DISPLAY);
+}
+
+/* Makes an EGL context current on the calling thread for the lifetime of the
+ * guard. A context can only be current on one thread at a time, releasing it
+ * lets the board be used from whichever thread calls into it. */
+class CurrentContext {
+ public:
+  CurrentContext(EGLDisplay display, EGLContext context) noexcept
+      : display_(display),
+        current_(eglMakeCurrent(display, EGL_NO_SURFACE, EGL_NO_SURFACE,
+                                context)) {}
+  ~CurrentContext() {
+    eglMakeCurrent(display_, EGL_NO_SURFACE, EGL_NO_SURFACE, EGL_NO_CONTEXT);
+  }
+
+  CurrentContext(const CurrentContext&) = delete;
+  CurrentContext& operator=(const CurrentContext&) = delete;
+
+  [[nodiscard]] bool IsCurrent() const noexcept { return current_; }
+
+ private:
+  EGLDisplay display_;
+  bool current_;
+};
+
+/* Compile and link the compute shader program. */
+[[nodiscard]] static GLuint CompileProgram() {
+  GLuint shader = glCreateShader(GL_COMPUTE_SHADER);
+  glShaderSource(shader, 1, &kShaderSource, nullptr);
+  glCompileShader(shader);
+
+  GLint compiled = GL_FALSE;
+  glGetShaderiv(shader, GL_COMPILE_STATUS, &compiled);
+  if (GL_FALSE == compiled) {
+    char log[512] = {};
+    glGetShaderInfoLog(shader, sizeof(log), nullptr, log);
+    glDeleteShader(shader);
+    throw std::runtime_error(std::string("unable to compile shader -> ") +
+                             log);
+  }
+
+  GLuint program = glCreateProgram();
+  glAttachShader(program, shader);
+  glLinkProgram(program);
+  glDeleteShader(shader);
+
+  GLint linked = GL_FALSE;
+  glGetProgramiv(program, GL_LINK_STATUS, &linked);
+  if (GL_FALSE == linked) {
+    glDeleteProgram(program);
+    throw std::runtime_error("unable to link shader program");
+  }
+  return program;
+}
+
+GpuBoard::GpuBoard(std::size_t num_rows, std::size_t num_cols, bool wrap,
+                   const Rule& rule)
+    : rows_(num_rows),
+      cols_(num_cols),
+      wrap_(wrap),
+      rule_(rule),
+      cells_(num_rows * num_cols, 0) {
+  try {
+    display_ = GetDisplay();
+    if (!eglInitialize(display_, nullptr, nullptr) ||
+        !eglBindAPI(EGL_OPENGL_API)) {
+      throw std::runtime_error("unable to initialize EGL");
+    }
+
+    /* the board lives in buffers, no surface is needed */
+    const EGLint kContextAttributes[] = {
+        EGL_CONTEXT_MAJOR_VERSION,
+        4,
+        EGL_CONTEXT_MINOR_VERSION,
+        3,
+        EGL_CONTEXT_OPENGL_PROFILE_MASK,
+        EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+        EGL_NONE,
+    };
+    context_ = eglCreateContext(display_, EGL_NO_CONFIG_KHR, EGL_NO_CONTEXT,
+                                kContextAttributes);
+    if (EGL_NO_CONTEXT == context_) {
+      throw std::runtime_error("unable to create an OpenGL 4.3 context");
+    }
+    const CurrentContext kCurrent(display_, context_);
+    if (!kCurrent.IsCurrent()) {
+      throw std::runtime_error("unable to create an OpenGL 4.3 context");
+    }
+
+    program_ = CompileProgram();
+    glUseProgram(program_);
+    glUniform1i(glGetUniformLocation(program_, "rows"),
+                static_cast<GLint>(rows_));
+    glUniform1i(glGetUniformLocation(program_, "cols"),
+                static_cast<GLint>(cols_));
+    glUniform1i(glGetUniformLocation(program_, "wrap"), wrap_);
+    glUniform1ui(glGetUniformLocation(program_, "birth"),
+                 RuleMask(rule_, &Rule::Born));
+    glUniform1ui(glGetUniformLocation(program_, "survival"),
+                 RuleMask(rule_, &Rule::Survives));
+
+    glGenBuffers(2, buffers_);
+    for (GLuint buffer : buffers_) {
+      glBindBuffer(GL_SHADER_STORAGE_BUFFER, buffer);
+      glBufferData(GL_SHADER_STORAGE_BUFFER,
+                   cells_.size() * sizeof(cells_.front()), cells_.data(),
+                   GL_DYNAMIC_COPY);
+    }
+    if (GL_NO_ERROR != glGetError()) {
+      throw std::runtime_error("unable to allocate the board in GPU memory");
+    }
+  } catch (...) {
+    Release();
+    throw;
+  }
+}
+
+GpuBoard::~GpuBoard() { Release(); }
+
+void GpuBoard::Release() noexcept {
+  if (EGL_NO_CONTEXT != context_) {
+    {
+      const CurrentContext kCurrent(display_, context_);
+      glDeleteBuffers(2, buffers_);
+      glDeleteProgram(program_);
+    }
+    eglDestroyContext(display_, context_);
+  }
+  if (EGL_NO_DISPLAY != display_) {
+    eglTerminate(display_);
+  }
+}
+
+void GpuBoard::Download() const noexcept {
+  if (!host_stale_) {
+    return;
+  }
+
+  const CurrentContext kCurrent(display_, context_);
+  glBindBuffer(GL_SHADER_STORAGE_BUFFER, buffers_[0]);
+  glGetBufferSubData(GL_SHADER_STORAGE_BUFFER, 0,
+                     cells_.size() * sizeof(cells_.front()), cells_.data());
+  host_stale_ = false;
+}
+
+bool GpuBoard::IsAlive(std::size_t row, std::size_t col) const noexcept {
+  Download();
+  return cells_[row * cols_ + col];
+}
+
+void GpuBoard::SetCell(std::size_t row, std::size_t col, bool alive) {
+  Download();
+  cells_[row * cols_ + col] = alive;
+  device_stale_ = true;
+}
+
+std::uint64_t GpuBoard::Population() const noexcept {
+  Download();
+  return static_cast<std::uint64_t>(
+      std::count_if(cells_.begin(), cells_.end(),
+                    [](std::uint32_t cell) { return 0 != cell; }));
+}
+
+void GpuBoard::Tick() {
+  const CurrentContext kCurrent(display_, context_);
+  if (device_stale_) {
+    glBindBuffer(GL_SHADER_STORAGE_BUFFER, buffers_[0]);
+    glBufferSubData(GL_SHADER_STORAGE_BUFFER, 0,
+                    cells_.size() * sizeof(cells_.front()), cells_.data());
+    device_stale_ = false;
+  }
+
+  glBindBufferBase(GL_SHADER_STORAGE_BUFFER, 0, buffers_[0]);
+  glBindBufferBase(GL_SHADER_STORAGE_BUFFER, 1, buffers_[1]);
+  glDispatchCompute((static_cast<GLuint>(cols_) + kGroupSize - 1) / kGroupSize,
+                    (static_cast<GLuint>(rows_) + kGroupSize - 1) / kGroupSize,
+                    1);
+  glMemoryBarrier(GL_SHADER_STORAGE_BARRIER_BIT |
+                  GL_BUFFER_UPDATE_BARRIER_BIT);
+  std::swap(buffers_[0], buffers_[1]);
+
+  host_stale_ = true;
+  generation_++;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,343 @@
+#include "game/hashlife.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <functional>
+#include <stdexcept>
+#include <unordered_map>
+#include <utility>
+#include <vector>
+
+#include "game/hash.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+/* Upper limit on the number of cached nodes before unreachable nodes are
+ * garbage collected. Each node is roughly 64 bytes in size. */
+static constexpr std::size_t kMaxNodes = 1 << 20;
+
+/* Level of the root node on construction. Level 3 is the smallest node that can
+ * be padded and advanced. */
+static constexpr int kMinRootLevel = 3;
+
+std::size_t HashLifeBoard::NodeKeyHash::operator()(
+    const NodeKey& key) const noexcept {
+  std::uint64_t seed = 0;
+  for (const Node* node : {key.nw, key.ne, key.sw, key.se}) {
+    seed = HashCombine(seed, std::hash<const Node*>{}(node));
+  }
+  return static_cast<std::size_t>(seed);
+}
+
+HashLifeBoard::Node* HashLifeBoard::Join(Node* nw, Node* ne, Node* sw,
+                                         Node* se) {
+  const NodeKey kKey = {.nw = nw, .ne = ne, .sw = sw, .se = se};
+  auto it = index_.find(kKey);
+  if (it != index_.end()) {
+    return it->second;
+  }
+
+  Node& node = nodes_.emplace_back();
+  node.nw = nw;
+  node.ne = ne;
+  node.sw = sw;
+  node.se = se;
+  node.level = nw->level + 1;
+  node.population =
+      nw->population + ne->population + sw->population + se->population;
+  index_.emplace(kKey, &node);
+  return &node;
+}
+
+HashLifeBoard::Node* HashLifeBoard::Empty(int level) {
+  while (static_cast<int>(empty_.size()) <= level) {
+    Node* prev = empty_.back();
+    empty_.push_back(Join(prev, prev, prev, prev));
+  }
+  return empty_[level];
+}
+
+HashLifeBoard::Node* HashLifeBoard::Centre(Node* node) {
+  Node* empty = Empty(node->level - 1);
+  return Join(Join(empty, empty, empty, node->nw),
+              Join(empty, empty, node->ne, empty),
+              Join(empty, node->sw, empty, empty),
+              Join(node->se, empty, empty, empty));
+}
+
+HashLifeBoard::Node* HashLifeBoard::Life4x4(Node* node) {
+  /* flatten the 4x4 block into a row major grid of cells */
+  bool cells[4][4] = {};
+  const Node* quadrants[2][2] = {{node->nw, node->ne}, {node->sw, node->se}};
+  for (int qr = 0; qr < 2; ++qr) {
+    for (int qc = 0; qc < 2; ++qc) {
+      const Node* quad = quadrants[qr][qc];
+      cells[2 * qr][2 * qc] = quad->nw->population;
+      cells[2 * qr][2 * qc + 1] = quad->ne->population;
+      cells[2 * qr + 1][2 * qc] = quad->sw->population;
+      cells[2 * qr + 1][2 * qc + 1] = quad->se->population;
+    }
+  }
+
+  /* compute the next state of the center 2x2 block */
+  Node* next[2][2] = {};
+  for (int r = 1; r <= 2; ++r) {
+    for (int c = 1; c <= 2; ++c) {
+      int num_live_neighbors = 0;
+      for (int dr = -1; dr <= 1; ++dr) {
+        for (int dc = -1; dc <= 1; ++dc) {
+          if ((dr || dc) && cells[r + dr][c + dc]) {
+            num_live_neighbors++;
+          }
+        }
+      }
+      const bool kAlive = (cells[r][c]) ? rule_.Survives(num_live_neighbors)
+                                        : rule_.Born(num_live_neighbors);
+      next[r - 1][c - 1] = (kAlive) ? live_ : dead_;
+    }
+  }
+  return Join(next[0][0], next[0][1], next[1][0], next[1][1]);
+}
+
+HashLifeBoard::Node* HashLifeBoard::Successor(Node* node, int step_log2) {
+  if (0 == node->population) {
+    return Empty(node->level - 1);
+  }
+
+  /* a level k node can be advanced at most 2^(k-2) generations */
+  step_log2 = std::min(step_log2, node->level - 2);
+  if (node->next && (node->next_log2 == step_log2)) {
+    return node->next;
+  }
+
+  Node* result = nullptr;
+  if (2 == node->level) {
+    result = Life4x4(node);
+  } else {
+    Node* nw = node->nw;
+    Node* ne = node->ne;
+    Node* sw = node->sw;
+    Node* se = node->se;
+
+    /* advance the nine overlapping level k-1 subnodes */
+    Node* n00 = Successor(nw, step_log2);
+    Node* n01 = Successor(Join(nw->ne, ne->nw, nw->se, ne->sw), step_log2);
+    Node* n02 = Successor(ne, step_log2);
+    Node* n10 = Successor(Join(nw->sw, nw->se, sw->nw, sw->ne), step_log2);
+    Node* n11 = Successor(Join(nw->se, ne->sw, sw->ne, se->nw), step_log2);
+    Node* n12 = Successor(Join(ne->sw, ne->se, se->nw, se->ne), step_log2);
+    Node* n20 = Successor(sw, step_log2);
+    Node* n21 = Successor(Join(sw->ne, se->nw, sw->se, se->sw), step_log2);
+    Node* n22 = Successor(se, step_log2);
+
+    if (step_log2 < (node->level - 2)) {
+      /* the subnodes have already been advanced far enough, stitch their
+       * centers together */
+      result = Join(Join(n00->se, n01->sw, n10->ne, n11->nw),
+                    Join(n01->se, n02->sw, n11->ne, n12->nw),
+                    Join(n10->se, n11->sw, n20->ne, n21->nw),
+                    Join(n11->se, n12->sw, n21->ne, n22->nw));
+    } else {
+      /* advance the four overlapping quadrants a second time */
+      result = Join(Successor(Join(n00, n01, n10, n11), step_log2),
+                    Successor(Join(n01, n02, n11, n12), step_log2),
+                    Successor(Join(n10, n11, n20, n21), step_log2),
+                    Successor(Join(n11, n12, n21, n22), step_log2));
+    }
+  }
+
+  node->next = result;
+  node->next_log2 = step_log2;
+  return result;
+}
+
+HashLifeBoard::Node* HashLifeBoard::SetCell(Node* node, std::int64_t x,
+                                            std::int64_t y, bool alive) {
+  if (0 == node->level) {
+    return (alive) ? live_ : dead_;
+  }
+
+  /* translate the coordinates to be relative to the quadrant's center */
+  const std::int64_t kOffset =
+      (1 == node->level) ? 0 : (std::int64_t{1} << (node->level - 2));
+  const bool kWest = (x < 0);
+  const bool kNorth = (y < 0);
+  const std::int64_t kChildX = (kWest) ? (x + kOffset) : (x - kOffset);
+  const std::int64_t kChildY = (kNorth) ? (y + kOffset) : (y - kOffset);
+
+  Node* nw = node->nw;
+  Node* ne = node->ne;
+  Node* sw = node->sw;
+  Node* se = node->se;
+  if (kNorth && kWest) {
+    nw = SetCell(nw, kChildX, kChildY, alive);
+  } else if (kNorth) {
+    ne = SetCell(ne, kChildX, kChildY, alive);
+  } else if (kWest) {
+    sw = SetCell(sw, kChildX, kChildY, alive);
+  } else {
+    se = SetCell(se, kChildX, kChildY, alive);
+  }
+  return Join(nw, ne, sw, se);
+}
+
+bool HashLifeBoard::GetCell(const Node* node, std::int64_t x,
+                            std::int64_t y) const noexcept {
+  while (node->population && node->level) {
+    const std::int64_t kOffset =
+        (1 == node->level) ? 0 : (std::int64_t{1} << (node->level - 2));
+    const bool kWest = (x < 0);
+    const bool kNorth = (y < 0);
+    x = (kWest) ? (x + kOffset) : (x - kOffset);
+    y = (kNorth) ? (y + kOffset) : (y - kOffset);
+    if (kNorth) {
+      node = (kWest) ? node->nw : node->ne;
+    } else {
+      node = (kWest) ? node->sw : node->se;
+    }
+  }
+  return node->population;
+}
+
+void HashLifeBoard::AppendLiveCells(const Node* node, std::int64_t row,
+                                    std::int64_t col,
+                                    std::vector<LiveCell>& cells) const {
+  /* (row, col) is the top left corner of the node, empty nodes are skipped */
+  if (0 == node->population) {
+    return;
+  } else if (0 == node->level) {
+    cells.push_back({.row = row, .col = col, .state = 1});
+    return;
+  }
+  const std::int64_t kHalf = std::int64_t{1} << (node->level - 1);
+  AppendLiveCells(node->nw, row, col, cells);
+  AppendLiveCells(node->ne, row, col + kHalf, cells);
+  AppendLiveCells(node->sw, row + kHalf, col, cells);
+  AppendLiveCells(node->se, row + kHalf, col + kHalf, cells);
+}
+
+HashLifeBoard::Node* HashLifeBoard::Copy(
+    Node* node, std::unordered_map<Node*, Node*>& copies) {
+  if (0 == node->level) {
+    return (node->population) ? live_ : dead_;
+  }
+
+  auto it = copies.find(node);
+  if (it != copies.end()) {
+    return it->second;
+  }
+  Node* copy = Join(Copy(node->nw, copies), Copy(node->ne, copies),
+                    Copy(node->sw, copies), Copy(node->se, copies));
+  copies.emplace(node, copy);
+  return copy;
+}
+
+void HashLifeBoard::CollectGarbage() {
+  /* copy the nodes reachable from the root into fresh storage, everything else
+   * (including the memoized results) is dropped */
+  std::deque<Node> old_nodes = std::move(nodes_);
+  nodes_ = std::deque<Node>();
+  index_.clear();
+  empty_.clear();
+
+  dead_ = &nodes_.emplace_back();
+  live_ = &nodes_.emplace_back();
+  live_->population = 1;
+  empty_.push_back(dead_);
+
+  std::unordered_map<Node*, Node*> copies;
+  root_ = Copy(root_, copies);
+}
+
+HashLifeBoard::HashLifeBoard(std::size_t num_rows, std::size_t num_cols,
+                             const Rule& rule)
+    : rows_(num_rows), cols_(num_cols), rule_(rule) {
+  if (rule_.Born(0)) {
+    throw std::invalid_argument(
+        "the hashlife engine does not support B0 rules -> " + rule_.ToString());
+  }
+
+  dead_ = &nodes_.emplace_back();
+  live_ = &nodes_.emplace_back();
+  live_->population = 1;
+  empty_.push_back(dead_);
+  root_ = Empty(kMinRootLevel);
+}
+
+bool HashLifeBoard::IsAlive(std::size_t row, std::size_t col) const noexcept {
+  const std::int64_t kHalfWidth = std::int64_t{1} << (root_->level - 1);
+  const std::int64_t kX = static_cast<std::int64_t>(col);
+  const std::int64_t kY = static_cast<std::int64_t>(row);
+  if ((kX >= kHalfWidth) || (kY >= kHalfWidth)) {
+    return false;
+  }
+  return GetCell(root_, kX, kY);
+}
+
+void HashLifeBoard::SetCell(std::size_t row, std::size_t col, bool alive) {
+  const std::int64_t kX = static_cast<std::int64_t>(col);
+  const std::int64_t kY = static_cast<std::int64_t>(row);
+  while ((kX >= (std::int64_t{1} << (root_->level - 1))) ||
+         (kY >= (std::int64_t{1} << (root_->level - 1)))) {
+    root_ = Centre(root_);
+  }
+  root_ = SetCell(root_, kX, kY, alive);
+}
+
+std::vector<LiveCell> HashLifeBoard::LiveCells() const {
+  /* the root is centered on the origin of the universe */
+  const std::int64_t kHalfWidth = std::int64_t{1} << (root_->level - 1);
+  std::vector<LiveCell> cells;
+  cells.reserve(root_->population);
+  AppendLiveCells(root_, -kHalfWidth, -kHalfWidth, cells);
+  std::sort(cells.begin(), cells.end());
+  return cells;
+}
+
+void HashLifeBoard::Tick() {
+  if (nodes_.size() > kMaxNodes) {
+    CollectGarbage();
+  }
+
+  Step(0);
+}
+
+void HashLifeBoard::Advance(std::uint64_t generations) {
+  /* the generations are split into steps of decreasing powers of two */
+  for (int step_log2 = 63; step_log2 >= 0; --step_log2) {
+    if (generations & (std::uint64_t{1} << step_log2)) {
+      if (nodes_.size() > kMaxNodes) {
+        CollectGarbage();
+      }
+      Step(step_log2);
+    }
+  }
+}
+
+void HashLifeBoard::Step(int step_log2) {
+  /* grow the universe until the pattern is surrounded by enough empty space
+   * that nothing can escape the center of the root during the update, a step
+   * of 2^step_log2 generations needs a root of a level of at least
+   * step_log2 + 2 */
+  auto is_padded = [](const Node* node) {
+    return (node->nw->population == node->nw->se->se->population) &&
+           (node->ne->population == node->ne->sw->sw->population) &&
+           (node->sw->population == node->sw->ne->ne->population) &&
+           (node->se->population == node->se->nw->nw->population);
+  };
+  while (!is_padded(root_) || (root_->level < (step_log2 + 2))) {
+    root_ = Centre(root_);
+  }
+
+  /* the successor of the centered root is a node of the root's original level
+   * and location advanced by 2^step_log2 generations */
+  root_ = Successor(Centre(root_), step_log2);
+  generation_ += std::uint64_t{1} << step_log2;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,121 @@
+#include "game/history.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/engine.h"
+#include "game/pipeline.h"
+
+namespace gol {
+namespace game {
+
+/* Append value to bytes using 7 bits per byte, least significant bits first.
+ * The high bit of each byte is set when more bytes follow. */
+static void WriteVarint(std::uint64_t value, std::vector<std::uint8_t>& bytes) {
+  while (value >= 0x80) {
+    bytes.push_back(static_cast<std::uint8_t>(value | 0x80));
+    value >>= 7;
+  }
+  bytes.push_back(static_cast<std::uint8_t>(value));
+}
+
+/* Read a varint starting at bytes[pos], pos is advanced past it. */
+[[nodiscard]] static std::uint64_t ReadVarint(
+    const std::vector<std::uint8_t>& bytes, std::size_t& pos) noexcept {
+  std::uint64_t value = 0;
+  for (int shift = 0; pos < bytes.size(); shift += 7) {
+    const std::uint8_t kByte = bytes[pos++];
+    value |= static_cast<std::uint64_t>(kByte & 0x7f) << shift;
+    if (!(kByte & 0x80)) {
+      break;
+    }
+  }
+  return value;
+}
+
+void History::Push(const Frame& frame) {
+  /* the row major cells alternate between runs of dead and live cells, the
+   * first run is dead and may be empty, the runs of multi-state frames are
+   * each followed by the state of their cells */
+  EncodedFrame encoded = {.rows = frame.cells.Rows(),
+                          .cols = frame.cells.Cols(),
+                          .generation = frame.generation,
+                          .population = frame.population,
+                          .automaton = frame.cells.GetAutomaton(),
+                          .runs = {}};
+  const bool kMultiState = (nullptr != encoded.automaton);
+  std::uint8_t state = 0;
+  std::uint64_t run = 0;
+  for (std::size_t i = 0; i < encoded.rows; ++i) {
+    for (std::size_t j = 0; j < encoded.cols; ++j) {
+      const std::uint8_t kState = frame.cells.State(i, j);
+      if (kState != state) {
+        WriteVarint(run, encoded.runs);
+        if (kMultiState) {
+          WriteVarint(state, encoded.runs);
+        }
+        state = kState;
+        run = 0;
+      }
+      run++;
+    }
+  }
+  WriteVarint(run, encoded.runs);
+  if (kMultiState) {
+    WriteVarint(state, encoded.runs);
+  }
+  encoded.runs.shrink_to_fit();
+
+  const std::size_t kFrameBytes = sizeof(encoded) + encoded.runs.size();
+  if (kFrameBytes > cap_bytes_) {
+    return;
+  }
+  while (bytes_ + kFrameBytes > cap_bytes_) {
+    bytes_ -= sizeof(frames_.front()) + frames_.front().runs.size();
+    frames_.pop_front();
+  }
+  bytes_ += kFrameBytes;
+  frames_.push_back(std::move(encoded));
+}
+
+Frame History::At(std::size_t index) const {
+  const EncodedFrame& kEncoded = frames_.at(index);
+  Frame frame(
+      GameOfLifeBoard(kEncoded.rows, kEncoded.cols, false, kEncoded.automaton));
+  frame.generation = kEncoded.generation;
+  frame.population = kEncoded.population;
+
+  std::uint8_t state = 0;
+  std::size_t cell = 0;
+  std::size_t pos = 0;
+  while (pos < kEncoded.runs.size()) {
+    const std::uint64_t kRun = ReadVarint(kEncoded.runs, pos);
+    if (kEncoded.automaton) {
+      state = static_cast<std::uint8_t>(ReadVarint(kEncoded.runs, pos));
+    }
+    if (state) {
+      for (std::uint64_t k = 0; k < kRun; ++k) {
+        frame.cells.SetState((cell + k) / kEncoded.cols,
+                             (cell + k) % kEncoded.cols, state);
+      }
+    }
+    cell += kRun;
+    if (!kEncoded.automaton) {
+      state = !state;
+    }
+  }
+  return frame;
+}
+
+void History::Clear() noexcept {
+  frames_.clear();
+  bytes_ = 0;
+}
+
+}  // namespace game
+}  // namespace gol
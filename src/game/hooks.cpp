@@ -0,0 +1,31 @@
+#include "game/hooks.h"
+
+#include <utility>
+#include <vector>
+
+#include "game/pipeline.h"
+#include "game/stats.h"
+
+namespace gol {
+namespace game {
+
+GenerationHooks::Handle GenerationHooks::OnGeneration(GenerationHook hook) {
+  const Handle kHandle = next_handle_++;
+  hooks_.emplace_back(kHandle, std::move(hook));
+  return kHandle;
+}
+
+void GenerationHooks::Remove(Handle handle) noexcept {
+  std::erase_if(hooks_, [handle](const auto& hook) {
+    return hook.first == handle;
+  });
+}
+
+void GenerationHooks::Notify(const Frame& frame, const StepStats& step) const {
+  for (const auto& [handle, hook] : hooks_) {
+    hook(frame, step);
+  }
+}
+
+}  // namespace game
+}  // namespace gol
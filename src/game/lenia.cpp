@@ -0,0 +1,145 @@
+#include "game/lenia.h"
+
+#include <algorithm>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <stdexcept>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+
+namespace gol {
+namespace game {
+
+/* Return the state value is shown in, the nearest of the evenly spaced
+ * levels. */
+static std::uint8_t Quantize(float value) {
+  return static_cast<std::uint8_t>(
+      std::lround(value * (LeniaBoard::kLevels - 1)));
+}
+
+LeniaBoard::LeniaBoard(std::size_t num_rows, std::size_t num_cols, bool wrap,
+                       const LeniaParameters& params)
+    : params_(params),
+      wrap_(wrap),
+      values_(num_rows * num_cols, 0.0f),
+      next_(num_rows * num_cols, 0.0f),
+      cells_(num_rows, num_cols, wrap,
+             std::make_shared<StaticStates>("lenia", kLevels, Colors())) {
+  if (params_.radius < 1) {
+    throw std::invalid_argument("lenia kernel radius must be positive");
+  }
+
+  /* the kernel is a smooth ring peaking halfway to the radius, its weights
+   * sum up to 1 so that the potential of a cell lies in [0, 1]. Cells are
+   * addressed by their offset in the padded values */
+  const int kRadius = params_.radius;
+  const auto kStride = static_cast<std::ptrdiff_t>(num_cols + (2 * kRadius));
+  padded_.resize((num_rows + (2 * kRadius)) * kStride, 0.0f);
+  float total = 0.0f;
+  for (int dr = -kRadius; dr <= kRadius; ++dr) {
+    for (int dc = -kRadius; dc <= kRadius; ++dc) {
+      const float kDistance =
+          std::sqrt(static_cast<float>((dr * dr) + (dc * dc))) / kRadius;
+      if ((kDistance <= 0.0f) || (kDistance >= 1.0f)) {
+        continue;
+      }
+      const float kWeight =
+          std::exp(4.0f - (1.0f / (kDistance * (1.0f - kDistance))));
+      kernel_.push_back({.offset = (dr * kStride) + dc, .weight = kWeight});
+      total += kWeight;
+    }
+  }
+  for (KernelCell& cell : kernel_) {
+    cell.weight /= total;
+  }
+}
+
+void LeniaBoard::SetValue(std::size_t row, std::size_t col, float value) {
+  value = std::clamp(value, 0.0f, 1.0f);
+  values_[Index(row, col)] = value;
+  cells_.SetState(row, col, Quantize(value));
+}
+
+void LeniaBoard::Pad() {
+  /* the values are surrounded by a border as wide as the kernel radius, it
+   * holds the cells across the opposite edge or zeros on a bounded board */
+  const auto kRadius = static_cast<long long>(params_.radius);
+  const auto kRows = static_cast<long long>(Rows());
+  const auto kCols = static_cast<long long>(Cols());
+  const long long kStride = kCols + (2 * kRadius);
+  for (long long i = 0; i < kRows + (2 * kRadius); ++i) {
+    long long r = i - kRadius;
+    if (wrap_) {
+      r = ((r % kRows) + kRows) % kRows;
+    }
+    for (long long j = 0; j < kStride; ++j) {
+      long long c = j - kRadius;
+      if (wrap_) {
+        c = ((c % kCols) + kCols) % kCols;
+      }
+      const bool kInside = (r >= 0) && (c >= 0) && (r < kRows) && (c < kCols);
+      padded_[(i * kStride) + j] =
+          (kInside) ? values_[Index(static_cast<std::size_t>(r),
+                                    static_cast<std::size_t>(c))]
+                    : 0.0f;
+    }
+  }
+}
+
+void LeniaBoard::Tick() {
+  Pad();
+
+  /* the growth rate is a bell curve centered on mu, scaled to [-1, 1] */
+  const float kVariance = 2.0f * params_.sigma * params_.sigma;
+  const std::size_t kStride = Cols() + (2 * params_.radius);
+  for (std::size_t i = 0; i < Rows(); ++i) {
+    for (std::size_t j = 0; j < Cols(); ++j) {
+      const float* kCenter =
+          &padded_[((i + params_.radius) * kStride) + j + params_.radius];
+      float potential = 0.0f;
+      for (const KernelCell& cell : kernel_) {
+        potential += cell.weight * kCenter[cell.offset];
+      }
+      const float kDelta = potential - params_.mu;
+      const float kGrowth =
+          (2.0f * std::exp(-(kDelta * kDelta) / kVariance)) - 1.0f;
+      next_[Index(i, j)] = std::clamp(
+          values_[Index(i, j)] + (params_.dt * kGrowth), 0.0f, 1.0f);
+    }
+  }
+  values_.swap(next_);
+
+  for (std::size_t i = 0; i < Rows(); ++i) {
+    for (std::size_t j = 0; j < Cols(); ++j) {
+      cells_.SetState(i, j, Quantize(values_[Index(i, j)]));
+    }
+  }
+  generation_++;
+}
+
+std::vector<std::uint32_t> LeniaBoard::Colors() {
+  /* the states fade linearly from dark blue through teal to yellow */
+  const std::uint32_t kStops[] = {0x440154, 0x21918c, 0xfde725};
+  const int kSteps = kLevels - 2;
+  std::vector<std::uint32_t> colors;
+  for (int i = 0; i <= kSteps; ++i) {
+    const int kStop = (2 * i < kSteps) ? 0 : 1;
+    const int kOffset = (2 * i) - (kStop * kSteps);
+    std::uint32_t color = 0;
+    for (int shift = 0; shift <= 16; shift += 8) {
+      const int kFrom = static_cast<int>((kStops[kStop] >> shift) & 0xff);
+      const int kTo = static_cast<int>((kStops[kStop + 1] >> shift) & 0xff);
+      const int kChannel = kFrom + ((kTo - kFrom) * kOffset) / kSteps;
+      color |= static_cast<std::uint32_t>(kChannel) << shift;
+    }
+    colors.push_back(color);
+  }
+  return colors;
+}
+
+}  // namespace game
+}  // namespace gol
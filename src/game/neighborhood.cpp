@@ -0,0 +1,96 @@
+#include "game/neighborhood.h"
+
+#include <array>
+#include <cstdlib>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace gol {
+namespace game {
+
+struct ShapeName {
+  const char* name;
+  NeighborhoodShape shape;
+};
+
+static constexpr std::array<ShapeName, 4> kShapeNames = {{
+    {"moore", NeighborhoodShape::kMoore},
+    {"vonneumann", NeighborhoodShape::kVonNeumann},
+    {"circular", NeighborhoodShape::kCircular},
+    {"hexagonal", NeighborhoodShape::kHexagonal},
+}};
+
+/* Return the number of steps between a cell and the cell dr rows below and dc
+ * columns right of it on a hexagonal grid skewed into rows, whose diagonal
+ * neighbors are the northwest and southeast ones. Rows and columns are axial
+ * coordinates, the distance is half the sum of the differences along the
+ * three axes. */
+[[nodiscard]] static int HexDistance(int dr, int dc) {
+  return (std::abs(dr) + std::abs(dc) + std::abs(dr - dc)) / 2;
+}
+
+CellNeighborhood::CellNeighborhood()
+    : CellNeighborhood(NeighborhoodShape::kMoore, 1) {}
+
+CellNeighborhood::CellNeighborhood(NeighborhoodShape shape, int radius,
+                                   bool include_center)
+    : shape_(shape), radius_(radius), include_center_(include_center) {
+  if ((radius_ < 1) || (radius_ > kMaxRadius)) {
+    throw std::invalid_argument("neighborhood radius must lie in [1, " +
+                                std::to_string(kMaxRadius) + "]");
+  }
+
+  for (int dr = -radius_; dr <= radius_; ++dr) {
+    for (int dc = -radius_; dc <= radius_; ++dc) {
+      if (Contains(dr, dc) && ((0 != dr) || (0 != dc) || include_center_)) {
+        offsets_.push_back({.row = dr, .col = dc});
+      }
+    }
+  }
+}
+
+bool CellNeighborhood::Contains(int dr, int dc) const noexcept {
+  /* a circular neighborhood holds the cells whose centers lie within
+   * radius + 1/2 of the cell's center, i.e., dr^2 + dc^2 <= r^2 + r */
+  switch (shape_) {
+    case NeighborhoodShape::kVonNeumann:
+      return (std::abs(dr) + std::abs(dc)) <= radius_;
+    case NeighborhoodShape::kCircular:
+      return ((dr * dr) + (dc * dc)) <= ((radius_ * radius_) + radius_);
+    case NeighborhoodShape::kHexagonal:
+      return HexDistance(dr, dc) <= radius_;
+    case NeighborhoodShape::kMoore:
+      break;
+  }
+  return true;
+}
+
+NeighborhoodShape ParseNeighborhoodShape(const std::string& name) {
+  for (const ShapeName& info : kShapeNames) {
+    if (name == info.name) {
+      return info.shape;
+    }
+  }
+  throw std::invalid_argument("unknown neighborhood -> " + name);
+}
+
+std::vector<std::string> NeighborhoodShapeNames() {
+  std::vector<std::string> names;
+  for (const ShapeName& info : kShapeNames) {
+    names.push_back(info.name);
+  }
+  return names;
+}
+
+std::string ToString(NeighborhoodShape shape) {
+  for (const ShapeName& info : kShapeNames) {
+    if (shape == info.shape) {
+      return info.name;
+    }
+  }
+  return "";
+}
+
+}  // namespace game
+}  // namespace gol
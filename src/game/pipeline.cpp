@@ -0,0 +1,154 @@
+#include "game/pipeline.h"
+
+#include <cstddef>
+#include <exception>
+#include <iterator>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <stdexcept>
+#include <stop_token>
+#include <thread>
+#include <utility>
+#include <vector>
+
+#include "game/ant.h"
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/engine.h"
+#include "game/evolution.h"
+#include "game/lenia.h"
+
+namespace gol {
+namespace game {
+
+Frame::Frame(const Engine& engine)
+    : cells(engine.Rows(), engine.Cols()),
+      generation(engine.Generation()),
+      population(engine.Population()) {
+  /* bit-packed boards are copied wholesale */
+  if (const auto* board = dynamic_cast<const GameOfLifeBoard*>(&engine)) {
+    cells = *board;
+    return;
+  } else if (const auto* ants = dynamic_cast<const AntBoard*>(&engine)) {
+    cells = ants->Cells();
+    return;
+  } else if (const auto* lenia = dynamic_cast<const LeniaBoard*>(&engine)) {
+    cells = lenia->Cells();
+    return;
+  }
+
+  /* the states of other multi-state engines are kept alongside the bits */
+  if (engine.NumStates() > 2) {
+    cells = GameOfLifeBoard(
+        engine.Rows(), engine.Cols(), false,
+        std::make_shared<StaticStates>("frame", engine.NumStates(),
+                                       std::vector<std::uint32_t>{}));
+  }
+  /* unbounded universes list the cells beyond the window as well */
+  for (const LiveCell& cell : engine.LiveCells()) {
+    if ((cell.row >= 0) && (cell.col >= 0) &&
+        (static_cast<std::size_t>(cell.row) < engine.Rows()) &&
+        (static_cast<std::size_t>(cell.col) < engine.Cols())) {
+      cells.SetState(static_cast<std::size_t>(cell.row),
+                     static_cast<std::size_t>(cell.col), cell.state);
+    } else {
+      outside.push_back(cell);
+    }
+  }
+}
+
+Pipeline::Pipeline(Engine& engine, std::size_t capacity)
+    : engine_(&engine), capacity_(capacity) {
+  if (0 == capacity_) {
+    throw std::invalid_argument("pipeline capacity must be at least 1");
+  }
+}
+
+void Pipeline::Start() {
+  if (Running()) {
+    return;
+  }
+  worker_ =
+      std::jthread([this](std::stop_token stop_token) { Run(stop_token); });
+}
+
+void Pipeline::Stop() noexcept {
+  if (!Running()) {
+    return;
+  }
+
+  /* request_stop() wakes the worker if it is blocked on a full queue */
+  worker_.request_stop();
+  worker_.join();
+  worker_ = std::jthread();
+
+  std::scoped_lock lock(mutex_);
+  frames_.clear();
+  error_ = nullptr;
+}
+
+bool Pipeline::Ready() {
+  std::scoped_lock lock(mutex_);
+  return !frames_.empty() || error_;
+}
+
+Frame Pipeline::Pop() {
+  if (!Running()) {
+    throw std::logic_error("pop from a stopped pipeline");
+  }
+
+  std::unique_lock lock(mutex_);
+  not_empty_.wait(lock, [this] { return !frames_.empty() || error_; });
+  if (error_) {
+    std::rethrow_exception(error_);
+  }
+
+  Frame frame = std::move(frames_.front());
+  frames_.pop_front();
+  not_full_.notify_one();
+  return frame;
+}
+
+std::optional<Frame> Pipeline::TryPop() {
+  std::scoped_lock lock(mutex_);
+  if (error_) {
+    std::rethrow_exception(error_);
+  }
+  if (frames_.empty()) {
+    return std::nullopt;
+  }
+
+  Frame frame = std::move(frames_.front());
+  frames_.pop_front();
+  not_full_.notify_one();
+  return frame;
+}
+
+void Pipeline::Run(std::stop_token stop_token) {
+  try {
+    /* the current generation was queued before the pipeline was started,
+     * generations are only computed once dereferenced */
+    const Evolution kGenerations(*engine_);
+    for (auto generation = std::ranges::next(kGenerations.begin());
+         !stop_token.stop_requested(); ++generation) {
+      Frame frame(*generation);
+
+      std::unique_lock lock(mutex_);
+      if (!not_full_.wait(lock, stop_token, [this] {
+            return frames_.size() < capacity_;
+          })) {
+        return;
+      }
+      frames_.push_back(std::move(frame));
+      not_empty_.notify_one();
+    }
+  } catch (...) {
+    std::scoped_lock lock(mutex_);
+    error_ = std::current_exception();
+    not_empty_.notify_one();
+  }
+}
+
+}  // namespace game
+}  // namespace gol
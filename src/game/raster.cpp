@@ -0,0 +1,179 @@
+#include "game/raster.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace game {
+
+struct SymmetryName {
+  const char* name;
+  Symmetry symmetry;
+};
+
+static constexpr SymmetryName kSymmetryNames[] = {
+    {"none", Symmetry::kNone},
+    {"horizontal", Symmetry::kHorizontal},
+    {"vertical", Symmetry::kVertical},
+    {"4-fold", Symmetry::kFourFold},
+    {"8-fold", Symmetry::kEightFold},
+};
+
+Symmetry ParseSymmetry(const std::string& name) {
+  for (const SymmetryName& symmetry : kSymmetryNames) {
+    if (name == symmetry.name) {
+      return symmetry.symmetry;
+    }
+  }
+  throw std::invalid_argument("unknown symmetry -> " + name);
+}
+
+std::vector<std::string> SymmetryNames() {
+  std::vector<std::string> names;
+  for (const SymmetryName& symmetry : kSymmetryNames) {
+    names.push_back(symmetry.name);
+  }
+  return names;
+}
+
+std::string ToString(Symmetry symmetry) {
+  for (const SymmetryName& name : kSymmetryNames) {
+    if (symmetry == name.symmetry) {
+      return name.name;
+    }
+  }
+  return "";
+}
+
+RasterCells RasterLine(RasterCell from, RasterCell to) {
+  /* Bresenham's algorithm, the error tracks the distance of the next cell
+   * from the ideal line along both axes */
+  const std::int64_t kRows = std::abs(to.row - from.row);
+  const std::int64_t kCols = std::abs(to.col - from.col);
+  const std::int64_t kRowStep = (from.row < to.row) ? 1 : -1;
+  const std::int64_t kColStep = (from.col < to.col) ? 1 : -1;
+  std::int64_t error = kCols - kRows;
+  RasterCells cells;
+  RasterCell cell = from;
+  while (true) {
+    cells.push_back(cell);
+    if ((cell.row == to.row) && (cell.col == to.col)) {
+      break;
+    }
+    const std::int64_t kDoubled = 2 * error;
+    if (kDoubled > -kRows) {
+      error -= kRows;
+      cell.col += kColStep;
+    }
+    if (kDoubled < kCols) {
+      error += kCols;
+      cell.row += kRowStep;
+    }
+  }
+  return cells;
+}
+
+RasterCells RasterRectangle(RasterCell corner, RasterCell other) {
+  const RasterCell kTopLeft = {.row = std::min(corner.row, other.row),
+                               .col = std::min(corner.col, other.col)};
+  const RasterCell kBottomRight = {.row = std::max(corner.row, other.row),
+                                   .col = std::max(corner.col, other.col)};
+  RasterCells cells;
+  for (std::int64_t j = kTopLeft.col; j <= kBottomRight.col; ++j) {
+    cells.push_back({.row = kTopLeft.row, .col = j});
+    if (kBottomRight.row != kTopLeft.row) {
+      cells.push_back({.row = kBottomRight.row, .col = j});
+    }
+  }
+  for (std::int64_t i = kTopLeft.row + 1; i < kBottomRight.row; ++i) {
+    cells.push_back({.row = i, .col = kTopLeft.col});
+    if (kBottomRight.col != kTopLeft.col) {
+      cells.push_back({.row = i, .col = kBottomRight.col});
+    }
+  }
+  return cells;
+}
+
+RasterCells RasterDisk(RasterCell center, std::int64_t radius) {
+  /* (radius + 1/2)^2 rounded down, as in circular neighborhoods */
+  const std::int64_t kLimit = (radius * radius) + radius;
+  RasterCells cells;
+  for (std::int64_t i = -radius; i <= radius; ++i) {
+    for (std::int64_t j = -radius; j <= radius; ++j) {
+      if ((i * i) + (j * j) <= kLimit) {
+        cells.push_back({.row = center.row + i, .col = center.col + j});
+      }
+    }
+  }
+  return cells;
+}
+
+RasterCells Thicken(const RasterCells& cells, std::int64_t radius) {
+  RasterCells thick;
+  for (const RasterCell& cell : cells) {
+    const RasterCells kDisk = RasterDisk(cell, radius);
+    thick.insert(thick.end(), kDisk.begin(), kDisk.end());
+  }
+  std::sort(thick.begin(), thick.end());
+  thick.erase(std::unique(thick.begin(), thick.end()), thick.end());
+  return thick;
+}
+
+RasterCells Mirror(const RasterCells& cells, Symmetry symmetry,
+                   std::size_t rows, std::size_t cols) {
+  const bool kFlipRows = (Symmetry::kNone != symmetry) &&
+                         (Symmetry::kVertical != symmetry);
+  const bool kFlipCols = (Symmetry::kNone != symmetry) &&
+                         (Symmetry::kHorizontal != symmetry);
+  const bool kTranspose = (Symmetry::kEightFold == symmetry);
+
+  /* offsets from the center are doubled to keep them whole on boards with
+   * an even side, halving rounds them down */
+  const auto kRows = static_cast<std::int64_t>(rows);
+  const auto kCols = static_cast<std::int64_t>(cols);
+  const auto kHalve = [](std::int64_t value) {
+    return (value >= 0) ? value / 2 : -((1 - value) / 2);
+  };
+  RasterCells mirrored;
+  for (const RasterCell& cell : cells) {
+    const std::int64_t kRowOffset = (2 * cell.row) - (kRows - 1);
+    const std::int64_t kColOffset = (2 * cell.col) - (kCols - 1);
+    for (int transposed = 0; transposed <= kTranspose; ++transposed) {
+      const std::int64_t kRow = (transposed) ? kColOffset : kRowOffset;
+      const std::int64_t kCol = (transposed) ? kRowOffset : kColOffset;
+      for (int row_sign = 1; row_sign >= (kFlipRows ? -1 : 1); row_sign -= 2) {
+        for (int col_sign = 1; col_sign >= (kFlipCols ? -1 : 1);
+             col_sign -= 2) {
+          mirrored.push_back({.row = kHalve((row_sign * kRow) + kRows - 1),
+                              .col = kHalve((col_sign * kCol) + kCols - 1)});
+        }
+      }
+    }
+  }
+  std::sort(mirrored.begin(), mirrored.end());
+  mirrored.erase(std::unique(mirrored.begin(), mirrored.end()),
+                 mirrored.end());
+  return mirrored;
+}
+
+void Paint(const RasterCells& cells, std::uint8_t state, Engine& board) {
+  for (const RasterCell& cell : cells) {
+    if ((cell.row < 0) || (cell.col < 0) ||
+        (static_cast<std::size_t>(cell.row) >= board.Rows()) ||
+        (static_cast<std::size_t>(cell.col) >= board.Cols())) {
+      continue;
+    }
+    board.SetState(static_cast<std::size_t>(cell.row),
+                   static_cast<std::size_t>(cell.col), state);
+  }
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,467 @@
+#include "game/rule.h"
+
+#include <algorithm>
+#include <array>
+#include <bit>
+#include <bitset>
+#include <cctype>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/neighborhood.h"
+
+namespace gol {
+namespace game {
+
+struct RulePreset {
+  const char* name;
+  const char* rulestring;
+};
+
+static constexpr std::array<RulePreset, 10> kRulePresets = {{
+    {"life", "B3/S23"},
+    {"highlife", "B36/S23"},
+    {"seeds", "B2/S"},
+    {"daynight", "B3678/S34678"},
+    {"life34", "B34/S34"},
+    {"diamoeba", "B35678/S5678"},
+    {"bosco", "R5,C0,M1,S34..58,B34..45,NM"},
+    {"majority", "R4,C0,M1,S41..81,B41..81,NM"},
+    {"waffle", "R7,C0,M1,S100..200,B75..170,NM"},
+    {"globe", "R8,C0,M0,S163..223,B74..252,NM"},
+}};
+
+/* Hensel letters of the arrangements of 1 to 4 live Moore neighbors, and one
+ * arrangement of each letter given as the cells of a 3x3 block in row major
+ * order, bit 4 being the center. Arrangements of 5 to 7 neighbors take the
+ * letter of their complement. */
+static constexpr std::array<const char*, 5> kHenselLetters = {
+    "", "ce", "ceaikn", "ceaiknjqry", "ceaiknjqrytwz"};
+static constexpr std::array<std::array<std::uint16_t, 13>, 5> kHenselBlocks = {{
+    {},
+    {1, 2},
+    {5, 10, 3, 40, 33, 68},
+    {69, 42, 11, 7, 98, 13, 14, 70, 41, 97},
+    {325, 170, 15, 45, 99, 71, 106, 102, 43, 101, 105, 78, 108},
+}};
+
+/* Return the Hensel letter of every arrangement of live Moore neighbors, '\0'
+ * for the arrangements of 0 and 8 neighbors. */
+[[nodiscard]] static const std::array<char, Rule::kNumConfigs>&
+HenselTable() {
+  static const std::array<char, Rule::kNumConfigs> kTable = [] {
+    /* neighbors in the bit order of a configuration */
+    static constexpr std::array<std::pair<int, int>, 8> kCells = {{
+        {-1, -1}, {-1, 0}, {-1, 1}, {0, -1}, {0, 1}, {1, -1}, {1, 0}, {1, 1},
+    }};
+    const auto kBit = [](int row, int col) {
+      for (std::size_t i = 0; i < kCells.size(); ++i) {
+        if ((kCells[i].first == row) && (kCells[i].second == col)) {
+          return static_cast<int>(i);
+        }
+      }
+      return 0;
+    };
+
+    std::array<char, Rule::kNumConfigs> table = {};
+    for (std::size_t count = 1; count < kHenselLetters.size(); ++count) {
+      const std::string kLetters = kHenselLetters[count];
+      for (std::size_t k = 0; k < kLetters.size(); ++k) {
+        /* the block drops its center to become a configuration, which is then
+         * rotated and reflected into the arrangements sharing its letter */
+        const std::uint16_t kBlock = kHenselBlocks[count][k];
+        const int kConfig = (kBlock & 0xf) | ((kBlock >> 5) << 4);
+        for (int symmetry = 0; symmetry < 8; ++symmetry) {
+          int image = 0;
+          for (std::size_t i = 0; i < kCells.size(); ++i) {
+            if (!(kConfig & (1 << i))) {
+              continue;
+            }
+            auto [row, col] = kCells[i];
+            for (int turn = 0; turn < symmetry % 4; ++turn) {
+              const int kRow = row;
+              row = col;
+              col = -kRow;
+            }
+            image |= 1 << kBit(row, (symmetry < 4) ? col : -col);
+          }
+          table[image] = kLetters[k];
+        }
+      }
+    }
+    for (int config = 0; config < Rule::kNumConfigs; ++config) {
+      if (std::popcount(static_cast<unsigned>(config)) > 4) {
+        table[config] = table[~config & 0xff];
+      }
+    }
+    return table;
+  }();
+  return kTable;
+}
+
+Rule::Rule() noexcept {
+  birth_.set(3);
+  survival_.set(2);
+  survival_.set(3);
+  FillConfigs();
+}
+
+void Rule::FillConfigs() noexcept {
+  /* arrangements of neighbor counts given without letters are all selected,
+   * the counts of non-totalistic rules are those with selected arrangements.
+   * A rule selecting either all or none of the arrangements of each count is
+   * totalistic, however it was written */
+  for (int config = 0; config < kNumConfigs; ++config) {
+    const int kCount = std::popcount(static_cast<unsigned>(config));
+    if (birth_[kCount]) {
+      birth_configs_.set(config);
+    }
+    if (survival_[kCount]) {
+      survival_configs_.set(config);
+    }
+  }
+  for (int config = 0; config < kNumConfigs; ++config) {
+    const int kCount = std::popcount(static_cast<unsigned>(config));
+    birth_[kCount] = birth_[kCount] || birth_configs_[config];
+    survival_[kCount] = survival_[kCount] || survival_configs_[config];
+  }
+  totalistic_ = true;
+  for (int config = 0; config < kNumConfigs; ++config) {
+    const int kCount = std::popcount(static_cast<unsigned>(config));
+    if ((birth_configs_[config] != birth_[kCount]) ||
+        (survival_configs_[config] != survival_[kCount])) {
+      totalistic_ = false;
+    }
+  }
+}
+
+Rule::Rule(const std::string& rulestring) {
+  if (std::string::npos != rulestring.find(',')) {
+    ParseHrot(rulestring);
+    return;
+  }
+
+  /* a trailing 'V' selects the von Neumann neighborhood, a trailing 'H' the
+   * hexagonal one */
+  std::string body = rulestring;
+  const char kSuffix =
+      (body.empty()) ? '\0'
+                     : std::toupper(static_cast<unsigned char>(body.back()));
+  if ('V' == kSuffix) {
+    body.pop_back();
+    neighborhood_ = CellNeighborhood(NeighborhoodShape::kVonNeumann, 1);
+  } else if ('H' == kSuffix) {
+    body.pop_back();
+    neighborhood_ = CellNeighborhood(NeighborhoodShape::kHexagonal, 1);
+  }
+
+  const std::size_t kSeparator = body.find('/');
+  if (kSeparator == std::string::npos) {
+    throw std::invalid_argument("rulestring missing '/' separator -> " +
+                                rulestring);
+  }
+
+  bool seen_birth = false;
+  bool seen_survival = false;
+  for (const std::string& component :
+       {body.substr(0, kSeparator), body.substr(kSeparator + 1)}) {
+    if (component.empty()) {
+      throw std::invalid_argument("empty rulestring component -> " +
+                                  rulestring);
+    }
+
+    NeighborSet* counts = nullptr;
+    ConfigSet* configs = nullptr;
+    const char kType = std::toupper(static_cast<unsigned char>(component[0]));
+    if (('B' == kType) && !seen_birth) {
+      counts = &birth_;
+      configs = &birth_configs_;
+      seen_birth = true;
+    } else if (('S' == kType) && !seen_survival) {
+      counts = &survival_;
+      configs = &survival_configs_;
+      seen_survival = true;
+    } else {
+      throw std::invalid_argument("invalid rulestring component -> " +
+                                  component);
+    }
+
+    std::size_t i = 1;
+    while (i < component.size()) {
+      const int kCount = component[i++] - '0';
+      if ((kCount < 0) || (kCount > neighborhood_.Size())) {
+        throw std::invalid_argument("invalid neighbor count in rulestring -> " +
+                                    rulestring);
+      }
+
+      /* letters after a count select arrangements of its neighbors, letters
+       * after a '-' leave them out */
+      const bool kExclude = (i < component.size()) && ('-' == component[i]);
+      const std::size_t kFirst = (kExclude) ? i + 1 : i;
+      i = kFirst;
+      while ((i < component.size()) &&
+             std::isalpha(static_cast<unsigned char>(component[i]))) {
+        i++;
+      }
+      if ((kFirst == i) && !kExclude) {
+        counts->set(kCount);
+        continue;
+      }
+      if ((kFirst == i) || !neighborhood_.IsMoore()) {
+        throw std::invalid_argument("invalid Hensel letters in rulestring -> " +
+                                    rulestring);
+      }
+      std::string letters = component.substr(kFirst, i - kFirst);
+      std::transform(letters.begin(), letters.end(), letters.begin(),
+                     [](char c) {
+                       return static_cast<char>(
+                           std::tolower(static_cast<unsigned char>(c)));
+                     });
+      const std::string kValid =
+          kHenselLetters[std::min(kCount, kMaxNeighbors - kCount)];
+      if (std::string::npos != letters.find_first_not_of(kValid)) {
+        throw std::invalid_argument("invalid Hensel letters in rulestring -> " +
+                                    rulestring);
+      }
+      for (int config = 0; config < kNumConfigs; ++config) {
+        if (std::popcount(static_cast<unsigned>(config)) != kCount) {
+          continue;
+        }
+        const bool kListed =
+            std::string::npos != letters.find(HenselTable()[config]);
+        if (kListed != kExclude) {
+          configs->set(config);
+        }
+      }
+    }
+  }
+  FillConfigs();
+}
+
+/* Return the integer value of digits, a component of an HROT rulestring. */
+[[nodiscard]] static int HrotNumber(const std::string& digits,
+                                    const std::string& rulestring) {
+  if (digits.empty() || (digits.size() > 3) ||
+      (std::string::npos != digits.find_first_not_of("0123456789"))) {
+    throw std::invalid_argument("invalid number in rulestring -> " +
+                                rulestring);
+  }
+  return std::stoi(digits);
+}
+
+void Rule::ParseHrot(const std::string& rulestring) {
+  std::vector<std::string> tokens;
+  std::size_t begin = 0;
+  for (std::size_t end = rulestring.find(','); std::string::npos != end;
+       end = rulestring.find(',', begin)) {
+    tokens.push_back(rulestring.substr(begin, end - begin));
+    begin = end + 1;
+  }
+  tokens.push_back(rulestring.substr(begin));
+
+  /* counts are gathered until the neighborhood they index is known, a token
+   * starting with a digit continues the count list of the last component */
+  std::vector<std::pair<int, int>> birth_ranges;
+  std::vector<std::pair<int, int>> survival_ranges;
+  std::vector<std::pair<int, int>>* ranges = nullptr;
+  int radius = 0;
+  bool include_center = false;
+  NeighborhoodShape shape = NeighborhoodShape::kMoore;
+  for (const std::string& token : tokens) {
+    if (token.empty()) {
+      throw std::invalid_argument("empty rulestring component -> " +
+                                  rulestring);
+    }
+    const char kType = std::toupper(static_cast<unsigned char>(token[0]));
+    std::string value = token;
+    if (std::isalpha(static_cast<unsigned char>(kType))) {
+      value = token.substr(1);
+      ranges = nullptr;
+    }
+
+    if ('R' == kType) {
+      radius = HrotNumber(value, rulestring);
+    } else if ('C' == kType) {
+      const int kStates = HrotNumber(value, rulestring);
+      if ((0 != kStates) && (2 != kStates)) {
+        throw std::invalid_argument(
+            "rulestring must have 0 or 2 states -> " + rulestring);
+      }
+    } else if ('M' == kType) {
+      include_center = (0 != HrotNumber(value, rulestring));
+    } else if ('N' == kType) {
+      const char kShape =
+          (value.empty()) ? '\0'
+                          : std::toupper(static_cast<unsigned char>(value[0]));
+      if (('M' == kShape) && (1 == value.size())) {
+        shape = NeighborhoodShape::kMoore;
+      } else if (('N' == kShape) && (1 == value.size())) {
+        shape = NeighborhoodShape::kVonNeumann;
+      } else if (('C' == kShape) && (1 == value.size())) {
+        shape = NeighborhoodShape::kCircular;
+      } else if (('H' == kShape) && (1 == value.size())) {
+        shape = NeighborhoodShape::kHexagonal;
+      } else {
+        throw std::invalid_argument("invalid neighborhood in rulestring -> " +
+                                    rulestring);
+      }
+      continue;
+    } else if (('B' == kType) || ('S' == kType) || !ranges) {
+      if ('B' == kType) {
+        ranges = &birth_ranges;
+      } else if ('S' == kType) {
+        ranges = &survival_ranges;
+      } else {
+        throw std::invalid_argument("invalid rulestring component -> " +
+                                    token);
+      }
+    }
+    if (!ranges || value.empty()) {
+      continue;
+    }
+
+    /* ranges are written as a-b (HROT) or a..b (Larger than Life) */
+    const std::size_t kDash = value.find('-');
+    const std::size_t kDots = value.find("..");
+    if (std::string::npos != kDash) {
+      ranges->emplace_back(HrotNumber(value.substr(0, kDash), rulestring),
+                           HrotNumber(value.substr(kDash + 1), rulestring));
+    } else if (std::string::npos != kDots) {
+      ranges->emplace_back(HrotNumber(value.substr(0, kDots), rulestring),
+                           HrotNumber(value.substr(kDots + 2), rulestring));
+    } else {
+      const int kCount = HrotNumber(value, rulestring);
+      ranges->emplace_back(kCount, kCount);
+    }
+  }
+  if (0 == radius) {
+    throw std::invalid_argument("rulestring missing radius -> " + rulestring);
+  }
+
+  neighborhood_ = CellNeighborhood(shape, radius, include_center);
+  for (auto [ranges_of, counts] :
+       {std::pair{&birth_ranges, &birth_}, {&survival_ranges, &survival_}}) {
+    for (const auto& [low, high] : *ranges_of) {
+      if ((low > high) || (high > neighborhood_.Size())) {
+        throw std::invalid_argument("invalid neighbor count in rulestring -> " +
+                                    rulestring);
+      }
+      for (int count = low; count <= high; ++count) {
+        counts->set(count);
+      }
+    }
+  }
+  FillConfigs();
+}
+
+/* Return the set counts as a comma separated list of counts and a-b
+ * ranges. */
+template <std::size_t N>
+[[nodiscard]] static std::string HrotCounts(const std::bitset<N>& counts) {
+  std::string list;
+  for (std::size_t i = 0; i < N; ++i) {
+    if (!counts[i]) {
+      continue;
+    }
+    std::size_t last = i;
+    while ((last + 1 < N) && counts[last + 1]) {
+      last++;
+    }
+    list += (list.empty()) ? "" : ",";
+    list += std::to_string(i);
+    if (last > i) {
+      list += "-" + std::to_string(last);
+    }
+    i = last;
+  }
+  return list;
+}
+
+/* Return the Hensel letters selecting the arrangements of count neighbors in
+ * configs, empty when all of them are selected. */
+[[nodiscard]] static std::string HenselSuffix(
+    const std::bitset<Rule::kNumConfigs>& configs, int count) {
+  std::string letters =
+      kHenselLetters[std::min(count, Rule::kMaxNeighbors - count)];
+  std::sort(letters.begin(), letters.end());
+  std::string listed;
+  std::string missing;
+  for (const char kLetter : letters) {
+    for (int config = 0; config < Rule::kNumConfigs; ++config) {
+      if ((std::popcount(static_cast<unsigned>(config)) == count) &&
+          (HenselTable()[config] == kLetter)) {
+        ((configs[config]) ? listed : missing) += kLetter;
+        break;
+      }
+    }
+  }
+  if (missing.empty()) {
+    return "";
+  }
+  return (missing.size() < listed.size()) ? "-" + missing : listed;
+}
+
+std::string Rule::ToString() const {
+  const bool kVonNeumann =
+      neighborhood_ == CellNeighborhood(NeighborhoodShape::kVonNeumann, 1);
+  const bool kHexagonal =
+      neighborhood_ == CellNeighborhood(NeighborhoodShape::kHexagonal, 1);
+  if (!neighborhood_.IsMoore() && !kVonNeumann && !kHexagonal) {
+    const char kShapes[] = {'M', 'N', 'C', 'H'};
+    return "R" + std::to_string(neighborhood_.Radius()) + ",C0,M" +
+           ((neighborhood_.IncludesCenter()) ? "1" : "0") + ",S" +
+           HrotCounts(survival_) + ",B" + HrotCounts(birth_) + ",N" +
+           kShapes[static_cast<int>(neighborhood_.Shape())];
+  }
+
+  std::string rulestring = "B";
+  for (int i = 0; i <= kMaxNeighbors; ++i) {
+    if (birth_[i]) {
+      rulestring += std::to_string(i) + HenselSuffix(birth_configs_, i);
+    }
+  }
+  rulestring += "/S";
+  for (int i = 0; i <= kMaxNeighbors; ++i) {
+    if (survival_[i]) {
+      rulestring += std::to_string(i) + HenselSuffix(survival_configs_, i);
+    }
+  }
+  if (kVonNeumann) {
+    rulestring += "V";
+  } else if (kHexagonal) {
+    rulestring += "H";
+  }
+  return rulestring;
+}
+
+Rule ParseRule(const std::string& name) {
+  std::string lower = name;
+  std::transform(lower.begin(), lower.end(), lower.begin(), [](char c) {
+    return static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  });
+  for (const RulePreset& preset : kRulePresets) {
+    if (lower == preset.name) {
+      return Rule(preset.rulestring);
+    }
+  }
+  if ((std::string::npos == name.find('/')) &&
+      (std::string::npos == name.find(','))) {
+    throw std::invalid_argument("unknown rule -> " + name);
+  }
+  return Rule(name);
+}
+
+std::vector<std::string> RulePresetNames() {
+  std::vector<std::string> names;
+  for (const RulePreset& preset : kRulePresets) {
+    names.push_back(preset.name);
+  }
+  return names;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,597 @@
+#include "game/rule_table.h"
+
+#include <algorithm>
+#include <array>
+#include <bit>
+#include <bitset>
+#include <cctype>
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <istream>
+#include <map>
+#include <memory>
+#include <set>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+
+namespace gol {
+namespace game {
+
+struct TableNeighborhood {
+  const char* name;
+  std::array<int, Automaton::kNumNeighbors> ring;
+  int size;
+};
+
+/* the neighbors of each neighborhood clockwise from the north, in the order
+ * transitions list them */
+static constexpr std::array<TableNeighborhood, 3> kTableNeighborhoods = {{
+    {"moore",
+     {Automaton::kNorth, Automaton::kNorthEast, Automaton::kEast,
+      Automaton::kSouthEast, Automaton::kSouth, Automaton::kSouthWest,
+      Automaton::kWest, Automaton::kNorthWest},
+     8},
+    {"vonneumann",
+     {Automaton::kNorth, Automaton::kEast, Automaton::kSouth, Automaton::kWest},
+     4},
+    {"hexagonal",
+     {Automaton::kNorth, Automaton::kEast, Automaton::kSouthEast,
+      Automaton::kSouth, Automaton::kWest, Automaton::kNorthWest},
+     6},
+}};
+
+/* the inputs of trees over 8 and 4 neighbors from the root to the leaves */
+static constexpr std::array<int, 9> kMooreTreeOrder = {
+    Automaton::kNorthWest, Automaton::kNorthEast, Automaton::kSouthWest,
+    Automaton::kSouthEast, Automaton::kNorth,     Automaton::kWest,
+    Automaton::kEast,      Automaton::kSouth,     Automaton::kNumNeighbors,
+};
+static constexpr std::array<int, 5> kVonNeumannTreeOrder = {
+    Automaton::kNorth, Automaton::kWest, Automaton::kEast, Automaton::kSouth,
+    Automaton::kNumNeighbors,
+};
+
+using StateSet = std::bitset<256>;
+
+[[nodiscard]] static std::invalid_argument Error(int line,
+                                                 const std::string& reason) {
+  return std::invalid_argument("rule file line " + std::to_string(line) +
+                               ": " + reason);
+}
+
+[[nodiscard]] static std::string Trim(const std::string& text) {
+  const std::size_t kFirst = text.find_first_not_of(" \t\r");
+  if (std::string::npos == kFirst) {
+    return "";
+  }
+  return text.substr(kFirst, text.find_last_not_of(" \t\r") - kFirst + 1);
+}
+
+[[nodiscard]] static std::string Lower(std::string text) {
+  std::transform(text.begin(), text.end(), text.begin(), [](char c) {
+    return static_cast<char>(std::tolower(static_cast<unsigned char>(c)));
+  });
+  return text;
+}
+
+/* Return the integer text holds or -1 if it is not a number. */
+[[nodiscard]] static int ToNumber(const std::string& text) {
+  if (text.empty() || (text.size() > 6) ||
+      (text.find_first_not_of("0123456789") != std::string::npos)) {
+    return -1;
+  }
+  return std::stoi(text);
+}
+
+/* Split text at each separator, trimming the parts. */
+[[nodiscard]] static std::vector<std::string> Split(const std::string& text,
+                                                    char separator) {
+  std::vector<std::string> parts;
+  std::size_t begin = 0;
+  for (std::size_t end = text.find(separator); std::string::npos != end;
+       end = text.find(separator, begin)) {
+    parts.push_back(Trim(text.substr(begin, end - begin)));
+    begin = end + 1;
+  }
+  parts.push_back(Trim(text.substr(begin)));
+  return parts;
+}
+
+struct TableSymmetries {
+  int rotations = 1;    /**< Evenly spaced rotations of the neighbors. */
+  bool mirror = false;  /**< Each rotation is also mirrored. */
+  bool permute = false; /**< Any order of the neighbors. */
+};
+
+/* Return the symmetries named name, e.g., 'rotate4reflect', of a
+ * neighborhood of size cells. */
+[[nodiscard]] static TableSymmetries ParseSymmetries(const std::string& name,
+                                                     int size,
+                                                     int line_number) {
+  TableSymmetries symmetries;
+  std::string rotation = name;
+  if ("permute" == name) {
+    symmetries.permute = true;
+    return symmetries;
+  } else if ("reflect_horizontal" == name) {
+    symmetries.mirror = true;
+    return symmetries;
+  } else if (name.ends_with("reflect")) {
+    symmetries.mirror = true;
+    rotation.resize(name.size() - 7);
+  }
+  if (rotation.starts_with("rotate")) {
+    symmetries.rotations = ToNumber(rotation.substr(6));
+  } else if ("none" != name) {
+    symmetries.rotations = -1;
+  }
+  if ((symmetries.rotations < 1) || (size % symmetries.rotations)) {
+    throw Error(line_number, "unsupported symmetries -> " + name);
+  }
+  return symmetries;
+}
+
+[[nodiscard]] static std::vector<int> Numbers(const std::string& line,
+                                              int line_number) {
+  std::istringstream stream(line);
+  std::vector<int> numbers;
+  std::string word;
+  while (stream >> word) {
+    const int kNumber = ToNumber(word);
+    if (kNumber < 0) {
+      throw Error(line_number, "expected a number -> " + word);
+    }
+    numbers.push_back(kNumber);
+  }
+  return numbers;
+}
+
+RuleTable::RuleTable(std::istream& input) {
+  /* comments run from '#' to the end of the line */
+  std::vector<std::string> lines;
+  for (std::string line; std::getline(input, line);) {
+    lines.push_back(Trim(line.substr(0, line.find('#'))));
+  }
+
+  std::map<std::string, std::pair<std::vector<std::string>, int>> sections;
+  std::string section;
+  for (int i = 0; i < static_cast<int>(lines.size()); ++i) {
+    if (lines[i].starts_with('@')) {
+      const std::size_t kEnd = lines[i].find_first_of(" \t");
+      section = lines[i].substr(0, kEnd);
+      if (sections.contains(section)) {
+        throw Error(i + 1, "repeated section -> " + section);
+      }
+      sections[section] = {{}, i + 2};
+      if ("@RULE" == section) {
+        name_ = Trim(lines[i].substr(section.size()));
+      }
+    } else if (!section.empty()) {
+      sections[section].first.push_back(lines[i]);
+    } else if (!lines[i].empty()) {
+      throw Error(i + 1, "rule files must start with @RULE");
+    }
+  }
+  if (name_.empty()) {
+    throw std::invalid_argument("rule file does not name its rule");
+  }
+
+  const bool kTable = sections.contains("@TABLE");
+  const bool kTree = sections.contains("@TREE");
+  if (kTable == kTree) {
+    throw std::invalid_argument(
+        "rule file must hold either a @TABLE or a @TREE section");
+  } else if (kTable) {
+    ParseTable(sections["@TABLE"].first, sections["@TABLE"].second);
+  } else {
+    ParseTree(sections["@TREE"].first, sections["@TREE"].second);
+  }
+
+  /* the default colors fade from red to yellow */
+  const int kLive = num_states_ - 1;
+  for (int i = 0; i < kLive; ++i) {
+    const int kGreen = (kLive > 1) ? (255 * i) / (kLive - 1) : 0;
+    colors_.push_back(0xff0000 | (static_cast<std::uint32_t>(kGreen) << 8));
+  }
+  if (sections.contains("@COLORS")) {
+    ParseColors(sections["@COLORS"].first, sections["@COLORS"].second);
+  }
+}
+
+void RuleTable::ParseTable(const std::vector<std::string>& lines,
+                           int first_line) {
+  const TableNeighborhood* neighborhood = &kTableNeighborhoods[0];
+  std::string symmetries = "none";
+  std::map<std::string, StateSet> variables;
+  bool has_states = false;
+
+  /* transitions refer to interned sets of states, the first set matching
+   * any state stands for the neighbors outside of the neighborhood */
+  std::vector<StateSet> sets = {StateSet().set()};
+  const auto kIntern = [&sets](const StateSet& set) {
+    const auto kFound = std::find(sets.begin(), sets.end(), set);
+    if (sets.end() != kFound) {
+      return static_cast<int>(kFound - sets.begin());
+    }
+    sets.push_back(set);
+    return static_cast<int>(sets.size() - 1);
+  };
+  std::vector<std::array<int, kNumInputs>> transitions;
+
+  for (int i = 0; i < static_cast<int>(lines.size()); ++i) {
+    const std::string& kLine = lines[i];
+    const int kLineNumber = first_line + i;
+    if (kLine.empty()) {
+      continue;
+    }
+
+    const std::size_t kColon = kLine.find(':');
+    if (!kLine.starts_with("var ") && (std::string::npos != kColon)) {
+      const std::string kKey = Lower(Trim(kLine.substr(0, kColon)));
+      const std::string kValue = Trim(kLine.substr(kColon + 1));
+      if ("n_states" == kKey) {
+        const int kStates = ToNumber(kValue);
+        if ((kStates < 2) || (kStates > 255)) {
+          throw Error(kLineNumber, "n_states must lie in [2, 255]");
+        }
+        num_states_ = static_cast<std::uint8_t>(kStates);
+        has_states = true;
+      } else if ("neighborhood" == kKey) {
+        const auto kFound = std::find_if(
+            kTableNeighborhoods.begin(), kTableNeighborhoods.end(),
+            [&kValue](const TableNeighborhood& candidate) {
+              return Lower(kValue) == candidate.name;
+            });
+        if (kTableNeighborhoods.end() == kFound) {
+          throw Error(kLineNumber, "unsupported neighborhood -> " + kValue);
+        }
+        neighborhood = &*kFound;
+      } else if ("symmetries" == kKey) {
+        symmetries = Lower(kValue);
+      } else {
+        throw Error(kLineNumber, "unknown key -> " + kKey);
+      }
+      continue;
+    }
+    if (!has_states) {
+      throw Error(kLineNumber, "n_states must precede the transitions");
+    }
+
+    /* a state set stands for a state number or a variable */
+    const auto kStates = [&](const std::string& token) {
+      const int kNumber = ToNumber(token);
+      if ((kNumber >= 0) && (kNumber < num_states_)) {
+        return StateSet().set(kNumber);
+      } else if (kNumber >= 0) {
+        throw Error(kLineNumber, "state out of range -> " + token);
+      } else if (!variables.contains(token)) {
+        throw Error(kLineNumber, "unknown variable -> " + token);
+      }
+      return variables[token];
+    };
+
+    if (kLine.starts_with("var ")) {
+      const std::size_t kEquals = kLine.find('=');
+      const std::string kName = Trim(kLine.substr(4, kEquals - 4));
+      const std::string kValues =
+          (std::string::npos == kEquals) ? "" : Trim(kLine.substr(kEquals + 1));
+      if (kName.empty() || (ToNumber(kName) >= 0) || (kValues.size() < 2) ||
+          !kValues.starts_with('{') || !kValues.ends_with('}')) {
+        throw Error(kLineNumber, "variables are declared as var a={0,1}");
+      }
+      StateSet states;
+      for (const std::string& value :
+           Split(kValues.substr(1, kValues.size() - 2), ',')) {
+        states |= kStates(value);
+      }
+      variables[kName] = states;
+      continue;
+    }
+
+    /* commas may be left out when every state is a single character */
+    std::vector<std::string> tokens;
+    if (std::string::npos != kLine.find(',')) {
+      tokens = Split(kLine, ',');
+    } else {
+      for (char c : kLine) {
+        if (!std::isspace(static_cast<unsigned char>(c))) {
+          tokens.emplace_back(1, c);
+        }
+      }
+    }
+    const int kSize = neighborhood->size;
+    const TableSymmetries kSymmetries =
+        ParseSymmetries(symmetries, kSize, kLineNumber);
+    if (static_cast<int>(tokens.size()) != kSize + 2) {
+      throw Error(kLineNumber, "transitions of the neighborhood list " +
+                                   std::to_string(kSize + 2) + " states");
+    }
+
+    /* variables appearing more than once or as the output are bound, each of
+     * their states makes a transition of its own */
+    std::vector<std::string> bound;
+    for (std::size_t j = 0; j < tokens.size(); ++j) {
+      const bool kRepeated =
+          std::count(tokens.begin(), tokens.end() - 1, tokens[j]) > 1;
+      const bool kOutput = (tokens.size() - 1 == j);
+      if ((ToNumber(tokens[j]) < 0) && (kRepeated || kOutput) &&
+          (std::find(bound.begin(), bound.end(), tokens[j]) == bound.end())) {
+        if (kOutput && (std::find(tokens.begin(), tokens.end() - 1,
+                                  tokens[j]) == tokens.end() - 1)) {
+          throw Error(kLineNumber, "output variable is not an input -> " +
+                                       tokens[j]);
+        }
+        kStates(tokens[j]);
+        bound.push_back(tokens[j]);
+      }
+    }
+    if (ToNumber(tokens.back()) >= 0) {
+      kStates(tokens.back());
+    }
+
+    std::vector<std::vector<int>> bound_states;
+    for (const std::string& name : bound) {
+      bound_states.emplace_back();
+      for (int state = 0; state < num_states_; ++state) {
+        if (variables[name][state]) {
+          bound_states.back().push_back(state);
+        }
+      }
+    }
+    std::vector<std::size_t> choice(bound.size(), 0);
+    do {
+      const auto kResolve = [&](const std::string& token) {
+        const auto kBound = std::find(bound.begin(), bound.end(), token);
+        if (bound.end() == kBound) {
+          return kStates(token);
+        }
+        const std::size_t kIndex = kBound - bound.begin();
+        return StateSet().set(bound_states[kIndex][choice[kIndex]]);
+      };
+      std::vector<int> ring;
+      for (int j = 0; j < kSize; ++j) {
+        ring.push_back(kIntern(kResolve(tokens[j + 1])));
+      }
+      const int kCenterSet = kIntern(kResolve(tokens[0]));
+      const StateSet kOutput = kResolve(tokens.back());
+      int output = 0;
+      while (!kOutput[output]) {
+        output++;
+      }
+
+      /* the symmetries turn each transition into the arrangements of its
+       * neighbors that are rotations or reflections of the given one */
+      std::set<std::vector<int>> arrangements;
+      if (kSymmetries.permute) {
+        std::vector<int> sorted = ring;
+        std::sort(sorted.begin(), sorted.end());
+        do {
+          arrangements.insert(sorted);
+        } while (std::next_permutation(sorted.begin(), sorted.end()));
+      }
+      const int kStep = kSize / kSymmetries.rotations;
+      for (int r = 0; (r < kSymmetries.rotations) && !kSymmetries.permute;
+           ++r) {
+        for (int mirror = 0; mirror <= ((kSymmetries.mirror) ? 1 : 0);
+             ++mirror) {
+          std::vector<int> arrangement(kSize);
+          for (int j = 0; j < kSize; ++j) {
+            const int kFrom = (mirror) ? (kSize - j) % kSize : j;
+            arrangement[j] = ring[(kFrom + (r * kStep)) % kSize];
+          }
+          arrangements.insert(arrangement);
+        }
+      }
+      for (const std::vector<int>& arrangement : arrangements) {
+        std::array<int, kNumInputs> transition{};
+        transition[kCenter] = kCenterSet;
+        for (int j = 0; j < kSize; ++j) {
+          transition[neighborhood->ring[j]] = arrangement[j];
+        }
+        transitions.push_back(transition);
+        outputs_.push_back(static_cast<std::uint8_t>(output));
+      }
+
+      /* advance to the next combination of states of the bound variables */
+      std::size_t k = 0;
+      while ((k < choice.size()) &&
+             (++choice[k] == bound_states[k].size())) {
+        choice[k++] = 0;
+      }
+      if (k == choice.size()) {
+        break;
+      }
+    } while (true);
+  }
+  if (!has_states) {
+    throw std::invalid_argument("rule table does not set n_states");
+  }
+
+  /* bit t of the mask of an input and state is set if transition t accepts
+   * that state for that input */
+  words_per_mask_ = (transitions.size() + 63) / 64;
+  masks_.assign(kNumInputs * num_states_ * words_per_mask_, 0);
+  for (std::size_t t = 0; t < transitions.size(); ++t) {
+    for (int input = 0; input < kNumInputs; ++input) {
+      const StateSet& kSet = sets[transitions[t][input]];
+      for (int state = 0; state < num_states_; ++state) {
+        if (kSet[state]) {
+          masks_[(((input * num_states_) + state) * words_per_mask_) +
+                 (t / 64)] |= Word{1} << (t % 64);
+        }
+      }
+    }
+  }
+}
+
+void RuleTable::ParseTree(const std::vector<std::string>& lines,
+                          int first_line) {
+  int num_neighbors = 0;
+  int num_nodes = -1;
+  int states = 0;
+  std::vector<int> depths;
+  for (int i = 0; i < static_cast<int>(lines.size()); ++i) {
+    const std::string& kLine = lines[i];
+    const int kLineNumber = first_line + i;
+    const std::size_t kEquals = kLine.find('=');
+    if (kLine.empty()) {
+      continue;
+    } else if (std::string::npos != kEquals) {
+      const std::string kKey = Trim(kLine.substr(0, kEquals));
+      const int kValue = ToNumber(Trim(kLine.substr(kEquals + 1)));
+      if ("num_states" == kKey) {
+        if ((kValue < 2) || (kValue > 255)) {
+          throw Error(kLineNumber, "num_states must lie in [2, 255]");
+        }
+        states = kValue;
+        num_states_ = static_cast<std::uint8_t>(kValue);
+      } else if ("num_neighbors" == kKey) {
+        if ((4 != kValue) && (8 != kValue)) {
+          throw Error(kLineNumber, "num_neighbors must be 4 or 8");
+        }
+        num_neighbors = kValue;
+      } else if ("num_nodes" == kKey) {
+        num_nodes = kValue;
+      } else {
+        throw Error(kLineNumber, "unknown key -> " + kKey);
+      }
+      continue;
+    } else if (!states || !num_neighbors || (num_nodes < 0)) {
+      throw Error(kLineNumber,
+                  "num_states, num_neighbors and num_nodes must precede the "
+                  "nodes");
+    }
+
+    /* children of nodes at depth 1 are states, others are nodes one level
+     * closer to the leaves, given by their offset in the tree */
+    const std::vector<int> kNode = Numbers(kLine, kLineNumber);
+    if (static_cast<int>(kNode.size()) != states + 1) {
+      throw Error(kLineNumber, "nodes list their depth and " +
+                                   std::to_string(states) + " children");
+    }
+    const int kDepth = kNode[0];
+    if ((kDepth < 1) || (kDepth > num_neighbors + 1)) {
+      throw Error(kLineNumber, "node depth out of range");
+    }
+    for (int j = 1; j <= states; ++j) {
+      if ((1 == kDepth) && (kNode[j] >= states)) {
+        throw Error(kLineNumber, "state out of range");
+      } else if ((1 < kDepth) &&
+                 ((kNode[j] >= static_cast<int>(depths.size())) ||
+                  (depths[kNode[j]] != kDepth - 1))) {
+        throw Error(kLineNumber, "children must be earlier nodes one level "
+                                 "deeper");
+      }
+      tree_.push_back(static_cast<std::uint32_t>(
+          (1 == kDepth) ? kNode[j] : kNode[j] * states));
+    }
+    depths.push_back(kDepth);
+  }
+  if (!states || depths.empty() ||
+      (static_cast<int>(depths.size()) != num_nodes) ||
+      (depths.back() != num_neighbors + 1)) {
+    throw std::invalid_argument(
+        "rule tree must end with its root after num_nodes nodes");
+  }
+
+  tree_root_ = static_cast<std::uint32_t>(tree_.size() - states);
+  if (8 == num_neighbors) {
+    tree_order_.assign(kMooreTreeOrder.begin(), kMooreTreeOrder.end());
+  } else {
+    tree_order_.assign(kVonNeumannTreeOrder.begin(),
+                       kVonNeumannTreeOrder.end());
+  }
+}
+
+void RuleTable::ParseColors(const std::vector<std::string>& lines,
+                            int first_line) {
+  const auto kColor = [](int red, int green, int blue) {
+    return static_cast<std::uint32_t>((red << 16) | (green << 8) | blue);
+  };
+  for (int i = 0; i < static_cast<int>(lines.size()); ++i) {
+    const int kLineNumber = first_line + i;
+    const std::vector<int> kNumbers = Numbers(lines[i], kLineNumber);
+    if (kNumbers.empty()) {
+      continue;
+    } else if (std::any_of(kNumbers.begin() + 1, kNumbers.end(),
+                           [](int value) { return value > 255; })) {
+      throw Error(kLineNumber, "color components must lie in [0, 255]");
+    } else if (6 == kNumbers.size()) {
+      const int kLive = static_cast<int>(colors_.size());
+      for (int state = 0; state < kLive; ++state) {
+        std::array<int, 3> channels{};
+        for (int c = 0; c < 3; ++c) {
+          const int kRange = kNumbers[c + 3] - kNumbers[c];
+          channels[c] = kNumbers[c] +
+                        ((kLive > 1) ? (kRange * state) / (kLive - 1) : 0);
+        }
+        colors_[state] = kColor(channels[0], channels[1], channels[2]);
+      }
+    } else if (4 == kNumbers.size()) {
+      /* the color of state 0 is the background of the board */
+      if ((kNumbers[0] > 0) && (kNumbers[0] < num_states_)) {
+        colors_[kNumbers[0] - 1] =
+            kColor(kNumbers[1], kNumbers[2], kNumbers[3]);
+      }
+    } else {
+      throw Error(kLineNumber, "colors are given as 'state red green blue'");
+    }
+  }
+}
+
+std::uint8_t RuleTable::NextByTable(
+    const std::uint8_t* inputs) const noexcept {
+  /* the first transition accepting all inputs applies */
+  for (std::size_t w = 0; w < words_per_mask_; ++w) {
+    Word matches = ~Word{0};
+    for (int input = 0; (input < kNumInputs) && matches; ++input) {
+      matches &= masks_[(((input * num_states_) + inputs[input]) *
+                         words_per_mask_) +
+                        w];
+    }
+    if (matches) {
+      return outputs_[(w * 64) + std::countr_zero(matches)];
+    }
+  }
+  return inputs[kCenter];
+}
+
+std::uint8_t RuleTable::NextByTree(const std::uint8_t* inputs) const noexcept {
+  std::uint32_t node = tree_root_;
+  for (int input : tree_order_) {
+    node = tree_[node + inputs[input]];
+  }
+  return static_cast<std::uint8_t>(node);
+}
+
+std::uint8_t RuleTable::Next(std::uint8_t state,
+                             const Neighborhood& neighbors) const noexcept {
+  std::array<std::uint8_t, kNumInputs> inputs{};
+  std::copy(neighbors.begin(), neighbors.end(), inputs.begin());
+  inputs[kCenter] = state;
+  const auto kInvalid = [this](std::uint8_t input) {
+    return input >= num_states_;
+  };
+  if (std::any_of(inputs.begin(), inputs.end(), kInvalid)) {
+    return state;
+  }
+  return (tree_.empty()) ? NextByTable(inputs.data())
+                         : NextByTree(inputs.data());
+}
+
+std::shared_ptr<const RuleTable> LoadRuleTable(const std::string& filename) {
+  std::ifstream fhandle(filename);
+  if (!fhandle) {
+    throw std::invalid_argument("invalid file path -> " + filename);
+  }
+  return std::make_shared<RuleTable>(fhandle);
+}
+
+}  // namespace game
+}  // namespace gol
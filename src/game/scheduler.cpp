@@ -0,0 +1,61 @@
+#include "game/scheduler.h"
+
+#include <chrono>
+
+namespace gol {
+namespace game {
+
+/* weight of the latest frame in the average time between frames */
+static constexpr double kSmoothing = 0.1;
+
+/* fraction of the target rate below which the scheduler falls behind */
+static constexpr double kTolerance = 0.9;
+
+FrameScheduler::FrameScheduler(std::chrono::milliseconds interval,
+                               Clock::time_point now)
+    : interval_(interval), due_(now + interval_), last_(now) {}
+
+void FrameScheduler::SetInterval(std::chrono::milliseconds interval) noexcept {
+  due_ += interval - interval_;
+  interval_ = interval;
+  average_s_ = 0.0;
+}
+
+std::chrono::milliseconds FrameScheduler::TimeLeft(
+    Clock::time_point now) const noexcept {
+  if (Due(now)) {
+    return std::chrono::milliseconds(0);
+  }
+  return std::chrono::ceil<std::chrono::milliseconds>(due_ - now);
+}
+
+void FrameScheduler::Advance(Clock::time_point now) noexcept {
+  const double kElapsedS = std::chrono::duration<double>(now - last_).count();
+  average_s_ = (average_s_ > 0.0)
+                   ? average_s_ + (kSmoothing * (kElapsedS - average_s_))
+                   : kElapsedS;
+  last_ = now;
+
+  due_ += interval_;
+  if (due_ <= now) {
+    due_ = now + interval_;
+  }
+}
+
+void FrameScheduler::Reset(Clock::time_point now) noexcept {
+  due_ = now + interval_;
+  last_ = now;
+  average_s_ = 0.0;
+}
+
+double FrameScheduler::Rate() const noexcept {
+  return (average_s_ > 0.0) ? 1.0 / average_s_ : 0.0;
+}
+
+bool FrameScheduler::Behind() const noexcept {
+  const double kIntervalS = std::chrono::duration<double>(interval_).count();
+  return (average_s_ > 0.0) && (kIntervalS < kTolerance * average_s_);
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,93 @@
+#include "game/sparse.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <functional>
+#include <stdexcept>
+#include <vector>
+
+#include "game/hash.h"
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+std::size_t SparseBoard::CellHash::operator()(
+    const Cell& cell) const noexcept {
+  return static_cast<std::size_t>(
+      HashCombine(std::hash<std::int64_t>{}(cell.row),
+                  std::hash<std::int64_t>{}(cell.col)));
+}
+
+SparseBoard::SparseBoard(std::size_t num_rows, std::size_t num_cols,
+                         const Rule& rule)
+    : rows_(num_rows), cols_(num_cols), rule_(rule) {
+  if (rule_.Born(0)) {
+    throw std::invalid_argument(
+        "the sparse engine does not support B0 rules -> " + rule_.ToString());
+  }
+}
+
+bool SparseBoard::IsAlive(std::size_t row, std::size_t col) const noexcept {
+  return live_.contains({.row = static_cast<std::int64_t>(row),
+                         .col = static_cast<std::int64_t>(col)});
+}
+
+std::vector<LiveCell> SparseBoard::LiveCells() const {
+  std::vector<LiveCell> cells;
+  cells.reserve(live_.size());
+  for (const Cell& cell : live_) {
+    cells.push_back({.row = cell.row, .col = cell.col, .state = 1});
+  }
+  std::sort(cells.begin(), cells.end());
+  return cells;
+}
+
+void SparseBoard::SetCell(std::size_t row, std::size_t col, bool alive) {
+  const Cell kCell = {.row = static_cast<std::int64_t>(row),
+                      .col = static_cast<std::int64_t>(col)};
+  if (alive) {
+    live_.insert(kCell);
+  } else {
+    live_.erase(kCell);
+  }
+}
+
+void SparseBoard::Tick() {
+  /* only cells adjacent to a live cell can have a nonzero neighbor count */
+  counts_.clear();
+  for (const Cell& cell : live_) {
+    for (std::int64_t i = -1; i <= 1; ++i) {
+      for (std::int64_t j = -1; j <= 1; ++j) {
+        if ((0 != i) || (0 != j)) {
+          ++counts_[{.row = cell.row + i, .col = cell.col + j}];
+        }
+      }
+    }
+  }
+
+  next_.clear();
+  for (const auto& [kCell, kCount] : counts_) {
+    const bool kAlive = live_.contains(kCell);
+    if ((kAlive && rule_.Survives(kCount)) ||
+        (!kAlive && rule_.Born(kCount))) {
+      next_.insert(kCell);
+    }
+  }
+
+  /* isolated live cells have no entry in the neighbor tallies */
+  if (rule_.Survives(0)) {
+    for (const Cell& cell : live_) {
+      if (!counts_.contains(cell)) {
+        next_.insert(cell);
+      }
+    }
+  }
+
+  live_.swap(next_);
+  generation_++;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,74 @@
+#include "game/stats.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/pipeline.h"
+
+namespace gol {
+namespace game {
+
+StepStats Step(Engine& engine) {
+  const Frame kBefore(engine);
+  engine.Tick();
+  return CompareFrames(kBefore, Frame(engine));
+}
+
+/* Return the number of cells of lhs at positions without a cell of rhs, both
+ * listed in row major order. */
+[[nodiscard]] static std::uint64_t CountNotIn(
+    const std::vector<LiveCell>& lhs,
+    const std::vector<LiveCell>& rhs) noexcept {
+  std::uint64_t count = 0;
+  std::size_t j = 0;
+  for (const LiveCell& cell : lhs) {
+    while ((j < rhs.size()) &&
+           ((rhs[j].row < cell.row) ||
+            ((rhs[j].row == cell.row) && (rhs[j].col < cell.col)))) {
+      j++;
+    }
+    if ((j == rhs.size()) || (rhs[j].row != cell.row) ||
+        (rhs[j].col != cell.col)) {
+      count++;
+    }
+  }
+  return count;
+}
+
+StepStats CompareFrames(const Frame& before, const Frame& after) noexcept {
+  /* the cells beyond the window count like those within it */
+  return {.generation = after.generation,
+          .population = after.population,
+          .births = after.cells.CountAliveNotIn(before.cells) +
+                    CountNotIn(after.outside, before.outside),
+          .deaths = before.cells.CountAliveNotIn(after.cells) +
+                    CountNotIn(before.outside, after.outside)};
+}
+
+void StatsHistory::Push(const StepStats& stats) {
+  if (0 == capacity_) {
+    return;
+  }
+  if (steps_.size() == capacity_) {
+    steps_.pop_front();
+  }
+  steps_.push_back(stats);
+}
+
+StepStats StatsHistory::Totals() const noexcept {
+  StepStats totals;
+  for (const StepStats& step : steps_) {
+    totals.births += step.births;
+    totals.deaths += step.deaths;
+  }
+  if (!steps_.empty()) {
+    totals.generation = steps_.back().generation;
+    totals.population = steps_.back().population;
+  }
+  return totals;
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,50 @@
+#include "game/swar.h"
+
+#include <array>
+#include <cstdint>
+
+#include "game/rule.h"
+
+namespace gol {
+namespace game {
+
+std::uint64_t NextCells(
+    const std::array<std::uint64_t, Rule::kMaxNeighbors>& neighbors,
+    std::uint64_t alive, const Rule& rule) noexcept {
+  /* count_bits[k] holds bit k of the neighbor count of every cell */
+  std::uint64_t count_bits[4] = {0, 0, 0, 0};
+  for (std::uint64_t neighbor : neighbors) {
+    std::uint64_t carry = neighbor;
+    for (std::uint64_t& count_bit : count_bits) {
+      const std::uint64_t kNextCarry = count_bit & carry;
+      count_bit ^= carry;
+      carry = kNextCarry;
+    }
+  }
+
+  std::uint64_t born = 0;
+  std::uint64_t survives = 0;
+  for (int n = 0; n <= Rule::kMaxNeighbors; ++n) {
+    if (!rule.Born(n) && !rule.Survives(n)) {
+      continue;
+    }
+
+    /* select the cells whose neighbor count equals n */
+    std::uint64_t matches = ~std::uint64_t{0};
+    for (int k = 0; k < 4; ++k) {
+      matches &= ((n >> k) & 1) ? count_bits[k] : ~count_bits[k];
+    }
+    if (rule.Born(n)) {
+      born |= matches;
+    }
+    if (rule.Survives(n)) {
+      survives |= matches;
+    }
+  }
+
+  /* life by reproduction, death by under/overpopulation */
+  return (~alive & born) | (alive & survives);
+}
+
+}  // namespace game
+}  // namespace gol
@@ -0,0 +1,100 @@
+#include "game/undo.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+
+#include "game/engine.h"
+#include "game/raster.h"
+
+namespace gol {
+namespace game {
+
+void UndoStack::Save(const Engine& board, const RasterCells& cells) {
+  for (const RasterCell& cell : cells) {
+    if ((cell.row >= 0) && (cell.col >= 0) &&
+        (static_cast<std::size_t>(cell.row) < board.Rows()) &&
+        (static_cast<std::size_t>(cell.col) < board.Cols())) {
+      SaveCell(board, static_cast<std::size_t>(cell.row),
+               static_cast<std::size_t>(cell.col));
+    }
+  }
+}
+
+void UndoStack::SaveAll(const Engine& board) {
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    for (std::size_t j = 0; j < board.Cols(); ++j) {
+      SaveCell(board, i, j);
+    }
+  }
+}
+
+bool UndoStack::Undo(Engine& board) {
+  if (undo_.empty()) {
+    return false;
+  }
+  /* a cell saved twice by an edit is restored to the state saved first */
+  Edit edit = std::move(undo_.back());
+  undo_.pop_back();
+  for (auto cell = edit.rbegin(); cell != edit.rend(); ++cell) {
+    const std::uint8_t kState = board.State(cell->row, cell->col);
+    board.SetState(cell->row, cell->col, cell->state);
+    cell->state = kState;
+  }
+  redo_.push_back(std::move(edit));
+  Begin();
+  return true;
+}
+
+bool UndoStack::Redo(Engine& board) {
+  if (redo_.empty()) {
+    return false;
+  }
+  Edit edit = std::move(redo_.back());
+  redo_.pop_back();
+  for (SavedCell& cell : edit) {
+    const std::uint8_t kState = board.State(cell.row, cell.col);
+    board.SetState(cell.row, cell.col, cell.state);
+    cell.state = kState;
+  }
+  undo_.push_back(std::move(edit));
+  Begin();
+  return true;
+}
+
+void UndoStack::Clear() noexcept {
+  cells_ = 0;
+  open_ = false;
+  undo_.clear();
+  redo_.clear();
+}
+
+void UndoStack::SaveCell(const Engine& board, std::size_t row,
+                         std::size_t col) {
+  if (overflow_) {
+    return;
+  }
+  if (!open_) {
+    for (const Edit& edit : redo_) {
+      cells_ -= edit.size();
+    }
+    redo_.clear();
+    undo_.emplace_back();
+    open_ = true;
+  }
+  undo_.back().push_back(
+      {.row = row, .col = col, .state = board.State(row, col)});
+  cells_++;
+
+  while ((cells_ > cap_cells_) && (undo_.size() > 1)) {
+    cells_ -= undo_.front().size();
+    undo_.pop_front();
+  }
+  if (cells_ > cap_cells_) {
+    Clear();
+    overflow_ = true;
+  }
+}
+
+}  // namespace game
+}  // namespace gol
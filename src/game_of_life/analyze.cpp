@@ -0,0 +1,72 @@
+#include "analyze.h"
+
+#include <algorithm>
+#include <cstdint>
+#include <cstdlib>
+#include <iostream>
+#include <numeric>
+#include <string>
+
+#include "game/analysis.h"
+#include "game/engine.h"
+
+namespace gol {
+namespace cli {
+
+std::uint64_t AnalyzedGenerations(const Config &conf) noexcept {
+  return (conf.max_generations) ? conf.max_generations : 1000;
+}
+
+/* Return the speed of a spaceship moving by (dx, dy) every period
+ * generations in the usual notation, e.g. 'c/4 diagonal'. */
+[[nodiscard]] static std::string SpeedName(std::int64_t dx, std::int64_t dy,
+                                           std::uint64_t period) {
+  const std::uint64_t kCells = static_cast<std::uint64_t>(
+      std::max(std::llabs(dx), std::llabs(dy)));
+  const std::uint64_t kGcd = std::gcd(kCells, period);
+  const std::string kDirection =
+      (!dx || !dy) ? "orthogonal"
+      : (std::llabs(dx) == std::llabs(dy)) ? "diagonal"
+                                           : "oblique";
+  return ((kCells / kGcd > 1) ? std::to_string(kCells / kGcd) : "") + "c/" +
+         std::to_string(period / kGcd) + " " + kDirection;
+}
+
+void RunAnalyze(const Config &conf, gol::game::Engine &board) {
+  const gol::game::Analysis kAnalysis =
+      gol::game::Analyze(board, AnalyzedGenerations(conf));
+  switch (kAnalysis.fate) {
+    case gol::game::Fate::kUndetermined:
+      std::cout << "fate: undetermined, "
+                << ((kAnalysis.reached_edge) ? "reached the board edge"
+                                             : "no cycle found")
+                << std::endl;
+      break;
+    case gol::game::Fate::kDiesOut:
+      std::cout << "fate: dies out" << std::endl;
+      break;
+    case gol::game::Fate::kStable:
+      std::cout << "fate: still life" << std::endl;
+      break;
+    case gol::game::Fate::kOscillator:
+      std::cout << "fate: oscillator" << std::endl;
+      std::cout << "period: " << kAnalysis.period << std::endl;
+      break;
+    case gol::game::Fate::kSpaceship:
+      std::cout << "fate: spaceship" << std::endl;
+      std::cout << "period: " << kAnalysis.period << std::endl;
+      std::cout << "velocity: "
+                << SpeedName(kAnalysis.dx, kAnalysis.dy, kAnalysis.period)
+                << ", moving (" << kAnalysis.dx << ", " << kAnalysis.dy
+                << ") cells per period" << std::endl;
+      break;
+  }
+  std::cout << "generation: " << kAnalysis.generation << std::endl;
+  std::cout << "population: " << kAnalysis.population << std::endl;
+  if (kAnalysis.escaped) {
+    std::cout << "escaped: " << kAnalysis.escaped << std::endl;
+  }
+}
+
+}  // namespace cli
+}  // namespace gol
@@ -0,0 +1,24 @@
+#ifndef ANALYZE_H_
+#define ANALYZE_H_
+
+#include <cstdint>
+
+#include "game/engine.h"
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* Return the number of generations analyzed, the generation limit or 1000
+ * generations by default. */
+[[nodiscard]] std::uint64_t AnalyzedGenerations(const Config &conf) noexcept;
+
+/* Run board until its fate is known and print it, one 'key: value' line per
+ * fact. */
+void RunAnalyze(const Config &conf, gol::game::Engine &board);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
@@ -0,0 +1,31 @@
+#include "bench.h"
+
+#include <algorithm>
+#include <chrono>
+#include <cstdint>
+#include <iomanip>
+#include <iostream>
+
+#include "game/engine.h"
+
+namespace gol {
+namespace cli {
+
+void RunBench(const Config &conf, gol::game::Engine &board) {
+  const std::uint64_t kGenerations =
+      (conf.max_generations) ? conf.max_generations : 1000;
+  const auto kStart = std::chrono::steady_clock::now();
+  board.Advance(kGenerations);
+  const std::chrono::duration<double> kElapsed =
+      std::chrono::steady_clock::now() - kStart;
+  const double kSeconds = std::max(kElapsed.count(), 1e-9);
+  std::cout << kGenerations << " generations of a " << board.Cols() << "x"
+            << board.Rows() << " board in " << std::fixed
+            << std::setprecision(3) << kSeconds * 1000.0 << " ms, "
+            << std::setprecision(0) << kGenerations / kSeconds
+            << " generations/s, population " << board.Population()
+            << std::endl;
+}
+
+}  // namespace cli
+}  // namespace gol
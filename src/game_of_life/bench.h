@@ -0,0 +1,18 @@
+#ifndef BENCH_H_
+#define BENCH_H_
+
+#include "game/engine.h"
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* Advance board by the generation limit, 1000 generations by default, and
+ * print how long it took. */
+void RunBench(const Config &conf, gol::game::Engine &board);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
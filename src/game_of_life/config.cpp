@@ -0,0 +1,991 @@
+#include "config.h"
+
+#include <getopt.h>
+
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <filesystem>
+#include <fstream>
+#include <iostream>
+#include <iterator>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/elementary.h"
+#include "game/factory.h"
+#include "game/history.h"
+#include "game/raster.h"
+#include "game/rule.h"
+#include "game/rule_table.h"
+#include "graphics/cell_style.h"
+#include "graphics/grid.h"
+#include "graphics/keymap.h"
+#include "graphics/renderer.h"
+#include "graphics/theme.h"
+#include "pattern/align.h"
+#include "pattern/diagnostics.h"
+#include "pattern/json.h"
+#include "pattern/library.h"
+#include "pattern/loader.h"
+#include "pattern/toml.h"
+
+namespace gol {
+namespace cli {
+
+static void PrintUsage() noexcept {
+  std::cout << "usage: life [COMMAND] [OPTION]... INIT_STATE" << std::endl;
+  std::cout << "ncurses rendering of Conway's game of life" << std::endl;
+  std::cout << "commands:" << std::endl;
+  std::cout << "\trun\t\t\tdraw the simulation, the default command"
+            << std::endl;
+  std::cout << "\tedit\t\t\tstart in edit mode, same as run --edit"
+            << std::endl;
+  std::cout << "\tbench\t\t\ttime the engine over --max-generations "
+               "generations (default 1000) without drawing"
+            << std::endl;
+  std::cout << "\tconvert IN OUT\t\twrite the pattern file IN to OUT in the "
+               "format of its extension, '-' writes RLE to stdout"
+            << std::endl;
+  std::cout << "\tvalidate FILE...\tlist the malformed lines of pattern "
+               "files and print their population and bounding box, exits "
+               "with 1 if any file is malformed"
+            << std::endl;
+  std::cout << "\tanalyze\t\t\ttell whether INIT_STATE dies out, settles, "
+               "oscillates or moves as a spaceship within --max-generations "
+               "generations (default 1000)"
+            << std::endl;
+  std::cout << "options:" << std::endl;
+  std::cout << "\t-L, --pattern\t\tstart from an embedded pattern instead of "
+               "INIT_STATE"
+            << std::endl;
+  std::cout << "\t-l, --list-patterns\tprint the embedded patterns"
+            << std::endl;
+  std::cout << "\t-A, --place\t\tadd a pattern file or embedded pattern at "
+               "FILE[@X,Y][:ORIENTATION], ORIENTATION is one of 'rot0' "
+               "'rot90' 'rot180' 'rot270' 'fliph' or 'flipv', may be repeated"
+            << std::endl;
+  std::cout << "\t-V, --versus\t\tbattle the initial state (red) against a "
+               "pattern file or embedded pattern (blue) under Immigration"
+            << std::endl;
+  std::cout << "\t-W, --lenient\t\tskip malformed lines of pattern files with "
+               "a warning instead of exiting"
+            << std::endl;
+  std::cout << "\t    --watch\t\trestart the simulation whenever INIT_STATE "
+               "changes on disk"
+            << std::endl;
+  std::cout << "\t    --normalize\t\tmove converted patterns to the origin"
+            << std::endl;
+  std::cout << "\t-B, --board-size\tsize of the board in cells given as WxH, "
+               "at most 32768x32768 (default terminal size)"
+            << std::endl;
+  std::cout << "\t-C, --align\t\twhere the initial state is placed, one of";
+  for (const std::string &name : gol::pattern::AnchorNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " (default origin)" << std::endl;
+  std::cout << "\t-X, --offset\t\tmove the aligned initial state X columns "
+               "right and Y rows down, given as X,Y"
+            << std::endl;
+  std::cout << "\t-Z, --scale\t\thow patterns larger than the screen are "
+               "shown, one of 'fit' (default), 'fill' to also magnify small "
+               "patterns, 'pan' or 'crop'"
+            << std::endl;
+  std::cout << "\t-N, --no-scale\t\tpan across large patterns instead of "
+               "zooming out, same as --scale pan"
+            << std::endl;
+  std::cout << "\t-b, --follow\t\tkeep the live cells centered on the "
+               "screen, toggled with 'o'"
+            << std::endl;
+  std::cout << "\t-q, --auto-fit\t\tzoom such that all live cells fit on the "
+               "screen and keep them centered, toggled with 'a'"
+            << std::endl;
+  std::cout << "\t-x, --random\t\tstart from a random soup instead of "
+               "INIT_STATE"
+            << std::endl;
+  std::cout << "\t-d, --density\t\tfraction of live cells in the random "
+               "soup (default 0.3)"
+            << std::endl;
+  std::cout << "\t-s, --seed\t\tseed of the random soup and the noise "
+               "(default random)"
+            << std::endl;
+  std::cout << "\t-Q, --noise\t\tprobability of each cell flipping its "
+               "state every generation (default 0)"
+            << std::endl;
+  std::cout << "\t-t, --update-rate-ms\tspeed of simulation in milliseconds"
+            << std::endl;
+  std::cout << "\t-g, --max-generations\texit after this many generations "
+               "(default unlimited)"
+            << std::endl;
+  std::cout << "\t-z, --skip\t\tstart this many generations in, also the "
+               "number of generations skipped with 'f' (default 1000)"
+            << std::endl;
+  std::cout << "\t-v, --render-every\tdraw every Nth generation only, the "
+               "generations in between are computed at full speed (default 1)"
+            << std::endl;
+  std::cout << "\t-r, --rule\t\tbirth/survival rule in B/S or HROT notation "
+               "or one "
+               "of";
+  for (const std::string &name : gol::game::RulePresetNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << ", or a multi-state automaton, one of";
+  for (const std::string &name : gol::game::AutomatonNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " or a Generations rule in S/B/C notation, a Golly rule file "
+               "ending in '.rule', 'ant' for Langton's ants, 'lenia' for "
+               "continuous Lenia, or an elementary 1D rule given as W<n> "
+               "such as 'W30' or 'W110' (default B3/S23)"
+            << std::endl;
+  std::cout << "\t-M, --ants\t\tnumber of ants placed on the middle row when "
+               "the initial state holds none (default 1)"
+            << std::endl;
+  std::cout << "\t-e, --engine\t\tsimulation engine, one of";
+  for (const std::string &name : gol::game::EngineNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " (default naive)" << std::endl;
+  std::cout << "\t-j, --threads\t\tnumber of threads used by the naive "
+               "engine (default 1)"
+            << std::endl;
+  std::cout << "\t-w, --wrap\t\twrap around the board edges" << std::endl;
+  std::cout << "\t-n, --no-status\t\thide the status bar" << std::endl;
+  std::cout << "\t-S, --stop-on-stable\texit once the board dies out or "
+               "stops changing, or a battle is decided"
+            << std::endl;
+  std::cout << "\t-H, --history-mb\tmemory used to rewind the simulation in "
+               "MiB (default "
+            << (gol::game::History::kDefaultCapBytes >> 20) << ")"
+            << std::endl;
+  std::cout << "\t-a, --age-colors\tcolor live cells by age" << std::endl;
+  std::cout << "\t-I, --trails\t\tfade out dead cells over a few generations"
+            << std::endl;
+  std::cout << "\t-J, --diff\t\tshow births in green, deaths in red and "
+               "surviving cells in white"
+            << std::endl;
+  std::cout << "\t-c, --color\t\tcolor theme, one of";
+  for (const std::string &name : gol::graphics::ThemeNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " (default classic)" << std::endl;
+  std::cout << "\t-R, --render\t\trender mode, one of 'block' (default), "
+               "'braille', 'hex', 'sixel' or 'kitty'"
+            << std::endl;
+  std::cout << "\t-Y, --alive-char	character of live cells in block mode "
+               "(default \u2588)"
+            << std::endl;
+  std::cout << "\t-y, --dead-char		character of dead cells in block mode "
+               "(default space)"
+            << std::endl;
+  std::cout << "\t-T, --ascii		draw live cells as '#' for terminals "
+               "without the block character"
+            << std::endl;
+  std::cout << "\t-P, --record\t\twrite each generation to a replay file"
+            << std::endl;
+  std::cout << "\t-p, --replay\t\tplay back a replay file instead of "
+               "INIT_STATE"
+            << std::endl;
+  std::cout << "\t    --resume\t\tresume a session saved with 'S' instead "
+               "of INIT_STATE, also the file 'S' saves to (default "
+               "session.gol)"
+            << std::endl;
+  std::cout << "\t-m, --stats-out\t\twrite the population, births and deaths "
+               "of each generation to a CSV file, or JSON lines for a .json "
+               "file"
+            << std::endl;
+  std::cout << "\t-G, --gif\t\twrite the run to an animated GIF on exit"
+            << std::endl;
+  std::cout << "\t-i, --gif-every\t\tcapture every Nth generation in the GIF "
+               "(default 1)"
+            << std::endl;
+  std::cout << "\t-F, --export-frames\twrite each generation to a PNG file in "
+               "this directory"
+            << std::endl;
+  std::cout << "\t-K, --export-scale\tpixels per cell side of the exported "
+               "PNG files (default 1)"
+            << std::endl;
+  std::cout << "\t-E, --edit\t\tstart in edit mode, INIT_STATE is optional"
+            << std::endl;
+  std::cout << "\t    --symmetry\t\tmirror the cells placed in edit mode "
+               "across the board center, one of";
+  for (const std::string &name : gol::game::SymmetryNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " (default none), cycled with 'm'" << std::endl;
+  std::cout << "\t-o, --output\t\tfile edited patterns are saved to "
+               "(default pattern.txt)"
+            << std::endl;
+  std::cout << "\t-f, --snapshot-format\tformat of the snapshots taken with "
+               "'s', one of";
+  for (const std::string &name : gol::pattern::FormatNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << " or 'svg' (default rle)" << std::endl;
+  std::cout << "\t-O, --stream\t\twrite each generation to stdout instead of "
+               "drawing it, in one of";
+  for (const std::string &name : gol::pattern::FormatNames()) {
+    std::cout << " '" << name << "'";
+  }
+  std::cout << std::endl;
+  std::cout << "\t-k, --config\t\tread default options from this TOML or JSON "
+               "file instead of gol.toml"
+            << std::endl;
+  std::cout << "\t-D, --pattern-dir\tdirectory searched for pattern files "
+               "that are not found in the working directory"
+            << std::endl;
+  std::cout << "\t    --no-FLAG\t\tturn off a flag set in a config file, "
+               "e.g. --no-wrap, --status turns off --no-status"
+            << std::endl;
+  std::cout << "\t-h, --help\t\tprint this help page" << std::endl;
+  std::cout << "\tINIT_STATE\t\tcoordinates of the initial live cells or an "
+               "RLE (.rle), Life 1.06 (.lif), plaintext (.cells) or JSON "
+               "(.json) pattern file, '-' reads it from stdin"
+            << std::endl;
+}
+
+static void PrintPatterns() noexcept {
+  for (const gol::pattern::LibraryPattern &pattern :
+       gol::pattern::PatternLibrary()) {
+    std::cout << pattern.name << "\t" << pattern.description << std::endl;
+  }
+}
+
+void PrintErrorAndExit(const std::string &err_msg) noexcept {
+  std::cerr << "error: " << err_msg;
+  std::exit(EXIT_FAILURE);
+}
+
+[[nodiscard]] static gol::graphics::RenderMode ParseRenderMode(
+    const std::string &name) {
+  if ("block" == name) {
+    return gol::graphics::RenderMode::kBlock;
+  } else if ("braille" == name) {
+    return gol::graphics::RenderMode::kBraille;
+  } else if ("hex" == name) {
+    return gol::graphics::RenderMode::kHex;
+  } else if ("sixel" == name) {
+    return gol::graphics::RenderMode::kSixel;
+  } else if ("kitty" == name) {
+    return gol::graphics::RenderMode::kKitty;
+  }
+  throw std::invalid_argument("unknown render mode -> " + name);
+}
+
+/* Return the name ParseRenderMode() reads as mode. */
+[[nodiscard]] static std::string RenderModeName(
+    gol::graphics::RenderMode mode) {
+  switch (mode) {
+    case gol::graphics::RenderMode::kBlock:
+      return "block";
+    case gol::graphics::RenderMode::kBraille:
+      return "braille";
+    case gol::graphics::RenderMode::kHex:
+      return "hex";
+    case gol::graphics::RenderMode::kSixel:
+      return "sixel";
+    case gol::graphics::RenderMode::kKitty:
+      return "kitty";
+    case gol::graphics::RenderMode::kNone:
+      break;
+  }
+  /* headless runs are not selected by name */
+  return "block";
+}
+
+std::optional<Subcommand> ParseSubcommand(const std::string &name) {
+  if ("run" == name) {
+    return Subcommand::kRun;
+  } else if ("edit" == name) {
+    return Subcommand::kEdit;
+  } else if ("bench" == name) {
+    return Subcommand::kBench;
+  } else if ("convert" == name) {
+    return Subcommand::kConvert;
+  } else if ("validate" == name) {
+    return Subcommand::kValidate;
+  } else if ("analyze" == name) {
+    return Subcommand::kAnalyze;
+  }
+  return std::nullopt;
+}
+
+[[nodiscard]] static ScaleMode ParseScaleMode(const std::string &name) {
+  if ("fit" == name) {
+    return ScaleMode::kFit;
+  } else if ("fill" == name) {
+    return ScaleMode::kFill;
+  } else if ("pan" == name) {
+    return ScaleMode::kPan;
+  } else if ("crop" == name) {
+    return ScaleMode::kCrop;
+  }
+  throw std::invalid_argument("unknown scale mode -> " + name);
+}
+
+/* Return the name ParseScaleMode() reads as mode. */
+[[nodiscard]] static std::string ScaleModeName(ScaleMode mode) {
+  switch (mode) {
+    case ScaleMode::kFit:
+      return "fit";
+    case ScaleMode::kFill:
+      return "fill";
+    case ScaleMode::kPan:
+      return "pan";
+    case ScaleMode::kCrop:
+      return "crop";
+  }
+  return "fit";
+}
+
+void SetRule(const std::string &name, Config &conf) {
+  conf.simulation = gol::game::Simulation::kLife;
+  conf.rule_name = name;
+  if (name.ends_with(".rule")) {
+    conf.automaton = gol::game::LoadRuleTable(name);
+    return;
+  }
+
+  const std::optional<std::uint8_t> kCode =
+      gol::game::ParseElementaryRule(name);
+  conf.automaton = gol::game::FindAutomaton(name);
+  if (kCode) {
+    conf.simulation = gol::game::Simulation::kElementary;
+    conf.elementary_rule = *kCode;
+  } else if ("ant" == name) {
+    conf.simulation = gol::game::Simulation::kAnt;
+  } else if ("lenia" == name) {
+    conf.simulation = gol::game::Simulation::kLenia;
+  } else if (!conf.automaton) {
+    conf.rule = gol::game::ParseRule(name);
+  }
+}
+
+/* Parse a board size of the form WxH into builder. */
+static void ParseBoardSize(const std::string &spec, ConfigBuilder &builder) {
+  const std::size_t kX = spec.find('x');
+  if ((std::string::npos == kX) || (0 == kX) || (spec.size() - 1 == kX) ||
+      (spec.find_first_not_of("0123456789x") != std::string::npos) ||
+      (spec.find('x', kX + 1) != std::string::npos)) {
+    throw std::invalid_argument("board size must be WxH -> " + spec);
+  }
+  builder.BoardSize(std::stoull(spec.substr(0, kX)),
+                    std::stoull(spec.substr(kX + 1)));
+}
+
+/* Values of the options without a short name. */
+static constexpr int kSymmetryOption = 256;
+static constexpr int kResumeOption = 257;
+static constexpr int kWatchOption = 258;
+static constexpr int kNormalizeOption = 259;
+/* The --no-<flag> forms of flags add this value to the value of the flag. */
+static constexpr int kNegatedOption = 512;
+
+/* Command line options, config files accept the same long names as keys. */
+static const struct option kLongOptions[] = {
+    {"pattern", required_argument, 0, 'L'},
+    {"list-patterns", no_argument, 0, 'l'},
+    {"place", required_argument, 0, 'A'},
+    {"versus", required_argument, 0, 'V'},
+    {"lenient", no_argument, 0, 'W'},
+    {"board-size", required_argument, 0, 'B'},
+    {"align", required_argument, 0, 'C'},
+    {"offset", required_argument, 0, 'X'},
+    {"scale", required_argument, 0, 'Z'},
+    {"no-scale", no_argument, 0, 'N'},
+    {"follow", no_argument, 0, 'b'},
+    {"auto-fit", no_argument, 0, 'q'},
+    {"random", no_argument, 0, 'x'},
+    {"density", required_argument, 0, 'd'},
+    {"noise", required_argument, 0, 'Q'},
+    {"seed", required_argument, 0, 's'},
+    {"update-rate-ms", required_argument, 0, 't'},
+    {"max-generations", required_argument, 0, 'g'},
+    {"skip", required_argument, 0, 'z'},
+    {"render-every", required_argument, 0, 'v'},
+    {"rule", required_argument, 0, 'r'},
+    {"ants", required_argument, 0, 'M'},
+    {"engine", required_argument, 0, 'e'},
+    {"threads", required_argument, 0, 'j'},
+    {"wrap", no_argument, 0, 'w'},
+    {"no-status", no_argument, 0, 'n'},
+    {"stop-on-stable", no_argument, 0, 'S'},
+    {"history-mb", required_argument, 0, 'H'},
+    {"age-colors", no_argument, 0, 'a'},
+    {"trails", no_argument, 0, 'I'},
+    {"diff", no_argument, 0, 'J'},
+    {"color", required_argument, 0, 'c'},
+    {"render", required_argument, 0, 'R'},
+    {"alive-char", required_argument, 0, 'Y'},
+    {"dead-char", required_argument, 0, 'y'},
+    {"ascii", no_argument, 0, 'T'},
+    {"grid", required_argument, 0, 'u'},
+    {"grid-labels", no_argument, 0, 'U'},
+    {"record", required_argument, 0, 'P'},
+    {"replay", required_argument, 0, 'p'},
+    {"resume", required_argument, 0, kResumeOption},
+    {"watch", no_argument, 0, kWatchOption},
+    {"normalize", no_argument, 0, kNormalizeOption},
+    {"stats-out", required_argument, 0, 'm'},
+    {"gif", required_argument, 0, 'G'},
+    {"gif-every", required_argument, 0, 'i'},
+    {"export-frames", required_argument, 0, 'F'},
+    {"export-scale", required_argument, 0, 'K'},
+    {"edit", no_argument, 0, 'E'},
+    {"symmetry", required_argument, 0, kSymmetryOption},
+    {"output", required_argument, 0, 'o'},
+    {"snapshot-format", required_argument, 0, 'f'},
+    {"stream", required_argument, 0, 'O'},
+    {"config", required_argument, 0, 'k'},
+    {"pattern-dir", required_argument, 0, 'D'},
+    {"help", no_argument, 0, 'h'},
+    {"no-lenient", no_argument, 0, kNegatedOption | 'W'},
+    {"no-follow", no_argument, 0, kNegatedOption | 'b'},
+    {"no-auto-fit", no_argument, 0, kNegatedOption | 'q'},
+    {"no-random", no_argument, 0, kNegatedOption | 'x'},
+    {"no-wrap", no_argument, 0, kNegatedOption | 'w'},
+    {"status", no_argument, 0, kNegatedOption | 'n'},
+    {"no-stop-on-stable", no_argument, 0, kNegatedOption | 'S'},
+    {"no-age-colors", no_argument, 0, kNegatedOption | 'a'},
+    {"no-trails", no_argument, 0, kNegatedOption | 'I'},
+    {"no-diff", no_argument, 0, kNegatedOption | 'J'},
+    {"no-ascii", no_argument, 0, kNegatedOption | 'T'},
+    {"no-grid-labels", no_argument, 0, kNegatedOption | 'U'},
+    {"no-watch", no_argument, 0, kNegatedOption | kWatchOption},
+    {"no-normalize", no_argument, 0, kNegatedOption | kNormalizeOption},
+    {"no-edit", no_argument, 0, kNegatedOption | 'E'},
+    {0, 0, 0, 0},
+};
+static constexpr const char *kShortOptions =
+    "hlwnSbqaIJExWNTU"
+    "L:A:V:B:C:X:Z:t:g:z:v:r:M:e:j:o:c:R:Y:y:u:H:f:P:p:m:G:i:F:K:d:Q:s:O:k:D:";
+
+/* Apply the option opt with argument arg, which is null for flags, to conf.
+ * Flags are turned off when opt holds kNegatedOption. */
+static void ApplyOption(int opt, const char *arg, ConfigBuilder &builder) {
+  const bool kOn = !(opt & kNegatedOption);
+  switch (opt & ~kNegatedOption) {
+    case 'L':
+      builder.Set(&Config::pattern, arg);
+      break;
+    case 'l':
+      PrintPatterns();
+      std::exit(EXIT_SUCCESS);
+    case 'A':
+      builder.Place(arg);
+      break;
+    case 'V':
+      builder.Set(&Config::versus, arg);
+      break;
+    case 'W':
+      builder.Set(&Config::lenient, kOn);
+      break;
+    case 'B':
+      ParseBoardSize(arg, builder);
+      break;
+    case 'C':
+      builder.Set(&Config::alignment, &gol::pattern::Alignment::anchor,
+                  gol::pattern::ParseAnchor(arg));
+      break;
+    case 'X':
+      builder.Set(&Config::alignment, &gol::pattern::Alignment::offset,
+                  gol::pattern::ParseOffset(arg));
+      break;
+    case 'Z':
+      builder.Set(&Config::scale_mode, ParseScaleMode(arg));
+      break;
+    case 'N':
+      builder.Set(&Config::scale_mode, ScaleMode::kPan);
+      break;
+    case 'x':
+      builder.Set(&Config::random, kOn);
+      break;
+    case 'd':
+      builder.Density(std::stod(arg));
+      break;
+    case 'Q':
+      builder.Noise(std::stod(arg));
+      break;
+    case 's':
+      builder.Set(&Config::seed, std::stoull(arg));
+      break;
+    case 't':
+      builder.UpdateRate(static_cast<int>(std::stod(arg)));
+      break;
+    case 'g':
+      builder.MaxGenerations(std::stoll(arg));
+      break;
+    case 'z':
+      builder.Skip(std::stoll(arg));
+      break;
+    case 'v':
+      builder.RenderEvery(std::stoll(arg));
+      break;
+    case 'r':
+      builder.Rule(arg);
+      break;
+    case 'M':
+      builder.NumAnts(std::stoll(arg));
+      break;
+    case 'e':
+      builder.Engine(gol::game::ParseEngineType(arg));
+      break;
+    case 'j':
+      builder.Threads(std::stoi(arg));
+      break;
+    case 'w':
+      builder.Wrap(kOn);
+      break;
+    case 'n':
+      builder.Set(&Config::show_status, !kOn);
+      break;
+    case 'S':
+      builder.Set(&Config::stop_on_stable, kOn);
+      break;
+    case 'b':
+      builder.Set(&Config::follow, kOn);
+      break;
+    case 'q':
+      builder.Set(&Config::auto_fit, kOn);
+      break;
+    case 'H':
+      builder.HistoryMb(std::stoll(arg));
+      break;
+    case 'a':
+      builder.Set(&Config::shading, &gol::graphics::Shading::ages, kOn);
+      break;
+    case 'I':
+      builder.Set(&Config::shading, &gol::graphics::Shading::trails, kOn);
+      break;
+    case 'J':
+      builder.Set(&Config::shading, &gol::graphics::Shading::diff, kOn);
+      break;
+    case 'c':
+      builder.Theme(arg);
+      break;
+    case 'R':
+      builder.Set(&Config::render_mode, ParseRenderMode(arg));
+      break;
+    case 'Y':
+      builder.Set(&Config::cell_style, &gol::graphics::CellStyle::alive,
+                  gol::graphics::ParseCellChar(arg));
+      break;
+    case 'y':
+      builder.Set(&Config::cell_style, &gol::graphics::CellStyle::dead,
+                  gol::graphics::ParseCellChar(arg));
+      break;
+    case 'T':
+      builder.Set(&Config::cell_style, (kOn) ? gol::graphics::kAsciiCellStyle
+                                             : gol::graphics::CellStyle{});
+      break;
+    case 'u':
+      builder.GridSpacing(std::stoll(arg));
+      break;
+    case 'U':
+      builder.Set(&Config::grid, &gol::graphics::GridOverlay::labels, kOn);
+      break;
+    case 'P':
+      builder.Set(&Config::record_file, arg);
+      break;
+    case 'p':
+      builder.Set(&Config::replay_file, arg);
+      break;
+    case kResumeOption:
+      builder.Set(&Config::resume_file, arg).Set(&Config::session_file, arg);
+      break;
+    case kWatchOption:
+      builder.Set(&Config::watch, kOn);
+      break;
+    case kNormalizeOption:
+      builder.Set(&Config::normalize, kOn);
+      break;
+    case 'm':
+      builder.Set(&Config::stats_file, arg);
+      break;
+    case 'G':
+      builder.Set(&Config::gif_file, arg);
+      break;
+    case 'i':
+      builder.GifEvery(std::stoll(arg));
+      break;
+    case 'F':
+      builder.Set(&Config::frames_dir, arg);
+      break;
+    case 'K':
+      builder.FrameScale(std::stoll(arg));
+      break;
+    case 'E':
+      builder.Set(&Config::edit, kOn);
+      break;
+    case kSymmetryOption:
+      builder.Set(&Config::symmetry, gol::game::ParseSymmetry(arg));
+      break;
+    case 'o':
+      builder.Set(&Config::output_file, arg);
+      break;
+    case 'f':
+      /* SVG snapshots are images rather than loadable patterns */
+      if ("svg" == std::string(arg)) {
+        builder.Set(&Config::snapshot_svg, true);
+      } else {
+        builder.Set(&Config::snapshot_svg, false)
+            .Set(&Config::snapshot_format, gol::pattern::ParseFormat(arg));
+      }
+      break;
+    case 'O':
+      builder.Set(&Config::stream, true)
+          .Set(&Config::stream_format, gol::pattern::ParseFormat(arg));
+      break;
+    case 'k':
+      /* config files are read before the other options */
+      break;
+    case 'D':
+      builder.Set(&Config::pattern_dir, arg);
+      break;
+    case 'h':
+      PrintUsage();
+      std::exit(EXIT_SUCCESS);
+  }
+}
+
+/* Return whether the flag opt has a --no-<flag> form, or is itself one. */
+[[nodiscard]] static bool HasNegatedOption(int opt) {
+  for (const struct option *option = kLongOptions; option->name; option++) {
+    if (option->val == (opt ^ kNegatedOption)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/* Return the long option named key if config files may set it, else null. */
+[[nodiscard]] static const struct option *FindConfigOption(
+    const std::string &key) {
+  if (("config" == key) || ("help" == key) || ("list-patterns" == key)) {
+    return nullptr;
+  }
+  for (const struct option *option = kLongOptions; option->name; option++) {
+    if (key == option->name) {
+      return option;
+    }
+  }
+  return nullptr;
+}
+
+/* Apply the members of the JSON object json to builder as ConfigFromJson()
+ * reads them. */
+static void ApplyJsonConfig(const gol::pattern::JsonValue &json,
+                            ConfigBuilder &builder) {
+  using Type = gol::pattern::JsonValue::Type;
+  for (const gol::pattern::JsonValue::Member &member : json.AsObject()) {
+    const std::string &kKey = member.first;
+    const gol::pattern::JsonValue &kValue = member.second;
+    const struct option *option = FindConfigOption(kKey);
+    if (!option) {
+      throw std::invalid_argument("unknown option '" + kKey + "'");
+    }
+
+    if (no_argument == option->has_arg) {
+      if (Type::kBool != kValue.GetType()) {
+        throw std::invalid_argument("'" + kKey + "' must be true or false");
+      } else if (kValue.AsBool()) {
+        ApplyOption(option->val, nullptr, builder);
+      } else if (HasNegatedOption(option->val)) {
+        ApplyOption(option->val ^ kNegatedOption, nullptr, builder);
+      }
+      continue;
+    }
+
+    const std::vector<gol::pattern::JsonValue> kArgs =
+        (Type::kArray == kValue.GetType())
+            ? kValue.AsArray()
+            : std::vector<gol::pattern::JsonValue>{kValue};
+    for (const gol::pattern::JsonValue &arg : kArgs) {
+      /* numbers are passed on as written, e.g. 100 rather than 100.0 */
+      if (Type::kString == arg.GetType()) {
+        ApplyOption(option->val, arg.AsString().c_str(), builder);
+      } else if (Type::kNumber == arg.GetType()) {
+        ApplyOption(option->val, gol::pattern::DumpJson(arg).c_str(),
+                    builder);
+      } else {
+        throw std::invalid_argument("'" + kKey +
+                                    "' must be a string or a number");
+      }
+    }
+  }
+}
+
+/* Return the config files read when --config is not given, the per-user file
+ * followed by the one in the working directory, which takes precedence. */
+[[nodiscard]] static std::vector<std::string> DefaultConfigFiles() {
+  std::vector<std::string> candidates;
+  const char *kConfigHome = std::getenv("XDG_CONFIG_HOME");
+  const char *kHome = std::getenv("HOME");
+  if (kConfigHome && *kConfigHome) {
+    candidates.push_back(std::string(kConfigHome) + "/gol/gol.toml");
+  } else if (kHome && *kHome) {
+    candidates.push_back(std::string(kHome) + "/.config/gol/gol.toml");
+  }
+  candidates.push_back("gol.toml");
+
+  std::vector<std::string> files;
+  for (const std::string &candidate : candidates) {
+    if (std::filesystem::is_regular_file(candidate)) {
+      files.push_back(candidate);
+    }
+  }
+  return files;
+}
+
+/* Apply the options set in the TOML config file filename to builder. Keys are
+ * long option names, flags are set by 'true' and turned off by 'false', and
+ * arrays repeat an option.
+ * Keys of the [keys] table are command names bound to the listed keys. Files
+ * ending in .json hold an object read as ConfigFromJson() does instead. */
+static void ApplyConfigFile(const std::string &filename,
+                            ConfigBuilder &builder) {
+  if (filename.ends_with(".json")) {
+    std::ifstream fhandle(filename);
+    if (!fhandle) {
+      throw std::invalid_argument("invalid file path -> " + filename);
+    }
+    const std::string kText((std::istreambuf_iterator<char>(fhandle)),
+                            std::istreambuf_iterator<char>());
+    try {
+      ApplyJsonConfig(gol::pattern::ParseJson(kText), builder);
+    } catch (const gol::pattern::ParseError &e) {
+      gol::pattern::ParseDiagnostic diagnostic = e.Diagnostic();
+      diagnostic.source = filename;
+      throw gol::pattern::ParseError(diagnostic);
+    } catch (const std::logic_error &e) {
+      throw gol::pattern::ParseError({.source = filename, .reason = e.what()});
+    }
+    return;
+  }
+
+  for (const gol::pattern::TomlEntry &entry :
+       gol::pattern::LoadToml(filename)) {
+    const auto kFail = [&filename, &entry](const std::string &reason) {
+      throw gol::pattern::ParseError(
+          {.source = filename, .line = entry.line, .reason = reason});
+    };
+
+    const gol::pattern::TomlValue &kValue = entry.value;
+    const std::vector<gol::pattern::TomlValue> kArgs =
+        (gol::pattern::TomlType::kArray == kValue.type)
+            ? kValue.elements
+            : std::vector<gol::pattern::TomlValue>{kValue};
+
+    /* the [keys] table binds a key name or an array of them to a command,
+     * an empty array unbinds the command */
+    if (entry.key.starts_with("keys.")) {
+      std::vector<int> keys;
+      try {
+        for (const gol::pattern::TomlValue &arg : kArgs) {
+          if (gol::pattern::TomlType::kString != arg.type) {
+            kFail("'" + entry.key + "' must list key names");
+          }
+          keys.push_back(gol::graphics::ParseKey(arg.text));
+        }
+        builder.Bind(gol::graphics::ParseCommand(entry.key.substr(5)), keys);
+      } catch (const std::invalid_argument &e) {
+        kFail(e.what());
+      }
+      continue;
+    }
+
+    const struct option *option = FindConfigOption(entry.key);
+    if (!option) {
+      kFail("unknown option '" + entry.key + "'");
+    }
+
+    if (no_argument == option->has_arg) {
+      if (gol::pattern::TomlType::kBoolean != kValue.type) {
+        kFail("'" + entry.key + "' must be true or false");
+      } else if ("true" == kValue.text) {
+        ApplyOption(option->val, nullptr, builder);
+      } else if (HasNegatedOption(option->val)) {
+        ApplyOption(option->val ^ kNegatedOption, nullptr, builder);
+      }
+      continue;
+    }
+
+    for (const gol::pattern::TomlValue &arg : kArgs) {
+      if ((gol::pattern::TomlType::kBoolean == arg.type) ||
+          (gol::pattern::TomlType::kArray == arg.type)) {
+        kFail("'" + entry.key + "' must be a string or a number");
+      }
+      try {
+        ApplyOption(option->val, arg.text.c_str(), builder);
+      } catch (const std::logic_error &e) {
+        kFail(e.what());
+      }
+    }
+  }
+}
+
+std::string ResolvePatternPath(const std::string &path, const Config &conf) {
+  if (conf.pattern_dir.empty() || ("-" == path) ||
+      std::filesystem::exists(path)) {
+    return path;
+  }
+  const std::filesystem::path kCandidate =
+      std::filesystem::path(conf.pattern_dir) / path;
+  return (std::filesystem::exists(kCandidate)) ? kCandidate.string() : path;
+}
+
+Config ConfigBuilder::Build() const {
+  Config conf = conf_;
+  if (!conf.init_state.empty()) {
+    conf.init_state = ResolvePatternPath(conf.init_state, conf);
+  } else if (!conf.edit && !conf.random && conf.pattern.empty() &&
+             conf.placements.empty() && conf.replay_file.empty() &&
+             conf.resume_file.empty() &&
+             (gol::game::Simulation::kLife == conf.simulation)) {
+    throw std::invalid_argument("missing initial state configuration file");
+  }
+
+  const int kNumInitStates = !conf.init_state.empty() + conf.random +
+                             !conf.pattern.empty() +
+                             !conf.replay_file.empty() +
+                             !conf.resume_file.empty();
+  if (kNumInitStates > 1) {
+    throw std::invalid_argument(
+        "only one of INIT_STATE, --pattern, --random, --replay and --resume "
+        "may be given");
+  } else if (!conf.placements.empty() && !conf.replay_file.empty()) {
+    throw std::invalid_argument("--place cannot be combined with --replay");
+  } else if (conf.board_width && !conf.replay_file.empty()) {
+    throw std::invalid_argument(
+        "--board-size cannot be combined with --replay");
+  } else if (conf.board_width && !conf.resume_file.empty()) {
+    throw std::invalid_argument(
+        "--board-size cannot be combined with --resume");
+  } else if (conf.stream && conf.edit) {
+    throw std::invalid_argument("--stream cannot be combined with --edit");
+  } else if (conf.watch &&
+             (conf.init_state.empty() || ("-" == conf.init_state))) {
+    throw std::invalid_argument("--watch needs an INIT_STATE file");
+  } else if (conf.watch && (conf.stream || !conf.versus.empty())) {
+    throw std::invalid_argument(
+        "--watch cannot be combined with --stream or --versus");
+  } else if (conf.bench && (conf.stream || conf.edit || conf.watch)) {
+    throw std::invalid_argument(
+        "bench cannot be combined with --stream, --edit or --watch");
+  } else if (conf.analyze && (conf.stream || conf.edit || conf.watch)) {
+    throw std::invalid_argument(
+        "analyze cannot be combined with --stream, --edit or --watch");
+  } else if ((conf.stream || conf.bench || conf.analyze) &&
+             (!conf.record_file.empty() || !conf.gif_file.empty() ||
+              !conf.frames_dir.empty())) {
+    throw std::invalid_argument(
+        "--record, --gif and --export-frames cannot be combined with "
+        "--stream, bench or analyze");
+  } else if (conf.analyze && (conf.random || !conf.replay_file.empty())) {
+    throw std::invalid_argument("analyze needs INIT_STATE or --pattern");
+  } else if (!conf.versus.empty() &&
+             (conf.random || !conf.replay_file.empty() ||
+              !conf.resume_file.empty())) {
+    throw std::invalid_argument("--versus needs INIT_STATE or --pattern");
+  } else if (!conf.versus.empty() && conf.rule_given) {
+    throw std::invalid_argument(
+        "--versus runs Immigration, it cannot be combined with --rule");
+  } else if (conf.wrap && !gol::game::SupportsWrap(conf.engine_type)) {
+    throw std::invalid_argument("the selected engine does not support --wrap");
+  }
+  return conf;
+}
+
+ConfigBuilder ParseArguments(int argc, char **argv) {
+  /* config files are applied before the command line such that options
+   * given on the command line take precedence */
+  std::vector<std::string> config_files = DefaultConfigFiles();
+  int opt = '\0';
+  int long_index = 0;
+  opterr = 0;
+  while (-1 != (opt = getopt_long(argc, argv, kShortOptions, kLongOptions,
+                                  &long_index))) {
+    if ('k' == opt) {
+      config_files = {optarg};
+    }
+  }
+  optind = 0;
+  opterr = 1;
+
+  ConfigBuilder builder;
+  for (const std::string &config_file : config_files) {
+    ApplyConfigFile(config_file, builder);
+  }
+  /* a configured rule is a default, patterns still run under their own */
+  builder.Set(&Config::rule_given, false);
+  while (-1 != (opt = getopt_long(argc, argv, kShortOptions, kLongOptions,
+                                  &long_index))) {
+    if ('?' == opt) {
+      std::exit(EXIT_FAILURE);
+    }
+    ApplyOption(opt, optarg, builder);
+  }
+  return builder;
+}
+
+gol::pattern::JsonValue ToJson(const Config &conf) {
+  gol::pattern::JsonValue json = gol::pattern::JsonValue::Object();
+  if (!conf.rule_name.empty()) {
+    json.Set("rule", conf.rule_name);
+  }
+  if (conf.board_width) {
+    json.Set("board-size", std::to_string(conf.board_width) + "x" +
+                               std::to_string(conf.board_height));
+  }
+  json.Set("scale", ScaleModeName(conf.scale_mode));
+  json.Set("random", conf.random);
+  json.Set("density", conf.density);
+  json.Set("noise", conf.noise);
+  /* a string keeps the seeds beyond the integers JSON numbers hold exactly */
+  if (conf.seed) {
+    json.Set("seed", std::to_string(*conf.seed));
+  }
+  json.Set("update-rate-ms", conf.update_rate_ms);
+  /* no limit and no skipped generations have no option value */
+  if (conf.max_generations) {
+    json.Set("max-generations", conf.max_generations);
+  }
+  if (conf.skip) {
+    json.Set("skip", conf.skip);
+  }
+  json.Set("render-every", conf.render_every);
+  json.Set("ants", std::uint64_t{conf.num_ants});
+  for (const std::string &name : gol::game::EngineNames()) {
+    if (gol::game::ParseEngineType(name) == conf.engine_type) {
+      json.Set("engine", name);
+    }
+  }
+  json.Set("threads", conf.num_threads);
+  json.Set("wrap", conf.wrap);
+  json.Set("no-status", !conf.show_status);
+  json.Set("stop-on-stable", conf.stop_on_stable);
+  json.Set("follow", conf.follow);
+  json.Set("auto-fit", conf.auto_fit);
+  json.Set("history-mb", std::uint64_t{conf.history_mb});
+  json.Set("symmetry", gol::game::ToString(conf.symmetry));
+  json.Set("age-colors", conf.shading.ages);
+  json.Set("trails", conf.shading.trails);
+  json.Set("diff", conf.shading.diff);
+  json.Set("color", conf.theme.name);
+  json.Set("render", RenderModeName(conf.render_mode));
+  json.Set("grid", std::uint64_t{conf.grid.spacing});
+  json.Set("grid-labels", conf.grid.labels);
+  return json;
+}
+
+ConfigBuilder ConfigFromJson(const gol::pattern::JsonValue &json) {
+  ConfigBuilder builder;
+  ApplyJsonConfig(json, builder);
+  return builder;
+}
+
+}  // namespace cli
+}  // namespace gol
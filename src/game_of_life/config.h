@@ -0,0 +1,355 @@
+#ifndef CONFIG_H_
+#define CONFIG_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/factory.h"
+#include "game/history.h"
+#include "game/raster.h"
+#include "game/rule.h"
+#include "graphics/cell_style.h"
+#include "graphics/grid.h"
+#include "graphics/keymap.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/theme.h"
+#include "pattern/align.h"
+#include "pattern/compose.h"
+#include "pattern/json.h"
+#include "pattern/loader.h"
+
+namespace gol {
+namespace cli {
+
+/* How patterns larger than the screen are displayed. */
+enum class ScaleMode {
+  kFit,  /* the board grows to hold the pattern and is zoomed out to show it */
+  kFill, /* as kFit, small patterns are also magnified and centered */
+  kPan,  /* the board grows to hold the pattern, which is panned across */
+  kCrop, /* cells beyond the screen are dropped */
+};
+
+/* What the program does, selected by the first argument. */
+enum class Subcommand {
+  kRun,      /* draw the simulation */
+  kEdit,     /* as kRun, starting in edit mode */
+  kBench,    /* time the engine without drawing */
+  kConvert,  /* write a pattern file in another format */
+  kValidate, /* check pattern files for malformed lines */
+  kAnalyze,  /* tell how a pattern evolves without drawing it */
+};
+
+/* The options of a run. */
+struct Config {
+  std::string init_state;
+  std::string pattern_dir;
+  std::string title;
+  std::string pattern;
+  std::vector<gol::pattern::Placement> placements;
+  std::string versus;
+  bool lenient = false;
+  gol::pattern::Alignment alignment;
+  std::size_t board_width = 0;
+  std::size_t board_height = 0;
+  ScaleMode scale_mode = ScaleMode::kFit;
+  bool random = false;
+  double density = 0.3;
+  double noise = 0.0;
+  std::optional<std::uint64_t> seed;
+  std::string record_file;
+  std::string replay_file;
+  std::string resume_file;
+  std::string session_file = "session.gol";
+  std::string stats_file;
+  std::string gif_file;
+  std::uint64_t gif_every = 1;
+  std::string frames_dir;
+  std::size_t frame_scale = 1;
+  std::string output_file = "pattern.txt";
+  gol::pattern::Format snapshot_format = gol::pattern::Format::kRle;
+  bool snapshot_svg = false;
+  bool stream = false;
+  bool bench = false;
+  bool analyze = false;
+  gol::pattern::Format stream_format = gol::pattern::Format::kRle;
+  int update_rate_ms = 100;
+  std::uint64_t max_generations = 0;
+  std::uint64_t skip = 0;
+  std::uint64_t render_every = 1;
+  gol::game::Rule rule;
+  std::string rule_name;
+  std::shared_ptr<const gol::game::Automaton> automaton;
+  gol::game::Simulation simulation = gol::game::Simulation::kLife;
+  std::uint8_t elementary_rule = 30;
+  std::size_t num_ants = 1;
+  bool rule_given = false;
+  gol::game::EngineType engine_type = gol::game::EngineType::kNaive;
+  int num_threads = 1;
+  bool wrap = false;
+  bool show_status = true;
+  bool stop_on_stable = false;
+  bool follow = false;
+  bool auto_fit = false;
+  std::size_t history_mb = gol::game::History::kDefaultCapBytes >> 20;
+  bool edit = false;
+  bool watch = false;
+  bool normalize = false;
+  gol::game::Symmetry symmetry = gol::game::Symmetry::kNone;
+  gol::graphics::Shading shading;
+  gol::graphics::Theme theme;
+  gol::graphics::CellStyle cell_style;
+  gol::graphics::GridOverlay grid;
+  gol::graphics::Keymap keymap;
+  gol::graphics::RenderMode render_mode = gol::graphics::RenderMode::kBlock;
+};
+
+/* Select the automaton, Life-like rule or simulation named name. */
+void SetRule(const std::string &name, Config &conf);
+
+/* Builds a Config from options set in any order. Each value is validated as
+ * it is set and the combination of options once the config is built, options
+ * left unset keep their defaults. Options validated by a setter of their own
+ * must be set through it rather than through Set(). */
+class ConfigBuilder {
+ public:
+  /* Set the option stored in field to value. */
+  template <typename T, typename U>
+  ConfigBuilder &Set(T Config::*field, U &&value) {
+    conf_.*field = std::forward<U>(value);
+    return *this;
+  }
+
+  /* Set the member field of the option group to value. */
+  template <typename T, typename F, typename U>
+  ConfigBuilder &Set(T Config::*group, F T::*field, U &&value) {
+    (conf_.*group).*field = std::forward<U>(value);
+    return *this;
+  }
+
+  /* Start from the pattern file path, looked up in the pattern directory if
+   * it does not exist once built. */
+  ConfigBuilder &InitState(const std::string &path) {
+    conf_.init_state = path;
+    return *this;
+  }
+
+  /* Add a pattern at the placement spec. */
+  ConfigBuilder &Place(const std::string &spec) {
+    conf_.placements.push_back(gol::pattern::ParsePlacement(spec));
+    return *this;
+  }
+
+  /* Bind keys to command, an empty list unbinds it. */
+  ConfigBuilder &Bind(gol::graphics::Command command,
+                      const std::vector<int> &keys) {
+    conf_.keymap.Bind(command, keys);
+    return *this;
+  }
+
+  /* Run the automaton, Life-like rule or simulation named name, patterns
+   * then no longer run under their own rule. */
+  ConfigBuilder &Rule(const std::string &name) {
+    SetRule(name, conf_);
+    conf_.rule_given = true;
+    return *this;
+  }
+
+  /* Draw the board in the colors of the theme named name. */
+  ConfigBuilder &Theme(const std::string &name) {
+    conf_.theme = gol::graphics::FindTheme(name);
+    return *this;
+  }
+
+  /* Size the board to width x height cells instead of the terminal. */
+  ConfigBuilder &BoardSize(std::size_t width, std::size_t height) {
+    if ((0 == width) || (0 == height)) {
+      throw std::invalid_argument("board size must be positive -> " +
+                                  std::to_string(width) + "x" +
+                                  std::to_string(height));
+    } else if ((width > gol::game::GameOfLifeBoard::kMaxSide) ||
+               (height > gol::game::GameOfLifeBoard::kMaxSide)) {
+      throw std::invalid_argument(
+          "board size must be at most " +
+          std::to_string(gol::game::GameOfLifeBoard::kMaxSide) + "x" +
+          std::to_string(gol::game::GameOfLifeBoard::kMaxSide) + " -> " +
+          std::to_string(width) + "x" + std::to_string(height));
+    }
+    conf_.board_width = width;
+    conf_.board_height = height;
+    return *this;
+  }
+
+  /* Wrap around the board edges, the engine must support it. */
+  ConfigBuilder &Wrap(bool wrap) {
+    conf_.wrap = wrap;
+    return *this;
+  }
+
+  /* Advance the board with the engine of the given type. */
+  ConfigBuilder &Engine(gol::game::EngineType type) {
+    conf_.engine_type = type;
+    return *this;
+  }
+
+  /* Stop after the given number of generations. */
+  ConfigBuilder &MaxGenerations(std::int64_t generations) {
+    if (generations <= 0) {
+      throw std::invalid_argument(
+          "max generations must be a positive integer");
+    }
+    conf_.max_generations = static_cast<std::uint64_t>(generations);
+    return *this;
+  }
+
+  /* Fill the given fraction of the random soup. */
+  ConfigBuilder &Density(double density) {
+    if (!(density >= 0.0) || (density > 1.0)) {
+      throw std::invalid_argument("density must be between 0 and 1");
+    }
+    conf_.density = density;
+    return *this;
+  }
+
+  /* Flip each cell with the given probability after every generation. */
+  ConfigBuilder &Noise(double noise) {
+    if (!(noise >= 0.0) || (noise > 1.0)) {
+      throw std::invalid_argument("noise must be between 0 and 1");
+    }
+    conf_.noise = noise;
+    return *this;
+  }
+
+  /* Wait update_rate_ms between two generations. */
+  ConfigBuilder &UpdateRate(int update_rate_ms) {
+    if (update_rate_ms <= 0) {
+      throw std::invalid_argument("update rate must be a positive integer");
+    }
+    conf_.update_rate_ms = update_rate_ms;
+    return *this;
+  }
+
+  /* Start the run after the given number of generations. */
+  ConfigBuilder &Skip(std::int64_t generations) {
+    if (generations <= 0) {
+      throw std::invalid_argument("skip must be a positive integer");
+    }
+    conf_.skip = static_cast<std::uint64_t>(generations);
+    return *this;
+  }
+
+  /* Draw every Nth generation only. */
+  ConfigBuilder &RenderEvery(std::int64_t every) {
+    if (every <= 0) {
+      throw std::invalid_argument(
+          "render interval must be a positive integer");
+    }
+    conf_.render_every = static_cast<std::uint64_t>(every);
+    return *this;
+  }
+
+  /* Start Langton's ant simulations with the given number of ants. */
+  ConfigBuilder &NumAnts(std::int64_t num_ants) {
+    if (num_ants < 0) {
+      throw std::invalid_argument("ant count must be a non-negative integer");
+    }
+    conf_.num_ants = static_cast<std::size_t>(num_ants);
+    return *this;
+  }
+
+  /* Advance the board on the given number of threads. */
+  ConfigBuilder &Threads(int num_threads) {
+    if (num_threads <= 0) {
+      throw std::invalid_argument("thread count must be a positive integer");
+    }
+    conf_.num_threads = num_threads;
+    return *this;
+  }
+
+  /* Keep up to history_mb MiB of frames to rewind. */
+  ConfigBuilder &HistoryMb(std::int64_t history_mb) {
+    if (history_mb < 0) {
+      throw std::invalid_argument(
+          "history size must be a non-negative integer");
+    }
+    conf_.history_mb = static_cast<std::size_t>(history_mb);
+    return *this;
+  }
+
+  /* Draw grid lines every spacing cells, 0 hides them. */
+  ConfigBuilder &GridSpacing(std::int64_t spacing) {
+    if (spacing < 0) {
+      throw std::invalid_argument(
+          "grid spacing must be a non-negative integer");
+    }
+    conf_.grid.spacing = static_cast<std::size_t>(spacing);
+    return *this;
+  }
+
+  /* Capture every Nth generation in the GIF. */
+  ConfigBuilder &GifEvery(std::int64_t every) {
+    if (every <= 0) {
+      throw std::invalid_argument("GIF interval must be a positive integer");
+    }
+    conf_.gif_every = static_cast<std::uint64_t>(every);
+    return *this;
+  }
+
+  /* Export frames with scale x scale pixels per cell. */
+  ConfigBuilder &FrameScale(std::int64_t scale) {
+    if (scale <= 0) {
+      throw std::invalid_argument("export scale must be a positive integer");
+    }
+    conf_.frame_scale = static_cast<std::size_t>(scale);
+    return *this;
+  }
+
+  /* Return the config once the options are checked to fit together.
+   * Throws std::invalid_argument when they do not. */
+  [[nodiscard]] Config Build() const;
+
+ private:
+  Config conf_; /* Options set so far. */
+};
+
+/* Return the subcommand named name if any. */
+[[nodiscard]] std::optional<Subcommand> ParseSubcommand(
+    const std::string &name);
+
+/* Return a builder holding the options of the config files followed by those
+ * of the command line, which take precedence. optind is left at the first
+ * operand. */
+[[nodiscard]] ConfigBuilder ParseArguments(int argc, char **argv);
+
+/* Return the settings of conf as a JSON object keyed by long option names,
+ * e.g. {"update-rate-ms":100,"wrap":false,...}, which config files ending in
+ * .json hold as well. The initial state, placed patterns, input and output
+ * files, cell characters and key bindings are not part of it. */
+[[nodiscard]] gol::pattern::JsonValue ToJson(const Config &conf);
+
+/* Return a builder holding the options of json, an object keyed by long option
+ * names whose flags are booleans and whose other options are strings, numbers
+ * or arrays of them repeating the option.
+ * Throws std::logic_error when an option is unknown or invalid. */
+[[nodiscard]] ConfigBuilder ConfigFromJson(const gol::pattern::JsonValue &json);
+
+/* Return path, or its location in the pattern directory when it does not
+ * exist relative to the working directory. */
+[[nodiscard]] std::string ResolvePatternPath(const std::string &path,
+                                             const Config &conf);
+
+/* Print err_msg to stderr and exit with a failure status. */
+void PrintErrorAndExit(const std::string &err_msg) noexcept;
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
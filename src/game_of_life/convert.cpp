@@ -0,0 +1,43 @@
+#include "convert.h"
+
+#include <fstream>
+#include <iostream>
+#include <stdexcept>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/loader.h"
+#include "pattern/metadata.h"
+#include "pattern/pattern.h"
+
+namespace gol {
+namespace cli {
+
+void ConvertPattern(const Config &conf, const std::string &output) {
+  gol::pattern::Diagnostics diagnostics(!conf.lenient);
+  gol::pattern::PatternMetadata metadata;
+  gol::pattern::Pattern pattern(
+      gol::pattern::LoadPattern(conf.init_state, &diagnostics, &metadata));
+  for (const gol::pattern::ParseDiagnostic &warning :
+       diagnostics.Warnings()) {
+    std::cerr << "warning: " << gol::pattern::ToString(warning) << std::endl;
+  }
+  if (conf.normalize) {
+    pattern.Normalize();
+  }
+
+  if ("-" == output) {
+    gol::pattern::Write(gol::pattern::Format::kRle, std::cout,
+                        pattern.Cells(), &metadata);
+    return;
+  }
+  std::ofstream fhandle(output);
+  if (!fhandle) {
+    throw std::runtime_error("unable to open " + output);
+  }
+  gol::pattern::Write(gol::pattern::DetectFormat(output, ""), fhandle,
+                      pattern.Cells(), &metadata);
+}
+
+}  // namespace cli
+}  // namespace gol
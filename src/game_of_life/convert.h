@@ -0,0 +1,18 @@
+#ifndef CONVERT_H_
+#define CONVERT_H_
+
+#include <string>
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* Write the pattern file conf.init_state to output in the format named by
+ * the extension of output, or as RLE to stdout when output is '-'. */
+void ConvertPattern(const Config &conf, const std::string &output);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
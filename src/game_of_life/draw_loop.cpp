@@ -0,0 +1,904 @@
+#include "draw_loop.h"
+
+#include <algorithm>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <cstdio>
+#include <ctime>
+#include <exception>
+#include <filesystem>
+#include <fstream>
+#include <limits>
+#include <optional>
+#include <random>
+#include <stdexcept>
+#include <string>
+#include <system_error>
+#include <utility>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/battle.h"
+#include "game/cycle.h"
+#include "game/engine.h"
+#include "game/factory.h"
+#include "game/history.h"
+#include "game/hooks.h"
+#include "game/pipeline.h"
+#include "game/raster.h"
+#include "game/scheduler.h"
+#include "game/stats.h"
+#include "game/undo.h"
+#include "graphics/cell_style.h"
+#include "graphics/gif.h"
+#include "graphics/grid.h"
+#include "graphics/png.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/svg.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+#include "pattern/align.h"
+#include "pattern/compose.h"
+#include "pattern/diagnostics.h"
+#include "pattern/export.h"
+#include "pattern/loader.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+#include "pattern/replay.h"
+#include "pattern/session.h"
+#include "pattern/soup.h"
+#include "pattern/stats_file.h"
+
+#include "edit.h"
+#include "run.h"
+
+using gol::pattern::Position2D;
+using gol::pattern::Position2DVec;
+
+namespace gol {
+namespace cli {
+
+/* Return the time filename was last modified, or nothing while it cannot be
+ * read. */
+[[nodiscard]] static std::optional<std::filesystem::file_time_type>
+ModifiedTime(const std::string &filename) {
+  std::error_code error;
+  const std::filesystem::file_time_type kTime =
+      std::filesystem::last_write_time(filename, error);
+  if (error) {
+    return std::nullopt;
+  }
+  return kTime;
+}
+
+/* Replace the cells of board with the initial state and placed patterns as
+ * they are on disk now and start over from the first generation. Cells beyond
+ * the board edges are left out, the board is untouched when loading fails. */
+static void ReloadInitState(const Config &conf, gol::game::Engine &board) {
+  gol::pattern::Diagnostics diagnostics(!conf.lenient);
+  Position2DVec cells =
+      gol::pattern::LoadPattern(conf.init_state, &diagnostics);
+  const Position2DVec kPlacedCells =
+      gol::pattern::Compose(conf.placements, &diagnostics);
+  cells.insert(cells.end(), kPlacedCells.begin(), kPlacedCells.end());
+  Position2DVec visible_cells = gol::pattern::PlacePattern(
+      cells, conf.alignment, board.Rows(), board.Cols());
+  std::erase_if(visible_cells, [&board](const Position2D &pos) {
+    return !gol::pattern::OnBoard(pos, board.Rows(), board.Cols());
+  });
+
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    for (std::size_t j = 0; j < board.Cols(); ++j) {
+      board.SetCell(i, j, false);
+    }
+  }
+  InitializeBoard(visible_cells, board);
+  board.SetGeneration(0);
+  board.Advance(conf.skip);
+}
+
+static void SaveBoard(const gol::game::Engine &board,
+                      const std::string &filename,
+                      gol::pattern::Format format) {
+  std::ofstream fhandle(filename);
+  if (!fhandle) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+  gol::pattern::Write(format, fhandle, gol::pattern::ToPoints(board));
+}
+
+/* Save the displayed frame along with the rule, speed and camera of the run
+ * to the session file --resume continues from. */
+static void WriteSession(const gol::game::Frame &frame, const Config &conf,
+                         int update_rate_ms,
+                         const gol::graphics::Viewport &viewport) {
+  gol::pattern::SaveSession(
+      {.rows = frame.cells.Rows(),
+       .cols = frame.cells.Cols(),
+       .wrap = conf.wrap,
+       .rule = (conf.rule_name.empty()) ? conf.rule.ToString() : conf.rule_name,
+       .generation = frame.generation,
+       .update_rate_ms = update_rate_ms,
+       .view_row = viewport.Row(),
+       .view_col = viewport.Col(),
+       .zoom = viewport.Zoom(),
+       .cell_size = viewport.CellSize(),
+       .cells = gol::pattern::ToPoints(frame.cells)},
+      conf.session_file);
+}
+
+static void SaveSvg(const gol::game::Engine &board,
+                    const gol::game::CellAges *ages,
+                    const std::string &filename,
+                    const gol::graphics::Theme &theme,
+                    const gol::graphics::Shading &shading) {
+  std::ofstream fhandle(filename);
+  if (!fhandle) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+  fhandle << gol::graphics::ToSvg(board, ages,
+                                  {.theme = theme, .shading = shading});
+}
+
+/* Return the name of a snapshot file of generation taken now. */
+[[nodiscard]] static std::string SnapshotFilename(
+    std::uint64_t generation, const std::string &extension) {
+  const std::time_t kNow = std::time(nullptr);
+  char timestamp[32] = {};
+  std::strftime(timestamp, sizeof(timestamp), "%Y%m%d-%H%M%S",
+                std::localtime(&kNow));
+  return std::string("life-") + timestamp + "-g" +
+         std::to_string(generation) + extension;
+}
+
+/* Append frame to gif if its generation is a multiple of every. */
+static void CaptureFrame(const gol::game::Frame &frame,
+                         const gol::game::CellAges &ages,
+                         const gol::graphics::Shading &shading,
+                         std::uint64_t every, int delay_ms,
+                         gol::graphics::GifWriter &gif) {
+  if (0 == (frame.generation % every)) {
+    gif.AddFrame(gol::graphics::CellStyles(frame.cells, &ages, shading),
+                 delay_ms);
+  }
+}
+
+/* Write frame to a PNG file in dir named after its generation. */
+static void ExportFrame(const gol::game::Frame &frame,
+                        const gol::game::CellAges &ages,
+                        const gol::graphics::Shading &shading,
+                        const std::filesystem::path &dir, std::size_t scale,
+                        const std::vector<gol::graphics::Rgb> &palette) {
+  char filename[32] = {};
+  std::snprintf(filename, sizeof(filename), "gen-%06llu.png",
+                static_cast<unsigned long long>(frame.generation));
+  gol::graphics::WritePng(dir / filename, frame.cells.Cols(),
+                          frame.cells.Rows(), scale, palette,
+                          gol::graphics::CellStyles(frame.cells, &ages,
+                                                    shading));
+}
+
+std::size_t ViewportWidth(int width, int height,
+                          const gol::graphics::Renderer &renderer) noexcept {
+  const std::size_t kChars = renderer.CharactersPerDot();
+  const auto kWidth = static_cast<std::size_t>(std::max(0, width));
+  const std::size_t kShear =
+      renderer.ShearColumns(static_cast<std::size_t>(std::max(0, height)));
+  return (kWidth >= kShear + kChars) ? (kWidth - kShear) / kChars : 0;
+}
+
+void RunDrawLoop(gol::graphics::ScreenDimension dim, const Config &conf,
+                 gol::graphics::Viewport viewport, gol::game::Engine &board,
+                 gol::graphics::Renderer &renderer) {
+  using gol::graphics::Command;
+
+  const int kMinUpdateRateMs = 1;
+  const int kMaxUpdateRateMs = 10000;
+  const int kPanStep = 4;
+  const std::uint64_t kSkip = (conf.skip) ? conf.skip : 1000;
+  const int kHudRows = (conf.show_status) ? 2 : 1;
+
+  int update_rate_ms = conf.update_rate_ms;
+  gol::graphics::GridOverlay grid = conf.grid;
+  const std::size_t kGridSpacing =
+      (0 == conf.grid.spacing) ? gol::graphics::GridOverlay::kDefaultSpacing
+                               : conf.grid.spacing;
+  bool paused = false;
+  bool editing = conf.edit;
+  std::size_t cursor_row = board.Rows() / 2;
+  std::size_t cursor_col = board.Cols() / 2;
+
+  /* the selected rectangle spans from the anchor to the cursor, copied cells
+   * are stamped at the cursor */
+  std::optional<std::pair<std::size_t, std::size_t>> anchor;
+  gol::pattern::Pattern clipboard;
+
+  /* the pencil paints while the pen is down, the other tools draw from the
+   * marked cell to the cursor */
+  const std::int64_t kMaxBrush = 16;
+  Tool tool = Tool::kPencil;
+  std::int64_t brush = 0;
+  bool pen_down = false;
+  std::optional<gol::game::RasterCell> mark;
+
+  /* the picker lists the patterns to insert, the picked one is held as the
+   * copied cells and shown as a ghost at the cursor until dropped */
+  std::vector<PickerEntry> picker_entries;
+  std::vector<std::string> picker_labels;
+  bool picking = false;
+  std::size_t picked = 0;
+  bool placing = false;
+
+  /* every edit is mirrored across the axes of the symmetry, and saved to be
+   * undone until edit mode is left */
+  gol::game::Symmetry symmetry = conf.symmetry;
+  gol::game::UndoStack undo;
+  const auto kPaintMirrored = [&](const gol::game::RasterCells &cells,
+                                  std::uint8_t state) {
+    const gol::game::RasterCells kCells =
+        gol::game::Mirror(cells, symmetry, board.Rows(), board.Cols());
+    undo.Save(board, kCells);
+    gol::game::Paint(kCells, state, board);
+  };
+  bool stable = false;
+  std::uint64_t period = 0;
+  std::string message;
+  if (conf.random) {
+    message = "random soup seed " + std::to_string(*conf.seed);
+  } else if (conf.noise > 0.0) {
+    message = "noise seed " + std::to_string(*conf.seed);
+  }
+  gol::game::CellAges ages(board.Rows(), board.Cols());
+  ages.Update(board);
+  gol::game::CycleDetector cycles;
+
+  /* the board is advanced on a worker thread which is stopped while editing,
+   * the loop renders the frames it produces */
+  gol::game::Pipeline pipeline(board);
+  gol::game::Frame frame(board);
+
+  /* the displayed frames are kept to rewind the simulation, rewound is the
+   * number of frames the displayed one is behind the newest */
+  gol::game::History history(conf.history_mb << 20);
+  std::size_t rewound = 0;
+
+  std::optional<gol::pattern::ReplayWriter> recorder;
+  if (!conf.record_file.empty()) {
+    recorder.emplace(conf.record_file, board.Rows(), board.Cols(),
+                     board.NumStates());
+  }
+  std::optional<gol::pattern::StatsWriter> stats;
+  if (!conf.stats_file.empty()) {
+    stats.emplace(conf.stats_file);
+  }
+
+  /* the population graph shows the most recent steps */
+  gol::game::StatsHistory steps;
+  bool show_graph = false;
+
+  /* the view follows the live cells, and zooms to fit them, until panned or
+   * zoomed by hand */
+  bool following = conf.follow;
+  bool fitting = conf.auto_fit;
+
+  /* exported images are drawn in the colors of the theme, each GIF frame is
+   * shown for as long as the generations it stands for */
+  const std::vector<gol::graphics::Rgb> kPalette =
+      gol::graphics::StylePalette(conf.theme);
+  const int kGifScale = 4;
+  const int kGifDelayMs = static_cast<int>(std::min<std::uint64_t>(
+      conf.update_rate_ms * conf.gif_every, std::numeric_limits<int>::max()));
+  std::optional<gol::graphics::GifWriter> gif;
+  if (!conf.gif_file.empty()) {
+    gif.emplace(conf.gif_file, board.Cols(), board.Rows(), kGifScale,
+                kPalette);
+  }
+  if (!conf.frames_dir.empty()) {
+    std::filesystem::create_directories(conf.frames_dir);
+  }
+
+  /* whatever is kept, written or analyzed per generation is a hook called
+   * once the frame of the generation is displayed, the first frame included,
+   * further observers are attached the same way */
+  using gol::game::StepStats;
+  gol::game::GenerationHooks hooks;
+  hooks.OnGeneration(
+      [&](const gol::game::Frame &generation, const StepStats &step) {
+        steps.Push(step);
+        history.Push(generation);
+      });
+  hooks.OnGeneration([&](const gol::game::Frame &generation,
+                         const StepStats &) {
+    /* still lifes are reported as stable rather than as period 1 cycles */
+    const std::uint64_t kHash = gol::game::HashCells(generation.cells);
+    const std::uint64_t kPeriod =
+        cycles.Update(kHash, generation.generation).value_or(0);
+    if ((kPeriod > 1) && (kPeriod != period)) {
+      message = "period-" + std::to_string(kPeriod) +
+                " cycle detected at generation " +
+                std::to_string(generation.generation);
+    } else if ((kPeriod <= 1) && (period > 1)) {
+      message.clear();
+    }
+    period = kPeriod;
+  });
+  if (recorder) {
+    hooks.OnGeneration(
+        [&](const gol::game::Frame &generation, const StepStats &) {
+          recorder->Write(generation);
+        });
+  }
+  if (stats) {
+    hooks.OnGeneration(
+        [&](const gol::game::Frame &, const StepStats &step) {
+          stats->Write(step);
+        });
+  }
+  if (gif) {
+    hooks.OnGeneration(
+        [&](const gol::game::Frame &generation, const StepStats &) {
+          CaptureFrame(generation, ages, conf.shading, conf.gif_every,
+                       kGifDelayMs, *gif);
+        });
+  }
+  if (!conf.frames_dir.empty()) {
+    hooks.OnGeneration(
+        [&](const gol::game::Frame &generation, const StepStats &) {
+          ExportFrame(generation, ages, conf.shading, conf.frames_dir,
+                      conf.frame_scale, kPalette);
+        });
+  }
+  hooks.Notify(frame, FirstStats(frame));
+  if (!editing) {
+    pipeline.Start();
+  }
+
+  /* generations are shown at the update rate however long computing and
+   * drawing them takes, the scheduler tells when they cannot keep up */
+  using Clock = gol::game::FrameScheduler::Clock;
+  const int kPollMs = 5;
+  gol::game::FrameScheduler scheduler{
+      std::chrono::milliseconds(update_rate_ms)};
+
+  /* the watched pattern file is checked at least every kWatchPollMs, files
+   * missing while an editor replaces them are waited for */
+  const int kWatchPollMs = 250;
+  std::optional<std::filesystem::file_time_type> watched;
+  if (conf.watch) {
+    watched = ModifiedTime(conf.init_state);
+  }
+  const auto kWatchedChanged = [&] {
+    if (!conf.watch) {
+      return false;
+    }
+    const auto kModified = ModifiedTime(conf.init_state);
+    return kModified && (kModified != watched);
+  };
+
+  /* display the board as is, forgetting the frames leading up to it, the
+   * pipeline must be stopped */
+  const auto kRestartFromBoard = [&] {
+    frame = gol::game::Frame(board);
+    ages.Update(board);
+    stable = false;
+    period = 0;
+    cycles.Clear();
+    cycles.Update(gol::game::HashCells(frame.cells), frame.generation);
+    history.Clear();
+    history.Push(frame);
+    rewound = 0;
+    steps.Clear();
+    steps.Push(FirstStats(frame));
+    scheduler.Reset(Clock::now());
+  };
+
+  gol::graphics::Clear();
+  renderer.Invalidate();
+  while (true) {
+    if ((following || fitting) && !editing) {
+      if (const auto kBounds = frame.cells.LiveBounds()) {
+        if (fitting) {
+          viewport.StepZoomToFit(kBounds->bottom - kBounds->top + 1,
+                                 kBounds->right - kBounds->left + 1);
+        }
+        viewport.CenterOn((kBounds->top + kBounds->bottom) / 2,
+                          (kBounds->left + kBounds->right) / 2);
+      }
+    }
+    renderer.DrawBoard(frame.cells, viewport, &ages);
+    if (editing) {
+      if (anchor) {
+        renderer.DrawSelection(
+            Span(anchor->first, anchor->second, cursor_row, cursor_col),
+            viewport);
+      }
+      if (mark) {
+        const gol::game::RasterCell kCursor = {
+            .row = static_cast<std::int64_t>(cursor_row),
+            .col = static_cast<std::int64_t>(cursor_col)};
+        if (const auto kBounds =
+                ToolBounds(tool, *mark, kCursor, brush, board)) {
+          renderer.DrawSelection(*kBounds, viewport);
+        }
+      }
+      if (placing) {
+        for (const gol::game::RasterCell &cell :
+             GhostCells(clipboard, cursor_row, cursor_col, symmetry, board)) {
+          const auto kRow = static_cast<std::size_t>(cell.row);
+          const auto kCol = static_cast<std::size_t>(cell.col);
+          renderer.DrawSelection(
+              {.top = kRow, .left = kCol, .bottom = kRow, .right = kCol},
+              viewport);
+        }
+      }
+      renderer.DrawCursor(cursor_row, cursor_col, viewport);
+    }
+    if (show_graph) {
+      std::vector<std::uint64_t> populations;
+      for (std::size_t i = 0; i < steps.Size(); ++i) {
+        populations.push_back(steps.At(i).population);
+      }
+      renderer.DrawGraph(populations, viewport);
+    }
+    gol::graphics::DrawInstructions(dim, editing, conf.keymap);
+
+    /* battles are over once a player is gone or the board settles */
+    std::string score;
+    gol::game::BattleOutcome outcome = gol::game::BattleOutcome::kUndecided;
+    if (!conf.versus.empty()) {
+      const gol::game::BattleScore kScore =
+          gol::game::ScoreBattle(frame.cells);
+      score = "red: " + std::to_string(kScore.red) +
+              "  blue: " + std::to_string(kScore.blue);
+      outcome = gol::game::JudgeBattle(kScore, stable || (period > 1));
+    }
+    if (conf.show_status) {
+      gol::graphics::DrawStatusBar(dim, {.title = conf.title,
+                                         .generation = frame.generation,
+                                         .population = frame.population,
+                                         .score = score,
+                                         .outcome =
+                                             gol::game::ToString(outcome),
+                                         .update_rate_ms = update_rate_ms,
+                                         .behind = !paused && !editing &&
+                                                   scheduler.Behind(),
+                                         .rate = scheduler.Rate() *
+                                                 conf.render_every,
+                                         .paused = paused,
+                                         .editing = editing,
+                                         .stable = stable,
+                                         .following = following,
+                                         .fitting = fitting,
+                                         .rewound = rewound,
+                                         .zoom = viewport.Zoom(),
+                                         .cell_size = viewport.CellSize(),
+                                         .message = message});
+    }
+    if (picking) {
+      gol::graphics::DrawMenu(dim, "insert pattern", picker_labels, picked);
+    }
+    gol::graphics::Refresh();
+    renderer.Present();
+
+    if (conf.max_generations && (frame.generation >= conf.max_generations)) {
+      break;
+    } else if (conf.stop_on_stable &&
+               (stable || (gol::game::BattleOutcome::kUndecided != outcome))) {
+      break;
+    }
+
+    /* waiting on user input doubles as the delay between generations, once
+     * the next generation is due the wait goes on without redrawing until it
+     * is computed */
+    const bool kRunning = !paused && !editing;
+    const Command kCommand = [&] {
+      while (true) {
+        const Clock::time_point kNow = Clock::now();
+        int delay_ms = update_rate_ms;
+        if (kRunning) {
+          delay_ms = (scheduler.Due(kNow))
+                         ? kPollMs
+                         : static_cast<int>(scheduler.TimeLeft(kNow).count());
+        }
+        if (conf.watch) {
+          delay_ms = std::min(delay_ms, kWatchPollMs);
+        }
+        gol::graphics::EnableInputDelay(delay_ms);
+        const Command kRead = gol::graphics::ReadCommand(conf.keymap, editing);
+        if ((Command::kNone != kRead) || !kRunning || kWatchedChanged() ||
+            (scheduler.Due(Clock::now()) && (rewound || pipeline.Ready()))) {
+          return kRead;
+        }
+      }
+    }();
+
+    /* a changed pattern file restarts the simulation from its cells, the
+     * board is kept while the file fails to load */
+    if (kWatchedChanged()) {
+      watched = ModifiedTime(conf.init_state);
+      pipeline.Stop();
+      try {
+        ReloadInitState(conf, board);
+        message = "reloaded " + conf.init_state;
+        undo.Clear();
+        anchor.reset();
+        pen_down = false;
+        mark.reset();
+      } catch (const std::exception &e) {
+        message = e.what();
+      }
+      kRestartFromBoard();
+      if (!editing) {
+        if (recorder) {
+          recorder->Write(frame);
+        }
+        pipeline.Start();
+      }
+    }
+
+    /* the picker takes the keys while it is open */
+    if (picking && (Command::kResize != kCommand)) {
+      if ((Command::kMoveUp == kCommand) && (picked > 0)) {
+        picked--;
+      } else if ((Command::kMoveDown == kCommand) &&
+                 (picked + 1 < picker_entries.size())) {
+        picked++;
+      } else if (Command::kToggleCell == kCommand) {
+        try {
+          clipboard = LoadPickerEntry(picker_entries[picked]);
+          placing = true;
+          message = "inserting " + picker_entries[picked].name;
+        } catch (const std::exception &e) {
+          message = e.what();
+        }
+        picking = false;
+      } else if ((Command::kQuit == kCommand) ||
+                 (Command::kInsert == kCommand)) {
+        picking = false;
+      }
+      if (!picking) {
+        gol::graphics::Clear();
+        renderer.Invalidate();
+      }
+      continue;
+    }
+
+    if ((Command::kQuit == kCommand) && placing) {
+      /* Esc drops the held pattern rather than quitting */
+      placing = false;
+      message.clear();
+    } else if (Command::kQuit == kCommand) {
+      break;
+    } else if ((Command::kToggleEdit == kCommand) ||
+               ((Command::kInsert == kCommand) && !editing)) {
+      /* patterns are inserted in edit mode */
+      editing = !editing;
+      message.clear();
+      anchor.reset();
+      pen_down = false;
+      mark.reset();
+      undo.Clear();
+      placing = false;
+      if (editing) {
+        /* catch up with the generations computed ahead of the display */
+        pipeline.Stop();
+        kRestartFromBoard();
+      } else {
+        /* the edited board is where the simulation resumes from */
+        if (recorder) {
+          recorder->Write(frame);
+        }
+        pipeline.Start();
+        scheduler.Reset(Clock::now());
+      }
+    } else if (Command::kTogglePause == kCommand) {
+      paused = !paused;
+      scheduler.Reset(Clock::now());
+    } else if (Command::kSpeedUp == kCommand) {
+      update_rate_ms = std::max(kMinUpdateRateMs, update_rate_ms / 2);
+      scheduler.SetInterval(std::chrono::milliseconds(update_rate_ms));
+    } else if (Command::kSlowDown == kCommand) {
+      update_rate_ms = std::min(kMaxUpdateRateMs, update_rate_ms * 2);
+      scheduler.SetInterval(std::chrono::milliseconds(update_rate_ms));
+    } else if (Command::kZoomIn == kCommand) {
+      viewport.ZoomIn();
+      fitting = false;
+    } else if (Command::kZoomOut == kCommand) {
+      viewport.ZoomOut();
+      fitting = false;
+    } else if (Command::kToggleGrid == kCommand) {
+      grid.spacing = (0 == grid.spacing) ? kGridSpacing : 0;
+      renderer.SetGrid(grid);
+    } else if (Command::kToggleGraph == kCommand) {
+      show_graph = !show_graph;
+    } else if (Command::kToggleFollow == kCommand) {
+      following = !following;
+    } else if (Command::kToggleFit == kCommand) {
+      fitting = !fitting;
+    } else if (Command::kResize == kCommand) {
+      /* the board keeps its size while the viewport shows more or less of
+       * it, zoomed out patterns are refit to the new screen */
+      dim = gol::graphics::ScreenSize();
+      const std::size_t kHeight =
+          static_cast<std::size_t>(std::max(0, dim.height - kHudRows));
+      const std::size_t kWidth =
+          ViewportWidth(dim.width, dim.height - kHudRows, renderer);
+      if (ScaleMode::kFit == conf.scale_mode) {
+        viewport = gol::graphics::Viewport(
+            kHeight, kWidth, frame.cells.Rows(), frame.cells.Cols(),
+            viewport.DotRows(), viewport.DotCols());
+        viewport.ZoomToFit(frame.cells.Rows(), frame.cells.Cols());
+      } else {
+        viewport.Resize(kHeight, kWidth);
+      }
+      gol::graphics::Clear();
+      renderer.Invalidate();
+    } else if (Command::kSnapshot == kCommand) {
+      const std::string kFilename = SnapshotFilename(
+          frame.generation,
+          (conf.snapshot_svg)
+              ? ".svg"
+              : gol::pattern::FormatExtension(conf.snapshot_format));
+      try {
+        if (conf.snapshot_svg) {
+          SaveSvg(frame.cells, &ages, kFilename, conf.theme, conf.shading);
+        } else {
+          SaveBoard(frame.cells, kFilename, conf.snapshot_format);
+        }
+        message = "saved snapshot to " + kFilename;
+      } catch (const std::exception &e) {
+        message = e.what();
+      }
+    } else if (Command::kSaveSession == kCommand) {
+      /* ants, Lenia values and the rows of elementary automata are more than
+       * their cells, replays are resumed by replaying them. Sessions only
+       * hold the cells of the board, those that left the board of an
+       * unbounded engine would be lost */
+      if (!conf.replay_file.empty()) {
+        message = "replays cannot be saved as sessions";
+      } else if (gol::game::Simulation::kLife != conf.simulation) {
+        message = "only Life-like sessions can be saved";
+      } else if (!frame.outside.empty()) {
+        message = "sessions cannot be saved once cells left the board";
+      } else {
+        try {
+          WriteSession(frame, conf, update_rate_ms, viewport);
+          message = "saved session to " + conf.session_file;
+        } catch (const std::exception &e) {
+          message = e.what();
+        }
+      }
+    } else if (Command::kFastForward == kCommand) {
+      /* the generations computed ahead of the display count towards the
+       * skipped ones */
+      pipeline.Stop();
+      const std::uint64_t kTarget = frame.generation + kSkip;
+      if (board.Generation() < kTarget) {
+        board.Advance(kTarget - board.Generation());
+      }
+      kRestartFromBoard();
+      message = "skipped to generation " + std::to_string(frame.generation);
+      if (!editing) {
+        if (recorder) {
+          recorder->Write(frame);
+        }
+        pipeline.Start();
+      }
+    } else if ((Command::kRandomize == kCommand) ||
+               (Command::kClear == kCommand)) {
+      /* the simulation continues from the replaced board */
+      pipeline.Stop();
+      if (editing) {
+        undo.Begin();
+        undo.SaveAll(board);
+      }
+      for (std::size_t i = 0; i < board.Rows(); ++i) {
+        for (std::size_t j = 0; j < board.Cols(); ++j) {
+          board.SetCell(i, j, false);
+        }
+      }
+      message.clear();
+      if (Command::kRandomize == kCommand) {
+        const std::uint64_t kSeed = std::random_device()();
+        InitializeBoard(gol::pattern::RandomSoup(board.Rows(), board.Cols(),
+                                                 conf.density, kSeed,
+                                                 SoupColors(conf)),
+                        board);
+        message = "random soup seed " + std::to_string(kSeed);
+      }
+      kRestartFromBoard();
+      if (!editing) {
+        if (recorder) {
+          recorder->Write(frame);
+        }
+        pipeline.Start();
+      }
+    }
+
+    if (editing) {
+      /* the simulation is frozen while the user edits the board */
+      const std::size_t kLastRow = cursor_row;
+      const std::size_t kLastCol = cursor_col;
+      const gol::game::RasterCell kCursor = {
+          .row = static_cast<std::int64_t>(cursor_row),
+          .col = static_cast<std::int64_t>(cursor_col)};
+      if ((Command::kMoveUp == kCommand) && (cursor_row > 0)) {
+        cursor_row--;
+      } else if ((Command::kMoveDown == kCommand) &&
+                 (cursor_row + 1 < board.Rows())) {
+        cursor_row++;
+      } else if ((Command::kMoveLeft == kCommand) && (cursor_col > 0)) {
+        cursor_col--;
+      } else if ((Command::kMoveRight == kCommand) &&
+                 (cursor_col + 1 < board.Cols())) {
+        cursor_col++;
+      } else if (Command::kToggleCell == kCommand) {
+        /* cells of multi-state automata cycle through the states */
+        undo.Begin();
+        kPaintMirrored({kCursor}, (board.State(cursor_row, cursor_col) + 1) %
+                                      board.NumStates());
+        kRestartFromBoard();
+      } else if ((Command::kUndo == kCommand) ||
+                 (Command::kRedo == kCommand)) {
+        pen_down = false;
+        const bool kDone = (Command::kUndo == kCommand) ? undo.Undo(board)
+                                                         : undo.Redo(board);
+        if (kDone) {
+          kRestartFromBoard();
+        } else {
+          message = (Command::kUndo == kCommand) ? "nothing to undo"
+                                                  : "nothing to redo";
+        }
+      } else if (Command::kInsert == kCommand) {
+        if (picker_entries.empty()) {
+          picker_entries = PickerEntries(conf);
+          for (const PickerEntry &entry : picker_entries) {
+            picker_labels.push_back(entry.label);
+          }
+        }
+        picking = !picker_entries.empty();
+      } else if (Command::kSelect == kCommand) {
+        /* selecting again cancels the selection */
+        if (anchor) {
+          anchor.reset();
+        } else {
+          anchor.emplace(cursor_row, cursor_col);
+        }
+      } else if ((Command::kCopy == kCommand) && anchor) {
+        clipboard = CopyRegion(
+            board, Span(anchor->first, anchor->second, cursor_row, cursor_col));
+        anchor.reset();
+        placing = true;
+        message =
+            "copied " + std::to_string(clipboard.Cells().size()) + " cells";
+      } else if (Command::kPaste == kCommand) {
+        undo.Begin();
+        StampPattern(clipboard, cursor_row, cursor_col, symmetry, undo, board);
+        kRestartFromBoard();
+      } else if (Command::kRotate == kCommand) {
+        clipboard.Rotate90().Normalize();
+      } else if (Command::kFlip == kCommand) {
+        clipboard.FlipH().Normalize();
+      } else if (Command::kNextTool == kCommand) {
+        tool = NextTool(tool);
+        pen_down = false;
+        mark.reset();
+        message = "tool: " + ToolName(tool);
+      } else if ((Command::kBrushSmaller == kCommand) ||
+                 (Command::kBrushLarger == kCommand)) {
+        brush = std::clamp<std::int64_t>(
+            brush + ((Command::kBrushLarger == kCommand) ? 1 : -1), 0,
+            kMaxBrush);
+        message = "brush radius: " + std::to_string(brush);
+      } else if (Command::kNextSymmetry == kCommand) {
+        symmetry = NextSymmetry(symmetry);
+        message = "symmetry: " + gol::game::ToString(symmetry);
+      } else if (Command::kDraw == kCommand) {
+        /* shapes are drawn on the second press, from the cell marked by the
+         * first */
+        if (Tool::kPencil == tool) {
+          /* a stroke is undone at once */
+          pen_down = !pen_down;
+          undo.Begin();
+        } else if (mark) {
+          undo.Begin();
+          kPaintMirrored(ToolCells(tool, *mark, kCursor, brush), 1);
+          mark.reset();
+          kRestartFromBoard();
+        } else {
+          mark = kCursor;
+        }
+      } else if (Command::kSave == kCommand) {
+        try {
+          SaveBoard(board, conf.output_file,
+                    gol::pattern::Format::kCoordinates);
+          message = "saved to " + conf.output_file;
+        } catch (const std::exception &e) {
+          message = e.what();
+        }
+      }
+      const bool kMoved = (kLastRow != cursor_row) || (kLastCol != cursor_col);
+      if (pen_down && (kMoved || (Command::kDraw == kCommand))) {
+        const gol::game::RasterCell kPen = {
+            .row = static_cast<std::int64_t>(cursor_row),
+            .col = static_cast<std::int64_t>(cursor_col)};
+        kPaintMirrored(ToolCells(tool, kPen, kPen, brush), 1);
+        kRestartFromBoard();
+      }
+      viewport.Follow(cursor_row, cursor_col);
+      continue;
+    }
+
+    if (Command::kMoveUp == kCommand) {
+      viewport.Pan(-kPanStep, 0);
+      following = false;
+      fitting = false;
+    } else if (Command::kMoveDown == kCommand) {
+      viewport.Pan(kPanStep, 0);
+      following = false;
+      fitting = false;
+    } else if (Command::kMoveLeft == kCommand) {
+      viewport.Pan(0, -kPanStep);
+      following = false;
+      fitting = false;
+    } else if (Command::kMoveRight == kCommand) {
+      viewport.Pan(0, kPanStep);
+      following = false;
+      fitting = false;
+    }
+
+    /* frames behind the newest one are replayed from the history */
+    const bool kDue = !paused && scheduler.Due(Clock::now());
+    if (paused && (Command::kStepBack == kCommand) &&
+        (rewound + 1 < history.Size())) {
+      rewound++;
+      frame = history.At(history.Size() - rewound - 1);
+      ages.Update(frame.cells);
+      continue;
+    } else if (rewound && (kDue || (Command::kStep == kCommand))) {
+      rewound--;
+      frame = history.At(history.Size() - rewound - 1);
+      ages.Update(frame.cells);
+      if (kDue) {
+        scheduler.Advance(Clock::now());
+      }
+      continue;
+    }
+
+    /* while paused, the board only advances on an explicit step command,
+     * a running simulation skips the update if the next frame is not ready */
+    std::optional<gol::game::Frame> next;
+    if (Command::kStep == kCommand) {
+      next = pipeline.Pop();
+    } else if (kDue) {
+      next = pipeline.TryPop();
+    }
+    if (next && kDue) {
+      scheduler.Advance(Clock::now());
+    }
+
+    /* once the update starts, the generations up to the next one drawn are
+     * processed without being drawn, stopping early at the generation limit
+     * or when the board settles */
+    for (std::uint64_t i = 1; next; ++i) {
+      stable = IsStable(frame, *next);
+      const gol::game::StepStats kStep =
+          gol::game::CompareFrames(frame, *next);
+      frame = std::move(*next);
+      ages.Update(frame.cells);
+      hooks.Notify(frame, kStep);
+
+      next.reset();
+      if ((i < conf.render_every) && !stable &&
+          !(conf.max_generations &&
+            (frame.generation >= conf.max_generations))) {
+        next = pipeline.Pop();
+      }
+    }
+  }
+}
+
+}  // namespace cli
+}  // namespace gol
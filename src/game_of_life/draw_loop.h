@@ -0,0 +1,30 @@
+#ifndef DRAW_LOOP_H_
+#define DRAW_LOOP_H_
+
+#include <cstddef>
+
+#include "game/engine.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/viewport.h"
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* Return the number of viewport columns fitting in width screen columns next
+ * to height screen rows. */
+[[nodiscard]] std::size_t ViewportWidth(
+    int width, int height, const gol::graphics::Renderer &renderer) noexcept;
+
+/* Draw board on the screen of size dim, through viewport and renderer, and
+ * handle the commands of the user until told to quit. */
+void RunDrawLoop(gol::graphics::ScreenDimension dim, const Config &conf,
+                 gol::graphics::Viewport viewport, gol::game::Engine &board,
+                 gol::graphics::Renderer &renderer);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
@@ -0,0 +1,225 @@
+#include "edit.h"
+
+#include <algorithm>
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <filesystem>
+#include <optional>
+#include <string>
+#include <system_error>
+#include <utility>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/raster.h"
+#include "game/undo.h"
+#include "pattern/library.h"
+#include "pattern/loader.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+using gol::pattern::Position2D;
+using gol::pattern::Position2DVec;
+
+namespace gol {
+namespace cli {
+
+std::vector<PickerEntry> PickerEntries(const Config &conf) {
+  std::size_t width = 0;
+  for (const gol::pattern::LibraryPattern &pattern :
+       gol::pattern::PatternLibrary()) {
+    width = std::max(width, pattern.name.size());
+  }
+  std::vector<PickerEntry> entries;
+  for (const gol::pattern::LibraryPattern &pattern :
+       gol::pattern::PatternLibrary()) {
+    entries.push_back(
+        {.label = pattern.name + std::string(width - pattern.name.size(), ' ') +
+                  "  " + pattern.description,
+         .name = pattern.name,
+         .path = ""});
+  }
+
+  /* a missing pattern directory offers no files */
+  std::vector<PickerEntry> files;
+  std::error_code error;
+  for (const auto &file :
+       std::filesystem::directory_iterator(conf.pattern_dir, error)) {
+    if (file.is_regular_file()) {
+      const std::string kName = file.path().filename().string();
+      files.push_back(
+          {.label = kName, .name = kName, .path = file.path().string()});
+    }
+  }
+  std::sort(files.begin(), files.end(),
+            [](const PickerEntry &a, const PickerEntry &b) {
+              return a.name < b.name;
+            });
+  entries.insert(entries.end(), files.begin(), files.end());
+  return entries;
+}
+
+gol::pattern::Pattern LoadPickerEntry(const PickerEntry &entry) {
+  gol::pattern::Pattern pattern((entry.path.empty())
+                                    ? gol::pattern::FindPattern(entry.name)
+                                    : gol::pattern::LoadPattern(entry.path));
+  pattern.Normalize();
+  return pattern;
+}
+
+gol::game::CellBounds Span(std::size_t row, std::size_t col,
+                           std::size_t other_row, std::size_t other_col) {
+  return {.top = std::min(row, other_row),
+          .left = std::min(col, other_col),
+          .bottom = std::max(row, other_row),
+          .right = std::max(col, other_col)};
+}
+
+gol::pattern::Pattern CopyRegion(const gol::game::Engine &board,
+                                 const gol::game::CellBounds &bounds) {
+  Position2DVec cells;
+  for (std::size_t i = bounds.top; i <= bounds.bottom; ++i) {
+    for (std::size_t j = bounds.left; j <= bounds.right; ++j) {
+      const std::uint8_t kState = board.State(i, j);
+      if (kState) {
+        cells.push_back({.x = static_cast<std::int32_t>(j),
+                         .y = static_cast<std::int32_t>(i),
+                         .state = kState});
+      }
+    }
+  }
+  gol::pattern::Pattern pattern(std::move(cells));
+  pattern.Normalize();
+  return pattern;
+}
+
+void StampPattern(const gol::pattern::Pattern &pattern, std::size_t row,
+                  std::size_t col, gol::game::Symmetry symmetry,
+                  gol::game::UndoStack &undo, gol::game::Engine &board) {
+  for (const Position2D &pos : pattern.Cells()) {
+    const gol::game::RasterCell kCell = {
+        .row = pos.y + static_cast<std::int64_t>(row),
+        .col = pos.x + static_cast<std::int64_t>(col)};
+    const gol::game::RasterCells kCells =
+        gol::game::Mirror({kCell}, symmetry, board.Rows(), board.Cols());
+    undo.Save(board, kCells);
+    gol::game::Paint(kCells, pos.state, board);
+  }
+}
+
+std::string ToolName(Tool tool) {
+  switch (tool) {
+    case Tool::kPencil:
+      return "pencil";
+    case Tool::kLine:
+      return "line";
+    case Tool::kRectangle:
+      return "rectangle";
+    case Tool::kCircle:
+      return "circle";
+  }
+  return "";
+}
+
+Tool NextTool(Tool tool) {
+  switch (tool) {
+    case Tool::kPencil:
+      return Tool::kLine;
+    case Tool::kLine:
+      return Tool::kRectangle;
+    case Tool::kRectangle:
+      return Tool::kCircle;
+    case Tool::kCircle:
+      return Tool::kPencil;
+  }
+  return Tool::kPencil;
+}
+
+gol::game::Symmetry NextSymmetry(gol::game::Symmetry symmetry) {
+  switch (symmetry) {
+    case gol::game::Symmetry::kNone:
+      return gol::game::Symmetry::kHorizontal;
+    case gol::game::Symmetry::kHorizontal:
+      return gol::game::Symmetry::kVertical;
+    case gol::game::Symmetry::kVertical:
+      return gol::game::Symmetry::kFourFold;
+    case gol::game::Symmetry::kFourFold:
+      return gol::game::Symmetry::kEightFold;
+    case gol::game::Symmetry::kEightFold:
+      return gol::game::Symmetry::kNone;
+  }
+  return gol::game::Symmetry::kNone;
+}
+
+gol::game::RasterCells GhostCells(const gol::pattern::Pattern &pattern,
+                                  std::size_t row, std::size_t col,
+                                  gol::game::Symmetry symmetry,
+                                  const gol::game::Engine &board) {
+  gol::game::RasterCells cells;
+  for (const Position2D &pos : pattern.Cells()) {
+    cells.push_back({.row = pos.y + static_cast<std::int64_t>(row),
+                     .col = pos.x + static_cast<std::int64_t>(col)});
+  }
+  cells = gol::game::Mirror(cells, symmetry, board.Rows(), board.Cols());
+  std::erase_if(cells, [&board](const gol::game::RasterCell &cell) {
+    return (cell.row < 0) || (cell.col < 0) ||
+           (static_cast<std::size_t>(cell.row) >= board.Rows()) ||
+           (static_cast<std::size_t>(cell.col) >= board.Cols());
+  });
+  return cells;
+}
+
+/* Return the radius of the circle around center reaching cursor. */
+[[nodiscard]] static std::int64_t CircleRadius(gol::game::RasterCell center,
+                                               gol::game::RasterCell cursor) {
+  return std::llround(std::hypot(cursor.row - center.row,
+                                 cursor.col - center.col));
+}
+
+gol::game::RasterCells ToolCells(Tool tool, gol::game::RasterCell mark,
+                                 gol::game::RasterCell cursor,
+                                 std::int64_t brush) {
+  switch (tool) {
+    case Tool::kPencil:
+      return gol::game::RasterDisk(cursor, brush);
+    case Tool::kLine:
+      return gol::game::Thicken(gol::game::RasterLine(mark, cursor), brush);
+    case Tool::kRectangle:
+      return gol::game::Thicken(gol::game::RasterRectangle(mark, cursor),
+                                brush);
+    case Tool::kCircle:
+      /* the disk is filled, thickening it only grows its radius */
+      return gol::game::RasterDisk(mark, CircleRadius(mark, cursor) + brush);
+  }
+  return {};
+}
+
+std::optional<gol::game::CellBounds> ToolBounds(
+    Tool tool, gol::game::RasterCell mark, gol::game::RasterCell cursor,
+    std::int64_t brush, const gol::game::Engine &board) {
+  std::int64_t top = std::min(mark.row, cursor.row) - brush;
+  std::int64_t left = std::min(mark.col, cursor.col) - brush;
+  std::int64_t bottom = std::max(mark.row, cursor.row) + brush;
+  std::int64_t right = std::max(mark.col, cursor.col) + brush;
+  if (Tool::kCircle == tool) {
+    const std::int64_t kRadius = CircleRadius(mark, cursor) + brush;
+    top = mark.row - kRadius;
+    left = mark.col - kRadius;
+    bottom = mark.row + kRadius;
+    right = mark.col + kRadius;
+  }
+  const auto kRows = static_cast<std::int64_t>(board.Rows());
+  const auto kCols = static_cast<std::int64_t>(board.Cols());
+  if ((bottom < 0) || (right < 0) || (top >= kRows) || (left >= kCols)) {
+    return std::nullopt;
+  }
+  return gol::game::CellBounds{
+      .top = static_cast<std::size_t>(std::max<std::int64_t>(top, 0)),
+      .left = static_cast<std::size_t>(std::max<std::int64_t>(left, 0)),
+      .bottom = static_cast<std::size_t>(std::min(bottom, kRows - 1)),
+      .right = static_cast<std::size_t>(std::min(right, kCols - 1))};
+}
+
+}  // namespace cli
+}  // namespace gol
@@ -0,0 +1,92 @@
+#ifndef EDIT_H_
+#define EDIT_H_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "game/engine.h"
+#include "game/raster.h"
+#include "game/undo.h"
+#include "pattern/pattern.h"
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* How cells are painted in edit mode. */
+enum class Tool {
+  kPencil,    /* the brush paints under the cursor while the pen is down */
+  kLine,      /* a line from the marked cell to the cursor */
+  kRectangle, /* the outline of the rectangle from the marked cell */
+  kCircle,    /* a disk around the marked cell reaching the cursor */
+};
+
+/* A pattern offered by the insert picker, embedded patterns have no path. */
+struct PickerEntry {
+  std::string label;
+  std::string name;
+  std::string path;
+};
+
+/* Return the patterns offered by the insert picker, the embedded patterns
+ * followed by the files of the pattern directory sorted by name. */
+[[nodiscard]] std::vector<PickerEntry> PickerEntries(const Config &conf);
+
+/* Return the cells of the pattern of entry moved such that their bounding
+ * box starts at the origin. */
+[[nodiscard]] gol::pattern::Pattern LoadPickerEntry(const PickerEntry &entry);
+
+/* Return the rectangle of cells with opposite corners (row, col) and
+ * (other_row, other_col). */
+[[nodiscard]] gol::game::CellBounds Span(std::size_t row, std::size_t col,
+                                         std::size_t other_row,
+                                         std::size_t other_col);
+
+/* Return the live cells of board within bounds, moved such that their
+ * bounding box starts at the origin. */
+[[nodiscard]] gol::pattern::Pattern CopyRegion(
+    const gol::game::Engine &board, const gol::game::CellBounds &bounds);
+
+/* Bring the live cells of pattern and their mirror images to life with the
+ * origin at (row, col), the cells beyond the board edges are left out. The
+ * stamped cells are saved to undo. */
+void StampPattern(const gol::pattern::Pattern &pattern, std::size_t row,
+                  std::size_t col, gol::game::Symmetry symmetry,
+                  gol::game::UndoStack &undo, gol::game::Engine &board);
+
+/* Return the name of tool shown in the status bar. */
+[[nodiscard]] std::string ToolName(Tool tool);
+
+/* Return the tool selected after tool. */
+[[nodiscard]] Tool NextTool(Tool tool);
+
+/* Return the symmetry selected after symmetry. */
+[[nodiscard]] gol::game::Symmetry NextSymmetry(gol::game::Symmetry symmetry);
+
+/* Return the on board cells of pattern with the origin at (row, col) and
+ * their mirror images, where pattern would be stamped on board. */
+[[nodiscard]] gol::game::RasterCells GhostCells(
+    const gol::pattern::Pattern &pattern, std::size_t row, std::size_t col,
+    gol::game::Symmetry symmetry, const gol::game::Engine &board);
+
+/* Return the cells painted by tool from the marked cell to the cursor with a
+ * brush of radius brush. */
+[[nodiscard]] gol::game::RasterCells ToolCells(Tool tool,
+                                               gol::game::RasterCell mark,
+                                               gol::game::RasterCell cursor,
+                                               std::int64_t brush);
+
+/* Return the part of the board covered by the bounding box of the cells
+ * painted by tool, or nothing if it lies beyond the board edges. */
+[[nodiscard]] std::optional<gol::game::CellBounds> ToolBounds(
+    Tool tool, gol::game::RasterCell mark, gol::game::RasterCell cursor,
+    std::int64_t brush, const gol::game::Engine &board);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
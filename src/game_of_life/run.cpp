@@ -0,0 +1,428 @@
+#include "run.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <iostream>
+#include <memory>
+#include <optional>
+#include <random>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/ant.h"
+#include "game/automaton.h"
+#include "game/engine.h"
+#include "game/evolution.h"
+#include "game/factory.h"
+#include "game/hooks.h"
+#include "game/lenia.h"
+#include "game/neighborhood.h"
+#include "game/pipeline.h"
+#include "game/stats.h"
+#include "graphics/renderer.h"
+#include "graphics/screen.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+#include "pattern/align.h"
+#include "pattern/compose.h"
+#include "pattern/diagnostics.h"
+#include "pattern/export.h"
+#include "pattern/json.h"
+#include "pattern/library.h"
+#include "pattern/loader.h"
+#include "pattern/metadata.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+#include "pattern/replay.h"
+#include "pattern/session.h"
+#include "pattern/soup.h"
+#include "pattern/stats_file.h"
+
+#include "analyze.h"
+#include "bench.h"
+#include "draw_loop.h"
+
+using gol::pattern::Position2D;
+using gol::pattern::Position2DVec;
+
+namespace gol {
+namespace cli {
+
+void InitializeBoard(const Position2DVec &init_state,
+                     gol::game::Engine &board) {
+  for (const Position2D &pos : init_state) {
+    if (!gol::pattern::OnBoard(pos, board.Rows(), board.Cols())) {
+      throw std::runtime_error("position does not fit within board boundaries");
+    }
+    /* states the automaton lacks are plain live cells */
+    board.SetState(static_cast<std::size_t>(pos.y),
+                   static_cast<std::size_t>(pos.x),
+                   (pos.state < board.NumStates()) ? pos.state : 1);
+  }
+}
+
+std::uint8_t SoupColors(const Config &conf) {
+  const auto *colored =
+      dynamic_cast<const gol::game::ColoredLife *>(conf.automaton.get());
+  return (colored) ? colored->NumColors() : 1;
+}
+
+bool IsStable(const gol::game::Frame &previous,
+              const gol::game::Frame &next) noexcept {
+  return (0 == next.population) ||
+         ((previous.population == next.population) &&
+          previous.cells.SameCells(next.cells) &&
+          (previous.outside == next.outside));
+}
+
+gol::game::StepStats FirstStats(const gol::game::Frame &frame) noexcept {
+  return {.generation = frame.generation,
+          .population = frame.population,
+          .births = 0,
+          .deaths = 0};
+}
+
+/* Write each generation to stdout until the generation limit is reached or,
+ * with --stop-on-stable, the board settles. */
+static void RunStream(const Config &conf, gol::game::Engine &board) {
+  gol::game::GenerationHooks hooks;
+  std::optional<gol::pattern::StatsWriter> stats;
+  if (!conf.stats_file.empty()) {
+    stats.emplace(conf.stats_file);
+    hooks.OnGeneration([&stats](const gol::game::Frame &,
+                                const gol::game::StepStats &step) {
+      stats->Write(step);
+    });
+  }
+  /* the hooks run while board is at the generation of the frame, the cells
+   * beyond the window of unbounded engines are streamed as well */
+  hooks.OnGeneration([&conf, &board](const gol::game::Frame &frame,
+                                     const gol::game::StepStats &) {
+    const Position2DVec kCells = gol::pattern::ToPoints(board);
+    if (gol::pattern::Format::kJson == conf.stream_format) {
+      gol::pattern::WriteJsonLine(std::cout, frame.generation, kCells);
+    } else {
+      /* each generation is named such that consumers can split the stream */
+      gol::pattern::PatternMetadata metadata;
+      metadata.name = "generation " + std::to_string(frame.generation);
+      gol::pattern::Write(conf.stream_format, std::cout, kCells, &metadata);
+    }
+    std::cout.flush();
+  });
+
+  std::optional<gol::game::Frame> previous;
+  for (const gol::game::Engine &generation : gol::game::Evolve(board)) {
+    if (!std::cout) {
+      return;
+    }
+    gol::game::Frame frame(generation);
+    hooks.Notify(frame, (previous) ? gol::game::CompareFrames(*previous, frame)
+                                   : FirstStats(frame));
+
+    if (conf.max_generations && (frame.generation >= conf.max_generations)) {
+      return;
+    } else if (conf.stop_on_stable && previous && IsStable(*previous, frame)) {
+      return;
+    }
+    previous = std::move(frame);
+  }
+}
+
+void Run(Config conf) {
+  /* a resumed run takes its board, rule and speed from the session, the
+   * saved cells keep their position */
+  std::optional<gol::pattern::Session> session;
+  if (!conf.resume_file.empty()) {
+    session = gol::pattern::LoadSession(conf.resume_file);
+    conf.board_width = session->cols;
+    conf.board_height = session->rows;
+    conf.wrap = session->wrap;
+    conf.update_rate_ms = session->update_rate_ms;
+    conf.alignment = {};
+    if (!conf.rule_given && !session->rule.empty()) {
+      SetRule(session->rule, conf);
+    }
+    if (conf.wrap && !gol::game::SupportsWrap(conf.engine_type)) {
+      PrintErrorAndExit("the selected engine cannot resume a session "
+                        "wrapping around the board edges");
+    }
+  }
+
+  /* patterns are loaded before the screen is initialized such that parse
+   * errors and warnings are printed to the terminal */
+  gol::pattern::Diagnostics diagnostics(!conf.lenient);
+  Position2DVec init_cells;
+  gol::pattern::PatternMetadata metadata;
+  if (!conf.pattern.empty()) {
+    init_cells = gol::pattern::FindPattern(conf.pattern);
+    metadata.name = conf.pattern;
+  } else if (!conf.init_state.empty()) {
+    init_cells = gol::pattern::LoadPattern(conf.init_state, &diagnostics,
+                                           &metadata);
+  } else if (session) {
+    init_cells = session->cells;
+    metadata.name = conf.resume_file;
+  }
+  conf.title = gol::pattern::Title(metadata);
+
+  /* patterns meant for another rule run under it unless --rule is given */
+  if (!metadata.rule.empty() && !conf.rule_given && conf.versus.empty()) {
+    try {
+      SetRule(metadata.rule, conf);
+    } catch (const std::invalid_argument &e) {
+      diagnostics.Add({.source = conf.init_state,
+                       .line = 0,
+                       .reason = "ignoring unsupported rule '" +
+                                 metadata.rule + "'"});
+    }
+  }
+
+  /* reloading the board leaves ants, Lenia values and the rows of
+   * elementary automata behind */
+  if (conf.watch && (gol::game::Simulation::kLife != conf.simulation)) {
+    PrintErrorAndExit("--watch only supports Life-like rules");
+  } else if (conf.analyze &&
+             (gol::game::Simulation::kLife != conf.simulation)) {
+    PrintErrorAndExit("analyze only supports Life-like rules");
+  }
+
+  /* placed pattern files may also be found in the pattern directory, the
+   * embedded patterns take precedence as they do in Compose() */
+  const std::vector<std::string> kNames = gol::pattern::PatternNames();
+  for (gol::pattern::Placement &placement : conf.placements) {
+    if (std::find(kNames.begin(), kNames.end(), placement.source) ==
+        kNames.end()) {
+      placement.source = ResolvePatternPath(placement.source, conf);
+    }
+  }
+  const Position2DVec kPlacedCells =
+      gol::pattern::Compose(conf.placements, &diagnostics);
+  init_cells.insert(init_cells.end(), kPlacedCells.begin(), kPlacedCells.end());
+
+  /* a battle pits the initial state against the versus pattern, the two
+   * face each other across a gap */
+  if (!conf.versus.empty()) {
+    const std::int32_t kBattleGap = 8;
+    const bool kEmbedded =
+        std::find(kNames.begin(), kNames.end(), conf.versus) != kNames.end();
+    const Position2DVec kBlue =
+        (kEmbedded) ? gol::pattern::FindPattern(conf.versus)
+                    : gol::pattern::LoadPattern(
+                          ResolvePatternPath(conf.versus, conf), &diagnostics);
+    init_cells = gol::pattern::FaceOff(init_cells, kBlue, kBattleGap);
+    SetRule("immigration", conf);
+  }
+
+  for (const gol::pattern::ParseDiagnostic &warning : diagnostics.Warnings()) {
+    std::cerr << "warning: " << gol::pattern::ToString(warning) << std::endl;
+  }
+
+  /* a replay's board is sized by the recording rather than the screen */
+  std::unique_ptr<gol::game::Engine> board;
+  if (!conf.replay_file.empty()) {
+    board = std::make_unique<gol::pattern::ReplayBoard>(conf.replay_file);
+  }
+
+  /* the states of multi-state automata, the ants and the Lenia values are
+   * drawn in their own colors */
+  std::vector<std::uint32_t> state_colors;
+  if (conf.automaton) {
+    state_colors = conf.automaton->Colors();
+  } else if (gol::game::Simulation::kAnt == conf.simulation) {
+    state_colors = gol::game::AntBoard::Colors();
+  } else if (gol::game::Simulation::kLenia == conf.simulation) {
+    state_colors = gol::game::LeniaBoard::Colors();
+  }
+  for (const std::uint32_t kRgb : state_colors) {
+    conf.theme.states.push_back(gol::graphics::NearestColor(
+        {.red = static_cast<std::uint8_t>(kRgb >> 16),
+         .green = static_cast<std::uint8_t>(kRgb >> 8),
+         .blue = static_cast<std::uint8_t>(kRgb)}));
+  }
+
+  /* analyzed patterns are centered, unless given a size the board leaves
+   * them room to move at half the speed of light for the whole run */
+  if (conf.analyze) {
+    conf.alignment = {.anchor = gol::pattern::Anchor::kCenter, .offset = {}};
+  }
+  if (conf.analyze && !conf.board_width) {
+    const gol::pattern::Pattern kPattern(init_cells);
+    const std::size_t kMargin = AnalyzedGenerations(conf) / 2 + 2;
+    conf.board_width = kPattern.Width() + (2 * kMargin);
+    conf.board_height = kPattern.Height() + (2 * kMargin);
+  }
+
+  /* ncurses screen initialization, headless runs leave the terminal alone
+   * and are sized as a typical terminal. The guard restores the terminal
+   * however the program ends, errors are thus printed to a usable shell */
+  const bool kHeadless = conf.stream || conf.bench || conf.analyze;
+  gol::graphics::ScreenDimension dim = {.width = 80, .height = 24};
+  std::optional<gol::graphics::ScreenGuard> screen;
+  std::unique_ptr<gol::graphics::Renderer> renderer;
+  if (kHeadless) {
+    renderer = std::make_unique<gol::graphics::NullRenderer>();
+  } else {
+    /* hexagonal rules are drawn on sheared rows unless another mode than
+     * the default was picked */
+    if ((gol::graphics::RenderMode::kBlock == conf.render_mode) &&
+        !conf.automaton &&
+        (gol::game::Simulation::kLife == conf.simulation) &&
+        (gol::game::NeighborhoodShape::kHexagonal ==
+         conf.rule.GetNeighborhood().Shape())) {
+      conf.render_mode = gol::graphics::RenderMode::kHex;
+    }
+    dim = screen.emplace().Dimension();
+    renderer = gol::graphics::MakeRenderer(conf.render_mode, conf.cell_style);
+    renderer->SetTheme(conf.theme);
+    renderer->SetGrid(conf.grid);
+    renderer->SetShading(conf.shading);
+  }
+
+  /* construct the game board, the height is reduced to avoid accidentally
+   * bumping into the quit message and status bar that are displayed at the
+   * bottom of the screen */
+  const int kHudRows = (kHeadless) ? 0 : (conf.show_status) ? 2 : 1;
+  const int kBoardRows = dim.height - kHudRows;
+  const gol::graphics::ScreenPosition kDots = renderer->DotsPerCharacter();
+  const std::size_t kScreenRows = kBoardRows * kDots.row;
+  const std::size_t kScreenCols =
+      ViewportWidth(dim.width, kBoardRows, *renderer) * kDots.col;
+  const bool kFixedSize = (0 != conf.board_width);
+  const std::size_t kRows = (kFixedSize) ? conf.board_height : kScreenRows;
+  const std::size_t kCols = (kFixedSize) ? conf.board_width : kScreenCols;
+
+  /* the loaded and placed cells are aligned as a whole, unless cropped or
+   * given a size the board grows past the screen to hold all of them */
+  const gol::pattern::Pattern kInitPattern(
+      gol::pattern::PlacePattern(init_cells, conf.alignment, kRows, kCols));
+  const Position2D kPatternEnd = kInitPattern.Max();
+  const bool kScale =
+      !kInitPattern.Cells().empty() && (ScaleMode::kCrop != conf.scale_mode);
+  const std::size_t kPatternRows =
+      (kScale) ? static_cast<std::size_t>(std::max(0, kPatternEnd.y + 1)) : 0;
+  const std::size_t kPatternCols =
+      (kScale) ? static_cast<std::size_t>(std::max(0, kPatternEnd.x + 1)) : 0;
+  /* noisy runs are reproduced by passing the shown seed */
+  if ((conf.random || (conf.noise > 0.0)) && !conf.seed) {
+    conf.seed = std::random_device()();
+  }
+  if (!board) {
+    board = gol::game::MakeEngine(
+        conf.engine_type,
+        {.rows = (kFixedSize) ? kRows : std::max(kRows, kPatternRows),
+         .cols = (kFixedSize) ? kCols : std::max(kCols, kPatternCols),
+         .rule = conf.rule,
+         .wrap = conf.wrap,
+         .num_threads = conf.num_threads,
+         .automaton = conf.automaton,
+         .simulation = conf.simulation,
+         .elementary_rule = conf.elementary_rule,
+         .noise = conf.noise,
+         .noise_seed = conf.seed.value_or(0)});
+  }
+  if (conf.random) {
+    /* the seed is shown so that an interesting soup can be reproduced */
+    InitializeBoard(gol::pattern::RandomSoup(board->Rows(), board->Cols(),
+                                             conf.density, *conf.seed,
+                                             SoupColors(conf)),
+                    *board);
+  }
+
+  /* placed patterns are added on top of the initial state */
+  Position2DVec visible_cells = kInitPattern.Cells();
+  if (ScaleMode::kCrop == conf.scale_mode) {
+    std::erase_if(visible_cells, [&board](const Position2D &pos) {
+      return !gol::pattern::OnBoard(pos, board->Rows(), board->Cols());
+    });
+  }
+  InitializeBoard(visible_cells, *board);
+  if (session) {
+    board->SetGeneration(session->generation);
+  }
+
+  /* ants are spread evenly along the middle row unless the initial state
+   * placed some */
+  auto *ants = dynamic_cast<gol::game::AntBoard *>(board.get());
+  if (ants && ants->Ants().empty()) {
+    for (std::size_t i = 0; i < conf.num_ants; ++i) {
+      ants->AddAnt({.row = ants->Rows() / 2,
+                    .col = ((i + 1) * ants->Cols()) / (conf.num_ants + 1),
+                    .heading = gol::game::AntBoard::Heading::kNorth});
+    }
+  }
+
+  /* elementary automata grow from a single cell unless given a first row */
+  if ((gol::game::Simulation::kElementary == conf.simulation) &&
+      (0 == board->Population())) {
+    board->SetCell(0, board->Cols() / 2, true);
+  }
+
+  /* Lenia starts from a square of random values in the middle of the board
+   * unless given an initial state */
+  auto *lenia = dynamic_cast<gol::game::LeniaBoard *>(board.get());
+  if (lenia && (0 == lenia->Population())) {
+    if (!conf.seed) {
+      conf.seed = std::random_device()();
+    }
+    std::mt19937_64 rng(*conf.seed);
+    std::uniform_real_distribution<float> value(0.0f, 1.0f);
+    const std::size_t kSize = std::min(
+        {lenia->Rows(), lenia->Cols(),
+         static_cast<std::size_t>(4 * lenia->GetParameters().radius)});
+    const std::size_t kTop = (lenia->Rows() - kSize) / 2;
+    const std::size_t kLeft = (lenia->Cols() - kSize) / 2;
+    for (std::size_t i = kTop; i < kTop + kSize; ++i) {
+      for (std::size_t j = kLeft; j < kLeft + kSize; ++j) {
+        lenia->SetValue(i, j, value(rng));
+      }
+    }
+  }
+
+  /* the run starts after the skipped generations */
+  board->Advance(conf.skip);
+
+  if (conf.stream) {
+    RunStream(conf, *board);
+    return;
+  } else if (conf.bench) {
+    RunBench(conf, *board);
+    return;
+  } else if (conf.analyze) {
+    RunAnalyze(conf, *board);
+    return;
+  }
+
+  /* repeatedly draw the board until the user commands exit */
+  gol::graphics::Viewport viewport(
+      kBoardRows, ViewportWidth(dim.width, kBoardRows, *renderer),
+      board->Rows(), board->Cols(), kDots.row, kDots.col);
+  if (ScaleMode::kFit == conf.scale_mode) {
+    viewport.ZoomToFit(kPatternRows, kPatternCols);
+  } else if ((ScaleMode::kFill == conf.scale_mode) && kScale) {
+    /* the bounding box of the pattern fills the screen, the viewport is
+     * centered on it in case it does not start at the origin */
+    const Position2D kPatternStart = kInitPattern.Min();
+    viewport.ZoomToFill(kInitPattern.Height(), kInitPattern.Width());
+    viewport.CenterOn(
+        static_cast<std::size_t>(
+            std::max(0, kPatternStart.y + kPatternEnd.y + 1)) / 2,
+        static_cast<std::size_t>(
+            std::max(0, kPatternStart.x + kPatternEnd.x + 1)) / 2);
+  }
+  if (session) {
+    viewport.SetZoom(session->zoom, session->cell_size);
+    viewport.MoveTo(session->view_row, session->view_col);
+  }
+  RunDrawLoop(dim, conf, viewport, *board, *renderer);
+
+  /* cleanup the renderer's terminal resources followed by ncurses */
+  renderer.reset();
+  gol::graphics::DisableInputDelay();
+  screen.reset();
+}
+
+}  // namespace cli
+}  // namespace gol
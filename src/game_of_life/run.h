@@ -0,0 +1,41 @@
+#ifndef RUN_H_
+#define RUN_H_
+
+#include <cstdint>
+
+#include "game/engine.h"
+#include "game/pipeline.h"
+#include "game/stats.h"
+#include "pattern/position.h"
+
+#include "config.h"
+
+namespace gol {
+namespace cli {
+
+/* Set the cells of board to the states of init_state. Throws
+ * std::runtime_error when a cell lies beyond the board edges. */
+void InitializeBoard(const gol::pattern::Position2DVec &init_state,
+                     gol::game::Engine &board);
+
+/* Return the number of colors of the live cells of random soups. */
+std::uint8_t SoupColors(const Config &conf);
+
+/* Return true if the board died out or did not change between the previous
+ * and next frames. */
+[[nodiscard]] bool IsStable(const gol::game::Frame &previous,
+                            const gol::game::Frame &next) noexcept;
+
+/* Return the statistics of frame, the first generation written to a
+ * statistics file. */
+[[nodiscard]] gol::game::StepStats FirstStats(
+    const gol::game::Frame &frame) noexcept;
+
+/* Set up the board of conf and run it, drawn on the screen unless
+ * streamed, benchmarked or analyzed. */
+void Run(Config conf);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
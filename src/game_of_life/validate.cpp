@@ -0,0 +1,53 @@
+#include "validate.h"
+
+#include <cstdint>
+#include <exception>
+#include <iostream>
+#include <set>
+#include <string>
+#include <utility>
+
+#include "pattern/diagnostics.h"
+#include "pattern/loader.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+using gol::pattern::Position2D;
+using gol::pattern::Position2DVec;
+
+namespace gol {
+namespace cli {
+
+bool ValidatePattern(const std::string &filename) {
+  /* lenient parsing goes on past the first malformed line */
+  gol::pattern::Diagnostics diagnostics(false);
+  Position2DVec cells;
+  try {
+    cells = gol::pattern::LoadPattern(filename, &diagnostics);
+  } catch (const std::exception &e) {
+    std::cerr << "error: " << e.what() << std::endl;
+    return false;
+  }
+  for (const gol::pattern::ParseDiagnostic &problem :
+       diagnostics.Warnings()) {
+    std::cerr << "error: " << gol::pattern::ToString(problem) << std::endl;
+  }
+
+  /* cells listed more than once count once */
+  std::set<std::pair<std::int32_t, std::int32_t>> positions;
+  for (const Position2D &pos : cells) {
+    positions.insert({pos.y, pos.x});
+  }
+  const gol::pattern::Pattern kPattern(std::move(cells));
+  std::cout << filename << ": population " << positions.size();
+  if (!positions.empty()) {
+    std::cout << ", bounding box " << kPattern.Width() << "x"
+              << kPattern.Height() << " at (" << kPattern.Min().x << ", "
+              << kPattern.Min().y << ")";
+  }
+  std::cout << std::endl;
+  return diagnostics.Warnings().empty();
+}
+
+}  // namespace cli
+}  // namespace gol
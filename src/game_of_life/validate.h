@@ -0,0 +1,18 @@
+#ifndef VALIDATE_H_
+#define VALIDATE_H_
+
+#include <string>
+
+namespace gol {
+namespace cli {
+
+/* Parse the pattern file filename, printing its malformed lines to stderr
+ * and the population and bounding box of the parsed cells to stdout. The box
+ * is printed as WxH at the (x, y) position of its top left corner. Returns
+ * false if the file cannot be read or is malformed. */
+[[nodiscard]] bool ValidatePattern(const std::string &filename);
+
+}  // namespace cli
+}  // namespace gol
+
+#endif
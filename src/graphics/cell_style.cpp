@@ -0,0 +1,202 @@
+#include "graphics/cell_style.h"
+
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+[[nodiscard]] static std::uint8_t AgeStyle(std::uint32_t age) noexcept {
+  if (age <= 1) {
+    return kNewborn;
+  } else if (age <= 4) {
+    return kYoung;
+  } else if (age <= 19) {
+    return kMature;
+  }
+  return kOld;
+}
+
+[[nodiscard]] static std::uint8_t TrailStyle(std::uint8_t dead_for) noexcept {
+  if (dead_for <= 2) {
+    return kTrail;
+  } else if (dead_for <= 4) {
+    return kFadedTrail;
+  } else if (dead_for <= 6) {
+    return kFaintTrail;
+  }
+  return kDead;
+}
+
+std::uint8_t BlockStyle(const game::Engine& board, const game::CellAges* ages,
+                        std::size_t row, std::size_t col, std::size_t size,
+                        const Shading& shading) noexcept {
+  const std::size_t kLastRow = std::min(row + size, board.Rows());
+  const std::size_t kLastCol = std::min(col + size, board.Cols());
+  if (board.NumStates() > 2) {
+    std::uint8_t min_state = 0;
+    for (std::size_t i = row; i < kLastRow; ++i) {
+      for (std::size_t j = col; j < kLastCol; ++j) {
+        const std::uint8_t kCellState = board.State(i, j);
+        if (kCellState && (!min_state || (kCellState < min_state))) {
+          min_state = kCellState;
+        }
+      }
+    }
+    if (!min_state) {
+      return kDead;
+    }
+    return static_cast<std::uint8_t>(
+        std::min<int>(kState + min_state - 1, UINT8_MAX));
+  }
+
+  bool alive = false;
+  std::uint32_t max_age = 0;
+  std::uint8_t min_dead_for = game::CellAges::kLongDead;
+  for (std::size_t i = row; i < kLastRow; ++i) {
+    for (std::size_t j = col; j < kLastCol; ++j) {
+      if (!board.IsAlive(i, j)) {
+        if (ages) {
+          min_dead_for = std::min(min_dead_for, ages->DeadFor(i, j));
+        }
+        continue;
+      }
+      if (!ages || (!shading.ages && !shading.diff)) {
+        return kLive;
+      }
+      alive = true;
+      max_age = std::max(max_age, ages->Age(i, j));
+    }
+  }
+  if (shading.diff && alive) {
+    return (max_age <= 1) ? kBorn : kSurvived;
+  } else if (shading.diff) {
+    return (1 == min_dead_for) ? kDied : kDead;
+  } else if (!alive && shading.trails) {
+    return TrailStyle(min_dead_for);
+  } else if (!alive) {
+    return kDead;
+  }
+  return AgeStyle(max_age);
+}
+
+std::vector<std::uint8_t> CellStyles(const game::Engine& board,
+                                     const game::CellAges* ages,
+                                     const Shading& shading) {
+  std::vector<std::uint8_t> styles(board.Rows() * board.Cols(), kDead);
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    for (std::size_t j = 0; j < board.Cols(); ++j) {
+      styles[(i * board.Cols()) + j] =
+          BlockStyle(board, ages, i, j, 1, shading);
+    }
+  }
+  return styles;
+}
+
+std::array<Color, 3> TrailColors(const Theme& theme) noexcept {
+  /* the trails keep the hue of live cells at a half, a quarter and an eighth
+   * of their brightness */
+  const Rgb kLive = ToRgb(theme.live);
+  std::array<Color, 3> colors = {};
+  for (std::size_t i = 0; i < colors.size(); ++i) {
+    const auto kDim = [i](std::uint8_t channel) {
+      return static_cast<std::uint8_t>(channel >> (i + 1));
+    };
+    colors[i] = NearestColor({.red = kDim(kLive.red),
+                              .green = kDim(kLive.green),
+                              .blue = kDim(kLive.blue)});
+  }
+  return colors;
+}
+
+std::vector<Rgb> StylePalette(const Theme& theme) {
+  /* dead cells are black, the cursor is red, grid lines are dark gray, graph
+   * bars light gray and selected cells dark blue */
+  const Color kCursorColor = 1;
+  const Color kGridColor = 239;
+  const Color kGraphColor = 250;
+  const Color kSelectionColor = 18;
+  std::vector<Rgb> palette = {
+      {},
+      ToRgb(theme.live),
+      ToRgb(theme.ages[0]),
+      ToRgb(theme.ages[1]),
+      ToRgb(theme.ages[2]),
+      ToRgb(theme.ages[3]),
+      ToRgb(kCursorColor),
+      ToRgb(kGridColor),
+      ToRgb(kGraphColor),
+      ToRgb(kSelectionColor),
+  };
+  for (const Color kColor : TrailColors(theme)) {
+    palette.push_back(ToRgb(kColor));
+  }
+  for (const Color kColor : kDiffColors) {
+    palette.push_back(ToRgb(kColor));
+  }
+  /* states past the last palette index share its color */
+  for (const Color kColor : theme.states) {
+    if (palette.size() > UINT8_MAX) {
+      break;
+    }
+    palette.push_back(ToRgb(kColor));
+  }
+  return palette;
+}
+
+wchar_t ParseCellChar(const std::string& text) {
+  const auto kFail = [&text]() {
+    throw std::invalid_argument("invalid cell character -> " + text);
+  };
+  if (text.empty()) {
+    kFail();
+  }
+
+  /* decode a single UTF-8 sequence, the lead byte gives its length */
+  const auto kLead = static_cast<unsigned char>(text[0]);
+  std::size_t length = 1;
+  std::uint32_t code = kLead;
+  if (kLead >= 0xF0) {
+    length = 4;
+    code = kLead & 0x07;
+  } else if (kLead >= 0xE0) {
+    length = 3;
+    code = kLead & 0x0F;
+  } else if (kLead >= 0xC0) {
+    length = 2;
+    code = kLead & 0x1F;
+  } else if (kLead >= 0x80) {
+    kFail();
+  }
+  if (text.size() != length) {
+    kFail();
+  }
+  for (std::size_t i = 1; i < length; ++i) {
+    const auto kByte = static_cast<unsigned char>(text[i]);
+    if (0x80 != (kByte & 0xC0)) {
+      kFail();
+    }
+    code = (code << 6) | (kByte & 0x3F);
+  }
+
+  /* reject control characters, overlong encodings and surrogates */
+  const std::uint32_t kMinCode[] = {0, 0, 0x80, 0x800, 0x10000};
+  if ((code < 0x20) || ((code >= 0x7F) && (code < 0xA0)) ||
+      (code < kMinCode[length]) || (code > 0x10FFFF) ||
+      ((code >= 0xD800) && (code < 0xE000))) {
+    kFail();
+  }
+  return static_cast<wchar_t>(code);
+}
+
+}  // namespace graphics
+}  // namespace gol
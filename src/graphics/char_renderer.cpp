@@ -0,0 +1,425 @@
+#include "graphics/char_renderer.h"
+
+#include <curses.h>
+#include <ncurses.h>
+
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/graph.h"
+#include "graphics/grid.h"
+#include "graphics/screen.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/* Bit of the Unicode braille pattern raising the dot at [row][col]. */
+static constexpr std::array<std::array<std::uint8_t, 2>, 4> kBrailleDots = {{
+    {0x01, 0x08},
+    {0x02, 0x10},
+    {0x04, 0x20},
+    {0x40, 0x80},
+}};
+static constexpr wchar_t kBrailleBlank = 0x2800;
+
+/* Grid overlay characters, the middle dot is replaced by '.' for ASCII cell
+ * styles. Labels are made of digits. */
+static constexpr wchar_t kGridDot = 0x00B7;
+static constexpr wchar_t kGridCross = L'+';
+
+/* Graph bars are stacks of the Unicode lower eighth blocks following
+ * kGraphBlank, or of '|' for ASCII cell styles. Characters below the graph or
+ * highlighted by the selection are marked with kRedrawMark in the drawn
+ * overlay to redraw them. */
+static constexpr wchar_t kGraphBlank = 0x2580;
+static constexpr std::size_t kGraphLevels = 8;
+static constexpr wchar_t kRedrawMark = 0xffff;
+
+[[nodiscard]] static short ToTerminalColor(Color color) noexcept {
+  if (COLORS >= 256) {
+    return color;
+  }
+
+  /* approximate the color using the 8 standard colors (or the 16 standard and
+   * bright colors when available) */
+  const short kNumStandard = (COLORS >= 16) ? 16 : 8;
+  if (color < 16) {
+    return color % kNumStandard;
+  } else if (color >= 232) {
+    const short kGrayLevel = color - 232;
+    return ((kGrayLevel < 12) && (kNumStandard > 8)) ? 8 : COLOR_WHITE;
+  }
+
+  /* colors 16-231 encode a red, green, and blue component in the range
+   * [0, 5], keep the components that are at least half of the brightest */
+  const short kCube = color - 16;
+  const short kRed = kCube / 36;
+  const short kGreen = (kCube / 6) % 6;
+  const short kBlue = kCube % 6;
+  const short kMax = std::max({kRed, kGreen, kBlue});
+  if (0 == kMax) {
+    return COLOR_BLACK;
+  }
+  short basic = 0;
+  if ((2 * kRed) >= kMax) {
+    basic |= COLOR_RED;
+  }
+  if ((2 * kGreen) >= kMax) {
+    basic |= COLOR_GREEN;
+  }
+  if ((2 * kBlue) >= kMax) {
+    basic |= COLOR_BLUE;
+  }
+  return basic;
+}
+
+CharRenderer::CharRenderer(bool braille, const CellStyle& style,
+                           bool hex) noexcept
+    : braille_(braille), hex_(hex && !braille), style_(style) {
+  SetTheme(Theme());
+}
+
+ScreenPosition CharRenderer::DotsPerCharacter() const noexcept {
+  if (braille_) {
+    return {.row = kBrailleDots.size(), .col = kBrailleDots[0].size()};
+  }
+  return {.row = 1, .col = 1};
+}
+
+void CharRenderer::SetTheme(const Theme& theme) noexcept {
+  if (!has_colors()) {
+    return;
+  }
+  init_pair(kLive, ToTerminalColor(theme.live), -1);
+  init_pair(kNewborn, ToTerminalColor(theme.ages[0]), -1);
+  init_pair(kYoung, ToTerminalColor(theme.ages[1]), -1);
+  init_pair(kMature, ToTerminalColor(theme.ages[2]), -1);
+  init_pair(kOld, ToTerminalColor(theme.ages[3]), -1);
+  const std::array<Color, 3> kTrails = TrailColors(theme);
+  for (std::size_t i = 0; i < kTrails.size(); ++i) {
+    init_pair(static_cast<short>(kTrail + i), ToTerminalColor(kTrails[i]), -1);
+  }
+  for (std::size_t i = 0; i < kDiffColors.size(); ++i) {
+    init_pair(static_cast<short>(kBorn + i), ToTerminalColor(kDiffColors[i]),
+              -1);
+  }
+  for (std::size_t i = 0; i < theme.states.size(); ++i) {
+    const std::size_t kPair = kState + i;
+    if (kPair >= std::min<std::size_t>(COLOR_PAIRS, UINT8_MAX + 1)) {
+      break;
+    }
+    init_pair(static_cast<short>(kPair), ToTerminalColor(theme.states[i]), -1);
+  }
+  Invalidate();
+}
+
+void CharRenderer::SetGrid(const GridOverlay& grid) noexcept {
+  grid_ = grid;
+  Invalidate();
+}
+
+void CharRenderer::SetShading(const Shading& shading) noexcept {
+  shading_ = shading;
+  Invalidate();
+}
+
+/* Return the code of the character at screen position (row, col). The low byte
+ * holds the CellShade of the character. In braille mode, the high byte holds
+ * the raised dots and the style is that of the oldest dot. */
+std::uint16_t CharRenderer::CharCode(const game::Engine& board,
+                                     const game::CellAges* ages,
+                                     const Viewport& viewport, std::size_t row,
+                                     std::size_t col) const noexcept {
+  const std::size_t kZoom = viewport.Zoom();
+  const std::size_t kDotRow = row * viewport.DotRows();
+  const std::size_t kDotCol = col * viewport.DotCols();
+  if (!braille_) {
+    return BlockStyle(board, ages, viewport.BoardRow(kDotRow),
+                      viewport.BoardCol(kDotCol), kZoom, shading_);
+  }
+
+  /* the dots of dead cells, trails and deaths, are only raised in characters
+   * without live dots and take the style of the most recent death */
+  std::uint8_t dots = 0;
+  std::uint8_t style = kDead;
+  std::uint8_t dead_dots = 0;
+  std::uint8_t dead_style = kDead;
+  for (std::size_t i = 0; i < kBrailleDots.size(); ++i) {
+    for (std::size_t j = 0; j < kBrailleDots[i].size(); ++j) {
+      const std::uint8_t kDotStyle =
+          BlockStyle(board, ages, viewport.BoardRow(kDotRow + i),
+                     viewport.BoardCol(kDotCol + j), kZoom, shading_);
+      if (((kDotStyle >= kTrail) && (kDotStyle <= kFaintTrail)) ||
+          (kDied == kDotStyle)) {
+        dead_dots |= kBrailleDots[i][j];
+        dead_style = (kDead == dead_style) ? kDotStyle
+                                           : std::min(dead_style, kDotStyle);
+      } else if (kDead != kDotStyle) {
+        dots |= kBrailleDots[i][j];
+        style = std::max(style, kDotStyle);
+      }
+    }
+  }
+  if (!dots && dead_dots) {
+    return static_cast<std::uint16_t>((dead_dots << 8) | dead_style);
+  }
+  return static_cast<std::uint16_t>((dots << 8) | style);
+}
+
+/* Return the overlay character of each screen character in row major order,
+ * '\0' where the cell below shows through. */
+std::vector<wchar_t> CharRenderer::ComposeOverlay(
+    const game::Engine& board, const Viewport& viewport) const {
+  const std::size_t kHeight = viewport.Height();
+  const std::size_t kWidth = viewport.Width();
+  std::vector<wchar_t> overlay(kHeight * kWidth, L'\0');
+  if (0 == grid_.spacing) {
+    return overlay;
+  }
+
+  std::vector<std::optional<std::size_t>> rows(kHeight);
+  for (std::size_t i = 0; i < kHeight; ++i) {
+    rows[i] = GridLine(grid_, viewport, GridAxis::kRow, i * viewport.DotRows(),
+                       viewport.DotRows(), board.Rows());
+  }
+  std::vector<std::optional<std::size_t>> cols(kWidth);
+  for (std::size_t j = 0; j < kWidth; ++j) {
+    cols[j] = GridLine(grid_, viewport, GridAxis::kCol, j * viewport.DotCols(),
+                       viewport.DotCols(), board.Cols());
+  }
+
+  /* lines stop at the board edges when the board is smaller than the screen */
+  const wchar_t kDot = (braille_ || (style_.alive > 0x7f)) ? kGridDot : L'.';
+  for (std::size_t i = 0; i < kHeight; ++i) {
+    for (std::size_t j = 0; j < kWidth; ++j) {
+      if ((viewport.BoardRow(i * viewport.DotRows()) >= board.Rows()) ||
+          (viewport.BoardCol(j * viewport.DotCols()) >= board.Cols())) {
+        continue;
+      } else if (rows[i] && cols[j]) {
+        overlay[(i * kWidth) + j] = kGridCross;
+      } else if (rows[i] || cols[j]) {
+        overlay[(i * kWidth) + j] = kDot;
+      }
+    }
+  }
+  if (!grid_.labels) {
+    return overlay;
+  }
+
+  /* column labels run along the top row and row labels along the left edge,
+   * labels that would touch the previous column label are skipped */
+  std::size_t free_col = 0;
+  for (std::size_t j = 0; j < kWidth; ++j) {
+    const std::string kLabel = (cols[j]) ? std::to_string(*cols[j]) : "";
+    if (kLabel.empty() || (j < free_col) || ((j + kLabel.size()) > kWidth)) {
+      continue;
+    }
+    std::copy(kLabel.begin(), kLabel.end(), overlay.begin() + j);
+    free_col = j + kLabel.size() + 1;
+  }
+  for (std::size_t i = 1; i < kHeight; ++i) {
+    const std::string kLabel =
+        (rows[i]) ? std::to_string(*rows[i]).substr(0, kWidth) : "";
+    std::copy(kLabel.begin(), kLabel.end(),
+              overlay.begin() + (i * kWidth));
+  }
+  return overlay;
+}
+
+/* Return the screen column of the first character of the viewport character
+ * at (row, col). Hexagonal cells span two characters, each row is shifted
+ * left by one character from the row above such that a cell touches its
+ * northwest and southeast neighbors. */
+std::size_t CharRenderer::ScreenCol(const Viewport& viewport, std::size_t row,
+                                    std::size_t col) const noexcept {
+  if (!hex_) {
+    return col;
+  }
+  return (2 * col) + (viewport.Height() - 1 - row);
+}
+
+void CharRenderer::DrawChar(std::size_t row, std::size_t col,
+                            std::uint16_t code,
+                            wchar_t overlay) const noexcept {
+  const std::uint8_t kStyle = code & 0xff;
+  const short kPair = (has_colors()) ? kStyle : 0;
+  const bool kLabel = (overlay >= L'0') && (overlay <= L'9');
+  wchar_t glyph[] = {style_.alive, L'\0'};
+  if (braille_) {
+    glyph[0] = (kDead == kStyle)
+                   ? L' '
+                   : static_cast<wchar_t>(kBrailleBlank + (code >> 8));
+  } else if (kDead == kStyle) {
+    glyph[0] = style_.dead;
+  }
+  cchar_t cell;
+  setcchar(&cell, glyph, A_NORMAL, (kDead == kStyle) ? 0 : kPair, nullptr);
+
+  /* the overlay of a hexagonal cell covers its first character */
+  if (hex_) {
+    mvadd_wch(row, col + 1, &cell);
+  }
+  if (kLabel || ((L'\0' != overlay) && (kDead == kStyle))) {
+    /* grid lines are dimmed and only drawn over dead cells */
+    glyph[0] = overlay;
+    cchar_t mark;
+    setcchar(&mark, glyph, (kLabel) ? A_BOLD : A_DIM, 0, nullptr);
+    mvadd_wch(row, col, &mark);
+    return;
+  }
+  mvadd_wch(row, col, &cell);
+}
+
+void CharRenderer::DrawBoard(const game::Engine& board,
+                             const Viewport& viewport,
+                             const game::CellAges* ages) noexcept {
+  /* only characters that changed since the last frame are redrawn unless the
+   * screen was cleared, resized, or the viewport dimensions changed */
+  ScreenDimension dim;
+  getmaxyx(stdscr, dim.height, dim.width);
+  const std::size_t kNumCells = viewport.Height() * viewport.Width();
+  const bool kFullRedraw = (drawn_cells_.size() != kNumCells) ||
+                           (drawn_cols_ != viewport.Width()) ||
+                           (dim.width != drawn_dim_.width) ||
+                           (dim.height != drawn_dim_.height);
+  if (kFullRedraw) {
+    drawn_dim_ = dim;
+    drawn_cols_ = viewport.Width();
+    erase();
+    drawn_cells_.assign(kNumCells, kDead);
+    drawn_overlay_.assign(kNumCells, L'\0');
+  }
+
+  if (!has_colors()) {
+    ages = nullptr;
+  }
+
+  /* the overlay is composited above the cell layer */
+  const std::vector<wchar_t> kOverlay = ComposeOverlay(board, viewport);
+  std::size_t index = 0;
+  for (std::size_t i = 0; i < viewport.Height(); ++i) {
+    for (std::size_t j = 0; j < viewport.Width(); ++j, ++index) {
+      const std::uint16_t kCode = CharCode(board, ages, viewport, i, j);
+      if (kFullRedraw || (kCode != drawn_cells_[index]) ||
+          (kOverlay[index] != drawn_overlay_[index]) ||
+          (drawn_cursor_ == index)) {
+        DrawChar(i, ScreenCol(viewport, i, j), kCode, kOverlay[index]);
+        drawn_cells_[index] = kCode;
+        drawn_overlay_[index] = kOverlay[index];
+      }
+    }
+  }
+  drawn_cursor_.reset();
+}
+
+void CharRenderer::DrawCursor(std::size_t row, std::size_t col,
+                              const Viewport& viewport) noexcept {
+  const std::optional<ScreenPosition> kPos = viewport.ToScreen(row, col);
+  if (!kPos) {
+    return;
+  }
+  mvaddch(kPos->row, ScreenCol(viewport, kPos->row, kPos->col), '+' | A_BOLD);
+  drawn_cursor_ = (kPos->row * drawn_cols_) + kPos->col;
+}
+
+void CharRenderer::DrawSelection(const game::CellBounds& selection,
+                                 const Viewport& viewport) noexcept {
+  /* a character is highlighted when any of the cells it covers is selected,
+   * by reversing the video of the characters already drawn */
+  const auto kCovers = [&viewport](std::size_t first_cell, std::size_t dot,
+                                   std::size_t dots, std::size_t first,
+                                   std::size_t last) {
+    const std::size_t kFirst =
+        first_cell + ((dot * viewport.Zoom()) / viewport.CellSize());
+    const std::size_t kLast =
+        first_cell +
+        ((((dot + dots) * viewport.Zoom()) - 1) / viewport.CellSize());
+    return (kFirst <= last) && (kLast >= first);
+  };
+  for (std::size_t i = 0; i < viewport.Height(); ++i) {
+    if (!kCovers(viewport.Row(), i * viewport.DotRows(), viewport.DotRows(),
+                 selection.top, selection.bottom)) {
+      continue;
+    }
+    for (std::size_t j = 0; j < viewport.Width(); ++j) {
+      if (!kCovers(viewport.Col(), j * viewport.DotCols(), viewport.DotCols(),
+                   selection.left, selection.right)) {
+        continue;
+      }
+      const std::size_t kCol = ScreenCol(viewport, i, j);
+      for (std::size_t k = 0; k < CharactersPerDot(); ++k) {
+        cchar_t cell;
+        wchar_t glyph[CCHARW_MAX] = {};
+        attr_t attrs = A_NORMAL;
+        short pair = 0;
+        mvin_wch(i, kCol + k, &cell);
+        getcchar(&cell, glyph, &attrs, &pair, nullptr);
+        setcchar(&cell, glyph, attrs | A_REVERSE, pair, nullptr);
+        mvadd_wch(i, kCol + k, &cell);
+      }
+      const std::size_t kIndex = (i * drawn_cols_) + j;
+      if (kIndex < drawn_overlay_.size()) {
+        drawn_overlay_[kIndex] = kRedrawMark;
+      }
+    }
+  }
+}
+
+void CharRenderer::DrawGraph(const std::vector<std::uint64_t>& values,
+                             const Viewport& viewport) noexcept {
+  /* the graph is drawn in screen characters, hexagonal cells span two of
+   * them and rows are shifted right by one more than the row below */
+  const std::size_t kScreenCols = viewport.Width() * CharactersPerDot();
+  const std::size_t kWidth = std::min(kGraphWidth, kScreenCols);
+  const std::size_t kHeight = std::min(kGraphHeight, viewport.Height());
+  const bool kAscii = !braille_ && (style_.alive <= 0x7f);
+  const std::size_t kLevels = (kAscii) ? 1 : kGraphLevels;
+  const std::size_t kLeft = kScreenCols - kWidth;
+  const std::vector<std::size_t> kBars =
+      GraphBars(values, kWidth, kHeight * kLevels);
+  const std::size_t kStart = kLeft + (kWidth - kBars.size());
+  for (std::size_t i = 0; i < kHeight; ++i) {
+    const std::size_t kBase = (kHeight - 1 - i) * kLevels;
+    for (std::size_t j = 0; j < kBars.size(); ++j) {
+      const std::size_t kLevel =
+          std::min(kLevels, kBars[j] - std::min(kBars[j], kBase));
+      wchar_t glyph[] = {L' ', L'\0'};
+      if (kAscii && kLevel) {
+        glyph[0] = L'|';
+      } else if (kLevel) {
+        glyph[0] = static_cast<wchar_t>(kGraphBlank + kLevel);
+      }
+      cchar_t bar;
+      setcchar(&bar, glyph, A_BOLD, 0, nullptr);
+      mvadd_wch(i, kStart + j, &bar);
+    }
+  }
+
+  /* the characters below the graph are redrawn by the next DrawBoard() */
+  for (std::size_t i = 0; i < kHeight; ++i) {
+    const std::size_t kReach =
+        ScreenCol(viewport, i, 0) + CharactersPerDot() - 1;
+    const std::size_t kFirst =
+        std::min((kStart - std::min(kStart, kReach)) / CharactersPerDot(),
+                 viewport.Width());
+    for (std::size_t j = kFirst; j < viewport.Width(); ++j) {
+      const std::size_t kIndex = (i * drawn_cols_) + j;
+      if (kIndex < drawn_overlay_.size()) {
+        drawn_overlay_[kIndex] = kRedrawMark;
+      }
+    }
+  }
+}
+
+}  // namespace graphics
+}  // namespace gol
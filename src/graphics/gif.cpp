@@ -0,0 +1,193 @@
+#include "graphics/gif.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/* Largest LZW code allowed by the GIF format. */
+static constexpr int kMaxCode = 4095;
+
+/* Largest image dimension allowed by the GIF format. */
+static constexpr std::size_t kMaxDimension = 65535;
+
+/* Append value as a little endian 16-bit integer. */
+static void AppendWord(std::string& out, std::size_t value) {
+  out += static_cast<char>(value & 0xff);
+  out += static_cast<char>((value >> 8) & 0xff);
+}
+
+/* Packs variable width LZW codes least significant bit first and splits the
+ * resulting bytes into data sub-blocks of at most 255 bytes. */
+class CodeWriter {
+ public:
+  void Write(int code, int code_size) {
+    bits_ |= static_cast<std::uint32_t>(code) << num_bits_;
+    num_bits_ += code_size;
+    while (num_bits_ >= 8) {
+      bytes_ += static_cast<char>(bits_ & 0xff);
+      bits_ >>= 8;
+      num_bits_ -= 8;
+    }
+  }
+
+  [[nodiscard]] std::string Finish() {
+    if (num_bits_ > 0) {
+      bytes_ += static_cast<char>(bits_ & 0xff);
+    }
+
+    const std::size_t kMaxBlock = 255;
+    std::string blocks;
+    for (std::size_t i = 0; i < bytes_.size(); i += kMaxBlock) {
+      const std::size_t kSize = std::min(kMaxBlock, bytes_.size() - i);
+      blocks += static_cast<char>(kSize);
+      blocks.append(bytes_, i, kSize);
+    }
+    blocks += '\0';
+    return blocks;
+  }
+
+ private:
+  std::uint32_t bits_ = 0;
+  int num_bits_ = 0;
+  std::string bytes_;
+};
+
+/* LZW compress the palette indices using codes of min_code_size + 1 to 12
+ * bits. */
+[[nodiscard]] static std::string Compress(
+    const std::vector<std::uint8_t>& indices, int min_code_size) {
+  const int kClearCode = 1 << min_code_size;
+  const int kEndCode = kClearCode + 1;
+
+  /* children[code * kClearCode + index] is the code of the string formed by
+   * appending index to the string of code, 0 if not in the table */
+  std::vector<std::uint16_t> children((kMaxCode + 1) * kClearCode, 0);
+  int code_size = min_code_size + 1;
+  int max_code = kEndCode;
+
+  CodeWriter writer;
+  writer.Write(kClearCode, code_size);
+  int code = -1;
+  for (std::uint8_t index : indices) {
+    if (code < 0) {
+      code = index;
+      continue;
+    }
+
+    std::uint16_t& child = children[(code * kClearCode) + index];
+    if (child) {
+      code = child;
+      continue;
+    }
+
+    writer.Write(code, code_size);
+    child = static_cast<std::uint16_t>(++max_code);
+    if (max_code >= (1 << code_size)) {
+      code_size++;
+    }
+    if (kMaxCode == max_code) {
+      /* the table is full, start over */
+      writer.Write(kClearCode, code_size);
+      std::fill(children.begin(), children.end(), 0);
+      code_size = min_code_size + 1;
+      max_code = kEndCode;
+    }
+    code = index;
+  }
+  if (code >= 0) {
+    writer.Write(code, code_size);
+  }
+  writer.Write(kClearCode, code_size);
+  writer.Write(kEndCode, min_code_size + 1);
+  return writer.Finish();
+}
+
+GifWriter::GifWriter(const std::string& filename, std::size_t width,
+                     std::size_t height, std::size_t scale,
+                     const std::vector<Rgb>& palette)
+    : file_(filename, std::ios::binary),
+      width_(width),
+      height_(height),
+      scale_(scale) {
+  const std::size_t kMaxColors = 256;
+  if (palette.empty() || (palette.size() > kMaxColors)) {
+    throw std::invalid_argument("GIF palette must have 1 to 256 colors");
+  } else if (!scale_ || (width_ * scale_ > kMaxDimension) ||
+             (height_ * scale_ > kMaxDimension)) {
+    throw std::invalid_argument("GIF image size exceeds 65535 pixels");
+  }
+  if (!file_) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+
+  /* the color table holds a power of two colors, at least four since the
+   * minimum code size is at least 2 */
+  while ((1u << min_code_size_) < palette.size()) {
+    min_code_size_++;
+  }
+
+  std::string header = "GIF89a";
+  AppendWord(header, width_ * scale_);
+  AppendWord(header, height_ * scale_);
+  header += static_cast<char>(0xf0 | (min_code_size_ - 1));
+  header += '\0'; /* background color */
+  header += '\0'; /* pixel aspect ratio */
+  for (std::size_t i = 0; i < (1u << min_code_size_); ++i) {
+    const Rgb kColor = (i < palette.size()) ? palette[i] : Rgb{};
+    header += static_cast<char>(kColor.red);
+    header += static_cast<char>(kColor.green);
+    header += static_cast<char>(kColor.blue);
+  }
+
+  /* loop the animation forever */
+  header += "\x21\xff\x0bNETSCAPE2.0\x03\x01";
+  AppendWord(header, 0);
+  header += '\0';
+  file_ << header;
+}
+
+GifWriter::~GifWriter() {
+  if (file_.is_open()) {
+    file_ << '\x3b';
+  }
+}
+
+void GifWriter::AddFrame(const std::vector<std::uint8_t>& pixels,
+                         int delay_ms) {
+  std::vector<std::uint8_t> indices(width_ * scale_ * height_ * scale_, 0);
+  for (std::size_t y = 0; y < height_ * scale_; ++y) {
+    for (std::size_t x = 0; x < width_ * scale_; ++x) {
+      const std::size_t kPixel = ((y / scale_) * width_) + (x / scale_);
+      indices[(y * width_ * scale_) + x] =
+          (kPixel < pixels.size()) ? pixels[kPixel] : 0;
+    }
+  }
+
+  /* graphic control extension followed by the image descriptor */
+  const int kDelayCs = std::max(delay_ms, 0) / 10;
+  std::string frame = "\x21\xf9\x04";
+  frame += '\x04'; /* do not dispose of the frame */
+  AppendWord(frame, std::min(kDelayCs, 0xffff));
+  frame += '\0'; /* transparent color index, unused */
+  frame += '\0';
+  frame += '\x2c';
+  AppendWord(frame, 0);
+  AppendWord(frame, 0);
+  AppendWord(frame, width_ * scale_);
+  AppendWord(frame, height_ * scale_);
+  frame += '\0'; /* no local color table, not interlaced */
+  frame += static_cast<char>(min_code_size_);
+  file_ << frame << Compress(indices, min_code_size_);
+}
+
+}  // namespace graphics
+}  // namespace gol
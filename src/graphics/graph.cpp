@@ -0,0 +1,38 @@
+#include "graphics/graph.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
+namespace gol {
+namespace graphics {
+
+std::vector<std::size_t> GraphBars(const std::vector<std::uint64_t>& values,
+                                   std::size_t width, std::size_t height) {
+  const std::size_t kCount = std::min(width, values.size());
+  if ((0 == kCount) || (0 == height)) {
+    return {};
+  }
+
+  const auto kFirst = values.end() - static_cast<std::ptrdiff_t>(kCount);
+  const auto [kMin, kMax] = std::minmax_element(kFirst, values.end());
+  const std::uint64_t kRange = *kMax - *kMin;
+  std::vector<std::size_t> bars;
+  for (auto it = kFirst; it != values.end(); ++it) {
+    if (0 == kRange) {
+      bars.push_back(std::max<std::size_t>(1, height / 2));
+      continue;
+    }
+    /* the scaled value is computed in floating point to avoid overflowing
+     * large populations */
+    const double kScaled = static_cast<double>(*it - *kMin) /
+                           static_cast<double>(kRange) *
+                           static_cast<double>(height - 1);
+    bars.push_back(1 + static_cast<std::size_t>(kScaled + 0.5));
+  }
+  return bars;
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,41 @@
+#include "graphics/grid.h"
+
+#include <cstddef>
+#include <optional>
+
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+std::optional<std::size_t> GridLine(const GridOverlay& grid,
+                                    const Viewport& viewport, GridAxis axis,
+                                    std::size_t dot, std::size_t num_dots,
+                                    std::size_t board_size) noexcept {
+  /* a span covers num_dots * zoom / cell size cells, when that reaches the
+   * spacing every span contains a line and the grid would hide the board */
+  const std::size_t kZoom = viewport.Zoom();
+  const std::size_t kCellSize = viewport.CellSize();
+  if ((0 == grid.spacing) ||
+      ((grid.spacing * kCellSize) <= (num_dots * kZoom))) {
+    return std::nullopt;
+  }
+
+  for (std::size_t i = dot; i < (dot + num_dots); ++i) {
+    /* only the first dot of a magnified cell starts a line */
+    if (0 != ((i * kZoom) % kCellSize)) {
+      continue;
+    }
+    const std::size_t kFirst =
+        (GridAxis::kRow == axis) ? viewport.BoardRow(i) : viewport.BoardCol(i);
+    const std::size_t kLine =
+        kFirst + ((grid.spacing - (kFirst % grid.spacing)) % grid.spacing);
+    if ((kLine < (kFirst + kZoom)) && (kLine < board_size)) {
+      return kLine;
+    }
+  }
+  return std::nullopt;
+}
+
+}  // namespace graphics
+}  // namespace gol
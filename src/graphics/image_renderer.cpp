@@ -0,0 +1,249 @@
+#include "graphics/image_renderer.h"
+
+#include <curses.h>
+#include <ncurses.h>
+#include <sys/ioctl.h>
+#include <unistd.h>
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <cstdio>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/graph.h"
+#include "graphics/grid.h"
+#include "graphics/kitty.h"
+#include "graphics/screen.h"
+#include "graphics/sixel.h"
+#include "graphics/theme.h"
+#include "graphics/viewport.h"
+
+namespace gol {
+namespace graphics {
+
+/* Screen pixels per image dot along each axis. */
+static constexpr std::size_t kDotPixels = 4;
+
+/* Write raw escape sequences directly to the terminal bypassing ncurses. */
+static void WriteEscape(const std::string& sequence) noexcept {
+  std::fwrite(sequence.data(), 1, sequence.size(), stdout);
+  std::fflush(stdout);
+}
+
+/* Return the size of a screen character in pixels. */
+[[nodiscard]] static ScreenPosition CharacterPixels() noexcept {
+  /* assume a typical 10x20 font when the terminal does not report its size in
+   * pixels */
+  ScreenPosition pixels = {.row = 20, .col = 10};
+  winsize size = {};
+  if ((-1 != ioctl(STDOUT_FILENO, TIOCGWINSZ, &size)) && size.ws_row &&
+      size.ws_col && size.ws_ypixel && size.ws_xpixel) {
+    pixels.row = size.ws_ypixel / size.ws_row;
+    pixels.col = size.ws_xpixel / size.ws_col;
+  }
+  return pixels;
+}
+
+ImageRenderer::ImageRenderer(bool kitty) noexcept : kitty_(kitty) {}
+
+ImageRenderer::~ImageRenderer() {
+  if (kitty_) {
+    WriteEscape(KittyDelete());
+  }
+}
+
+bool ImageRenderer::IsKittySupported() noexcept {
+  /* follow the kitty query with a primary device attributes request which all
+   * terminals answer, a kitty response arriving first indicates support */
+  const int kResponseTimeoutMs = 200;
+  const int kDeviceAttributesEnd = 'c';
+  WriteEscape(KittyQuery() + "\033[c");
+  timeout(kResponseTimeoutMs);
+  std::string response;
+  int ch = ERR;
+  while ((ERR != (ch = getch())) && (kDeviceAttributesEnd != ch)) {
+    response += static_cast<char>(ch);
+  }
+  timeout(-1);
+  return std::string::npos != response.find(KittyQueryResponse());
+}
+
+ScreenPosition ImageRenderer::DotsPerCharacter() const noexcept {
+  const ScreenPosition kPixels = CharacterPixels();
+  return {.row = std::max(std::size_t{1}, kPixels.row / kDotPixels),
+          .col = std::max(std::size_t{1}, kPixels.col / kDotPixels)};
+}
+
+void ImageRenderer::SetTheme(const Theme& theme) noexcept {
+  theme_ = theme;
+  Invalidate();
+}
+
+void ImageRenderer::SetGrid(const GridOverlay& grid) noexcept {
+  grid_ = grid;
+  Invalidate();
+}
+
+void ImageRenderer::SetShading(const Shading& shading) noexcept {
+  shading_ = shading;
+  Invalidate();
+}
+
+void ImageRenderer::DrawBoard(const game::Engine& board,
+                              const Viewport& viewport,
+                              const game::CellAges* ages) noexcept {
+  /* the characters below the image are left blank, they only need to be
+   * erased when the screen is resized */
+  ScreenDimension dim;
+  getmaxyx(stdscr, dim.height, dim.width);
+  if ((dim.width != drawn_dim_.width) || (dim.height != drawn_dim_.height)) {
+    drawn_dim_ = dim;
+    erase();
+    Invalidate();
+  }
+
+  const std::size_t kZoom = viewport.Zoom();
+  const std::size_t kRows = viewport.Height() * viewport.DotRows();
+  const std::size_t kCols = viewport.Width() * viewport.DotCols();
+  std::vector<std::uint8_t> dots(kRows * kCols, kDead);
+  for (std::size_t i = 0; i < kRows; ++i) {
+    for (std::size_t j = 0; j < kCols; ++j) {
+      dots[(i * kCols) + j] = BlockStyle(board, ages, viewport.BoardRow(i),
+                                         viewport.BoardCol(j), kZoom,
+                                         shading_);
+    }
+  }
+
+  /* grid lines are drawn over the dead dots */
+  if (0 != grid_.spacing) {
+    std::vector<bool> cols(kCols);
+    for (std::size_t j = 0; j < kCols; ++j) {
+      cols[j] = GridLine(grid_, viewport, GridAxis::kCol, j, 1, board.Cols())
+                    .has_value();
+    }
+    for (std::size_t i = 0; i < kRows; ++i) {
+      const bool kRowLine =
+          GridLine(grid_, viewport, GridAxis::kRow, i, 1, board.Rows())
+              .has_value();
+      for (std::size_t j = 0; j < kCols; ++j) {
+        std::uint8_t& dot = dots[(i * kCols) + j];
+        if ((kRowLine || cols[j]) && (kDead == dot) &&
+            (viewport.BoardRow(i) < board.Rows()) &&
+            (viewport.BoardCol(j) < board.Cols())) {
+          dot = kGrid;
+        }
+      }
+    }
+  }
+  if ((dots != dots_) || (kCols != cols_)) {
+    dots_ = std::move(dots);
+    cols_ = kCols;
+    chars_ = {.row = viewport.Height(), .col = viewport.Width()};
+    Invalidate();
+  }
+  cursor_.reset();
+  selections_.clear();
+  graph_.clear();
+}
+
+void ImageRenderer::DrawCursor(std::size_t row, std::size_t col,
+                               const Viewport& viewport) noexcept {
+  if (!viewport.ToScreen(row, col)) {
+    return;
+  }
+
+  /* the cursor is drawn as a dot of the board image */
+  const std::size_t kDotRow =
+      ((row - viewport.Row()) * viewport.CellSize()) / viewport.Zoom();
+  const std::size_t kDotCol =
+      ((col - viewport.Col()) * viewport.CellSize()) / viewport.Zoom();
+  cursor_ = (kDotRow * cols_) + kDotCol;
+}
+
+void ImageRenderer::DrawSelection(const game::CellBounds& selection,
+                                  const Viewport& viewport) noexcept {
+  if ((selection.bottom < viewport.Row()) ||
+      (selection.right < viewport.Col())) {
+    return;
+  }
+
+  /* each selection is kept as the rectangle of dots covering its cells */
+  const auto kFirstDot = [&viewport](std::size_t cell, std::size_t first) {
+    return ((std::max(cell, first) - first) * viewport.CellSize()) /
+           viewport.Zoom();
+  };
+  const auto kLastDot = [&viewport](std::size_t cell, std::size_t first) {
+    return (((cell - first + 1) * viewport.CellSize()) - 1) / viewport.Zoom();
+  };
+  selections_.push_back(
+      {.top = kFirstDot(selection.top, viewport.Row()),
+       .left = kFirstDot(selection.left, viewport.Col()),
+       .bottom = kLastDot(selection.bottom, viewport.Row()),
+       .right = kLastDot(selection.right, viewport.Col())});
+}
+
+void ImageRenderer::DrawGraph(const std::vector<std::uint64_t>& values,
+                              const Viewport& viewport) noexcept {
+  /* each bar is a column of dots */
+  const std::size_t kRows = (cols_) ? dots_.size() / cols_ : 0;
+  graph_rows_ = std::min(kGraphHeight * viewport.DotRows(), kRows);
+  graph_ = GraphBars(values, std::min(kGraphWidth * viewport.DotCols(), cols_),
+                     graph_rows_);
+}
+
+void ImageRenderer::Present() noexcept {
+  if (!dirty_ && (cursor_ == written_cursor_) &&
+      (selections_ == written_selections_) && (graph_ == written_graph_)) {
+    return;
+  }
+
+  const std::vector<Rgb> kPalette = StylePalette(theme_);
+  std::vector<std::uint8_t> pixels = dots_;
+  const std::size_t kRows = (cols_) ? pixels.size() / cols_ : 0;
+  for (const game::CellBounds& selection : selections_) {
+    /* selected live cells keep their color */
+    for (std::size_t i = selection.top; (i <= selection.bottom) && (i < kRows);
+         ++i) {
+      for (std::size_t j = selection.left;
+           (j <= selection.right) && (j < cols_); ++j) {
+        if (kDead == pixels[(i * cols_) + j]) {
+          pixels[(i * cols_) + j] = kSelection;
+        }
+      }
+    }
+  }
+  if (cursor_ && (*cursor_ < pixels.size())) {
+    pixels[*cursor_] = kCursor;
+  }
+
+  /* the graph covers the top right corner of the board, its bars rising
+   * from the bottom of the graph */
+  const std::size_t kLeft = cols_ - graph_.size();
+  for (std::size_t i = 0; i < graph_rows_; ++i) {
+    for (std::size_t j = 0; j < graph_.size(); ++j) {
+      pixels[(i * cols_) + kLeft + j] =
+          (graph_[j] >= (graph_rows_ - i)) ? kGraph : kDead;
+    }
+  }
+
+  /* draw the image in the top left corner and restore the cursor position
+   * expected by ncurses */
+  const std::string kImage =
+      (kitty_) ? EncodeKitty(pixels, cols_, chars_.col, chars_.row, kPalette)
+               : EncodeSixel(pixels, cols_, kDotPixels, kPalette);
+  WriteEscape("\0337\033[1;1H" + kImage + "\0338");
+  written_cursor_ = cursor_;
+  written_selections_ = selections_;
+  written_graph_ = graph_;
+  dirty_ = false;
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,225 @@
+#include "graphics/keymap.h"
+
+#include <curses.h>
+
+#include <algorithm>
+#include <array>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "graphics/screen.h"
+
+namespace gol {
+namespace graphics {
+
+struct CommandName {
+  const char* name;
+  Command command;
+};
+
+static constexpr std::array<CommandName, 37> kCommands = {{
+    {"quit", Command::kQuit},
+    {"pause", Command::kTogglePause},
+    {"step", Command::kStep},
+    {"step-back", Command::kStepBack},
+    {"speed-up", Command::kSpeedUp},
+    {"slow-down", Command::kSlowDown},
+    {"edit", Command::kToggleEdit},
+    {"up", Command::kMoveUp},
+    {"down", Command::kMoveDown},
+    {"left", Command::kMoveLeft},
+    {"right", Command::kMoveRight},
+    {"toggle-cell", Command::kToggleCell},
+    {"save", Command::kSave},
+    {"snapshot", Command::kSnapshot},
+    {"zoom-in", Command::kZoomIn},
+    {"zoom-out", Command::kZoomOut},
+    {"randomize", Command::kRandomize},
+    {"clear", Command::kClear},
+    {"grid", Command::kToggleGrid},
+    {"graph", Command::kToggleGraph},
+    {"fast-forward", Command::kFastForward},
+    {"follow", Command::kToggleFollow},
+    {"auto-fit", Command::kToggleFit},
+    {"select", Command::kSelect},
+    {"copy", Command::kCopy},
+    {"paste", Command::kPaste},
+    {"rotate", Command::kRotate},
+    {"flip", Command::kFlip},
+    {"next-tool", Command::kNextTool},
+    {"draw", Command::kDraw},
+    {"brush-smaller", Command::kBrushSmaller},
+    {"brush-larger", Command::kBrushLarger},
+    {"symmetry", Command::kNextSymmetry},
+    {"undo", Command::kUndo},
+    {"redo", Command::kRedo},
+    {"insert", Command::kInsert},
+    {"save-session", Command::kSaveSession},
+}};
+
+/* Commands that only act in edit mode share their keys with the others. */
+static constexpr std::array<Command, 14> kEditCommands = {
+    Command::kToggleCell,   Command::kSave,          Command::kSelect,
+    Command::kCopy,         Command::kPaste,         Command::kRotate,
+    Command::kFlip,         Command::kNextTool,      Command::kDraw,
+    Command::kBrushSmaller, Command::kBrushLarger,   Command::kNextSymmetry,
+    Command::kUndo,         Command::kRedo,
+};
+
+[[nodiscard]] static bool IsEditCommand(Command command) noexcept {
+  return std::find(kEditCommands.begin(), kEditCommands.end(), command) !=
+         kEditCommands.end();
+}
+
+struct NamedKey {
+  const char* name;
+  int key;
+};
+
+static constexpr std::array<NamedKey, 13> kKeyNames = {{
+    {"space", ' '},
+    {"enter", '\n'},
+    {"esc", 27},
+    {"tab", '\t'},
+    {"backspace", KEY_BACKSPACE},
+    {"up", KEY_UP},
+    {"down", KEY_DOWN},
+    {"left", KEY_LEFT},
+    {"right", KEY_RIGHT},
+    {"home", KEY_HOME},
+    {"end", KEY_END},
+    {"pageup", KEY_PPAGE},
+    {"pagedown", KEY_NPAGE},
+}};
+
+Keymap::Keymap() {
+  const std::pair<Command, std::vector<int>> kDefaults[] = {
+      {Command::kQuit, {'q', 27}},
+      {Command::kTogglePause, {' '}},
+      {Command::kStep, {'n', '.'}},
+      {Command::kStepBack, {'b'}},
+      {Command::kSpeedUp, {'+', ']'}},
+      {Command::kSlowDown, {'-', '['}},
+      {Command::kToggleEdit, {'e'}},
+      {Command::kMoveUp, {KEY_UP, 'k'}},
+      {Command::kMoveDown, {KEY_DOWN, 'j'}},
+      {Command::kMoveLeft, {KEY_LEFT, 'h'}},
+      {Command::kMoveRight, {KEY_RIGHT, 'l'}},
+      {Command::kToggleCell, {'x', '\n'}},
+      {Command::kSave, {'w'}},
+      {Command::kSnapshot, {'s'}},
+      {Command::kZoomIn, {'z'}},
+      {Command::kZoomOut, {'x'}},
+      {Command::kRandomize, {'r'}},
+      {Command::kClear, {'c'}},
+      {Command::kToggleGrid, {'g'}},
+      {Command::kToggleGraph, {'p'}},
+      {Command::kFastForward, {'f'}},
+      {Command::kToggleFollow, {'o'}},
+      {Command::kToggleFit, {'a'}},
+      {Command::kSelect, {'v'}},
+      {Command::kCopy, {'y'}},
+      {Command::kPaste, {'P'}},
+      {Command::kRotate, {'R'}},
+      {Command::kFlip, {'M'}},
+      {Command::kNextTool, {'T'}},
+      {Command::kDraw, {'d'}},
+      {Command::kBrushSmaller, {'{'}},
+      {Command::kBrushLarger, {'}'}},
+      {Command::kNextSymmetry, {'m'}},
+      {Command::kUndo, {'u'}},
+      {Command::kRedo, {'r' - 'a' + 1}},
+      {Command::kInsert, {'i'}},
+      {Command::kSaveSession, {'S'}},
+  };
+  for (const auto& [command, keys] : kDefaults) {
+    Bind(command, keys);
+  }
+}
+
+Command Keymap::Lookup(int key, bool editing) const noexcept {
+  /* terminals report Enter as a carriage return, a newline or a keypad key */
+  if (('\r' == key) || (KEY_ENTER == key)) {
+    key = '\n';
+  } else if (KEY_RESIZE == key) {
+    return Command::kResize;
+  }
+
+  Command found = Command::kNone;
+  for (const auto& [bound_key, command] : bindings_) {
+    if (key != bound_key) {
+      continue;
+    } else if (IsEditCommand(command) && editing) {
+      return command;
+    } else if (!IsEditCommand(command)) {
+      found = command;
+    }
+  }
+  return found;
+}
+
+void Keymap::Bind(Command command, const std::vector<int>& keys) {
+  std::erase_if(bindings_, [command, &keys](const auto& binding) {
+    return (command == binding.second) ||
+           ((IsEditCommand(command) == IsEditCommand(binding.second)) &&
+            (std::find(keys.begin(), keys.end(), binding.first) != keys.end()));
+  });
+  for (int key : keys) {
+    bindings_.emplace_back(key, command);
+  }
+}
+
+std::string Keymap::KeyName(Command command) const {
+  for (const auto& [key, bound_command] : bindings_) {
+    if (command != bound_command) {
+      continue;
+    }
+    for (const NamedKey& info : kKeyNames) {
+      if (key == info.key) {
+        return info.name;
+      }
+    }
+    if ((key >= 1) && (key <= 26)) {
+      return std::string("ctrl-") + static_cast<char>('a' + key - 1);
+    }
+    return std::string(1, static_cast<char>(key));
+  }
+  return "";
+}
+
+Command ParseCommand(const std::string& name) {
+  for (const CommandName& info : kCommands) {
+    if (name == info.name) {
+      return info.command;
+    }
+  }
+  throw std::invalid_argument("unknown command -> " + name);
+}
+
+std::vector<std::string> CommandNames() {
+  std::vector<std::string> names;
+  for (const CommandName& info : kCommands) {
+    names.push_back(info.name);
+  }
+  return names;
+}
+
+int ParseKey(const std::string& name) {
+  for (const NamedKey& info : kKeyNames) {
+    if (name == info.name) {
+      return info.key;
+    }
+  }
+  if ((6 == name.size()) && name.starts_with("ctrl-") && (name[5] >= 'a') &&
+      (name[5] <= 'z')) {
+    return name[5] - 'a' + 1;
+  } else if ((1 == name.size()) && (name[0] > ' ') && (name[0] <= '~')) {
+    return name[0];
+  }
+  throw std::invalid_argument("unknown key -> " + name);
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,90 @@
+#include "graphics/kitty.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/* The image and placement IDs used for the board image. */
+static const std::string kImageId = "i=1,p=1";
+
+/* The image ID used by queries. */
+static const std::string kQueryId = "i=31";
+
+/* Maximum number of payload bytes allowed per escape sequence. */
+static constexpr std::size_t kMaxChunk = 4096;
+
+[[nodiscard]] static std::string EncodeBase64(
+    const std::vector<std::uint8_t>& data) {
+  static constexpr char kAlphabet[] =
+      "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+  std::string out;
+  out.reserve(((data.size() + 2) / 3) * 4);
+  for (std::size_t i = 0; i < data.size(); i += 3) {
+    const std::size_t kRemaining = data.size() - i;
+    std::uint32_t group = data[i] << 16;
+    if (kRemaining > 1) {
+      group |= data[i + 1] << 8;
+    }
+    if (kRemaining > 2) {
+      group |= data[i + 2];
+    }
+    out += kAlphabet[(group >> 18) & 0x3f];
+    out += kAlphabet[(group >> 12) & 0x3f];
+    out += (kRemaining > 1) ? kAlphabet[(group >> 6) & 0x3f] : '=';
+    out += (kRemaining > 2) ? kAlphabet[group & 0x3f] : '=';
+  }
+  return out;
+}
+
+std::string EncodeKitty(const std::vector<std::uint8_t>& pixels,
+                        std::size_t width, std::size_t columns,
+                        std::size_t rows, const std::vector<Rgb>& palette) {
+  const std::size_t kHeight = (width) ? (pixels.size() / width) : 0;
+  std::vector<std::uint8_t> rgb;
+  rgb.reserve(pixels.size() * 3);
+  for (const std::uint8_t kIndex : pixels) {
+    const Rgb kColor = (kIndex < palette.size()) ? palette[kIndex] : Rgb{};
+    rgb.push_back(kColor.red);
+    rgb.push_back(kColor.green);
+    rgb.push_back(kColor.blue);
+  }
+  const std::string kPayload = EncodeBase64(rgb);
+
+  /* the first chunk carries the image parameters, the payload is split into
+   * chunks with m=1 marking all but the last */
+  std::string out;
+  std::size_t offset = 0;
+  do {
+    const std::size_t kSize = std::min(kMaxChunk, kPayload.size() - offset);
+    const bool kMore = (offset + kSize) < kPayload.size();
+    out += "\033_G";
+    if (0 == offset) {
+      out += "a=T,f=24,q=2,C=1," + kImageId + ",s=" + std::to_string(width) +
+             ",v=" + std::to_string(kHeight) + ",c=" + std::to_string(columns) +
+             ",r=" + std::to_string(rows) + ',';
+    }
+    out += (kMore) ? "m=1;" : "m=0;";
+    out.append(kPayload, offset, kSize);
+    out += "\033\\";
+    offset += kSize;
+  } while (offset < kPayload.size());
+  return out;
+}
+
+std::string KittyQuery() {
+  return "\033_G" + kQueryId + ",s=1,v=1,a=q,t=d,f=24;AAAA\033\\";
+}
+
+std::string KittyQueryResponse() { return kQueryId + ";OK"; }
+
+std::string KittyDelete() { return "\033_Ga=d,d=A,q=2\033\\"; }
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,140 @@
+#include "graphics/png.h"
+
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/* Append value as a big endian 32-bit integer. */
+static void AppendLong(std::string& out, std::uint32_t value) {
+  for (int shift = 24; shift >= 0; shift -= 8) {
+    out += static_cast<char>((value >> shift) & 0xff);
+  }
+}
+
+/* Return the CRC-32 of data as defined by the PNG specification. */
+[[nodiscard]] static std::uint32_t Crc32(const std::string& data) noexcept {
+  static const std::array<std::uint32_t, 256> kTable = [] {
+    std::array<std::uint32_t, 256> table = {};
+    for (std::uint32_t n = 0; n < table.size(); ++n) {
+      std::uint32_t c = n;
+      for (int k = 0; k < 8; ++k) {
+        c = (c & 1) ? (0xedb88320u ^ (c >> 1)) : (c >> 1);
+      }
+      table[n] = c;
+    }
+    return table;
+  }();
+
+  std::uint32_t crc = 0xffffffffu;
+  for (char byte : data) {
+    crc = kTable[(crc ^ static_cast<std::uint8_t>(byte)) & 0xff] ^ (crc >> 8);
+  }
+  return crc ^ 0xffffffffu;
+}
+
+/* Return the Adler-32 checksum of data as defined by zlib. */
+[[nodiscard]] static std::uint32_t Adler32(const std::string& data) noexcept {
+  const std::uint32_t kModulus = 65521;
+  std::uint32_t a = 1;
+  std::uint32_t b = 0;
+  for (char byte : data) {
+    a = (a + static_cast<std::uint8_t>(byte)) % kModulus;
+    b = (b + a) % kModulus;
+  }
+  return (b << 16) | a;
+}
+
+/* Wrap data in a zlib stream made of uncompressed deflate blocks. */
+[[nodiscard]] static std::string ZlibStore(const std::string& data) {
+  const std::size_t kMaxBlock = 65535;
+  std::string out = "\x78\x01";
+  std::size_t offset = 0;
+  do {
+    const std::size_t kSize = std::min(kMaxBlock, data.size() - offset);
+    const bool kFinal = (offset + kSize == data.size());
+    out += static_cast<char>(kFinal);
+    out += static_cast<char>(kSize & 0xff);
+    out += static_cast<char>(kSize >> 8);
+    out += static_cast<char>(~kSize & 0xff);
+    out += static_cast<char>((~kSize >> 8) & 0xff);
+    out.append(data, offset, kSize);
+    offset += kSize;
+  } while (offset < data.size());
+  AppendLong(out, Adler32(data));
+  return out;
+}
+
+/* Write a PNG chunk of the given type to os. */
+static void WriteChunk(std::ostream& os, const std::string& type,
+                       const std::string& data) {
+  std::string chunk;
+  AppendLong(chunk, static_cast<std::uint32_t>(data.size()));
+  chunk += type + data;
+  AppendLong(chunk, Crc32(type + data));
+  os << chunk;
+}
+
+void WritePng(const std::string& filename, std::size_t width,
+              std::size_t height, std::size_t scale,
+              const std::vector<Rgb>& palette,
+              const std::vector<std::uint8_t>& pixels) {
+  const std::size_t kMaxColors = 256;
+  if (!width || !height || !scale) {
+    throw std::invalid_argument("PNG image must not be empty");
+  } else if (palette.empty() || (palette.size() > kMaxColors)) {
+    throw std::invalid_argument("PNG palette must have 1 to 256 colors");
+  }
+
+  std::ofstream fhandle(filename, std::ios::binary);
+  if (!fhandle) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+
+  /* 8-bit palette indices, no interlacing */
+  std::string header;
+  AppendLong(header, static_cast<std::uint32_t>(width * scale));
+  AppendLong(header, static_cast<std::uint32_t>(height * scale));
+  header += "\x08\x03";
+  header += std::string(3, '\0');
+
+  std::string colors;
+  for (const Rgb& color : palette) {
+    colors += static_cast<char>(color.red);
+    colors += static_cast<char>(color.green);
+    colors += static_cast<char>(color.blue);
+  }
+
+  /* each scanline starts with filter type 0, the row is used as is */
+  std::string scanlines;
+  scanlines.reserve(height * scale * (width * scale + 1));
+  for (std::size_t y = 0; y < height * scale; ++y) {
+    scanlines += '\0';
+    for (std::size_t x = 0; x < width * scale; ++x) {
+      const std::size_t kPixel = ((y / scale) * width) + (x / scale);
+      scanlines += static_cast<char>((kPixel < pixels.size()) ? pixels[kPixel]
+                                                              : 0);
+    }
+  }
+
+  fhandle << "\x89PNG\r\n\x1a\n";
+  WriteChunk(fhandle, "IHDR", header);
+  WriteChunk(fhandle, "PLTE", colors);
+  WriteChunk(fhandle, "IDAT", ZlibStore(scanlines));
+  WriteChunk(fhandle, "IEND", "");
+  if (!fhandle) {
+    throw std::runtime_error("unable to write " + filename);
+  }
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,35 @@
+#include "graphics/renderer.h"
+
+#include <memory>
+
+#include "graphics/cell_style.h"
+#include "graphics/char_renderer.h"
+#include "graphics/image_renderer.h"
+
+namespace gol {
+namespace graphics {
+
+std::unique_ptr<Renderer> MakeRenderer(RenderMode mode,
+                                       const CellStyle& style) {
+  switch (mode) {
+    case RenderMode::kBraille:
+      return std::make_unique<CharRenderer>(true, style);
+    case RenderMode::kHex:
+      return std::make_unique<CharRenderer>(false, style, true);
+    case RenderMode::kSixel:
+      return std::make_unique<ImageRenderer>(false);
+    case RenderMode::kKitty:
+      if (ImageRenderer::IsKittySupported()) {
+        return std::make_unique<ImageRenderer>(true);
+      }
+      break;
+    case RenderMode::kNone:
+      return std::make_unique<NullRenderer>();
+    case RenderMode::kBlock:
+      break;
+  }
+  return std::make_unique<CharRenderer>(false, style);
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -2,29 +2,122 @@
 
 #include <curses.h>
 #include <ncurses.h>
+#include <signal.h>
+#include <unistd.h>
 
+#include <algorithm>
+#include <array>
+#include <clocale>
+#include <csignal>
 #include <cstddef>
+#include <cstdio>
+#include <cstdlib>
+#include <exception>
+#include <initializer_list>
+#include <string>
+#include <vector>
 
-#include "game/board.h"
+#include "graphics/keymap.h"
 
 namespace gol {
 namespace graphics {
 
+/* The terminal keys are read from when stdin is redirected. */
+static std::FILE* tty_input = nullptr;
+static SCREEN* tty_screen = nullptr;
+
 ScreenDimension InitScreen() noexcept {
-  initscr();
+  setlocale(LC_ALL, ""); /* required to output braille characters */
+
+  /* stdin may carry a piped pattern, keys are then read from the terminal */
+  if (!isatty(STDIN_FILENO)) {
+    tty_input = std::fopen("/dev/tty", "r");
+  }
+  if (tty_input) {
+    tty_screen = newterm(nullptr, stdout, tty_input);
+  }
+  if (!tty_screen) {
+    initscr();
+  }
   cbreak();             /* disable line buffering */
   keypad(stdscr, TRUE); /* enable the keypad */
   noecho();             /* disable character echoing */
   curs_set(0);          /* hide the cursor */
+  set_escdelay(25);     /* do not wait long for escape sequences */
+
+  if (has_colors()) {
+    start_color();
+    use_default_colors();
+  }
 
-  /* fetch the screen dimensions */
+  return ScreenSize();
+}
+
+ScreenDimension ScreenSize() noexcept {
   ScreenDimension screen_dim = {.width = 0, .height = 0};
   getmaxyx(stdscr, screen_dim.height, screen_dim.width);
-
   return screen_dim;
 }
 
-void TerminateScreen() noexcept { endwin(); }
+void TerminateScreen() noexcept {
+  endwin();
+  if (tty_screen) {
+    delscreen(tty_screen);
+    tty_screen = nullptr;
+  }
+  if (tty_input) {
+    std::fclose(tty_input);
+    tty_input = nullptr;
+  }
+}
+
+/* Signals whose default action ends the program, the terminal is restored
+ * before they are delivered. */
+static constexpr std::array<int, 9> kFatalSignals = {
+    SIGINT, SIGTERM, SIGHUP, SIGQUIT, SIGABRT, SIGSEGV, SIGBUS, SIGFPE, SIGILL};
+static std::array<struct sigaction, kFatalSignals.size()> saved_actions;
+static std::terminate_handler saved_terminate = nullptr;
+
+/* Restore the terminal. ncurses is not async-signal-safe, but the process is
+ * about to end and leaving the terminal unusable is worse. */
+static void RestoreTerminal() noexcept {
+  if (!isendwin()) {
+    endwin();
+  }
+}
+
+/* Restore the terminal and deliver signal with its default action. The signal
+ * is blocked while handled, it is delivered once the handler returns. */
+static void HandleFatalSignal(int signal) noexcept {
+  RestoreTerminal();
+  std::signal(signal, SIG_DFL);
+  std::raise(signal);
+}
+
+static void HandleTerminate() noexcept {
+  RestoreTerminal();
+  std::abort();
+}
+
+ScreenGuard::ScreenGuard() noexcept : dim_(InitScreen()) {
+  /* ncurses only restores the terminal on SIGINT and SIGTERM, its handlers
+   * are replaced such that every fatal signal restores it */
+  struct sigaction action = {};
+  action.sa_handler = HandleFatalSignal;
+  sigemptyset(&action.sa_mask);
+  for (std::size_t i = 0; i < kFatalSignals.size(); ++i) {
+    sigaction(kFatalSignals[i], &action, &saved_actions[i]);
+  }
+  saved_terminate = std::set_terminate(HandleTerminate);
+}
+
+ScreenGuard::~ScreenGuard() {
+  std::set_terminate(saved_terminate);
+  for (std::size_t i = 0; i < kFatalSignals.size(); ++i) {
+    sigaction(kFatalSignals[i], &saved_actions[i], nullptr);
+  }
+  TerminateScreen();
+}
 
 void Clear() noexcept { clear(); }
 
@@ -32,23 +125,180 @@ void EnableInputDelay(int delay_ms) noexcept { timeout(delay_ms); }
 
 void DisableInputDelay() noexcept { timeout(-1); }
 
-void DrawBoard(const game::GameOfLifeBoard& board) noexcept {
-  for (std::size_t i = 0; i < board.Rows(); ++i) {
-    for (std::size_t j = 0; j < board.Cols(); ++j) {
-      if (board[i][j]) {
-        mvaddch(i, j, ' ' | A_REVERSE);
-      }
+/* Return the first keys bound to commands joined by '/', or an empty string
+ * if one of the commands is unbound. */
+[[nodiscard]] static std::string HintKeys(
+    const Keymap& keymap, std::initializer_list<Command> commands) {
+  std::string keys;
+  for (Command command : commands) {
+    const std::string kName = keymap.KeyName(command);
+    if (kName.empty()) {
+      return "";
     }
+    keys += (keys.empty()) ? kName : "/" + kName;
   }
-  refresh();
+  return (keys == "up/down/left/right") ? "arrows" : keys;
 }
 
-void DrawInstructions(const ScreenDimension& screen_dim) noexcept {
-  mvprintw(screen_dim.height - 1, 0, "%s", "press q to quit");
-  refresh();
+void DrawInstructions(const ScreenDimension& screen_dim, bool editing,
+                      const Keymap& keymap) noexcept {
+  struct Hint {
+    std::initializer_list<Command> commands;
+    const char* label;
+  };
+  const std::initializer_list<Command> kMoves = {
+      Command::kMoveUp, Command::kMoveDown, Command::kMoveLeft,
+      Command::kMoveRight};
+  const std::initializer_list<Hint> kEditHints = {
+      {kMoves, "move"},
+      {{Command::kToggleCell}, "toggle cell"},
+      {{Command::kUndo, Command::kRedo}, "undo/redo"},
+      {{Command::kSelect, Command::kCopy, Command::kPaste},
+       "select/copy/paste"},
+      {{Command::kInsert}, "insert"},
+      {{Command::kRotate, Command::kFlip}, "rotate/flip"},
+      {{Command::kNextTool}, "tool"},
+      {{Command::kDraw}, "draw"},
+      {{Command::kBrushSmaller, Command::kBrushLarger}, "brush"},
+      {{Command::kNextSymmetry}, "symmetry"},
+      {{Command::kSave}, "save"},
+      {{Command::kSnapshot}, "snapshot"},
+      {{Command::kSaveSession}, "save session"},
+      {{Command::kRandomize, Command::kClear}, "random/clear"},
+      {{Command::kToggleGrid}, "grid"},
+      {{Command::kToggleEdit}, "leave edit mode"},
+  };
+  const std::initializer_list<Hint> kRunHints = {
+      {{Command::kQuit}, "quit"},
+      {{Command::kTogglePause}, "pause/resume"},
+      {{Command::kStep, Command::kStepBack}, "step/back"},
+      {{Command::kSpeedUp, Command::kSlowDown}, "speed"},
+      {{Command::kSnapshot}, "snapshot"},
+      {{Command::kSaveSession}, "save session"},
+      {{Command::kToggleEdit}, "edit"},
+      {{Command::kInsert}, "insert"},
+      {kMoves, "pan"},
+      {{Command::kZoomIn, Command::kZoomOut}, "zoom"},
+      {{Command::kRandomize, Command::kClear}, "random/clear"},
+      {{Command::kToggleGrid}, "grid"},
+      {{Command::kToggleGraph}, "graph"},
+      {{Command::kFastForward}, "skip"},
+      {{Command::kToggleFollow}, "follow"},
+      {{Command::kToggleFit}, "auto-fit"},
+  };
+
+  /* hints for commands without a key are left out */
+  std::string line;
+  for (const Hint& hint : (editing) ? kEditHints : kRunHints) {
+    const std::string kKeys = HintKeys(keymap, hint.commands);
+    if (!kKeys.empty()) {
+      line += (line.empty()) ? "" : "  ";
+      line += kKeys + ": " + hint.label;
+    }
+  }
+
+  move(screen_dim.height - 1, 0);
+  clrtoeol();
+  printw("%s", line.c_str());
+}
+
+void DrawStatusBar(const ScreenDimension& screen_dim,
+                   const Status& status) noexcept {
+  move(screen_dim.height - 2, 0);
+  clrtoeol();
+  if (!status.title.empty()) {
+    printw("%s  ", status.title.c_str());
+  }
+  printw("generation: %llu  population: %llu  delay: %d ms",
+         static_cast<unsigned long long>(status.generation),
+         static_cast<unsigned long long>(status.population),
+         status.update_rate_ms);
+  if (!status.score.empty()) {
+    printw("  %s", status.score.c_str());
+  }
+  if (status.zoom > 1) {
+    printw("  zoom: 1/%zu", status.zoom);
+  } else if (status.cell_size > 1) {
+    printw("  zoom: %zux", status.cell_size);
+  }
+  if (status.editing) {
+    printw("%s", "  [EDIT]");
+  } else if (status.paused) {
+    printw("%s", "  [PAUSED]");
+  }
+  if (status.fitting) {
+    printw("%s", "  [AUTO-FIT]");
+  } else if (status.following) {
+    printw("%s", "  [FOLLOW]");
+  }
+  if (status.behind) {
+    printw("  [BEHIND %.1f gen/s]", status.rate);
+  }
+  if (status.rewound) {
+    printw("  [REWOUND %zu]", status.rewound);
+  }
+  if (status.stable) {
+    printw("%s", "  [STABLE]");
+  }
+  if (!status.outcome.empty()) {
+    printw("  [%s]", status.outcome.c_str());
+  }
+  if (!status.message.empty()) {
+    printw("  %s", status.message.c_str());
+  }
 }
 
-bool Quit() noexcept { return ('q' == getch()); }
+void DrawMenu(const ScreenDimension& screen_dim, const std::string& title,
+              const std::vector<std::string>& items,
+              std::size_t selected) noexcept {
+  /* the menu is boxed and centered above the status bar and instructions */
+  std::size_t longest = title.size();
+  for (const std::string& item : items) {
+    longest = std::max(longest, item.size());
+  }
+  const int kWidth =
+      std::min(static_cast<int>(longest) + 4, screen_dim.width - 2);
+  const int kRows =
+      std::min(static_cast<int>(items.size()), screen_dim.height - 6);
+  if ((kWidth < 5) || (kRows <= 0)) {
+    return;
+  }
+  const int kTop = (screen_dim.height - 2 - (kRows + 2)) / 2;
+  const int kLeft = (screen_dim.width - kWidth) / 2;
+  const std::size_t kVisible = static_cast<std::size_t>(kRows);
+  const std::size_t kFirst =
+      std::min(selected - std::min(selected, kVisible / 2),
+               items.size() - kVisible);
+
+  for (int i = 0; i < kRows + 2; ++i) {
+    mvhline(kTop + i, kLeft, ' ', kWidth);
+  }
+  mvhline(kTop, kLeft + 1, ACS_HLINE, kWidth - 2);
+  mvhline(kTop + kRows + 1, kLeft + 1, ACS_HLINE, kWidth - 2);
+  mvvline(kTop + 1, kLeft, ACS_VLINE, kRows);
+  mvvline(kTop + 1, kLeft + kWidth - 1, ACS_VLINE, kRows);
+  mvaddch(kTop, kLeft, ACS_ULCORNER);
+  mvaddch(kTop, kLeft + kWidth - 1, ACS_URCORNER);
+  mvaddch(kTop + kRows + 1, kLeft, ACS_LLCORNER);
+  mvaddch(kTop + kRows + 1, kLeft + kWidth - 1, ACS_LRCORNER);
+  mvaddnstr(kTop, kLeft + 2, title.c_str(), kWidth - 4);
+  for (std::size_t i = 0; i < kVisible; ++i) {
+    const int kRow = kTop + 1 + static_cast<int>(i);
+    if (kFirst + i == selected) {
+      attron(A_REVERSE);
+      mvhline(kRow, kLeft + 1, ' ', kWidth - 2);
+    }
+    mvaddnstr(kRow, kLeft + 2, items[kFirst + i].c_str(), kWidth - 4);
+    attroff(A_REVERSE);
+  }
+}
+
+void Refresh() noexcept { refresh(); }
+
+Command ReadCommand(const Keymap& keymap, bool editing) noexcept {
+  const int kKey = getch();
+  return (ERR == kKey) ? Command::kNone : keymap.Lookup(kKey, editing);
+}
 
 }  // namespace graphics
 }  // namespace gol
@@ -0,0 +1,91 @@
+#include "graphics/sixel.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/* Number of pixel rows encoded by a single sixel character. */
+static constexpr std::size_t kSixelRows = 6;
+
+/* Append count copies of the sixel character ch using the repeat introducer for
+ * runs long enough to benefit from it. */
+static void AppendRun(std::string& out, char ch, std::size_t count) {
+  const std::size_t kMinRepeat = 4;
+  if (count >= kMinRepeat) {
+    out += '!' + std::to_string(count) + ch;
+  } else {
+    out.append(count, ch);
+  }
+}
+
+[[nodiscard]] static int ToPercent(std::uint8_t component) noexcept {
+  const int kMax = 255;
+  return (component * 100 + (kMax / 2)) / kMax;
+}
+
+std::string EncodeSixel(const std::vector<std::uint8_t>& pixels,
+                        std::size_t width, std::size_t scale,
+                        const std::vector<Rgb>& palette) {
+  const std::size_t kHeight = (width) ? (pixels.size() / width) : 0;
+  const std::size_t kImageWidth = width * scale;
+  const std::size_t kImageHeight = kHeight * scale;
+
+  /* P2 = 1 leaves pixels not covered by any color untouched such that the
+   * padding of the last band does not erase the screen below the image */
+  std::string out = "\033P0;1q";
+  out += "\"1;1;" + std::to_string(kImageWidth) + ';' +
+         std::to_string(kImageHeight);
+  for (std::size_t i = 0; i < palette.size(); ++i) {
+    out += '#' + std::to_string(i) + ";2;" +
+           std::to_string(ToPercent(palette[i].red)) + ';' +
+           std::to_string(ToPercent(palette[i].green)) + ';' +
+           std::to_string(ToPercent(palette[i].blue));
+  }
+
+  for (std::size_t band = 0; band < kImageHeight; band += kSixelRows) {
+    for (std::size_t color = 0; color < palette.size(); ++color) {
+      std::string line;
+      bool used = false;
+      char run_char = '\0';
+      std::size_t run_length = 0;
+      for (std::size_t x = 0; x < width; ++x) {
+        int bits = 0;
+        for (std::size_t k = 0; k < kSixelRows; ++k) {
+          const std::size_t kRow = band + k;
+          if ((kRow < kImageHeight) &&
+              (pixels[((kRow / scale) * width) + x] == color)) {
+            bits |= 1 << k;
+          }
+        }
+        used = used || bits;
+
+        const char kChar = static_cast<char>('?' + bits);
+        if (kChar != run_char) {
+          AppendRun(line, run_char, run_length);
+          run_char = kChar;
+          run_length = 0;
+        }
+        run_length += scale;
+      }
+      if (used) {
+        /* trailing empty sixels need not be sent */
+        if ('?' != run_char) {
+          AppendRun(line, run_char, run_length);
+        }
+        out += '#' + std::to_string(color) + line + '$';
+      }
+    }
+    out += '-';
+  }
+  out += "\033\\";
+  return out;
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,83 @@
+#include "graphics/svg.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <cstdio>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/ages.h"
+#include "game/engine.h"
+#include "graphics/cell_style.h"
+#include "graphics/theme.h"
+
+namespace gol {
+namespace graphics {
+
+/* Return color in the #rrggbb notation. */
+[[nodiscard]] static std::string ToHex(const Rgb& color) {
+  char hex[8] = {};
+  std::snprintf(hex, sizeof(hex), "#%02x%02x%02x", color.red, color.green,
+                color.blue);
+  return hex;
+}
+
+std::string ToSvg(const game::Engine& board, const game::CellAges* ages,
+                  const SvgOptions& options) {
+  if (!options.cell_size) {
+    throw std::invalid_argument("SVG cell size must be at least 1");
+  }
+
+  const std::size_t kSize = options.cell_size;
+  const std::size_t kWidth = board.Cols() * kSize;
+  const std::size_t kHeight = board.Rows() * kSize;
+  const std::vector<Rgb> kPalette = StylePalette(options.theme);
+  const std::vector<std::uint8_t> kStyles =
+      CellStyles(board, ages, options.shading);
+
+  std::ostringstream oss;
+  oss << "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"" << kWidth
+      << "\" height=\"" << kHeight << "\" viewBox=\"0 0 " << kWidth << ' '
+      << kHeight << "\">\n";
+  oss << "<rect width=\"100%\" height=\"100%\" fill=\""
+      << ToHex(kPalette[kDead]) << "\"/>\n";
+
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    std::size_t j = 0;
+    while (j < board.Cols()) {
+      const std::uint8_t kStyle = kStyles[(i * board.Cols()) + j];
+      std::size_t run = 1;
+      while ((j + run < board.Cols()) &&
+             (kStyles[(i * board.Cols()) + j + run] == kStyle)) {
+        run++;
+      }
+      if (kDead != kStyle) {
+        oss << "<rect x=\"" << j * kSize << "\" y=\"" << i * kSize
+            << "\" width=\"" << run * kSize << "\" height=\"" << kSize
+            << "\" fill=\"" << ToHex(kPalette[kStyle]) << "\"/>\n";
+      }
+      j += run;
+    }
+  }
+
+  if (options.grid) {
+    /* a single path of horizontal and vertical lines */
+    const Color kGridColor = 8;
+    oss << "<path stroke=\"" << ToHex(ToRgb(kGridColor))
+        << "\" stroke-width=\"1\" d=\"";
+    for (std::size_t i = 0; i <= board.Rows(); ++i) {
+      oss << "M0 " << i * kSize << 'H' << kWidth;
+    }
+    for (std::size_t j = 0; j <= board.Cols(); ++j) {
+      oss << 'M' << j * kSize << " 0V" << kHeight;
+    }
+    oss << "\"/>\n";
+  }
+  oss << "</svg>\n";
+  return oss.str();
+}
+
+}  // namespace graphics
+}  // namespace gol
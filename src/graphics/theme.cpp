@@ -0,0 +1,98 @@
+#include "graphics/theme.h"
+
+#include <array>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace gol {
+namespace graphics {
+
+static const std::array<Theme, 4> kThemes = {
+    Theme{},
+    Theme{.name = "matrix",
+          .live = 46,
+          .ages = {120, 46, 34, 22},
+          .states = {}},
+    Theme{.name = "heat",
+          .live = 196,
+          .ages = {226, 214, 202, 160},
+          .states = {}},
+    Theme{.name = "grayscale",
+          .live = 250,
+          .ages = {255, 250, 244, 238},
+          .states = {}},
+};
+
+Rgb ToRgb(Color color) noexcept {
+  static constexpr std::array<Rgb, 16> kStandard = {{
+      {0, 0, 0},
+      {205, 0, 0},
+      {0, 205, 0},
+      {205, 205, 0},
+      {0, 0, 238},
+      {205, 0, 205},
+      {0, 205, 205},
+      {229, 229, 229},
+      {127, 127, 127},
+      {255, 0, 0},
+      {0, 255, 0},
+      {255, 255, 0},
+      {92, 92, 255},
+      {255, 0, 255},
+      {0, 255, 255},
+      {255, 255, 255},
+  }};
+  static constexpr std::array<std::uint8_t, 6> kCubeLevels = {0,   95,  135,
+                                                              175, 215, 255};
+  if ((color < 0) || (color > 255)) {
+    return {};
+  } else if (color < 16) {
+    return kStandard[color];
+  } else if (color >= 232) {
+    const auto kGray = static_cast<std::uint8_t>(8 + ((color - 232) * 10));
+    return {.red = kGray, .green = kGray, .blue = kGray};
+  }
+  const int kCube = color - 16;
+  return {.red = kCubeLevels[kCube / 36],
+          .green = kCubeLevels[(kCube / 6) % 6],
+          .blue = kCubeLevels[kCube % 6]};
+}
+
+Color NearestColor(const Rgb& rgb) noexcept {
+  Color nearest = 16;
+  int min_distance = -1;
+  for (Color color = 16; color <= 255; ++color) {
+    const Rgb kCandidate = ToRgb(color);
+    const int kRed = kCandidate.red - rgb.red;
+    const int kGreen = kCandidate.green - rgb.green;
+    const int kBlue = kCandidate.blue - rgb.blue;
+    const int kDistance = (kRed * kRed) + (kGreen * kGreen) + (kBlue * kBlue);
+    if ((min_distance < 0) || (kDistance < min_distance)) {
+      nearest = color;
+      min_distance = kDistance;
+    }
+  }
+  return nearest;
+}
+
+Theme FindTheme(const std::string& name) {
+  for (const Theme& theme : kThemes) {
+    if (theme.name == name) {
+      return theme;
+    }
+  }
+  throw std::invalid_argument("unknown color theme -> " + name);
+}
+
+std::vector<std::string> ThemeNames() {
+  std::vector<std::string> names;
+  for (const Theme& theme : kThemes) {
+    names.push_back(theme.name);
+  }
+  return names;
+}
+
+}  // namespace graphics
+}  // namespace gol
@@ -0,0 +1,184 @@
+#include "graphics/viewport.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <optional>
+
+namespace gol {
+namespace graphics {
+
+/* Move an offset by delta while keeping it within [0, limit]. */
+[[nodiscard]] static std::size_t Shift(std::size_t offset, long long delta,
+                                       std::size_t limit) noexcept {
+  const long long kShifted = static_cast<long long>(offset) + delta;
+  if (kShifted < 0) {
+    return 0;
+  }
+  return std::min(static_cast<std::size_t>(kShifted), limit);
+}
+
+std::size_t Viewport::VisibleRows() const noexcept {
+  return (height_ * dot_rows_ * zoom_) / cell_size_;
+}
+
+std::size_t Viewport::VisibleCols() const noexcept {
+  return (width_ * dot_cols_ * zoom_) / cell_size_;
+}
+
+void Viewport::Clamp() noexcept {
+  /* the last row/column of the board should never scroll past the bottom
+   * right corner of the screen */
+  const std::size_t kVisibleRows = VisibleRows();
+  const std::size_t kVisibleCols = VisibleCols();
+  row_ = std::min(row_, (board_rows_ > kVisibleRows)
+                            ? (board_rows_ - kVisibleRows)
+                            : std::size_t{0});
+  col_ = std::min(col_, (board_cols_ > kVisibleCols)
+                            ? (board_cols_ - kVisibleCols)
+                            : std::size_t{0});
+}
+
+Viewport::Viewport(std::size_t height, std::size_t width,
+                   std::size_t board_rows, std::size_t board_cols,
+                   std::size_t dot_rows, std::size_t dot_cols) noexcept
+    : height_(height),
+      width_(width),
+      board_rows_(board_rows),
+      board_cols_(board_cols),
+      dot_rows_(dot_rows),
+      dot_cols_(dot_cols) {}
+
+void Viewport::Resize(std::size_t height, std::size_t width) noexcept {
+  height_ = height;
+  width_ = width;
+  Clamp();
+}
+
+void Viewport::Pan(int d_row, int d_col) noexcept {
+  /* magnified cells may span several characters, the viewport moves at least
+   * one cell per character */
+  const long long kCharRows = static_cast<long long>(
+      std::max(std::size_t{1}, (dot_rows_ * zoom_) / cell_size_));
+  const long long kCharCols = static_cast<long long>(
+      std::max(std::size_t{1}, (dot_cols_ * zoom_) / cell_size_));
+  row_ = Shift(row_, d_row * kCharRows, board_rows_);
+  col_ = Shift(col_, d_col * kCharCols, board_cols_);
+  Clamp();
+}
+
+void Viewport::ZoomIn() noexcept {
+  if (zoom_ > 1) {
+    zoom_ /= 2;
+  } else {
+    cell_size_ = std::min(kMaxCellSize, cell_size_ * 2);
+  }
+  Clamp();
+}
+
+void Viewport::ZoomOut() noexcept {
+  if (cell_size_ > 1) {
+    cell_size_ /= 2;
+  } else {
+    zoom_ = std::min(kMaxZoom, zoom_ * 2);
+  }
+  Clamp();
+}
+
+void Viewport::ZoomToFit(std::size_t rows, std::size_t cols) noexcept {
+  cell_size_ = 1;
+  while (((VisibleRows() < rows) || (VisibleCols() < cols)) &&
+         (zoom_ < kMaxZoom)) {
+    zoom_ *= 2;
+  }
+  Clamp();
+}
+
+void Viewport::ZoomToFill(std::size_t rows, std::size_t cols) noexcept {
+  const std::size_t kDotRows = height_ * dot_rows_;
+  const std::size_t kDotCols = width_ * dot_cols_;
+  if ((0 == rows) || (0 == cols) || (rows > kDotRows) || (cols > kDotCols)) {
+    ZoomToFit(rows, cols);
+    return;
+  }
+
+  zoom_ = 1;
+  cell_size_ = std::min({kMaxCellSize, kDotRows / rows, kDotCols / cols});
+  Clamp();
+}
+
+void Viewport::StepZoomToFit(std::size_t rows, std::size_t cols) noexcept {
+  const auto kFits = [&](std::size_t zoom, std::size_t cell_size) {
+    return ((height_ * dot_rows_ * zoom) / cell_size >= rows) &&
+           ((width_ * dot_cols_ * zoom) / cell_size >= cols);
+  };
+  std::size_t zoom = 1;
+  std::size_t cell_size = kMaxCellSize;
+  while (!kFits(zoom, cell_size) && (cell_size > 1)) {
+    cell_size /= 2;
+  }
+  while (!kFits(zoom, cell_size) && (zoom < kMaxZoom)) {
+    zoom *= 2;
+  }
+
+  /* levels are compared by the dots per board cell, cell_size / zoom */
+  if (cell_size * zoom_ < cell_size_ * zoom) {
+    ZoomOut();
+  } else if (cell_size * zoom_ > cell_size_ * zoom) {
+    ZoomIn();
+  }
+}
+
+void Viewport::SetZoom(std::size_t zoom, std::size_t cell_size) noexcept {
+  zoom_ = std::clamp(zoom, std::size_t{1}, kMaxZoom);
+  cell_size_ =
+      (zoom_ > 1) ? 1 : std::clamp(cell_size, std::size_t{1}, kMaxCellSize);
+  Clamp();
+}
+
+void Viewport::MoveTo(std::size_t row, std::size_t col) noexcept {
+  row_ = row;
+  col_ = col;
+  Clamp();
+}
+
+void Viewport::CenterOn(std::size_t row, std::size_t col) noexcept {
+  const std::size_t kHalfRows = VisibleRows() / 2;
+  const std::size_t kHalfCols = VisibleCols() / 2;
+  row_ = (row > kHalfRows) ? (row - kHalfRows) : 0;
+  col_ = (col > kHalfCols) ? (col - kHalfCols) : 0;
+  Clamp();
+}
+
+void Viewport::Follow(std::size_t row, std::size_t col) noexcept {
+  const std::size_t kVisibleRows = VisibleRows();
+  const std::size_t kVisibleCols = VisibleCols();
+  if (row < row_) {
+    row_ = row;
+  } else if (row >= row_ + kVisibleRows) {
+    row_ = row - kVisibleRows + 1;
+  }
+  if (col < col_) {
+    col_ = col;
+  } else if (col >= col_ + kVisibleCols) {
+    col_ = col - kVisibleCols + 1;
+  }
+  Clamp();
+}
+
+std::optional<ScreenPosition> Viewport::ToScreen(
+    std::size_t row, std::size_t col) const noexcept {
+  if ((row < row_) || (col < col_)) {
+    return std::nullopt;
+  }
+
+  const ScreenPosition kPos = {
+      .row = ((row - row_) * cell_size_) / (dot_rows_ * zoom_),
+      .col = ((col - col_) * cell_size_) / (dot_cols_ * zoom_)};
+  if ((kPos.row >= height_) || (kPos.col >= width_)) {
+    return std::nullopt;
+  }
+  return kPos;
+}
+
+}  // namespace graphics
+}  // namespace gol
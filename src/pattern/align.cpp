@@ -0,0 +1,99 @@
+#include "pattern/align.h"
+
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+struct AnchorName {
+  const char* name;
+  Anchor anchor;
+};
+
+static constexpr std::array<AnchorName, 3> kAnchors = {{
+    {"origin", Anchor::kOrigin},
+    {"top-left", Anchor::kTopLeft},
+    {"center", Anchor::kCenter},
+}};
+
+Anchor ParseAnchor(const std::string& name) {
+  for (const AnchorName& info : kAnchors) {
+    if (name == info.name) {
+      return info.anchor;
+    }
+  }
+  throw std::invalid_argument("unknown alignment -> " + name);
+}
+
+std::vector<std::string> AnchorNames() {
+  std::vector<std::string> names;
+  for (const AnchorName& info : kAnchors) {
+    names.push_back(info.name);
+  }
+  return names;
+}
+
+/* Parse an optionally negative integer spanning all of str. */
+[[nodiscard]] static std::int32_t ParseCoordinate(const std::string& str) {
+  const std::size_t kDigits = (str.starts_with('-')) ? 1 : 0;
+  if ((str.size() == kDigits) ||
+      (str.find_first_not_of("0123456789", kDigits) != std::string::npos)) {
+    throw std::invalid_argument("invalid offset -> " + str);
+  }
+  try {
+    return std::stoi(str);
+  } catch (const std::out_of_range&) {
+    throw std::invalid_argument("offset out of range -> " + str);
+  }
+}
+
+Position2D ParseOffset(const std::string& spec) {
+  const std::size_t kComma = spec.find(',');
+  if (std::string::npos == kComma) {
+    throw std::invalid_argument("offset must be X,Y -> " + spec);
+  }
+  return {.x = ParseCoordinate(spec.substr(0, kComma)),
+          .y = ParseCoordinate(spec.substr(kComma + 1)),
+          .state = 1};
+}
+
+/* Return the position along an axis of a screen of the given size at which a
+ * pattern of the given size is centered, or 0 when it does not fit. */
+[[nodiscard]] static std::int32_t CenterStart(std::size_t pattern,
+                                              std::size_t screen) noexcept {
+  return (pattern < screen) ? static_cast<std::int32_t>((screen - pattern) / 2)
+                            : 0;
+}
+
+Position2DVec PlacePattern(const Position2DVec& live_cells,
+                           const Alignment& alignment, std::size_t rows,
+                           std::size_t cols) {
+  Pattern pattern(live_cells);
+  const Position2D kMin = pattern.Min();
+  switch (alignment.anchor) {
+    case Anchor::kOrigin:
+      pattern.Translate(std::max(0, -kMin.x), std::max(0, -kMin.y));
+      break;
+    case Anchor::kTopLeft:
+      pattern.Normalize();
+      break;
+    case Anchor::kCenter:
+      pattern.Normalize().Translate(CenterStart(pattern.Width(), cols),
+                                    CenterStart(pattern.Height(), rows));
+      break;
+  }
+  pattern.Translate(alignment.offset.x, alignment.offset.y);
+  return pattern.Cells();
+}
+
+}  // namespace pattern
+}  // namespace gol
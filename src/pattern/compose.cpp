@@ -0,0 +1,158 @@
+#include "pattern/compose.h"
+
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "pattern/align.h"
+#include "pattern/diagnostics.h"
+#include "pattern/library.h"
+#include "pattern/loader.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+struct OrientationName {
+  const char* name;
+  Orientation orientation;
+};
+
+static constexpr std::array<OrientationName, 6> kOrientations = {{
+    {"rot0", Orientation::kIdentity},
+    {"rot90", Orientation::kRot90},
+    {"rot180", Orientation::kRot180},
+    {"rot270", Orientation::kRot270},
+    {"fliph", Orientation::kFlipH},
+    {"flipv", Orientation::kFlipV},
+}};
+
+Orientation ParseOrientation(const std::string& name) {
+  for (const OrientationName& info : kOrientations) {
+    if (name == info.name) {
+      return info.orientation;
+    }
+  }
+  throw std::invalid_argument("unknown orientation -> " + name);
+}
+
+Placement ParsePlacement(const std::string& spec) {
+  Placement placement{.source = spec};
+
+  /* the offset and orientation follow the last '@', without an offset only a
+   * known orientation is split off so that sources may contain either
+   * separator */
+  const std::size_t kAt = spec.rfind('@');
+  std::string suffix;
+  if (std::string::npos != kAt) {
+    placement.source = spec.substr(0, kAt);
+    suffix = spec.substr(kAt + 1);
+  } else if (const std::size_t kColon = spec.rfind(':');
+             (std::string::npos != kColon) &&
+             std::any_of(kOrientations.begin(), kOrientations.end(),
+                         [&spec, kColon](const OrientationName& info) {
+                           return spec.substr(kColon + 1) == info.name;
+                         })) {
+    placement.source = spec.substr(0, kColon);
+    suffix = spec.substr(kColon);
+  }
+
+  const std::size_t kColon = suffix.find(':');
+  if (std::string::npos != kColon) {
+    placement.orientation = ParseOrientation(suffix.substr(kColon + 1));
+    suffix.erase(kColon);
+  }
+  if (!suffix.empty()) {
+    const Position2D kOffset = ParseOffset(suffix);
+    placement.x = kOffset.x;
+    placement.y = kOffset.y;
+  }
+  if (placement.source.empty()) {
+    throw std::invalid_argument("placement is missing a pattern -> " + spec);
+  }
+  return placement;
+}
+
+Position2DVec Orient(const Position2DVec& live_cells, Orientation orientation) {
+  Pattern pattern(live_cells);
+  switch (orientation) {
+    case Orientation::kRot90:
+      pattern.Rotate90();
+      break;
+    case Orientation::kRot180:
+      pattern.Rotate90().Rotate90();
+      break;
+    case Orientation::kRot270:
+      pattern.Rotate90().Rotate90().Rotate90();
+      break;
+    case Orientation::kFlipH:
+      pattern.FlipH();
+      break;
+    case Orientation::kFlipV:
+      pattern.FlipV();
+      break;
+    case Orientation::kIdentity:
+      break;
+  }
+  return pattern.Normalize().Cells();
+}
+
+Position2DVec FaceOff(const Position2DVec& red, const Position2DVec& blue,
+                      std::int32_t gap) {
+  Pattern left(Orient(red, Orientation::kIdentity));
+  Pattern right(Orient(blue, Orientation::kRot180));
+  const auto kLeftHeight = static_cast<std::int32_t>(left.Height());
+  const auto kRightHeight = static_cast<std::int32_t>(right.Height());
+  const std::int32_t kTop = std::max(kLeftHeight, kRightHeight);
+  left.Translate(0, (kTop - kLeftHeight) / 2);
+  right.Translate(static_cast<std::int32_t>(left.Width()) + gap,
+                  (kTop - kRightHeight) / 2);
+
+  Position2DVec cells;
+  for (Position2D pos : left.Cells()) {
+    pos.state = 1;
+    cells.push_back(pos);
+  }
+  for (Position2D pos : right.Cells()) {
+    pos.state = 2;
+    cells.push_back(pos);
+  }
+  return cells;
+}
+
+Position2DVec Compose(const std::vector<Placement>& placements,
+                      Diagnostics* diagnostics) {
+  const std::vector<std::string> kNames = PatternNames();
+  Position2DVec composed;
+  for (const Placement& placement : placements) {
+    const bool kEmbedded = std::find(kNames.begin(), kNames.end(),
+                                     placement.source) != kNames.end();
+    Pattern pattern(Orient((kEmbedded) ? FindPattern(placement.source)
+                                       : LoadPattern(placement.source,
+                                                     diagnostics),
+                           placement.orientation));
+    pattern.Translate(placement.x, placement.y);
+    composed.insert(composed.end(), pattern.Cells().begin(),
+                    pattern.Cells().end());
+  }
+
+  /* overlapping patterns share cells */
+  std::sort(composed.begin(), composed.end(),
+            [](const Position2D& a, const Position2D& b) {
+              return (a.y != b.y) ? (a.y < b.y) : (a.x < b.x);
+            });
+  composed.erase(std::unique(composed.begin(), composed.end(),
+                             [](const Position2D& a, const Position2D& b) {
+                               return (a.x == b.x) && (a.y == b.y);
+                             }),
+                 composed.end());
+  return composed;
+}
+
+}  // namespace pattern
+}  // namespace gol
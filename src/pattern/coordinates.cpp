@@ -0,0 +1,62 @@
+#include "pattern/coordinates.h"
+
+#include <cinttypes>
+#include <cstddef>
+#include <cstdio>
+#include <istream>
+#include <ostream>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+Position2DVec ParseCoordinates(std::istream& is, Diagnostics* diagnostics,
+                               PatternMetadata* metadata) {
+  Diagnostics strict;
+  Diagnostics& report = (diagnostics) ? *diagnostics : strict;
+
+  std::string line;
+  std::size_t line_num = 0;
+  Position2DVec live_cells;
+  while (std::getline(is, line)) {
+    line_num++;
+    if (line.find_first_not_of(" \t\r") == std::string::npos) {
+      continue;
+    } else if ('#' == line[0]) {
+      if (metadata) {
+        ParseMetadataLine(line, *metadata);
+      }
+      continue;
+    }
+
+    /* the whole line must be a position, %n is only set on a full match */
+    Position2D pos;
+    int length = -1;
+    std::sscanf(line.c_str(), " (%" SCNd32 " , %" SCNd32 " )%n", &pos.y,
+                &pos.x, &length);
+    if ((length < 0) ||
+        (line.find_first_not_of(" \t\r", length) != std::string::npos)) {
+      report.Report(line_num, "expected '(row, col)' but found '" + line + "'");
+      continue;
+    }
+    live_cells.push_back(pos);
+  }
+  return live_cells;
+}
+
+void WriteCoordinates(std::ostream& os, const Position2DVec& live_cells,
+                      const PatternMetadata* metadata) {
+  if (metadata) {
+    WriteMetadataLines(os, *metadata);
+  }
+  for (const Position2D& pos : live_cells) {
+    os << "(" << pos.y << ", " << pos.x << ")\n";
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
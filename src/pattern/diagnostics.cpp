@@ -0,0 +1,44 @@
+#include "pattern/diagnostics.h"
+
+#include <cstddef>
+#include <string>
+
+namespace gol {
+namespace pattern {
+
+std::string ToString(const ParseDiagnostic& diagnostic) {
+  std::string location = diagnostic.source;
+  if (diagnostic.line) {
+    location += (location.empty()) ? "line " : ":";
+    location += std::to_string(diagnostic.line);
+    if (diagnostic.column) {
+      location += ":" + std::to_string(diagnostic.column);
+    }
+  }
+  return (location.empty()) ? diagnostic.reason
+                            : location + ": " + diagnostic.reason;
+}
+
+ParseError::ParseError(const ParseDiagnostic& diagnostic)
+    : std::runtime_error(ToString(diagnostic)), diagnostic_(diagnostic) {}
+
+void Diagnostics::Report(std::size_t line, const std::string& reason) {
+  Report(line, 0, reason);
+}
+
+void Diagnostics::Report(std::size_t line, std::size_t column,
+                         const std::string& reason) {
+  const ParseDiagnostic kDiagnostic = {
+      .source = "", .line = line, .column = column, .reason = reason};
+  if (strict_) {
+    throw ParseError(kDiagnostic);
+  }
+  warnings_.push_back(kDiagnostic);
+}
+
+void Diagnostics::Add(const ParseDiagnostic& warning) {
+  warnings_.push_back(warning);
+}
+
+}  // namespace pattern
+}  // namespace gol
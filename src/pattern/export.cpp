@@ -0,0 +1,53 @@
+#include "pattern/export.h"
+
+#include <cstdint>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+#include "game/engine.h"
+#include "pattern/coordinates.h"
+#include "pattern/plaintext.h"
+#include "pattern/position.h"
+#include "pattern/rle.h"
+
+namespace gol {
+namespace pattern {
+
+Position2DVec ToPoints(const game::Engine& board) {
+  Position2DVec live_cells;
+  for (const game::LiveCell& cell : board.LiveCells()) {
+    if (!std::in_range<std::int32_t>(cell.row) ||
+        !std::in_range<std::int32_t>(cell.col)) {
+      throw std::out_of_range("cell (" + std::to_string(cell.row) + ", " +
+                              std::to_string(cell.col) +
+                              ") lies beyond the pattern coordinates");
+    }
+    live_cells.push_back({.x = static_cast<std::int32_t>(cell.col),
+                          .y = static_cast<std::int32_t>(cell.row),
+                          .state = cell.state});
+  }
+  return live_cells;
+}
+
+std::string ToRle(const game::Engine& board) {
+  std::ostringstream oss;
+  WriteRle(oss, ToPoints(board));
+  return oss.str();
+}
+
+std::string ToPlaintext(const game::Engine& board) {
+  std::ostringstream oss;
+  WritePlaintext(oss, ToPoints(board));
+  return oss.str();
+}
+
+std::string ToCoordinates(const game::Engine& board) {
+  std::ostringstream oss;
+  WriteCoordinates(oss, ToPoints(board));
+  return oss.str();
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,671 @@
+#include "pattern/json.h"
+
+#include <cmath>
+#include <cstddef>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <istream>
+#include <iterator>
+#include <limits>
+#include <memory>
+#include <ostream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/rule.h"
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+/* Largest magnitude below which every integer is exactly representable as a
+ * double. */
+static constexpr double kMaxExactInteger = 9007199254740992.0;
+
+/* Arrays and objects nested deeper than this are rejected rather than risking
+ * a stack overflow in the recursive descent parser. */
+static constexpr int kMaxDepth = 256;
+
+JsonValue::JsonValue(std::int64_t value)
+    : type_(Type::kNumber), number_(static_cast<double>(value)) {}
+
+JsonValue::JsonValue(std::uint64_t value)
+    : type_(Type::kNumber), number_(static_cast<double>(value)) {}
+
+JsonValue JsonValue::Array() {
+  JsonValue value;
+  value.type_ = Type::kArray;
+  return value;
+}
+
+JsonValue JsonValue::Object() {
+  JsonValue value;
+  value.type_ = Type::kObject;
+  return value;
+}
+
+bool JsonValue::AsBool() const {
+  if (Type::kBool != type_) {
+    throw std::invalid_argument("expected a boolean");
+  }
+  return bool_;
+}
+
+double JsonValue::AsNumber() const {
+  if (Type::kNumber != type_) {
+    throw std::invalid_argument("expected a number");
+  }
+  return number_;
+}
+
+std::int64_t JsonValue::AsInt() const {
+  const double kNumber = AsNumber();
+  if (std::trunc(kNumber) != kNumber || std::abs(kNumber) > kMaxExactInteger) {
+    throw std::invalid_argument("expected an integer");
+  }
+  return static_cast<std::int64_t>(kNumber);
+}
+
+const std::string& JsonValue::AsString() const {
+  if (Type::kString != type_) {
+    throw std::invalid_argument("expected a string");
+  }
+  return string_;
+}
+
+const std::vector<JsonValue>& JsonValue::AsArray() const {
+  if (Type::kArray != type_) {
+    throw std::invalid_argument("expected an array");
+  }
+  return elements_;
+}
+
+const std::vector<JsonValue::Member>& JsonValue::AsObject() const {
+  if (Type::kObject != type_) {
+    throw std::invalid_argument("expected an object");
+  }
+  return members_;
+}
+
+const JsonValue& JsonValue::Get(const std::string& key) const {
+  static const JsonValue kNull;
+  for (const Member& member : AsObject()) {
+    if (key == member.first) {
+      return member.second;
+    }
+  }
+  return kNull;
+}
+
+bool JsonValue::Contains(const std::string& key) const {
+  for (const Member& member : AsObject()) {
+    if (key == member.first) {
+      return true;
+    }
+  }
+  return false;
+}
+
+void JsonValue::Push(JsonValue value) {
+  if (Type::kArray != type_) {
+    throw std::invalid_argument("expected an array");
+  }
+  elements_.push_back(std::move(value));
+}
+
+void JsonValue::Set(const std::string& key, JsonValue value) {
+  if (Type::kObject != type_) {
+    throw std::invalid_argument("expected an object");
+  }
+  for (Member& member : members_) {
+    if (key == member.first) {
+      member.second = std::move(value);
+      return;
+    }
+  }
+  members_.emplace_back(key, std::move(value));
+}
+
+/* Recursive descent parser over a complete JSON document. */
+class JsonParser {
+ public:
+  explicit JsonParser(const std::string& text) : text_(text) {}
+
+  [[nodiscard]] JsonValue ParseDocument() {
+    JsonValue value = ParseValue(0);
+    SkipWhitespace();
+    if (pos_ < text_.size()) {
+      Fail("unexpected trailing characters");
+    }
+    return value;
+  }
+
+ private:
+  [[noreturn]] void Fail(const std::string& reason) const {
+    throw ParseError({.source = "", .line = line_, .reason = reason});
+  }
+
+  void SkipWhitespace() {
+    while (pos_ < text_.size()) {
+      const char kChar = text_[pos_];
+      if ('\n' == kChar) {
+        line_++;
+      } else if (' ' != kChar && '\t' != kChar && '\r' != kChar) {
+        return;
+      }
+      pos_++;
+    }
+  }
+
+  [[nodiscard]] bool Consume(char expected) {
+    SkipWhitespace();
+    if (pos_ < text_.size() && expected == text_[pos_]) {
+      pos_++;
+      return true;
+    }
+    return false;
+  }
+
+  void Expect(char expected) {
+    if (!Consume(expected)) {
+      Fail(std::string("expected '") + expected + "'");
+    }
+  }
+
+  [[nodiscard]] JsonValue ParseValue(int depth) {
+    SkipWhitespace();
+    if (pos_ >= text_.size()) {
+      Fail("unexpected end of input");
+    }
+
+    const char kChar = text_[pos_];
+    if ('{' == kChar) {
+      return ParseObject(depth + 1);
+    } else if ('[' == kChar) {
+      return ParseArray(depth + 1);
+    } else if ('"' == kChar) {
+      return JsonValue(ParseString());
+    } else if ('-' == kChar || (kChar >= '0' && kChar <= '9')) {
+      return ParseNumber();
+    } else if (0 == text_.compare(pos_, 4, "true")) {
+      pos_ += 4;
+      return JsonValue(true);
+    } else if (0 == text_.compare(pos_, 5, "false")) {
+      pos_ += 5;
+      return JsonValue(false);
+    } else if (0 == text_.compare(pos_, 4, "null")) {
+      pos_ += 4;
+      return JsonValue();
+    }
+    Fail(std::string("unexpected character '") + kChar + "'");
+  }
+
+  [[nodiscard]] JsonValue ParseObject(int depth) {
+    if (depth > kMaxDepth) {
+      Fail("nesting too deep");
+    }
+    pos_++;
+
+    JsonValue object = JsonValue::Object();
+    if (Consume('}')) {
+      return object;
+    }
+    do {
+      SkipWhitespace();
+      if (pos_ >= text_.size() || '"' != text_[pos_]) {
+        Fail("expected a member name");
+      }
+      std::string key = ParseString();
+      Expect(':');
+      object.Set(key, ParseValue(depth));
+    } while (Consume(','));
+    Expect('}');
+    return object;
+  }
+
+  [[nodiscard]] JsonValue ParseArray(int depth) {
+    if (depth > kMaxDepth) {
+      Fail("nesting too deep");
+    }
+    pos_++;
+
+    JsonValue array = JsonValue::Array();
+    if (Consume(']')) {
+      return array;
+    }
+    do {
+      array.Push(ParseValue(depth));
+    } while (Consume(','));
+    Expect(']');
+    return array;
+  }
+
+  [[nodiscard]] JsonValue ParseNumber() {
+    const std::size_t kStart = pos_;
+    const auto kDigits = [this] {
+      const std::size_t kFirst = pos_;
+      while (pos_ < text_.size() && text_[pos_] >= '0' && text_[pos_] <= '9') {
+        pos_++;
+      }
+      return pos_ - kFirst;
+    };
+
+    if ('-' == text_[pos_]) {
+      pos_++;
+    }
+    const bool kLeadingZero = (pos_ < text_.size() && '0' == text_[pos_]);
+    const std::size_t kIntegerDigits = kDigits();
+    if (0 == kIntegerDigits || (kLeadingZero && kIntegerDigits > 1)) {
+      Fail("invalid number");
+    }
+    if (pos_ < text_.size() && '.' == text_[pos_]) {
+      pos_++;
+      if (0 == kDigits()) {
+        Fail("invalid number");
+      }
+    }
+    if (pos_ < text_.size() && ('e' == text_[pos_] || 'E' == text_[pos_])) {
+      pos_++;
+      if (pos_ < text_.size() && ('+' == text_[pos_] || '-' == text_[pos_])) {
+        pos_++;
+      }
+      if (0 == kDigits()) {
+        Fail("invalid number");
+      }
+    }
+
+    const double kNumber =
+        std::strtod(text_.substr(kStart, pos_ - kStart).c_str(), nullptr);
+    if (!std::isfinite(kNumber)) {
+      Fail("number out of range");
+    }
+    return JsonValue(kNumber);
+  }
+
+  [[nodiscard]] unsigned ParseHex4() {
+    if (pos_ + 4 > text_.size()) {
+      Fail("invalid unicode escape");
+    }
+    unsigned code = 0;
+    for (int i = 0; i < 4; ++i) {
+      const char kChar = text_[pos_++];
+      code <<= 4;
+      if (kChar >= '0' && kChar <= '9') {
+        code |= static_cast<unsigned>(kChar - '0');
+      } else if (kChar >= 'a' && kChar <= 'f') {
+        code |= static_cast<unsigned>(kChar - 'a' + 10);
+      } else if (kChar >= 'A' && kChar <= 'F') {
+        code |= static_cast<unsigned>(kChar - 'A' + 10);
+      } else {
+        Fail("invalid unicode escape");
+      }
+    }
+    return code;
+  }
+
+  [[nodiscard]] static std::string EncodeUtf8(unsigned code) {
+    std::string utf8;
+    if (code < 0x80) {
+      utf8 += static_cast<char>(code);
+    } else if (code < 0x800) {
+      utf8 += static_cast<char>(0xC0 | (code >> 6));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    } else if (code < 0x10000) {
+      utf8 += static_cast<char>(0xE0 | (code >> 12));
+      utf8 += static_cast<char>(0x80 | ((code >> 6) & 0x3F));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    } else {
+      utf8 += static_cast<char>(0xF0 | (code >> 18));
+      utf8 += static_cast<char>(0x80 | ((code >> 12) & 0x3F));
+      utf8 += static_cast<char>(0x80 | ((code >> 6) & 0x3F));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    }
+    return utf8;
+  }
+
+  [[nodiscard]] std::string ParseString() {
+    pos_++;
+
+    std::string value;
+    while (pos_ < text_.size() && '"' != text_[pos_]) {
+      const char kChar = text_[pos_++];
+      if (static_cast<unsigned char>(kChar) < 0x20) {
+        Fail("control character in string");
+      } else if ('\\' != kChar) {
+        value += kChar;
+        continue;
+      }
+
+      if (pos_ >= text_.size()) {
+        break;
+      }
+      const char kEscape = text_[pos_++];
+      switch (kEscape) {
+        case '"':
+        case '\\':
+        case '/':
+          value += kEscape;
+          break;
+        case 'b':
+          value += '\b';
+          break;
+        case 'f':
+          value += '\f';
+          break;
+        case 'n':
+          value += '\n';
+          break;
+        case 'r':
+          value += '\r';
+          break;
+        case 't':
+          value += '\t';
+          break;
+        case 'u': {
+          unsigned code = ParseHex4();
+          /* characters outside the basic plane are escaped as a UTF-16
+           * surrogate pair */
+          if (code >= 0xD800 && code < 0xDC00) {
+            if (0 != text_.compare(pos_, 2, "\\u")) {
+              Fail("invalid unicode escape");
+            }
+            pos_ += 2;
+            const unsigned kLow = ParseHex4();
+            if (kLow < 0xDC00 || kLow >= 0xE000) {
+              Fail("invalid unicode escape");
+            }
+            code = 0x10000 + ((code - 0xD800) << 10) + (kLow - 0xDC00);
+          } else if (code >= 0xDC00 && code < 0xE000) {
+            Fail("invalid unicode escape");
+          }
+          value += EncodeUtf8(code);
+          break;
+        }
+        default:
+          Fail(std::string("invalid escape '\\") + kEscape + "'");
+      }
+    }
+    if (pos_ >= text_.size()) {
+      Fail("unterminated string");
+    }
+    pos_++;
+    return value;
+  }
+
+  const std::string& text_;
+  std::size_t pos_ = 0;
+  std::size_t line_ = 1;
+};
+
+JsonValue ParseJson(const std::string& text) {
+  return JsonParser(text).ParseDocument();
+}
+
+static void DumpString(const std::string& value, std::string& out) {
+  static constexpr const char* kHex = "0123456789abcdef";
+
+  out += '"';
+  for (const char kChar : value) {
+    switch (kChar) {
+      case '"':
+        out += "\\\"";
+        break;
+      case '\\':
+        out += "\\\\";
+        break;
+      case '\n':
+        out += "\\n";
+        break;
+      case '\r':
+        out += "\\r";
+        break;
+      case '\t':
+        out += "\\t";
+        break;
+      default:
+        if (static_cast<unsigned char>(kChar) < 0x20) {
+          out += "\\u00";
+          out += kHex[(kChar >> 4) & 0xF];
+          out += kHex[kChar & 0xF];
+        } else {
+          out += kChar;
+        }
+    }
+  }
+  out += '"';
+}
+
+static void DumpValue(const JsonValue& value, std::string& out) {
+  switch (value.GetType()) {
+    case JsonValue::Type::kNull:
+      out += "null";
+      return;
+    case JsonValue::Type::kBool:
+      out += (value.AsBool()) ? "true" : "false";
+      return;
+    case JsonValue::Type::kNumber: {
+      const double kNumber = value.AsNumber();
+      if (!std::isfinite(kNumber)) {
+        out += "null";
+      } else if (std::trunc(kNumber) == kNumber &&
+                 std::abs(kNumber) <= kMaxExactInteger) {
+        out += std::to_string(static_cast<std::int64_t>(kNumber));
+      } else {
+        char buffer[32] = {};
+        std::snprintf(buffer, sizeof(buffer), "%.17g", kNumber);
+        out += buffer;
+      }
+      return;
+    }
+    case JsonValue::Type::kString:
+      DumpString(value.AsString(), out);
+      return;
+    case JsonValue::Type::kArray: {
+      out += '[';
+      bool first = true;
+      for (const JsonValue& element : value.AsArray()) {
+        out += (first) ? "" : ",";
+        DumpValue(element, out);
+        first = false;
+      }
+      out += ']';
+      return;
+    }
+    case JsonValue::Type::kObject: {
+      out += '{';
+      bool first = true;
+      for (const JsonValue::Member& member : value.AsObject()) {
+        out += (first) ? "" : ",";
+        DumpString(member.first, out);
+        out += ':';
+        DumpValue(member.second, out);
+        first = false;
+      }
+      out += '}';
+      return;
+    }
+  }
+}
+
+std::string DumpJson(const JsonValue& value) {
+  std::string out;
+  DumpValue(value, out);
+  return out;
+}
+
+JsonValue ToJson(const Position2D& pos) {
+  JsonValue json = JsonValue::Array();
+  json.Push(pos.x);
+  json.Push(pos.y);
+  if (1 != pos.state) {
+    json.Push(static_cast<std::uint64_t>(pos.state));
+  }
+  return json;
+}
+
+Position2D PositionFromJson(const JsonValue& json) {
+  const std::vector<JsonValue>& kCoordinates = json.AsArray();
+  if ((2 != kCoordinates.size()) && (3 != kCoordinates.size())) {
+    throw std::invalid_argument("expected an [x, y] or [x, y, state] position");
+  }
+
+  constexpr std::int64_t kMin = std::numeric_limits<std::int32_t>::min();
+  constexpr std::int64_t kMax = std::numeric_limits<std::int32_t>::max();
+  const std::int64_t kX = kCoordinates[0].AsInt();
+  const std::int64_t kY = kCoordinates[1].AsInt();
+  if (kX < kMin || kX > kMax || kY < kMin || kY > kMax) {
+    throw std::invalid_argument("position out of range");
+  }
+  const std::int64_t kState =
+      (3 == kCoordinates.size()) ? kCoordinates[2].AsInt() : 1;
+  if (kState < 1 || kState > std::numeric_limits<std::uint8_t>::max()) {
+    throw std::invalid_argument("cell state out of range");
+  }
+  return {.x = static_cast<std::int32_t>(kX),
+          .y = static_cast<std::int32_t>(kY),
+          .state = static_cast<std::uint8_t>(kState)};
+}
+
+JsonValue ToJson(const game::Rule& rule) { return rule.ToString(); }
+
+game::Rule RuleFromJson(const JsonValue& json) {
+  return game::Rule(json.AsString());
+}
+
+[[nodiscard]] static JsonValue CellsToJson(const Position2DVec& live_cells) {
+  JsonValue cells = JsonValue::Array();
+  for (const Position2D& cell : live_cells) {
+    cells.Push(ToJson(cell));
+  }
+  return cells;
+}
+
+[[nodiscard]] static Position2DVec CellsFromJson(const JsonValue& json) {
+  Position2DVec live_cells;
+  for (const JsonValue& cell : json.AsArray()) {
+    live_cells.push_back(PositionFromJson(cell));
+  }
+  return live_cells;
+}
+
+JsonValue ToJson(const game::GameOfLifeBoard& board) {
+  Position2DVec live_cells;
+  for (std::size_t i = 0; i < board.Rows(); ++i) {
+    for (std::size_t j = 0; j < board.Cols(); ++j) {
+      if (board.IsAlive(i, j)) {
+        live_cells.push_back({.x = static_cast<std::int32_t>(j),
+                              .y = static_cast<std::int32_t>(i),
+                              .state = board.State(i, j)});
+      }
+    }
+  }
+
+  JsonValue json = JsonValue::Object();
+  json.Set("rows", static_cast<std::uint64_t>(board.Rows()));
+  json.Set("cols", static_cast<std::uint64_t>(board.Cols()));
+  json.Set("wrap", board.Wraps());
+  if (board.GetAutomaton()) {
+    json.Set("automaton", board.GetAutomaton()->Name());
+  } else {
+    json.Set("rule", ToJson(board.GetRule()));
+  }
+  json.Set("generation", board.Generation());
+  json.Set("population", board.Population());
+  json.Set("cells", CellsToJson(live_cells));
+  return json;
+}
+
+game::GameOfLifeBoard BoardFromJson(const JsonValue& json) {
+  const std::int64_t kRows = json.Get("rows").AsInt();
+  const std::int64_t kCols = json.Get("cols").AsInt();
+  if (kRows <= 0 || kCols <= 0) {
+    throw std::invalid_argument("board dimensions must be positive");
+  }
+  const bool kWrap = json.Contains("wrap") && json.Get("wrap").AsBool();
+  const game::Rule kRule =
+      (json.Contains("rule")) ? RuleFromJson(json.Get("rule")) : game::Rule();
+
+  /* multi-state boards are named after their automaton instead of a rule */
+  std::shared_ptr<const game::Automaton> automaton;
+  if (json.Contains("automaton")) {
+    const std::string kName = json.Get("automaton").AsString();
+    automaton = game::FindAutomaton(kName);
+    if (!automaton) {
+      throw std::invalid_argument("unknown automaton -> " + kName);
+    }
+  }
+  const auto kNumRows = static_cast<std::size_t>(kRows);
+  const auto kNumCols = static_cast<std::size_t>(kCols);
+  game::GameOfLifeBoard board =
+      (automaton) ? game::GameOfLifeBoard(kNumRows, kNumCols, kWrap, automaton)
+                  : game::GameOfLifeBoard(kNumRows, kNumCols, kWrap, kRule);
+  for (const Position2D& cell : CellsFromJson(json.Get("cells"))) {
+    if (!OnBoard(cell, board.Rows(), board.Cols())) {
+      throw std::invalid_argument("cell lies outside of the board");
+    } else if (cell.state >= board.NumStates()) {
+      throw std::invalid_argument("cell state out of range");
+    }
+    board.SetState(static_cast<std::size_t>(cell.y),
+                   static_cast<std::size_t>(cell.x), cell.state);
+  }
+  return board;
+}
+
+Position2DVec ParseJsonPattern(std::istream& is, PatternMetadata* metadata) {
+  const std::string kContents((std::istreambuf_iterator<char>(is)),
+                              std::istreambuf_iterator<char>());
+  const JsonValue kJson = ParseJson(kContents);
+  try {
+    if (metadata) {
+      const auto kString = [&kJson](const std::string& key) {
+        return (kJson.Contains(key)) ? kJson.Get(key).AsString() : "";
+      };
+      metadata->name = kString("name");
+      metadata->author = kString("author");
+      metadata->description = kString("description");
+      metadata->rule = kString("rule");
+    }
+    return CellsFromJson(kJson.Get("cells"));
+  } catch (const std::invalid_argument& e) {
+    throw ParseError({.source = "", .line = 0, .reason = e.what()});
+  }
+}
+
+void WriteJsonPattern(std::ostream& os, const Position2DVec& live_cells,
+                      const PatternMetadata* metadata) {
+  JsonValue json = JsonValue::Object();
+  if (metadata) {
+    const std::pair<const char*, const std::string*> kMembers[] = {
+        {"name", &metadata->name},
+        {"author", &metadata->author},
+        {"description", &metadata->description},
+        {"rule", &metadata->rule},
+    };
+    for (const auto& [key, value] : kMembers) {
+      if (!value->empty()) {
+        json.Set(key, *value);
+      }
+    }
+  }
+  json.Set("cells", CellsToJson(live_cells));
+  os << DumpJson(json) << "\n";
+}
+
+void WriteJsonLine(std::ostream& os, std::uint64_t generation,
+                   const Position2DVec& live_cells) {
+  JsonValue json = JsonValue::Object();
+  json.Set("generation", generation);
+  json.Set("population", static_cast<std::uint64_t>(live_cells.size()));
+  json.Set("cells", CellsToJson(live_cells));
+  os << DumpJson(json) << "\n";
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,66 @@
+#include "pattern/library.h"
+
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "pattern/position.h"
+#include "pattern/rle.h"
+
+namespace gol {
+namespace pattern {
+
+const std::vector<LibraryPattern>& PatternLibrary() {
+  static const std::vector<LibraryPattern> kLibrary = {
+      {.name = "glider",
+       .description = "the smallest spaceship, moves diagonally",
+       .rle = "x = 3, y = 3\n"
+              "bo$2bo$3o!\n"},
+      {.name = "lwss",
+       .description = "lightweight spaceship, moves orthogonally",
+       .rle = "x = 5, y = 4\n"
+              "bo2bo$o$o3bo$4o!\n"},
+      {.name = "pulsar",
+       .description = "the most common period 3 oscillator",
+       .rle = "x = 13, y = 13\n"
+              "2b3o3b3o2$o4bobo4bo$o4bobo4bo$o4bobo4bo$2b3o3b3o2$2b3o3b3o$"
+              "o4bobo4bo$o4bobo4bo$o4bobo4bo2$2b3o3b3o!\n"},
+      {.name = "gosper",
+       .description = "Gosper glider gun, emits a glider every 30 "
+                      "generations",
+       .rle = "x = 36, y = 9\n"
+              "24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$"
+              "2o8bo3bob2o4bobo$10bo5bo7bo$11bo3bo$12b2o!\n"},
+      {.name = "r-pentomino",
+       .description = "methuselah stabilizing after 1103 generations",
+       .rle = "x = 3, y = 3\n"
+              "b2o$2o$bo!\n"},
+      {.name = "acorn",
+       .description = "methuselah stabilizing after 5206 generations",
+       .rle = "x = 7, y = 3\n"
+              "bo$3bo$2o2b3o!\n"},
+  };
+  return kLibrary;
+}
+
+std::vector<std::string> PatternNames() {
+  std::vector<std::string> names;
+  for (const LibraryPattern& pattern : PatternLibrary()) {
+    names.push_back(pattern.name);
+  }
+  return names;
+}
+
+Position2DVec FindPattern(const std::string& name) {
+  for (const LibraryPattern& pattern : PatternLibrary()) {
+    if (pattern.name == name) {
+      std::istringstream iss(pattern.rle);
+      return ParseRle(iss);
+    }
+  }
+  throw std::invalid_argument("unknown pattern -> " + name);
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,75 @@
+#include "pattern/life106.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <istream>
+#include <ostream>
+#include <sstream>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+Position2DVec ParseLife106(std::istream& is, Diagnostics* diagnostics,
+                           PatternMetadata* metadata) {
+  const std::string kHeader = "#Life 1.06";
+
+  Diagnostics strict;
+  Diagnostics& report = (diagnostics) ? *diagnostics : strict;
+
+  Position2DVec live_cells;
+  std::string line;
+  std::size_t line_num = 0;
+  while (std::getline(is, line)) {
+    line_num++;
+
+    /* a first line other than the header is parsed as part of the pattern in
+     * lenient mode */
+    if ((1 == line_num) && line.starts_with(kHeader)) {
+      continue;
+    } else if (1 == line_num) {
+      report.Report(line_num, "missing '" + kHeader + "' header");
+    }
+    if (line.empty()) {
+      continue;
+    } else if ('#' == line[0]) {
+      if (metadata && line.starts_with("#D")) {
+        ParseMetadataLine("#C" + line.substr(2), *metadata);
+      } else if (metadata) {
+        ParseMetadataLine(line, *metadata);
+      }
+      continue;
+    }
+
+    /* the whole line must be a coordinate pair, only whitespace may follow */
+    Position2D pos;
+    std::istringstream iss(line);
+    if (!(iss >> pos.x >> pos.y) || !(iss >> std::ws).eof()) {
+      report.Report(line_num, "invalid coordinate pair '" + line + "'");
+      continue;
+    }
+    live_cells.push_back(pos);
+  }
+  if (0 == line_num) {
+    report.Report(1, "missing '" + kHeader + "' header");
+  }
+  return live_cells;
+}
+
+void WriteLife106(std::ostream& os, const Position2DVec& live_cells,
+                  const PatternMetadata* metadata) {
+  os << "#Life 1.06\n";
+  if (metadata) {
+    WriteMetadataLines(os, *metadata);
+  }
+  for (const Position2D& pos : live_cells) {
+    os << pos.x << " " << pos.y << "\n";
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,190 @@
+#include "pattern/loader.h"
+
+#include <array>
+#include <fstream>
+#include <iostream>
+#include <istream>
+#include <iterator>
+#include <ostream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "pattern/coordinates.h"
+#include "pattern/diagnostics.h"
+#include "pattern/json.h"
+#include "pattern/life106.h"
+#include "pattern/metadata.h"
+#include "pattern/plaintext.h"
+#include "pattern/position.h"
+#include "pattern/rle.h"
+
+namespace gol {
+namespace pattern {
+
+struct FormatInfo {
+  const char* name;
+  const char* extension;
+  Format format;
+};
+
+static constexpr std::array<FormatInfo, 5> kFormats = {{
+    {"coords", ".txt", Format::kCoordinates},
+    {"rle", ".rle", Format::kRle},
+    {"life106", ".lif", Format::kLife106},
+    {"plaintext", ".cells", Format::kPlaintext},
+    {"json", ".json", Format::kJson},
+}};
+
+Format ParseFormat(const std::string& name) {
+  for (const FormatInfo& info : kFormats) {
+    if (name == info.name) {
+      return info.format;
+    }
+  }
+  throw std::invalid_argument("unknown pattern format -> " + name);
+}
+
+std::vector<std::string> FormatNames() {
+  std::vector<std::string> names;
+  for (const FormatInfo& info : kFormats) {
+    names.push_back(info.name);
+  }
+  return names;
+}
+
+std::string FormatExtension(Format format) {
+  for (const FormatInfo& info : kFormats) {
+    if (format == info.format) {
+      return info.extension;
+    }
+  }
+  return "";
+}
+
+[[nodiscard]] static std::string FirstNonBlankLine(
+    const std::string& contents, bool skip_comments) {
+  std::istringstream iss(contents);
+  std::string line;
+  while (std::getline(iss, line)) {
+    if (skip_comments && line.starts_with("#")) {
+      continue;
+    } else if (line.find_first_not_of(" \t\r") != std::string::npos) {
+      return line;
+    }
+  }
+  return "";
+}
+
+Format DetectFormat(const std::string& filename, const std::string& contents) {
+  if (filename.ends_with(".rle")) {
+    return Format::kRle;
+  } else if (filename.ends_with(".lif") || filename.ends_with(".life")) {
+    return Format::kLife106;
+  } else if (filename.ends_with(".cells")) {
+    return Format::kPlaintext;
+  } else if (filename.ends_with(".json")) {
+    return Format::kJson;
+  }
+
+  /* '#' comments are shared by the coordinate, RLE and Life 1.06 formats,
+   * the first line after them tells the formats apart */
+  const std::string kFirstLine = FirstNonBlankLine(contents, false);
+  const std::string kLine = FirstNonBlankLine(contents, true);
+  if (kFirstLine.starts_with("#Life 1.06")) {
+    return Format::kLife106;
+  } else if (kFirstLine.starts_with("{")) {
+    return Format::kJson;
+  } else if (kLine.starts_with("(")) {
+    return Format::kCoordinates;
+  } else if (kLine.starts_with("x") ||
+             (kFirstLine.starts_with("#") && !kLine.empty())) {
+    return Format::kRle;
+  } else if (kLine.starts_with("!") ||
+             (!kLine.empty() &&
+              (kLine.find_first_not_of(".O*\r") == std::string::npos))) {
+    return Format::kPlaintext;
+  }
+  return Format::kCoordinates;
+}
+
+Position2DVec Parse(Format format, std::istream& is,
+                    Diagnostics* diagnostics, PatternMetadata* metadata) {
+  switch (format) {
+    case Format::kRle:
+      return ParseRle(is, diagnostics, metadata);
+    case Format::kLife106:
+      return ParseLife106(is, diagnostics, metadata);
+    case Format::kPlaintext:
+      return ParsePlaintext(is, diagnostics, metadata);
+    case Format::kJson:
+      return ParseJsonPattern(is, metadata);
+    case Format::kCoordinates:
+      break;
+  }
+  return ParseCoordinates(is, diagnostics, metadata);
+}
+
+void Write(Format format, std::ostream& os, const Position2DVec& live_cells,
+           const PatternMetadata* metadata) {
+  switch (format) {
+    case Format::kRle:
+      WriteRle(os, live_cells, metadata);
+      return;
+    case Format::kLife106:
+      WriteLife106(os, live_cells, metadata);
+      return;
+    case Format::kPlaintext:
+      WritePlaintext(os, live_cells, metadata);
+      return;
+    case Format::kJson:
+      WriteJsonPattern(os, live_cells, metadata);
+      return;
+    case Format::kCoordinates:
+      break;
+  }
+  WriteCoordinates(os, live_cells, metadata);
+}
+
+Position2DVec LoadPattern(const std::string& filename,
+                          Diagnostics* diagnostics,
+                          PatternMetadata* metadata) {
+  const bool kStdin = ("-" == filename);
+  std::ifstream fhandle;
+  if (!kStdin) {
+    fhandle.open(filename);
+    if (!fhandle) {
+      throw std::invalid_argument("invalid file path ->" + filename);
+    }
+  }
+  std::istream& is = (kStdin) ? std::cin : fhandle;
+
+  /* the file is buffered in memory so that it can be inspected during format
+   * detection and then parsed from the beginning */
+  const std::string kContents((std::istreambuf_iterator<char>(is)),
+                              std::istreambuf_iterator<char>());
+  const std::string kSource = (kStdin) ? "<stdin>" : filename;
+  std::istringstream iss(kContents);
+  Diagnostics file_diagnostics(!diagnostics || diagnostics->Strict());
+  Position2DVec live_cells;
+  try {
+    live_cells = Parse(DetectFormat(filename, kContents), iss,
+                       &file_diagnostics, metadata);
+  } catch (const ParseError& e) {
+    ParseDiagnostic diagnostic = e.Diagnostic();
+    diagnostic.source = kSource;
+    throw ParseError(diagnostic);
+  }
+
+  if (diagnostics) {
+    for (ParseDiagnostic warning : file_diagnostics.Warnings()) {
+      warning.source = kSource;
+      diagnostics->Add(warning);
+    }
+  }
+  return live_cells;
+}
+
+}  // namespace pattern
+}  // namespace gol
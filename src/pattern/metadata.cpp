@@ -0,0 +1,72 @@
+#include "pattern/metadata.h"
+
+#include <cstddef>
+#include <ostream>
+#include <string>
+
+namespace gol {
+namespace pattern {
+
+/* Return str without leading and trailing whitespace. */
+[[nodiscard]] static std::string Trim(const std::string& str) {
+  const std::size_t kBegin = str.find_first_not_of(" \t\r");
+  if (std::string::npos == kBegin) {
+    return "";
+  }
+  return str.substr(kBegin, str.find_last_not_of(" \t\r") - kBegin + 1);
+}
+
+bool ParseMetadataLine(const std::string& line, PatternMetadata& metadata) {
+  if ((line.size() < 2) || ('#' != line[0])) {
+    return false;
+  }
+
+  const std::string kValue = Trim(line.substr(2));
+  switch (line[1]) {
+    case 'N':
+      metadata.name = kValue;
+      return true;
+    case 'O':
+      metadata.author = kValue;
+      return true;
+    case 'C':
+    case 'c':
+      if (!kValue.empty()) {
+        metadata.description +=
+            (metadata.description.empty()) ? kValue : " " + kValue;
+      }
+      return true;
+    case 'r':
+      metadata.rule = kValue;
+      return true;
+    default:
+      return false;
+  }
+}
+
+void WriteMetadataLines(std::ostream& os, const PatternMetadata& metadata) {
+  if (!metadata.name.empty()) {
+    os << "#N " << metadata.name << "\n";
+  }
+  if (!metadata.author.empty()) {
+    os << "#O " << metadata.author << "\n";
+  }
+  if (!metadata.description.empty()) {
+    os << "#C " << metadata.description << "\n";
+  }
+  if (!metadata.rule.empty()) {
+    os << "#r " << metadata.rule << "\n";
+  }
+}
+
+std::string Title(const PatternMetadata& metadata) {
+  if (metadata.name.empty()) {
+    return "";
+  } else if (metadata.author.empty()) {
+    return metadata.name;
+  }
+  return metadata.name + " by " + metadata.author;
+}
+
+}  // namespace pattern
+}  // namespace gol
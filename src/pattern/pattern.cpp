@@ -0,0 +1,94 @@
+#include "pattern/pattern.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <utility>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+Pattern::Pattern(Position2DVec cells) : cells_(std::move(cells)) {}
+
+Position2D Pattern::Min() const noexcept {
+  if (cells_.empty()) {
+    return {};
+  }
+
+  Position2D min = cells_.front();
+  for (const Position2D& pos : cells_) {
+    min.x = std::min(min.x, pos.x);
+    min.y = std::min(min.y, pos.y);
+  }
+  return min;
+}
+
+Position2D Pattern::Max() const noexcept {
+  if (cells_.empty()) {
+    return {};
+  }
+
+  Position2D max = cells_.front();
+  for (const Position2D& pos : cells_) {
+    max.x = std::max(max.x, pos.x);
+    max.y = std::max(max.y, pos.y);
+  }
+  return max;
+}
+
+std::size_t Pattern::Width() const noexcept {
+  return (cells_.empty()) ? 0
+                          : static_cast<std::size_t>(Max().x - Min().x) + 1;
+}
+
+std::size_t Pattern::Height() const noexcept {
+  return (cells_.empty()) ? 0
+                          : static_cast<std::size_t>(Max().y - Min().y) + 1;
+}
+
+Pattern& Pattern::Rotate90() {
+  /* the column of a cell is its distance from the bottom of the bounding box
+   * and its row is its distance from the left */
+  const Position2D kMin = Min();
+  const Position2D kMax = Max();
+  for (Position2D& pos : cells_) {
+    pos = {.x = kMin.x + (kMax.y - pos.y),
+           .y = kMin.y + (pos.x - kMin.x),
+           .state = pos.state};
+  }
+  return *this;
+}
+
+Pattern& Pattern::FlipH() {
+  const std::int32_t kSum = Min().x + Max().x;
+  for (Position2D& pos : cells_) {
+    pos.x = kSum - pos.x;
+  }
+  return *this;
+}
+
+Pattern& Pattern::FlipV() {
+  const std::int32_t kSum = Min().y + Max().y;
+  for (Position2D& pos : cells_) {
+    pos.y = kSum - pos.y;
+  }
+  return *this;
+}
+
+Pattern& Pattern::Translate(std::int32_t dx, std::int32_t dy) {
+  for (Position2D& pos : cells_) {
+    pos.x += dx;
+    pos.y += dy;
+  }
+  return *this;
+}
+
+Pattern& Pattern::Normalize() {
+  const Position2D kMin = Min();
+  return Translate(-kMin.x, -kMin.y);
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,106 @@
+#include "pattern/plaintext.h"
+
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <istream>
+#include <ostream>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+Position2DVec ParsePlaintext(std::istream& is, Diagnostics* diagnostics,
+                             PatternMetadata* metadata) {
+  Diagnostics strict;
+  Diagnostics& report = (diagnostics) ? *diagnostics : strict;
+
+  Position2DVec live_cells;
+  std::string line;
+  std::size_t row = 0;
+  std::size_t line_num = 0;
+  while (std::getline(is, line)) {
+    line_num++;
+    /* tolerate files saved with DOS line endings */
+    if (!line.empty() && ('\r' == line.back())) {
+      line.pop_back();
+    }
+
+    if (!line.empty() && ('!' == line[0])) {
+      /* comments map onto the RLE metadata tags */
+      if (metadata && line.starts_with("!Name:")) {
+        ParseMetadataLine("#N" + line.substr(6), *metadata);
+      } else if (metadata && line.starts_with("!Author:")) {
+        ParseMetadataLine("#O" + line.substr(8), *metadata);
+      } else if (metadata) {
+        ParseMetadataLine("#C" + line.substr(1), *metadata);
+      }
+      continue;
+    }
+
+    for (std::size_t col = 0; col < line.size(); ++col) {
+      if (('O' == line[col]) || ('*' == line[col])) {
+        live_cells.push_back({.x = static_cast<std::int32_t>(col),
+                              .y = static_cast<std::int32_t>(row),
+                              .state = 1});
+      } else if ('.' != line[col]) {
+        report.Report(line_num, "invalid cell '" + std::string(1, line[col]) +
+                                    "' on pattern row " +
+                                    std::to_string(row));
+      }
+    }
+    row++;
+  }
+  return live_cells;
+}
+
+void WritePlaintext(std::ostream& os, const Position2DVec& live_cells,
+                    const PatternMetadata* metadata) {
+  if (metadata && !metadata->name.empty()) {
+    os << "!Name: " << metadata->name << "\n";
+  }
+  if (metadata && !metadata->author.empty()) {
+    os << "!Author: " << metadata->author << "\n";
+  }
+  if (metadata && !metadata->description.empty()) {
+    os << "!" << metadata->description << "\n";
+  }
+
+  /* the grid cannot hold negative positions, such patterns are shifted right
+   * and down onto it */
+  Pattern pattern(live_cells);
+  const Position2D kMin = pattern.Min();
+  pattern.Translate(std::max(0, -kMin.x), std::max(0, -kMin.y));
+
+  Position2DVec sorted = pattern.Cells();
+  std::sort(sorted.begin(), sorted.end(),
+            [](const Position2D& a, const Position2D& b) {
+              return (a.y < b.y) || ((a.y == b.y) && (a.x < b.x));
+            });
+
+  /* rows are written up to their last live cell */
+  std::size_t row = 0;
+  std::string line;
+  for (const Position2D& pos : sorted) {
+    for (; row < static_cast<std::size_t>(pos.y); ++row) {
+      os << line << "\n";
+      line.clear();
+    }
+    const auto kCol = static_cast<std::size_t>(pos.x);
+    if (kCol >= line.size()) {
+      line.resize(kCol + 1, '.');
+    }
+    line[kCol] = 'O';
+  }
+  if (!sorted.empty()) {
+    os << line << "\n";
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,148 @@
+#include "pattern/replay.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <memory>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/pipeline.h"
+#include "pattern/export.h"
+#include "pattern/position.h"
+#include "pattern/rle.h"
+
+namespace gol {
+namespace pattern {
+
+static const std::string kReplayHeader = "#Replay 1";
+static const std::string kSizeTag = "#Size";
+static const std::string kStatesTag = "#States";
+static const std::string kGenerationTag = "#Generation";
+
+ReplayWriter::ReplayWriter(const std::string& filename, std::size_t num_rows,
+                           std::size_t num_cols, std::uint8_t num_states)
+    : file_(filename) {
+  if (!file_) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+  file_ << kReplayHeader << "\n"
+        << kSizeTag << " " << num_rows << " " << num_cols << "\n";
+  if (num_states > 2) {
+    file_ << kStatesTag << " " << static_cast<int>(num_states) << "\n";
+  }
+}
+
+void ReplayWriter::Write(const game::Frame& frame) {
+  file_ << kGenerationTag << " " << frame.generation << " "
+        << frame.population << "\n";
+  WriteRle(file_, ToPoints(frame.cells));
+}
+
+ReplayBoard::ReplayBoard(const std::string& filename)
+    : file_(filename), cells_(0, 0) {
+  if (!file_) {
+    throw std::invalid_argument("invalid file path ->" + filename);
+  }
+
+  std::string line;
+  if (!std::getline(file_, line) || !line.starts_with(kReplayHeader)) {
+    throw std::runtime_error("missing '" + kReplayHeader + "' header");
+  }
+
+  std::size_t num_rows = 0;
+  std::size_t num_cols = 0;
+  std::string tag;
+  std::getline(file_, line);
+  std::istringstream iss(line);
+  if (!(iss >> tag >> num_rows >> num_cols) || (tag != kSizeTag)) {
+    throw std::runtime_error("missing '" + kSizeTag + " ROWS COLS' line");
+  }
+
+  /* the same limits as those of --board-size, given as WxH */
+  const std::size_t kMax = game::GameOfLifeBoard::kMaxSide;
+  if ((0 == num_rows) || (0 == num_cols) || (num_rows > kMax) ||
+      (num_cols > kMax)) {
+    throw std::runtime_error(
+        "replay board size must be positive and at most " +
+        std::to_string(kMax) + "x" + std::to_string(kMax) + " -> " +
+        std::to_string(num_cols) + "x" + std::to_string(num_rows));
+  }
+  cells_ = game::GameOfLifeBoard(num_rows, num_cols);
+
+  /* the states of multi-state recordings are stored alongside the bits */
+  std::getline(file_, header_);
+  if (header_.starts_with(kStatesTag)) {
+    int num_states = 0;
+    std::istringstream states(header_);
+    if (!(states >> tag >> num_states) || (tag != kStatesTag) ||
+        (num_states < 2) || (num_states > UINT8_MAX)) {
+      throw std::runtime_error("malformed '" + kStatesTag + " STATES' line");
+    }
+    cells_ = game::GameOfLifeBoard(
+        num_rows, num_cols, false,
+        std::make_shared<game::StaticStates>(
+            "replay", static_cast<std::uint8_t>(num_states),
+            std::vector<std::uint32_t>{}));
+    std::getline(file_, header_);
+  }
+  if (!ReadFrame()) {
+    throw std::runtime_error("replay contains no frames");
+  }
+}
+
+bool ReplayBoard::ReadFrame() {
+  std::string tag;
+  std::uint64_t generation = 0;
+  std::uint64_t population = 0;
+  std::istringstream header(header_);
+  if (!(header >> tag >> generation >> population) ||
+      (tag != kGenerationTag)) {
+    return false;
+  }
+
+  /* the frame's cells extend to the next frame's header */
+  std::string line;
+  std::string body;
+  header_.clear();
+  while (std::getline(file_, line)) {
+    if (line.starts_with(kGenerationTag)) {
+      header_ = line;
+      break;
+    }
+    body += line + "\n";
+  }
+
+  std::istringstream iss(body);
+  game::GameOfLifeBoard cells(cells_.Rows(), cells_.Cols(), false,
+                              cells_.GetAutomaton());
+  for (const Position2D& pos : ParseRle(iss)) {
+    if (!OnBoard(pos, cells.Rows(), cells.Cols())) {
+      throw std::runtime_error("replay frame of generation " +
+                               std::to_string(generation) +
+                               " does not fit within the board");
+    } else if (pos.state >= cells.NumStates()) {
+      throw std::runtime_error(
+          "replay frame of generation " + std::to_string(generation) +
+          " holds cells in state " + std::to_string(pos.state) +
+          ", the replay has " + std::to_string(cells.NumStates()) +
+          " states");
+    }
+    cells.SetState(pos.y, pos.x, pos.state);
+  }
+
+  cells_ = std::move(cells);
+  generation_ = generation;
+  population_ = population;
+  return true;
+}
+
+void ReplayBoard::Tick() { ReadFrame(); }
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,240 @@
+#include "pattern/rle.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cstddef>
+#include <cstdint>
+#include <istream>
+#include <limits>
+#include <ostream>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/metadata.h"
+#include "pattern/pattern.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+[[nodiscard]] static bool IsHeaderLine(const std::string& line) noexcept {
+  std::size_t i = line.find_first_not_of(" \t");
+  return (i != std::string::npos) && (line[i] == 'x');
+}
+
+/* Return the value of the 'rule = ...' field of a header line, if any. The
+ * rule is the last field, HROT rulestrings contain commas themselves. */
+[[nodiscard]] static std::string HeaderRule(const std::string& line) {
+  const std::size_t kKey = line.find("rule");
+  const std::size_t kEquals = line.find('=', kKey);
+  if ((std::string::npos == kKey) || (std::string::npos == kEquals)) {
+    return "";
+  }
+
+  const std::size_t kBegin = line.find_first_not_of(" \t", kEquals + 1);
+  if (std::string::npos == kBegin) {
+    return "";
+  }
+  const std::size_t kEnd = line.find_first_of(" \t\r", kBegin);
+  return line.substr(kBegin, kEnd - kBegin);
+}
+
+/* Multi-state patterns mark states 1 to 24 with 'A' to 'X', higher states
+ * prefix the letter with 'p' (states 25 to 48) up to 'y' (states 241 to
+ * 255). */
+static constexpr std::int32_t kStatesPerLetterSet = 24;
+
+/* Longest run accepted, such that a run of live cells fits in memory. */
+static constexpr std::size_t kMaxRunCount = std::size_t{1} << 24;
+
+/* Return the multi-state RLE tag of a live cell state. */
+[[nodiscard]] static std::string StateTag(std::uint8_t state) {
+  const std::int32_t kIndex = state - 1;
+  std::string tag;
+  if (kIndex >= kStatesPerLetterSet) {
+    tag += static_cast<char>('p' + (kIndex / kStatesPerLetterSet) - 1);
+  }
+  tag += static_cast<char>('A' + (kIndex % kStatesPerLetterSet));
+  return tag;
+}
+
+Position2DVec ParseRle(std::istream& is, Diagnostics* diagnostics,
+                       PatternMetadata* metadata) {
+  Diagnostics strict;
+  Diagnostics& report = (diagnostics) ? *diagnostics : strict;
+
+  Position2DVec live_cells;
+  Position2D pos;
+  std::size_t run_count = 0;
+  std::size_t run_column = 0;
+  std::int32_t state_prefix = 0;
+  std::string line;
+  std::size_t line_num = 0;
+  while (std::getline(is, line)) {
+    line_num++;
+    if (line.empty()) {
+      continue;
+    } else if ('#' == line[0]) {
+      if (metadata) {
+        ParseMetadataLine(line, *metadata);
+      }
+      continue;
+    } else if (IsHeaderLine(line)) {
+      if (metadata && !HeaderRule(line).empty()) {
+        metadata->rule = HeaderRule(line);
+      }
+      continue;
+    }
+
+    for (std::size_t column = 1; column <= line.size(); ++column) {
+      const char c = line[column - 1];
+      if (std::isdigit(static_cast<unsigned char>(c))) {
+        if (!run_column) {
+          run_column = column;
+        }
+        /* saturate past the limit instead of overflowing */
+        run_count = std::min((run_count * 10) + (c - '0'), kMaxRunCount + 1);
+        continue;
+      }
+      if (std::isspace(static_cast<unsigned char>(c))) {
+        continue;
+      }
+      if ((c >= 'p') && (c <= 'y')) {
+        state_prefix = c - 'p' + 1;
+        continue;
+      }
+
+      if (run_count > kMaxRunCount) {
+        report.Report(line_num, run_column,
+                      "RLE run count exceeds " + std::to_string(kMaxRunCount));
+        run_count = 0;
+        run_column = 0;
+        state_prefix = 0;
+        continue;
+      }
+
+      /* a tag without a run count prefix appears exactly once */
+      const auto kRun = static_cast<std::int32_t>((run_count) ? run_count : 1);
+      const std::size_t kRunColumn = (run_column) ? run_column : column;
+      run_count = 0;
+      run_column = 0;
+      std::int32_t state = 1;
+      if ((c >= 'A') && (c <= 'X')) {
+        state = (state_prefix * kStatesPerLetterSet) + (c - 'A') + 1;
+      } else if (state_prefix) {
+        report.Report(line_num, std::string("invalid RLE state tag '") +
+                                    static_cast<char>('p' + state_prefix - 1) +
+                                    c + "'");
+        state_prefix = 0;
+        continue;
+      }
+      state_prefix = 0;
+
+      if (state > std::numeric_limits<std::uint8_t>::max()) {
+        report.Report(line_num, "RLE cell state " + std::to_string(state) +
+                                    " is out of range");
+      } else if ('!' == c) {
+        return live_cells;
+      } else if (('$' == c) &&
+                 (pos.y > std::numeric_limits<std::int32_t>::max() - kRun)) {
+        report.Report(line_num, kRunColumn, "RLE run moves past the last row");
+      } else if ((('.' == c) || std::isalpha(static_cast<unsigned char>(c))) &&
+                 (pos.x > std::numeric_limits<std::int32_t>::max() - kRun)) {
+        report.Report(line_num, kRunColumn,
+                      "RLE run moves past the last column");
+      } else if ('$' == c) {
+        pos.y += kRun;
+        pos.x = 0;
+      } else if (('b' == c) || ('.' == c)) {
+        pos.x += kRun;
+      } else if (std::isalpha(static_cast<unsigned char>(c))) {
+        pos.state = static_cast<std::uint8_t>(state);
+        for (std::int32_t i = 0; i < kRun; ++i) {
+          live_cells.push_back(pos);
+          pos.x++;
+        }
+      } else {
+        report.Report(line_num, std::string("invalid RLE tag '") + c + "'");
+      }
+    }
+  }
+  return live_cells;
+}
+
+void WriteRle(std::ostream& os, const Position2DVec& live_cells,
+              const PatternMetadata* metadata) {
+  const std::size_t kMaxLineLength = 70;
+
+  /* RLE cannot encode negative positions, such patterns are shifted right and
+   * down onto the origin */
+  Pattern pattern(live_cells);
+  const Position2D kMin = pattern.Min();
+  pattern.Translate(std::max(0, -kMin.x), std::max(0, -kMin.y));
+
+  Position2DVec sorted = pattern.Cells();
+  std::sort(sorted.begin(), sorted.end(),
+            [](const Position2D& a, const Position2D& b) {
+              return (a.y < b.y) || ((a.y == b.y) && (a.x < b.x));
+            });
+  sorted.erase(std::unique(sorted.begin(), sorted.end(),
+                           [](const Position2D& a, const Position2D& b) {
+                             return (a.x == b.x) && (a.y == b.y);
+                           }),
+               sorted.end());
+
+  const Position2D kMax = pattern.Max();
+  const std::size_t kWidth =
+      (sorted.empty()) ? 0 : static_cast<std::size_t>(kMax.x) + 1;
+  const std::size_t kHeight =
+      (sorted.empty()) ? 0 : static_cast<std::size_t>(kMax.y) + 1;
+  const bool kMultiState =
+      std::any_of(sorted.begin(), sorted.end(),
+                  [](const Position2D& pos) { return pos.state > 1; });
+  if (metadata) {
+    WriteMetadataLines(os, *metadata);
+  }
+  os << "x = " << kWidth << ", y = " << kHeight << "\n";
+
+  /* tags are wrapped such that no line exceeds the maximum length */
+  std::string line;
+  auto emit = [&os, &line](std::size_t run, const std::string& tag) {
+    std::string item = (run > 1) ? std::to_string(run) : "";
+    item += tag;
+    if (line.size() + item.size() > kMaxLineLength) {
+      os << line << "\n";
+      line.clear();
+    }
+    line += item;
+  };
+
+  std::int32_t row = 0;
+  std::int32_t col = 0;
+  for (std::size_t i = 0; i < sorted.size();) {
+    const Position2D& kStart = sorted[i];
+    if (kStart.y > row) {
+      emit(static_cast<std::size_t>(kStart.y - row), "$");
+      row = kStart.y;
+      col = 0;
+    }
+    if (kStart.x > col) {
+      emit(static_cast<std::size_t>(kStart.x - col), (kMultiState) ? "." : "b");
+    }
+
+    /* gather the live cells in the same state adjacent to the start of the
+     * run */
+    std::size_t run = 1;
+    while ((i + run < sorted.size()) && (sorted[i + run].y == row) &&
+           (sorted[i + run].x == kStart.x + static_cast<std::int32_t>(run)) &&
+           (sorted[i + run].state == kStart.state)) {
+      run++;
+    }
+    emit(run, (kMultiState) ? StateTag(kStart.state) : "o");
+    col = kStart.x + static_cast<std::int32_t>(run);
+    i += run;
+  }
+  emit(1, "!");
+  os << line << "\n";
+}
+
+}  // namespace pattern
+}  // namespace gol
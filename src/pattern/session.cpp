@@ -0,0 +1,119 @@
+#include "pattern/session.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <fstream>
+#include <iterator>
+#include <stdexcept>
+#include <string>
+
+#include "pattern/diagnostics.h"
+#include "pattern/json.h"
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+JsonValue ToJson(const Session& session) {
+  JsonValue view = JsonValue::Object();
+  view.Set("row", static_cast<std::uint64_t>(session.view_row));
+  view.Set("col", static_cast<std::uint64_t>(session.view_col));
+  view.Set("zoom", static_cast<std::uint64_t>(session.zoom));
+  view.Set("cell_size", static_cast<std::uint64_t>(session.cell_size));
+
+  JsonValue cells = JsonValue::Array();
+  for (const Position2D& cell : session.cells) {
+    cells.Push(ToJson(cell));
+  }
+
+  JsonValue json = JsonValue::Object();
+  json.Set("rows", static_cast<std::uint64_t>(session.rows));
+  json.Set("cols", static_cast<std::uint64_t>(session.cols));
+  json.Set("wrap", session.wrap);
+  if (!session.rule.empty()) {
+    json.Set("rule", session.rule);
+  }
+  json.Set("generation", session.generation);
+  json.Set("update_rate_ms", session.update_rate_ms);
+  json.Set("view", view);
+  json.Set("cells", cells);
+  return json;
+}
+
+Session SessionFromJson(const JsonValue& json) {
+  const auto kPositive = [](const JsonValue& value, const std::string& name) {
+    const std::int64_t kValue = value.AsInt();
+    if (kValue <= 0) {
+      throw std::invalid_argument(name + " must be positive");
+    }
+    return kValue;
+  };
+  const auto kSize = [](const JsonValue& value, const std::string& name) {
+    const std::int64_t kValue = value.AsInt();
+    if (kValue < 0) {
+      throw std::invalid_argument(name + " must not be negative");
+    }
+    return static_cast<std::size_t>(kValue);
+  };
+
+  Session session;
+  session.rows = static_cast<std::size_t>(kPositive(json.Get("rows"), "rows"));
+  session.cols = static_cast<std::size_t>(kPositive(json.Get("cols"), "cols"));
+  session.wrap = json.Contains("wrap") && json.Get("wrap").AsBool();
+  if (json.Contains("rule")) {
+    session.rule = json.Get("rule").AsString();
+  }
+  if (json.Contains("generation")) {
+    session.generation =
+        static_cast<std::uint64_t>(kSize(json.Get("generation"), "generation"));
+  }
+  if (json.Contains("update_rate_ms")) {
+    session.update_rate_ms = static_cast<int>(
+        kPositive(json.Get("update_rate_ms"), "update_rate_ms"));
+  }
+  if (json.Contains("view")) {
+    const JsonValue& kView = json.Get("view");
+    session.view_row = kSize(kView.Get("row"), "view row");
+    session.view_col = kSize(kView.Get("col"), "view column");
+    session.zoom = static_cast<std::size_t>(kPositive(kView.Get("zoom"),
+                                                      "zoom"));
+    session.cell_size = static_cast<std::size_t>(
+        kPositive(kView.Get("cell_size"), "cell size"));
+  }
+  for (const JsonValue& cell : json.Get("cells").AsArray()) {
+    session.cells.push_back(PositionFromJson(cell));
+    if (!OnBoard(session.cells.back(), session.rows, session.cols)) {
+      throw std::invalid_argument("cell lies outside of the board");
+    }
+  }
+  return session;
+}
+
+void SaveSession(const Session& session, const std::string& filename) {
+  std::ofstream fhandle(filename);
+  if (!fhandle) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+  fhandle << DumpJson(ToJson(session)) << "\n";
+}
+
+Session LoadSession(const std::string& filename) {
+  std::ifstream fhandle(filename);
+  if (!fhandle) {
+    throw std::invalid_argument("invalid file path ->" + filename);
+  }
+  const std::string kContents((std::istreambuf_iterator<char>(fhandle)),
+                              std::istreambuf_iterator<char>());
+  try {
+    return SessionFromJson(ParseJson(kContents));
+  } catch (const ParseError& e) {
+    ParseDiagnostic diagnostic = e.Diagnostic();
+    diagnostic.source = filename;
+    throw ParseError(diagnostic);
+  } catch (const std::invalid_argument& e) {
+    throw ParseError({.source = filename, .line = 0, .reason = e.what()});
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
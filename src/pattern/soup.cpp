@@ -0,0 +1,40 @@
+#include "pattern/soup.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <random>
+#include <stdexcept>
+
+#include "pattern/position.h"
+
+namespace gol {
+namespace pattern {
+
+Position2DVec RandomSoup(std::size_t rows, std::size_t cols, double density,
+                         std::uint64_t seed, std::uint8_t num_colors) {
+  if (!(density >= 0.0) || (density > 1.0)) {
+    throw std::invalid_argument("density must be between 0 and 1");
+  }
+
+  /* the standard distributions are implementation defined, a uniform double
+   * is built from the top 53 bits of the engine's fully specified output */
+  const double kScale = 1.0 / static_cast<double>(std::uint64_t{1} << 53);
+  std::mt19937_64 rng(seed);
+  Position2DVec live_cells;
+  for (std::size_t i = 0; i < rows; ++i) {
+    for (std::size_t j = 0; j < cols; ++j) {
+      if (static_cast<double>(rng() >> 11) * kScale < density) {
+        /* single colored soups draw no colors to stay the same */
+        const std::uint64_t kColor =
+            (num_colors > 1) ? ((rng() >> 32) * num_colors) >> 32 : 0;
+        live_cells.push_back({.x = static_cast<std::int32_t>(j),
+                              .y = static_cast<std::int32_t>(i),
+                              .state = static_cast<std::uint8_t>(1 + kColor)});
+      }
+    }
+  }
+  return live_cells;
+}
+
+}  // namespace pattern
+}  // namespace gol
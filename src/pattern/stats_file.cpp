@@ -0,0 +1,38 @@
+#include "pattern/stats_file.h"
+
+#include <stdexcept>
+#include <string>
+
+#include "game/stats.h"
+
+namespace gol {
+namespace pattern {
+
+StatsWriter::StatsWriter(const std::string& filename) : file_(filename) {
+  if (!file_) {
+    throw std::runtime_error("unable to open " + filename);
+  }
+  json_ = filename.ends_with(".json") || filename.ends_with(".jsonl");
+  if (!json_) {
+    file_ << "generation,population,births,deaths\n";
+  }
+}
+
+void StatsWriter::Write(const game::StepStats& stats) {
+  if (json_) {
+    file_ << "{\"generation\":" << stats.generation
+          << ",\"population\":" << stats.population
+          << ",\"births\":" << stats.births << ",\"deaths\":" << stats.deaths
+          << "}\n";
+  } else {
+    file_ << stats.generation << "," << stats.population << ","
+          << stats.births << "," << stats.deaths << "\n";
+  }
+  if (++num_unflushed_ >= kFlushInterval) {
+    file_.flush();
+    num_unflushed_ = 0;
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
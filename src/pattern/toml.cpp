@@ -0,0 +1,402 @@
+#include "pattern/toml.h"
+
+#include <cstddef>
+#include <fstream>
+#include <istream>
+#include <iterator>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "pattern/diagnostics.h"
+
+namespace gol {
+namespace pattern {
+
+/* Arrays nested deeper than this are rejected rather than risking a stack
+ * overflow in the recursive descent parser. */
+static constexpr int kMaxDepth = 64;
+
+/* Parser over a complete TOML document. */
+class TomlParser {
+ public:
+  explicit TomlParser(const std::string& text) : text_(text) {}
+
+  [[nodiscard]] TomlDocument ParseDocument() {
+    TomlDocument document;
+    std::set<std::string> keys;
+    std::set<std::string> tables;
+    std::string table;
+    while (!AtEnd()) {
+      SkipSpaces();
+      if (AtEnd()) {
+        break;
+      } else if (AtLineEnd()) {
+        SkipLineEnd();
+        continue;
+      }
+
+      if ('[' == Peek()) {
+        pos_++;
+        if (!AtEnd() && '[' == Peek()) {
+          Fail("arrays of tables are not supported");
+        }
+        table = ParseKey();
+        if (!Consume(']')) {
+          Fail("expected ']'");
+        }
+        if (!tables.insert(table).second) {
+          Fail("table '" + table + "' defined twice");
+        }
+      } else {
+        const std::size_t kLine = line_;
+        const std::string kKey = ParseKey();
+        const std::string kFullKey =
+            (table.empty()) ? kKey : table + "." + kKey;
+        if (!Consume('=')) {
+          Fail("expected '=' after key");
+        }
+        SkipSpaces();
+        TomlValue value = ParseValue(0);
+        if (!keys.insert(kFullKey).second) {
+          line_ = kLine;
+          Fail("key '" + kFullKey + "' defined twice");
+        }
+        document.push_back(
+            {.key = kFullKey, .value = std::move(value), .line = kLine});
+      }
+
+      SkipSpaces();
+      if (!AtLineEnd()) {
+        Fail("expected the end of the line");
+      }
+      SkipLineEnd();
+    }
+    return document;
+  }
+
+ private:
+  [[noreturn]] void Fail(const std::string& reason) const {
+    throw ParseError({.source = "", .line = line_, .reason = reason});
+  }
+
+  [[nodiscard]] bool AtEnd() const noexcept { return pos_ >= text_.size(); }
+
+  [[nodiscard]] char Peek() const noexcept { return text_[pos_]; }
+
+  /* a comment runs until the end of the line */
+  [[nodiscard]] bool AtLineEnd() const noexcept {
+    return AtEnd() || '\n' == Peek() || '#' == Peek() ||
+           0 == text_.compare(pos_, 2, "\r\n");
+  }
+
+  void SkipSpaces() noexcept {
+    while (!AtEnd() && (' ' == Peek() || '\t' == Peek())) {
+      pos_++;
+    }
+  }
+
+  void SkipLineEnd() noexcept {
+    while (!AtEnd() && '\n' != Peek()) {
+      pos_++;
+    }
+    if (!AtEnd()) {
+      pos_++;
+      line_++;
+    }
+  }
+
+  /* skips whitespace, comments and line breaks inside of arrays */
+  void SkipBlank() noexcept {
+    SkipSpaces();
+    while (!AtEnd() && AtLineEnd()) {
+      SkipLineEnd();
+      SkipSpaces();
+    }
+  }
+
+  [[nodiscard]] bool Consume(char expected) noexcept {
+    SkipSpaces();
+    if (!AtEnd() && expected == Peek()) {
+      pos_++;
+      return true;
+    }
+    return false;
+  }
+
+  [[nodiscard]] static bool IsBareKeyChar(char c) noexcept {
+    return (c >= 'A' && c <= 'Z') || (c >= 'a' && c <= 'z') ||
+           (c >= '0' && c <= '9') || '_' == c || '-' == c;
+  }
+
+  [[nodiscard]] std::string ParseKey() {
+    std::string key;
+    do {
+      SkipSpaces();
+      if (AtEnd()) {
+        Fail("expected a key");
+      }
+
+      std::string part;
+      if ('"' == Peek()) {
+        part = ParseBasicString();
+      } else if ('\'' == Peek()) {
+        part = ParseLiteralString();
+      } else {
+        while (!AtEnd() && IsBareKeyChar(Peek())) {
+          part += text_[pos_++];
+        }
+        if (part.empty()) {
+          Fail("expected a key");
+        }
+      }
+      key += (key.empty()) ? part : "." + part;
+    } while (Consume('.'));
+    return key;
+  }
+
+  [[nodiscard]] TomlValue ParseValue(int depth) {
+    if (AtEnd() || AtLineEnd()) {
+      Fail("expected a value");
+    }
+
+    TomlValue value;
+    if (0 == text_.compare(pos_, 3, "\"\"\"") ||
+        0 == text_.compare(pos_, 3, "'''")) {
+      Fail("multiline strings are not supported");
+    } else if ('"' == Peek()) {
+      value.text = ParseBasicString();
+    } else if ('\'' == Peek()) {
+      value.text = ParseLiteralString();
+    } else if ('[' == Peek()) {
+      value = ParseArray(depth + 1);
+    } else if ('{' == Peek()) {
+      Fail("inline tables are not supported");
+    } else {
+      std::string word;
+      while (!AtEnd() && !AtLineEnd() && ',' != Peek() && ']' != Peek() &&
+             ' ' != Peek() && '\t' != Peek()) {
+        word += text_[pos_++];
+      }
+      value = ParseScalar(word);
+    }
+    return value;
+  }
+
+  [[nodiscard]] TomlValue ParseArray(int depth) {
+    if (depth > kMaxDepth) {
+      Fail("nesting too deep");
+    }
+    pos_++;
+
+    TomlValue array = {.type = TomlType::kArray, .text = "", .elements = {}};
+    SkipBlank();
+    while (!AtEnd() && ']' != Peek()) {
+      array.elements.push_back(ParseValue(depth));
+      SkipBlank();
+      if (AtEnd() || ',' != Peek()) {
+        break;
+      }
+      pos_++;
+      SkipBlank();
+    }
+    if (AtEnd() || ']' != Peek()) {
+      Fail("expected ']' or ','");
+    }
+    pos_++;
+    return array;
+  }
+
+  /* digits optionally separated by single underscores */
+  [[nodiscard]] static bool IsDigits(const std::string& text) noexcept {
+    if (text.empty() || '_' == text.front() || '_' == text.back()) {
+      return false;
+    }
+    for (std::size_t i = 0; i < text.size(); ++i) {
+      if ('_' == text[i]) {
+        if ('_' == text[i + 1]) {
+          return false;
+        }
+      } else if (text[i] < '0' || text[i] > '9') {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  [[nodiscard]] TomlValue ParseScalar(const std::string& word) {
+    if ("true" == word || "false" == word) {
+      return {.type = TomlType::kBoolean, .text = word, .elements = {}};
+    }
+
+    /* split into [sign] integer [. fraction] [e exponent] */
+    const std::size_t kStart = ('+' == word[0] || '-' == word[0]) ? 1 : 0;
+    const std::size_t kExponent = word.find_first_of("eE", kStart);
+    const std::string kMantissa = word.substr(kStart, kExponent - kStart);
+    const std::size_t kPoint = kMantissa.find('.');
+    const std::string kInteger = kMantissa.substr(0, kPoint);
+    bool valid =
+        IsDigits(kInteger) && !(kInteger.size() > 1 && '0' == kInteger[0]);
+    if (std::string::npos != kPoint) {
+      valid = valid && IsDigits(kMantissa.substr(kPoint + 1));
+    }
+    if (std::string::npos != kExponent) {
+      std::string exponent = word.substr(kExponent + 1);
+      if (exponent.starts_with("+") || exponent.starts_with("-")) {
+        exponent.erase(0, 1);
+      }
+      valid = valid && IsDigits(exponent);
+    }
+    if (!valid) {
+      Fail("invalid value '" + word + "'");
+    }
+
+    TomlValue value = {.type = TomlType::kInteger, .text = "", .elements = {}};
+    if (std::string::npos != kPoint || std::string::npos != kExponent) {
+      value.type = TomlType::kFloat;
+    }
+    for (const char kChar : word) {
+      if ('_' != kChar) {
+        value.text += kChar;
+      }
+    }
+    return value;
+  }
+
+  [[nodiscard]] std::string ParseLiteralString() {
+    pos_++;
+    const std::size_t kEnd = text_.find_first_of("'\n", pos_);
+    if (std::string::npos == kEnd || '\n' == text_[kEnd]) {
+      Fail("unterminated string");
+    }
+    std::string value = text_.substr(pos_, kEnd - pos_);
+    pos_ = kEnd + 1;
+    return value;
+  }
+
+  [[nodiscard]] unsigned ParseHex(int digits) {
+    unsigned code = 0;
+    for (int i = 0; i < digits; ++i) {
+      if (AtEnd()) {
+        Fail("invalid unicode escape");
+      }
+      const char kChar = text_[pos_++];
+      code <<= 4;
+      if (kChar >= '0' && kChar <= '9') {
+        code |= static_cast<unsigned>(kChar - '0');
+      } else if (kChar >= 'a' && kChar <= 'f') {
+        code |= static_cast<unsigned>(kChar - 'a' + 10);
+      } else if (kChar >= 'A' && kChar <= 'F') {
+        code |= static_cast<unsigned>(kChar - 'A' + 10);
+      } else {
+        Fail("invalid unicode escape");
+      }
+    }
+    if (code > 0x10FFFF || (code >= 0xD800 && code < 0xE000)) {
+      Fail("invalid unicode escape");
+    }
+    return code;
+  }
+
+  [[nodiscard]] static std::string EncodeUtf8(unsigned code) {
+    std::string utf8;
+    if (code < 0x80) {
+      utf8 += static_cast<char>(code);
+    } else if (code < 0x800) {
+      utf8 += static_cast<char>(0xC0 | (code >> 6));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    } else if (code < 0x10000) {
+      utf8 += static_cast<char>(0xE0 | (code >> 12));
+      utf8 += static_cast<char>(0x80 | ((code >> 6) & 0x3F));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    } else {
+      utf8 += static_cast<char>(0xF0 | (code >> 18));
+      utf8 += static_cast<char>(0x80 | ((code >> 12) & 0x3F));
+      utf8 += static_cast<char>(0x80 | ((code >> 6) & 0x3F));
+      utf8 += static_cast<char>(0x80 | (code & 0x3F));
+    }
+    return utf8;
+  }
+
+  [[nodiscard]] std::string ParseBasicString() {
+    pos_++;
+
+    std::string value;
+    while (!AtEnd() && '"' != Peek() && '\n' != Peek()) {
+      const char kChar = text_[pos_++];
+      if ('\\' != kChar) {
+        value += kChar;
+        continue;
+      }
+
+      if (AtEnd()) {
+        break;
+      }
+      const char kEscape = text_[pos_++];
+      switch (kEscape) {
+        case '"':
+        case '\\':
+          value += kEscape;
+          break;
+        case 'b':
+          value += '\b';
+          break;
+        case 'f':
+          value += '\f';
+          break;
+        case 'n':
+          value += '\n';
+          break;
+        case 'r':
+          value += '\r';
+          break;
+        case 't':
+          value += '\t';
+          break;
+        case 'u':
+          value += EncodeUtf8(ParseHex(4));
+          break;
+        case 'U':
+          value += EncodeUtf8(ParseHex(8));
+          break;
+        default:
+          Fail(std::string("invalid escape '\\") + kEscape + "'");
+      }
+    }
+    if (AtEnd() || '"' != Peek()) {
+      Fail("unterminated string");
+    }
+    pos_++;
+    return value;
+  }
+
+  const std::string& text_;
+  std::size_t pos_ = 0;
+  std::size_t line_ = 1;
+};
+
+TomlDocument ParseToml(std::istream& is) {
+  const std::string kContents((std::istreambuf_iterator<char>(is)),
+                              std::istreambuf_iterator<char>());
+  return TomlParser(kContents).ParseDocument();
+}
+
+TomlDocument LoadToml(const std::string& filename) {
+  std::ifstream fhandle(filename);
+  if (!fhandle) {
+    throw std::invalid_argument("invalid file path -> " + filename);
+  }
+
+  try {
+    return ParseToml(fhandle);
+  } catch (const ParseError& e) {
+    ParseDiagnostic diagnostic = e.Diagnostic();
+    diagnostic.source = filename;
+    throw ParseError(diagnostic);
+  }
+}
+
+}  // namespace pattern
+}  // namespace gol
@@ -0,0 +1,173 @@
+#include <emscripten.h>
+
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/engine.h"
+#include "game/factory.h"
+#include "game/rule.h"
+#include "pattern/library.h"
+#include "pattern/position.h"
+#include "pattern/soup.h"
+
+using gol::pattern::Position2D;
+
+/* A board driven from JavaScript. The cell states are copied to a buffer of
+ * one byte per cell in row major order after every change, the canvas is
+ * drawn straight from the module memory holding it. */
+struct WebBoard {
+  std::unique_ptr<gol::game::Engine> engine;
+  std::vector<std::uint8_t> cells;
+};
+
+/* Copy the cell states of the engine of board to its buffer. */
+static void CopyCells(WebBoard& board) {
+  const gol::game::Engine& kEngine = *board.engine;
+  for (std::size_t i = 0; i < kEngine.Rows(); ++i) {
+    for (std::size_t j = 0; j < kEngine.Cols(); ++j) {
+      board.cells[(i * kEngine.Cols()) + j] = kEngine.State(i, j);
+    }
+  }
+}
+
+/* Return true if (row, col) lies within the board. */
+[[nodiscard]] static bool OnBoard(const WebBoard& board, int row, int col) {
+  return (row >= 0) && (col >= 0) &&
+         (static_cast<std::size_t>(row) < board.engine->Rows()) &&
+         (static_cast<std::size_t>(col) < board.engine->Cols());
+}
+
+extern "C" {
+
+/* Return a new rows x cols board running rule, a rulestring, preset or
+ * multi-state automaton name as accepted by --rule, or the empty string for
+ * Conway's Life. The board edges wrap around when wrap is nonzero. Returns
+ * null when the size or the rule is invalid. */
+EMSCRIPTEN_KEEPALIVE WebBoard* new_board(int rows, int cols, const char* rule,
+                                         int wrap) {
+  if ((rows <= 0) || (cols <= 0)) {
+    return nullptr;
+  }
+  try {
+    const std::string kRule = (rule) ? rule : "";
+    gol::game::EngineOptions options;
+    options.rows = static_cast<std::size_t>(rows);
+    options.cols = static_cast<std::size_t>(cols);
+    options.wrap = (0 != wrap);
+    if (!kRule.empty()) {
+      options.automaton = gol::game::FindAutomaton(kRule);
+      if (!options.automaton) {
+        options.rule = gol::game::ParseRule(kRule);
+      }
+    }
+    auto board = std::make_unique<WebBoard>();
+    board->engine =
+        gol::game::MakeEngine(gol::game::EngineType::kNaive, options);
+    board->cells.resize(board->engine->Rows() * board->engine->Cols());
+    return board.release();
+  } catch (const std::exception& e) {
+    return nullptr;
+  }
+}
+
+/* Free a board returned by new_board(). */
+EMSCRIPTEN_KEEPALIVE void delete_board(WebBoard* board) { delete board; }
+
+/* Return the number of rows of board. */
+EMSCRIPTEN_KEEPALIVE int rows(const WebBoard* board) {
+  return static_cast<int>(board->engine->Rows());
+}
+
+/* Return the number of columns of board. */
+EMSCRIPTEN_KEEPALIVE int cols(const WebBoard* board) {
+  return static_cast<int>(board->engine->Cols());
+}
+
+/* Set the cell at (row, col) to state, cells beyond the board edges are left
+ * out and states the automaton lacks are plain live cells. */
+EMSCRIPTEN_KEEPALIVE void set_cell(WebBoard* board, int row, int col,
+                                   int state) {
+  if (!OnBoard(*board, row, col)) {
+    return;
+  }
+  const auto kRow = static_cast<std::size_t>(row);
+  const auto kCol = static_cast<std::size_t>(col);
+  const auto kState = static_cast<std::uint8_t>(
+      ((state >= 0) && (state < board->engine->NumStates())) ? state : 1);
+  board->engine->SetState(kRow, kCol, kState);
+  board->cells[(kRow * board->engine->Cols()) + kCol] =
+      board->engine->State(kRow, kCol);
+}
+
+/* Kill every cell of board. */
+EMSCRIPTEN_KEEPALIVE void clear(WebBoard* board) {
+  for (std::size_t i = 0; i < board->engine->Rows(); ++i) {
+    for (std::size_t j = 0; j < board->engine->Cols(); ++j) {
+      board->engine->SetCell(i, j, false);
+    }
+  }
+  CopyCells(*board);
+}
+
+/* Add a random soup of the given density to board. */
+EMSCRIPTEN_KEEPALIVE void randomize(WebBoard* board, double density,
+                                    double seed) {
+  try {
+    for (const Position2D& pos : gol::pattern::RandomSoup(
+             board->engine->Rows(), board->engine->Cols(), density,
+             static_cast<std::uint64_t>(seed))) {
+      board->engine->SetCell(static_cast<std::size_t>(pos.y),
+                             static_cast<std::size_t>(pos.x), true);
+    }
+  } catch (const std::exception& e) {
+    return;
+  }
+  CopyCells(*board);
+}
+
+/* Add the embedded pattern name with its top left corner at (row, col) to
+ * board, cells beyond the board edges are left out. Returns 0 when no
+ * embedded pattern is named name. */
+EMSCRIPTEN_KEEPALIVE int load_pattern(WebBoard* board, const char* name,
+                                      int row, int col) {
+  try {
+    for (const Position2D& pos : gol::pattern::FindPattern(name)) {
+      set_cell(board, row + pos.y, col + pos.x, pos.state);
+    }
+  } catch (const std::exception& e) {
+    return 0;
+  }
+  return 1;
+}
+
+/* Advance board by generations generations. */
+EMSCRIPTEN_KEEPALIVE void step(WebBoard* board, int generations) {
+  if (generations > 0) {
+    board->engine->Advance(static_cast<std::uint64_t>(generations));
+    CopyCells(*board);
+  }
+}
+
+/* Return the buffer holding the state of each cell of board in row major
+ * order. The buffer stays valid until the board is deleted, but the view of
+ * the module memory has to be fetched again once the memory grows. */
+EMSCRIPTEN_KEEPALIVE const std::uint8_t* cells_ptr(const WebBoard* board) {
+  return board->cells.data();
+}
+
+/* Return the generation of board, as a double to spare JavaScript BigInts. */
+EMSCRIPTEN_KEEPALIVE double generation(const WebBoard* board) {
+  return static_cast<double>(board->engine->Generation());
+}
+
+/* Return the number of live cells of board. */
+EMSCRIPTEN_KEEPALIVE double population(const WebBoard* board) {
+  return static_cast<double>(board->engine->Population());
+}
+
+}  // extern "C"
@@ -0,0 +1,214 @@
+/* Round trips the live cells exported from each engine through the pattern
+ * writers and parsers. */
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <iostream>
+#include <memory>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/factory.h"
+#include "game/rule.h"
+#include "pattern/coordinates.h"
+#include "pattern/export.h"
+#include "pattern/json.h"
+#include "pattern/life106.h"
+#include "pattern/pattern.h"
+#include "pattern/plaintext.h"
+#include "pattern/position.h"
+#include "pattern/rle.h"
+
+using gol::pattern::Position2DVec;
+
+static int failures = 0;
+
+static void Check(bool condition, const std::string& what) {
+  if (!condition) {
+    std::cerr << "FAILED: " << what << "\n";
+    failures++;
+  }
+}
+
+[[nodiscard]] static bool SamePoints(const Position2DVec& lhs,
+                                     const Position2DVec& rhs) {
+  if (lhs.size() != rhs.size()) {
+    return false;
+  }
+  for (std::size_t i = 0; i < lhs.size(); ++i) {
+    if ((lhs[i].x != rhs[i].x) || (lhs[i].y != rhs[i].y) ||
+        (lhs[i].state != rhs[i].state)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+[[nodiscard]] static bool Contains(const Position2DVec& cells, std::int32_t x,
+                                   std::int32_t y) {
+  for (const gol::pattern::Position2D& cell : cells) {
+    if ((cell.x == x) && (cell.y == y)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+/* Check that each format reproduces the exported cells of board. */
+static void CheckRoundTrips(const gol::game::Engine& board,
+                            const std::string& name) {
+  const Position2DVec kPoints = gol::pattern::ToPoints(board);
+  Check(kPoints.size() == board.Population(),
+        name + ": every live cell is exported");
+
+  /* RLE and plaintext patterns are shifted onto non-negative positions */
+  gol::pattern::Pattern shifted(kPoints);
+  const gol::pattern::Position2D kMin = shifted.Min();
+  shifted.Translate(-std::min(kMin.x, 0), -std::min(kMin.y, 0));
+  const Position2DVec& kShifted = shifted.Cells();
+
+  std::istringstream rle(gol::pattern::ToRle(board));
+  Check(SamePoints(gol::pattern::ParseRle(rle), kShifted),
+        name + ": RLE round trip");
+
+  std::istringstream plaintext(gol::pattern::ToPlaintext(board));
+  Check(SamePoints(gol::pattern::ParsePlaintext(plaintext), kShifted),
+        name + ": plaintext round trip");
+
+  std::istringstream coordinates(gol::pattern::ToCoordinates(board));
+  Check(SamePoints(gol::pattern::ParseCoordinates(coordinates), kPoints),
+        name + ": coordinate list round trip");
+
+  std::ostringstream oss;
+  gol::pattern::WriteLife106(oss, kPoints);
+  std::istringstream life106(oss.str());
+  Check(SamePoints(gol::pattern::ParseLife106(life106), kPoints),
+        name + ": Life 1.06 round trip");
+}
+
+/* A glider heading up and to the left of its initial position. */
+static void PlaceGlider(gol::game::Engine& board, std::size_t row,
+                        std::size_t col) {
+  board.SetCell(row, col, true);
+  board.SetCell(row, col + 1, true);
+  board.SetCell(row, col + 2, true);
+  board.SetCell(row + 1, col, true);
+  board.SetCell(row + 2, col + 1, true);
+}
+
+static void TestEngines() {
+  const std::size_t kSize = 16;
+  const std::vector<std::string> kEngines = {"naive", "hashlife", "sparse",
+                                             "chunked"};
+  for (const std::string& kName : kEngines) {
+    const gol::game::EngineType kType = gol::game::ParseEngineType(kName);
+    gol::game::EngineOptions options;
+    options.rows = kSize;
+    options.cols = kSize;
+    std::unique_ptr<gol::game::Engine> board =
+        gol::game::MakeEngine(kType, options);
+    PlaceGlider(*board, 4, 4);
+    CheckRoundTrips(*board, kName);
+
+    if (gol::game::EngineType::kNaive == kType) {
+      continue;
+    }
+
+    /* the cells of unbounded engines outside the window are exported, be it
+     * below and right of the window or above and left of it */
+    board->SetCell(kSize + 20, kSize + 40, true);
+    board->SetCell(kSize + 20, kSize + 41, true);
+    board->SetCell(kSize + 21, kSize + 40, true);
+    board->SetCell(kSize + 21, kSize + 41, true);
+    board->Advance(40);
+    const Position2DVec kPoints = gol::pattern::ToPoints(*board);
+    Check(Contains(kPoints, kSize + 40, kSize + 20),
+          kName + ": cells right of and below the window are exported");
+    Check(Contains(kPoints, -5, -6),
+          kName + ": cells left of and above the window are exported");
+    CheckRoundTrips(*board, kName + " outside the window");
+  }
+}
+
+static void TestStates() {
+  gol::game::GameOfLifeBoard board(8, 8, false,
+                                   gol::game::FindAutomaton("brain"));
+  board.SetState(1, 2, 1);
+  board.SetState(1, 3, 2);
+  board.SetState(5, 6, 2);
+
+  /* only RLE stores the states of multi-state patterns */
+  std::istringstream rle(gol::pattern::ToRle(board));
+  const Position2DVec kCells = gol::pattern::ParseRle(rle);
+  Check(SamePoints(kCells, gol::pattern::ToPoints(board)),
+        "brain: cell states survive the RLE round trip");
+
+  /* JSON boards keep their automaton along with the states */
+  const std::vector<std::string> kAutomata = {"brain", "wireworld"};
+  for (const std::string& kName : kAutomata) {
+    gol::game::GameOfLifeBoard states(6, 7, true,
+                                      gol::game::FindAutomaton(kName));
+    states.SetState(0, 1, 1);
+    states.SetState(2, 3, 2);
+    states.SetState(5, 6, states.NumStates() - 1);
+    const gol::game::GameOfLifeBoard kRead = gol::pattern::BoardFromJson(
+        gol::pattern::ParseJson(gol::pattern::DumpJson(
+            gol::pattern::ToJson(states))));
+    Check(kRead.GetAutomaton() &&
+              (kRead.GetAutomaton()->Name() == states.GetAutomaton()->Name()),
+          kName + ": the JSON round trip keeps the automaton");
+    Check((kRead.Rows() == states.Rows()) && (kRead.Cols() == states.Cols()) &&
+              (kRead.Wraps() == states.Wraps()),
+          kName + ": the JSON round trip keeps the board size");
+    Check(SamePoints(gol::pattern::ToPoints(kRead),
+                     gol::pattern::ToPoints(states)),
+          kName + ": cell states survive the JSON round trip");
+  }
+}
+
+static void TestJsonBoards() {
+  gol::game::GameOfLifeBoard board(5, 6, false,
+                                   gol::game::ParseRule("B36/S23"));
+  PlaceGlider(board, 1, 2);
+  const gol::game::GameOfLifeBoard kRead = gol::pattern::BoardFromJson(
+      gol::pattern::ParseJson(gol::pattern::DumpJson(
+          gol::pattern::ToJson(board))));
+  Check(!kRead.GetAutomaton() &&
+            (kRead.GetRule().ToString() == board.GetRule().ToString()),
+        "life: the JSON round trip keeps the rule");
+  Check((kRead.Rows() == board.Rows()) && (kRead.Cols() == board.Cols()) &&
+            !kRead.Wraps(),
+        "life: the JSON round trip keeps the board size");
+  Check(SamePoints(gol::pattern::ToPoints(kRead),
+                   gol::pattern::ToPoints(board)),
+        "life: cells survive the JSON round trip");
+
+  /* the rule and edge wrapping default to those of a plain board */
+  const gol::game::GameOfLifeBoard kDefaults = gol::pattern::BoardFromJson(
+      gol::pattern::ParseJson(R"({"rows":3,"cols":4,"cells":[[3,2]]})"));
+  Check(!kDefaults.Wraps() && (kDefaults.GetRule().ToString() ==
+                               gol::game::Rule().ToString()),
+        "life: JSON boards default to Conway's rule without wrapping");
+  Check(kDefaults.IsAlive(2, 3) && (1 == kDefaults.Population()),
+        "life: JSON cells are stored as [x, y]");
+
+  bool threw = false;
+  try {
+    (void)gol::pattern::BoardFromJson(
+        gol::pattern::ParseJson(R"({"rows":3,"cols":4,"cells":[[4,0]]})"));
+  } catch (const std::invalid_argument&) {
+    threw = true;
+  }
+  Check(threw, "life: JSON cells outside of the board are rejected");
+}
+
+int main() {
+  TestEngines();
+  TestStates();
+  TestJsonBoards();
+  return (failures) ? 1 : 0;
+}
@@ -0,0 +1,100 @@
+/* Plays back recorded replays and compares each frame to the recorded board. */
+#include <cstddef>
+#include <cstdint>
+#include <cstdio>
+#include <filesystem>
+#include <iostream>
+#include <string>
+
+#include "game/automaton.h"
+#include "game/board.h"
+#include "game/pipeline.h"
+#include "pattern/replay.h"
+
+static int failures = 0;
+
+static void Check(bool condition, const std::string& what) {
+  if (!condition) {
+    std::cerr << "FAILED: " << what << "\n";
+    failures++;
+  }
+}
+
+[[nodiscard]] static bool SameStates(const gol::game::Engine& lhs,
+                                     const gol::game::Engine& rhs) {
+  if ((lhs.Rows() != rhs.Rows()) || (lhs.Cols() != rhs.Cols())) {
+    return false;
+  }
+  for (std::size_t i = 0; i < lhs.Rows(); ++i) {
+    for (std::size_t j = 0; j < lhs.Cols(); ++j) {
+      if (lhs.State(i, j) != rhs.State(i, j)) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
+
+/* Record kFrames generations of board and check that they play back with
+ * the same cell states. */
+static void CheckPlayback(gol::game::GameOfLifeBoard board,
+                          const std::string& name) {
+  const std::size_t kFrames = 8;
+  const std::string kFile =
+      (std::filesystem::temp_directory_path() / ("replay_test_" + name))
+          .string();
+  {
+    gol::pattern::ReplayWriter writer(kFile, board.Rows(), board.Cols(),
+                                      board.NumStates());
+    gol::game::GameOfLifeBoard next = board;
+    for (std::size_t i = 0; i < kFrames; ++i) {
+      writer.Write(gol::game::Frame(next));
+      next.Tick();
+    }
+  }
+
+  gol::pattern::ReplayBoard replay(kFile);
+  Check(replay.NumStates() == board.NumStates(),
+        name + ": the replay has the recorded number of states");
+  for (std::size_t i = 0; i < kFrames; ++i) {
+    Check(replay.Generation() == board.Generation(),
+          name + ": frame " + std::to_string(i) + " has its generation");
+    Check(SameStates(replay, board),
+          name + ": frame " + std::to_string(i) + " has the recorded states");
+
+    /* frames copied from the replay keep the states as well */
+    Check(SameStates(gol::game::Frame(replay).cells, board),
+          name + ": frame " + std::to_string(i) + " copies the states");
+    replay.Tick();
+    board.Tick();
+  }
+  std::remove(kFile.c_str());
+}
+
+int main() {
+  gol::game::GameOfLifeBoard life(12, 12);
+  life.SetCell(1, 2, true);
+  life.SetCell(2, 3, true);
+  life.SetCell(3, 1, true);
+  life.SetCell(3, 2, true);
+  life.SetCell(3, 3, true);
+  CheckPlayback(life, "life");
+
+  gol::game::GameOfLifeBoard brain(12, 12, false,
+                                   gol::game::FindAutomaton("brain"));
+  brain.SetState(5, 5, 1);
+  brain.SetState(5, 6, 1);
+  brain.SetState(6, 5, 2);
+  brain.SetState(6, 6, 2);
+  CheckPlayback(brain, "brain");
+
+  gol::game::GameOfLifeBoard wireworld(12, 12, false,
+                                       gol::game::FindAutomaton("wireworld"));
+  for (std::size_t j = 1; j < 11; ++j) {
+    wireworld.SetState(4, j, 3);
+  }
+  wireworld.SetState(4, 1, 1);
+  wireworld.SetState(4, 2, 2);
+  CheckPlayback(wireworld, "wireworld");
+  return (failures) ? 1 : 0;
+}